@@ -0,0 +1,425 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `cancel_token`, `chat`, `configure_from`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `from`, `from`, `from`, `from`, `from`, `from`, `from`, `from`, `from`, `from`, `try_from`
+
+
+            /// 默认对话参数
+ChatConfigDto  chatDefaultConfig() => RustLib.instance.api.crateApiChatApiChatDefaultConfig();
+
+/// `kind` 后端的默认连接参数
+ChatProviderDto  chatDefaultProvider({required ChatProviderKindDto kind }) => RustLib.instance.api.crateApiChatApiChatDefaultProvider(kind: kind);
+
+/// 连接 `provider` 并创建对话，清空之前的历史
+Future<void>  chatConfigure({required ChatProviderDto provider , required ChatConfigDto config }) => RustLib.instance.api.crateApiChatApiChatConfigure(provider: provider, config: config);
+
+/// 读取配置文件，文件不存在时返回默认配置；校验失败时错误信息指出对应的配置项
+///
+/// 不应用环境变量，返回的 API Key 只是文件中的值。
+Future<ChatAppConfigDto>  chatLoadConfig({required String path }) => RustLib.instance.api.crateApiChatApiChatLoadConfig(path: path);
+
+/// 校验后写入配置文件；旧格式的文件会被写为新格式，档案重名时返回错误
+Future<void>  chatSaveConfig({required String path , required ChatAppConfigDto config }) => RustLib.instance.api.crateApiChatApiChatSaveConfig(path: path, config: config);
+
+/// 按配置文件中的 `[provider]` 与 `profile` 档案创建对话，`profile` 为空时使用 `default_profile`
+///
+/// API Key 可被环境变量覆盖，见 `WISE_LOVER_API_KEY`。
+Future<void>  chatConfigureFromFile({required String path , String? profile }) => RustLib.instance.api.crateApiChatApiChatConfigureFromFile(path: path, profile: profile);
+
+/// 与 `chat_configure_from_file` 相同，但直接传入配置文本
+///
+/// `api_key` 不为空时代替配置文件与环境变量中的所有 API Key。
+Future<void>  chatConfigureFromToml({required String configToml , String? profile , String? apiKey }) => RustLib.instance.api.crateApiChatApiChatConfigureFromToml(configToml: configToml, profile: profile, apiKey: apiKey);
+
+/// 当前后端支持的功能
+Future<ChatCapabilitiesDto>  chatCapabilities() => RustLib.instance.api.crateApiChatApiChatCapabilities();
+
+/// 当前后端可用的模型
+Future<List<String>>  chatListModels() => RustLib.instance.api.crateApiChatApiChatListModels();
+
+/// 当前后端每个 API Key 的用量，不使用 Key 的后端为空
+Future<List<ChatKeyUsageDto>>  chatKeyUsage() => RustLib.instance.api.crateApiChatApiChatKeyUsage();
+
+/// 发送一条消息并返回回复，`mode` 默认为直接请求；同一时间只处理一条消息，后发送的会排队等待
+Future<ChatReplyDto>  chatSend({required String message , ChatInvokeModeDto? mode }) => RustLib.instance.api.crateApiChatApiChatSend(message: message, mode: mode);
+
+/// 停止正在进行以及排队中的异步任务轮询，例如用户离开聊天界面时
+void  chatCancel() => RustLib.instance.api.crateApiChatApiChatCancel();
+
+/// 以流式响应发送消息：每段增量文本立即写入 `sink`，便于气泡文字与口型尽早开始
+///
+/// 完整回复在结束后记入 `chat_history` 并返回；出错时错误发送到 Dart 端的 Stream。
+Stream<String>  chatSendStream({required String message }) => RustLib.instance.api.crateApiChatApiChatSendStream(message: message);
+
+/// 对话历史（不含角色设定）
+Future<List<ChatMessageDto>>  chatHistory() => RustLib.instance.api.crateApiChatApiChatHistory();
+
+/// 已移出上下文的早期对话的摘要
+Future<String?>  chatSummary() => RustLib.instance.api.crateApiChatApiChatSummary();
+
+/// 清空对话历史，保留配置
+Future<void>  chatReset() => RustLib.instance.api.crateApiChatApiChatReset();
+
+/// `store_dir` 中 `character` 的所有会话，最近更新的在前
+Future<List<ChatSessionDto>>  chatListSessions({required String storeDir , required String character }) => RustLib.instance.api.crateApiChatApiChatListSessions(storeDir: storeDir, character: character);
+
+/// 打开 `session_id` 会话并恢复最近的历史（会话不存在时返回错误），为 None 时新建会话；
+/// 之后的对话追加写入该会话，返回会话 id
+Future<String>  chatOpenSession({required String storeDir , required String character , String? sessionId }) => RustLib.instance.api.crateApiChatApiChatOpenSession(storeDir: storeDir, character: character, sessionId: sessionId);
+
+/// 停止写入当前会话，内存中的历史保留
+Future<void>  chatCloseSession() => RustLib.instance.api.crateApiChatApiChatCloseSession();
+
+/// 删除会话文件
+Future<void>  chatDeleteSession({required String storeDir , required String character , required String sessionId }) => RustLib.instance.api.crateApiChatApiChatDeleteSession(storeDir: storeDir, character: character, sessionId: sessionId);
+
+            /// 整个配置文件
+class ChatAppConfigDto  {
+                /// 未指定档案时使用，必须是 `profiles` 中的一个
+final String? defaultProfile;
+final ChatProviderDto provider;
+/// 各档案共用的默认值
+final ChatModelProfileDto defaults;
+final List<ChatProfileDto> profiles;
+
+                const ChatAppConfigDto({this.defaultProfile ,required this.provider ,required this.defaults ,required this.profiles ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => defaultProfile.hashCode^provider.hashCode^defaults.hashCode^profiles.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is ChatAppConfigDto &&
+                runtimeType == other.runtimeType
+                && defaultProfile == other.defaultProfile&& provider == other.provider&& defaults == other.defaults&& profiles == other.profiles;
+        
+            }
+
+/// 后端支持的功能
+class ChatCapabilitiesDto  {
+                final bool streaming;
+final bool asyncTask;
+final bool listModels;
+
+                const ChatCapabilitiesDto({required this.streaming ,required this.asyncTask ,required this.listModels ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => streaming.hashCode^asyncTask.hashCode^listModels.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is ChatCapabilitiesDto &&
+                runtimeType == other.runtimeType
+                && streaming == other.streaming&& asyncTask == other.asyncTask&& listModels == other.listModels;
+        
+            }
+
+/// 对话参数
+class ChatConfigDto  {
+                /// 模型名称，如 `glm-4-flash`
+final String languageModel;
+/// 角色设定
+final String? systemContent;
+final double temperature;
+final double topP;
+/// 每次请求最多带上的历史消息条数，更早的对话按 `summarize` 概括或丢弃
+final int maxHistory;
+/// 模型的上下文窗口（token），为空时按模型名称选择
+final int? contextTokens;
+/// 为回复预留的 token 数，只在 `context_tokens` 不为空时使用
+final int? replyTokens;
+/// 历史超出上下文窗口时概括为摘要，否则直接丢弃
+final bool summarize;
+
+                const ChatConfigDto({required this.languageModel ,this.systemContent ,required this.temperature ,required this.topP ,required this.maxHistory ,this.contextTokens ,this.replyTokens ,required this.summarize ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => languageModel.hashCode^systemContent.hashCode^temperature.hashCode^topP.hashCode^maxHistory.hashCode^contextTokens.hashCode^replyTokens.hashCode^summarize.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is ChatConfigDto &&
+                runtimeType == other.runtimeType
+                && languageModel == other.languageModel&& systemContent == other.systemContent&& temperature == other.temperature&& topP == other.topP&& maxHistory == other.maxHistory&& contextTokens == other.contextTokens&& replyTokens == other.replyTokens&& summarize == other.summarize;
+        
+            }
+
+/// 调用方式
+enum ChatInvokeModeDto {
+                    /// 直接请求并等待完整回复
+sync_,
+/// 提交异步任务后轮询结果
+asyncTask,
+/// 流式请求，收集完整回复后返回
+stream,
+                    ;
+                    
+                }
+
+/// 有多个 API Key 时的选用方式
+enum ChatKeySelectionDto {
+                    /// 轮流使用
+roundRobin,
+/// 优先使用排在前面的，其余作为备用
+priority,
+                    ;
+                    
+                }
+
+/// 一个 API Key 的用量
+class ChatKeyUsageDto  {
+                /// 只显示首尾几位的 Key
+final String label;
+final BigInt requests;
+final BigInt successes;
+final BigInt failures;
+final BigInt rateLimited;
+final BigInt unauthorized;
+/// 被停用时距恢复的秒数
+final int? disabledForSeconds;
+
+                const ChatKeyUsageDto({required this.label ,required this.requests ,required this.successes ,required this.failures ,required this.rateLimited ,required this.unauthorized ,this.disabledForSeconds ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => label.hashCode^requests.hashCode^successes.hashCode^failures.hashCode^rateLimited.hashCode^unauthorized.hashCode^disabledForSeconds.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is ChatKeyUsageDto &&
+                runtimeType == other.runtimeType
+                && label == other.label&& requests == other.requests&& successes == other.successes&& failures == other.failures&& rateLimited == other.rateLimited&& unauthorized == other.unauthorized&& disabledForSeconds == other.disabledForSeconds;
+        
+            }
+
+class ChatMessageDto  {
+                final ChatRoleDto role;
+final String content;
+
+                const ChatMessageDto({required this.role ,required this.content ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => role.hashCode^content.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is ChatMessageDto &&
+                runtimeType == other.runtimeType
+                && role == other.role&& content == other.content;
+        
+            }
+
+/// 配置文件中的模型档案，为空的项取 `defaults` 或内置默认值
+class ChatModelProfileDto  {
+                /// 为空时为档案名
+final String? languageModel;
+final String? systemContent;
+final double? temperature;
+final double? topP;
+final int? maxHistory;
+final int? contextTokens;
+final int? replyTokens;
+final bool? summarize;
+
+                const ChatModelProfileDto({this.languageModel ,this.systemContent ,this.temperature ,this.topP ,this.maxHistory ,this.contextTokens ,this.replyTokens ,this.summarize ,});
+
+                static Future<ChatModelProfileDto>  default_()=>RustLib.instance.api.crateApiChatApiChatModelProfileDtoDefault();
+
+
+                
+
+                
+        @override
+        int get hashCode => languageModel.hashCode^systemContent.hashCode^temperature.hashCode^topP.hashCode^maxHistory.hashCode^contextTokens.hashCode^replyTokens.hashCode^summarize.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is ChatModelProfileDto &&
+                runtimeType == other.runtimeType
+                && languageModel == other.languageModel&& systemContent == other.systemContent&& temperature == other.temperature&& topP == other.topP&& maxHistory == other.maxHistory&& contextTokens == other.contextTokens&& replyTokens == other.replyTokens&& summarize == other.summarize;
+        
+            }
+
+class ChatProfileDto  {
+                final String name;
+final ChatModelProfileDto profile;
+
+                const ChatProfileDto({required this.name ,required this.profile ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => name.hashCode^profile.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is ChatProfileDto &&
+                runtimeType == other.runtimeType
+                && name == other.name&& profile == other.profile;
+        
+            }
+
+class ChatProviderDto  {
+                final ChatProviderKindDto kind;
+/// 为空时使用后端的默认地址；OpenAI 兼容接口必须提供
+final String? baseUrl;
+/// ChatGLM 为 `id.secret` 格式；本地后端可为空
+final String? apiKey;
+/// 额外的 Key，与 `api_key` 一起按 `key_selection` 选用，被限流或鉴权失败的 Key 会暂时停用
+final List<String> apiKeys;
+final ChatKeySelectionDto keySelection;
+/// 异步任务方式的首次轮询间隔（毫秒），之后按指数退避增长
+final int pollIntervalMs;
+/// 异步任务方式的最大轮询间隔（毫秒）
+final int maxPollIntervalMs;
+/// 异步任务方式的最长等待时间（秒）
+final int taskTimeoutSeconds;
+/// 每次轮询后间隔的增长倍数
+final double pollMultiplier;
+/// 轮询间隔的随机抖动比例，0.2 表示在 ±20% 范围内浮动
+final double pollJitter;
+
+                const ChatProviderDto({required this.kind ,this.baseUrl ,this.apiKey ,required this.apiKeys ,required this.keySelection ,required this.pollIntervalMs ,required this.maxPollIntervalMs ,required this.taskTimeoutSeconds ,required this.pollMultiplier ,required this.pollJitter ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => kind.hashCode^baseUrl.hashCode^apiKey.hashCode^apiKeys.hashCode^keySelection.hashCode^pollIntervalMs.hashCode^maxPollIntervalMs.hashCode^taskTimeoutSeconds.hashCode^pollMultiplier.hashCode^pollJitter.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is ChatProviderDto &&
+                runtimeType == other.runtimeType
+                && kind == other.kind&& baseUrl == other.baseUrl&& apiKey == other.apiKey&& apiKeys == other.apiKeys&& keySelection == other.keySelection&& pollIntervalMs == other.pollIntervalMs&& maxPollIntervalMs == other.maxPollIntervalMs&& taskTimeoutSeconds == other.taskTimeoutSeconds&& pollMultiplier == other.pollMultiplier&& pollJitter == other.pollJitter;
+        
+            }
+
+/// 大语言模型后端
+enum ChatProviderKindDto {
+                    chatGlm,
+/// OpenAI 兼容接口，包括 llama.cpp server
+openAi,
+/// 本地 Ollama
+ollama,
+                    ;
+                    
+                }
+
+/// 一次发送的回复
+class ChatReplyDto  {
+                final String content;
+/// 不影响本次回复的失败，如概括早期对话或写入会话文件失败
+final List<String> warnings;
+
+                const ChatReplyDto({required this.content ,required this.warnings ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => content.hashCode^warnings.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is ChatReplyDto &&
+                runtimeType == other.runtimeType
+                && content == other.content&& warnings == other.warnings;
+        
+            }
+
+enum ChatRoleDto {
+                    system,
+user,
+assistant,
+                    ;
+                    
+                }
+
+/// 保存的会话
+class ChatSessionDto  {
+                final String id;
+final int messageCount;
+/// 估算的 token 总数
+final BigInt tokens;
+/// 第一条与最后一条消息的毫秒时间戳，空会话为 None
+final PlatformInt64? createdAtMs;
+final PlatformInt64? updatedAtMs;
+
+                const ChatSessionDto({required this.id ,required this.messageCount ,required this.tokens ,this.createdAtMs ,this.updatedAtMs ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => id.hashCode^messageCount.hashCode^tokens.hashCode^createdAtMs.hashCode^updatedAtMs.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is ChatSessionDto &&
+                runtimeType == other.runtimeType
+                && id == other.id&& messageCount == other.messageCount&& tokens == other.tokens&& createdAtMs == other.createdAtMs&& updatedAtMs == other.updatedAtMs;
+        
+            }
+            
\ No newline at end of file
@@ -1,146 +1,950 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
 import '../frb_generated.dart';
 import '../lib.dart';
+import 'package:collection/collection.dart';
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
 
-/// 加载一个 moc3 模型，返回句柄
-BigInt live2DModelLoad({required List<int> mocBytes}) => RustLib.instance.api
-    .crateApiLive2DModelApiLive2DModelLoad(mocBytes: mocBytes);
+
+            // These functions are ignored because they are not marked as `pub`: `canvas_size`, `core`, `draw_batch_dto`, `eye_blink_config`, `layer_kind`, `lip_sync_config`, `mask_group_dto`, `models`, `motion_event_sinks`, `next_handle`, `vec2_to_f32`, `vec4_to_f32`, `view_transform`, `viseme_timeline_dto`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `Live2dModelInstance`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`
+
+
+            /// 加载一个 moc3 模型，返回句柄
+BigInt  live2DModelLoad({required List<int> mocBytes }) => RustLib.instance.api.crateApiLive2DModelApiLive2DModelLoad(mocBytes: mocBytes);
 
 /// 卸载一个模型
-void live2DModelUnload({required BigInt handle}) => RustLib.instance.api
-    .crateApiLive2DModelApiLive2DModelUnload(handle: handle);
+void  live2DModelUnload({required BigInt handle }) => RustLib.instance.api.crateApiLive2DModelApiLive2DModelUnload(handle: handle);
 
 /// 设置模型参数值（用于动画）
-void live2DModelSetParameter({
-  required BigInt handle,
-  required String parameterId,
-  required double value,
-}) => RustLib.instance.api.crateApiLive2DModelApiLive2DModelSetParameter(
-  handle: handle,
-  parameterId: parameterId,
-  value: value,
-);
+void  live2DModelSetParameter({required BigInt handle , required String parameterId , required double value }) => RustLib.instance.api.crateApiLive2DModelApiLive2DModelSetParameter(handle: handle, parameterId: parameterId, value: value);
+
+/// 读入 model3.json（参数分组等），并按其中的 EyeBlink / LipSync 分组启用自动眨眼与口型同步
+void  live2DModelApplySettings({required BigInt handle , required String model3Json }) => RustLib.instance.api.crateApiLive2DModelApiLive2DModelApplySettings(handle: handle, model3Json: model3Json);
+
+/// 推进动作播放与程序化效果，并按层合成写入参数；应在 `live2d_model_step` 之前每帧调用
+///
+/// 合成顺序：动作 → 眨眼 → 表情 / 情绪 → 物理 → 呼吸 / 待机 → 口型 → 视线 → 用户覆盖 → 平滑
+void  live2DModelUpdate({required BigInt handle , required double deltaSeconds }) => RustLib.instance.api.crateApiLive2DModelApiLive2DModelUpdate(handle: handle, deltaSeconds: deltaSeconds);
+
+/// 配置自动眨眼；`enabled` 为 false 时关闭
+void  live2DModelConfigureEyeBlink({required BigInt handle , required EyeBlinkConfigDto config }) => RustLib.instance.api.crateApiLive2DModelApiLive2DModelConfigureEyeBlink(handle: handle, config: config);
+
+/// 设置呼吸 / 待机摆动效果；传入空列表则关闭。模型中不存在的参数会被忽略
+void  live2DModelSetIdleEffects({required BigInt handle , required List<IdleEffectDto> effects }) => RustLib.instance.api.crateApiLive2DModelApiLive2DModelSetIdleEffects(handle: handle, effects: effects);
+
+/// 官方默认的呼吸 / 待机摆动设置
+List<IdleEffectDto>  live2DDefaultIdleEffects() => RustLib.instance.api.crateApiLive2DModelApiLive2DDefaultIdleEffects();
+
+/// 设置视线跟随目标（归一化坐标，范围 [-1, 1]，Y 轴向上）；松开拖拽时传 (0, 0) 回正
+void  live2DModelSetLookTarget({required BigInt handle , required double x , required double y }) => RustLib.instance.api.crateApiLive2DModelApiLive2DModelSetLookTarget(handle: handle, x: x, y: y);
+
+/// 配置视线跟随的速度与参数映射
+void  live2DModelConfigureLookAt({required BigInt handle , required LookAtConfigDto config }) => RustLib.instance.api.crateApiLive2DModelApiLive2DModelConfigureLookAt(handle: handle, config: config);
+
+/// 把 WAV 音频预分析为口型时间轴（不需要模型句柄）
+Future<LipSyncTimelineDto>  live2DLipSyncAnalyzeWav({required List<int> wav , required LipSyncConfigDto config }) => RustLib.instance.api.crateApiLive2DModelApiLive2DLipSyncAnalyzeWav(wav: wav, config: config);
+
+/// 根据文本生成口型时间轴（中文按拼音韵母、日文按假名、拉丁字母按元音），用于没有音频的回复
+VisemeTimelineDto  live2DTextVisemes({required String text , required double charsPerSecond }) => RustLib.instance.api.crateApiLive2DModelApiLive2DTextVisemes(text: text, charsPerSecond: charsPerSecond);
+
+/// 按文本生成口型动作并播放，返回所用的时间轴；不会打断正在播放的身体动作，新的文本会淡出上一段
+VisemeTimelineDto  live2DModelPlayTextVisemes({required BigInt handle , required String text , required double charsPerSecond }) => RustLib.instance.api.crateApiLive2DModelApiLive2DModelPlayTextVisemes(handle: handle, text: text, charsPerSecond: charsPerSecond);
+
+/// 设置口型同步参数
+void  live2DModelConfigureLipSync({required BigInt handle , required LipSyncConfigDto config }) => RustLib.instance.api.crateApiLive2DModelApiLive2DModelConfigureLipSync(handle: handle, config: config);
+
+/// 从头播放预分析好的口型时间轴（与音频同时开始播放）
+void  live2DModelLipSyncPlay({required BigInt handle , required LipSyncTimelineDto timeline }) => RustLib.instance.api.crateApiLive2DModelApiLive2DModelLipSyncPlay(handle: handle, timeline: timeline);
+
+/// 按模型当前的口型参数分析 WAV 并从头播放
+void  live2DModelLipSyncPlayWav({required BigInt handle , required List<int> wav }) => RustLib.instance.api.crateApiLive2DModelApiLive2DModelLipSyncPlayWav(handle: handle, wav: wav);
+
+/// 推送一段正在播放的 PCM（交错排列、范围 [-1, 1]），用于 TTS 流式播放或麦克风回放
+///
+/// 不足一个分析帧的采样会保留到下一次推送；采样率或声道数改变时丢弃。
+void  live2DModelLipSyncPushPcm({required BigInt handle , required List<double> samples , required int sampleRate , required int channels }) => RustLib.instance.api.crateApiLive2DModelApiLive2DModelLipSyncPushPcm(handle: handle, samples: samples, sampleRate: sampleRate, channels: channels);
+
+/// 推送一段 16 位整型 PCM（交错排列）
+void  live2DModelLipSyncPushPcm16({required BigInt handle , required List<int> samples , required int sampleRate , required int channels }) => RustLib.instance.api.crateApiLive2DModelApiLive2DModelLipSyncPushPcm16(handle: handle, samples: samples, sampleRate: sampleRate, channels: channels);
+
+/// 停止口型同步，嘴巴平滑闭合
+void  live2DModelLipSyncStop({required BigInt handle }) => RustLib.instance.api.crateApiLive2DModelApiLive2DModelLipSyncStop(handle: handle);
+
+/// 读入一个 motion3.json，之后可用 `name` 播放；以 model3.json 中的 File 路径为名称时，情绪的动作组可以选到它
+void  live2DModelLoadMotion({required BigInt handle , required String name , required String motion3Json }) => RustLib.instance.api.crateApiLive2DModelApiLive2DModelLoadMotion(handle: handle, name: name, motion3Json: motion3Json);
+
+/// 播放已读入的动作，正在播放的动作会淡出；`looped` 为 None 时使用 motion3.json 的设置
+void  live2DModelStartMotion({required BigInt handle , required String name , bool? looped }) => RustLib.instance.api.crateApiLive2DModelApiLive2DModelStartMotion(handle: handle, name: name, looped: looped);
+
+/// 停止所有动作；`fade` 为 true 时淡出，否则立即停止
+void  live2DModelStopMotions({required BigInt handle , required bool fade }) => RustLib.instance.api.crateApiLive2DModelApiLive2DModelStopMotions(handle: handle, fade: fade);
+
+/// 订阅所有模型的动作 UserData 事件（用于同步音效、对话气泡等）
+///
+/// Dart 端取消订阅后，对应的 sink 在下一次发送事件失败时移除。
+Stream<MotionEventDto>  live2DMotionEvents() => RustLib.instance.api.crateApiLive2DModelApiLive2DMotionEvents();
+
+/// 读入情绪映射文件（JSON：`Default` 为通用定义，`Models` 按模型名覆盖）；
+/// `model_name` 用于选择 `Models` 中的条目
+void  live2DModelLoadEmotionMap({required BigInt handle , required String mappingJson , String? modelName }) => RustLib.instance.api.crateApiLive2DModelApiLive2DModelLoadEmotionMap(handle: handle, mappingJson: mappingJson, modelName: modelName);
+
+/// 读入一个 exp3.json，`name` 为 model3.json 中 Expressions 的 Name；
+/// 情绪映射的 `Expressions` 列出该名称时随情绪一起应用
+void  live2DModelLoadExpression({required BigInt handle , required String name , required String exp3Json }) => RustLib.instance.api.crateApiLive2DModelApiLive2DModelLoadExpression(handle: handle, name: name, exp3Json: exp3Json);
+
+/// 设置情绪过渡时间与衰减时间（秒）
+void  live2DModelSetEmotionTiming({required BigInt handle , required double transitionSeconds , required double decaySeconds }) => RustLib.instance.api.crateApiLive2DModelApiLive2DModelSetEmotionTiming(handle: handle, transitionSeconds: transitionSeconds, decaySeconds: decaySeconds);
+
+/// 切换情绪：`intensity` 为强度 [0, 1]，`duration_seconds` 后衰减回中性（None 表示一直保持）
+EmotionResultDto  live2DModelSetEmotion({required BigInt handle , required String name , required double intensity , double? durationSeconds }) => RustLib.instance.api.crateApiLive2DModelApiLive2DModelSetEmotion(handle: handle, name: name, intensity: intensity, durationSeconds: durationSeconds);
+
+/// 淡出当前情绪，回到中性
+void  live2DModelClearEmotion({required BigInt handle }) => RustLib.instance.api.crateApiLive2DModelApiLive2DModelClearEmotion(handle: handle);
+
+/// 为参数设置平滑（在所有输入之后、模型更新之前执行），用于消除多个来源同时写入时的抖动
+void  live2DModelSetParameterSmoothing({required BigInt handle , required String parameterId , required ParameterSmoothingDto smoothing }) => RustLib.instance.api.crateApiLive2DModelApiLive2DModelSetParameterSmoothing(handle: handle, parameterId: parameterId, smoothing: smoothing);
+
+/// 取消参数平滑；`parameter_id` 为 None 时取消全部
+void  live2DModelClearParameterSmoothing({required BigInt handle , String? parameterId }) => RustLib.instance.api.crateApiLive2DModelApiLive2DModelClearParameterSmoothing(handle: handle, parameterId: parameterId);
+
+/// 设置动画层的开关、权重 [0, 1] 与混合方式
+void  live2DModelSetLayer({required BigInt handle , required AnimationLayerDto layer , required bool enabled , required double weight , required LayerBlendDto blend }) => RustLib.instance.api.crateApiLive2DModelApiLive2DModelSetLayer(handle: handle, layer: layer, enabled: enabled, weight: weight, blend: blend);
+
+/// 在最上层固定参数值（优先于所有效果）；`value` 为 None 时取消固定
+void  live2DModelSetParameterOverride({required BigInt handle , required String parameterId , double? value }) => RustLib.instance.api.crateApiLive2DModelApiLive2DModelSetParameterOverride(handle: handle, parameterId: parameterId, value: value);
+
+/// 立即眨一次眼（正在眨眼时忽略）
+void  live2DModelForceBlink({required BigInt handle }) => RustLib.instance.api.crateApiLive2DModelApiLive2DModelForceBlink(handle: handle);
 
 /// 生成一帧渲染数据（目前不做参数动画，只是把底层顶点 / 颜色等导出来）
-FrameDto live2DModelStep({required BigInt handle}) =>
-    RustLib.instance.api.crateApiLive2DModelApiLive2DModelStep(handle: handle);
+FrameDto  live2DModelStep({required BigInt handle }) => RustLib.instance.api.crateApiLive2DModelApiLive2DModelStep(handle: handle);
+
+/// 生成一帧合批后的渲染数据：相邻且渲染状态相同的 drawable 合并为一次绘制
+BatchedFrameDto  live2DModelStepBatched({required BigInt handle }) => RustLib.instance.api.crateApiLive2DModelApiLive2DModelStepBatched(handle: handle);
+
+/// 设置 drawable 的排序方式（默认使用 Core 的渲染顺序）
+void  live2DModelSetDrawOrderMode({required BigInt handle , required DrawOrderModeDto mode }) => RustLib.instance.api.crateApiLive2DModelApiLive2DModelSetDrawOrderMode(handle: handle, mode: mode);
+
+/// 计算模型到视口的变换矩阵（考虑画布原点、适配方式、平移与缩放）
+ViewTransformDto  live2DModelViewTransform({required BigInt handle , required ViewportDto viewport }) => RustLib.instance.api.crateApiLive2DModelApiLive2DModelViewTransform(handle: handle, viewport: viewport);
+
+/// 把视口像素坐标（例如指针位置）换算为模型坐标
+F32Array2  live2DModelViewportToModel({required BigInt handle , required ViewportDto viewport , required double x , required double y }) => RustLib.instance.api.crateApiLive2DModelApiLive2DModelViewportToModel(handle: handle, viewport: viewport, x: x, y: y);
 
 /// 设置 Part 的不透明度
-void live2DModelSetPartOpacity({
-  required BigInt handle,
-  required String partId,
-  required double opacity,
-}) => RustLib.instance.api.crateApiLive2DModelApiLive2DModelSetPartOpacity(
-  handle: handle,
-  partId: partId,
-  opacity: opacity,
-);
+void  live2DModelSetPartOpacity({required BigInt handle , required String partId , required double opacity }) => RustLib.instance.api.crateApiLive2DModelApiLive2DModelSetPartOpacity(handle: handle, partId: partId, opacity: opacity);
+
+/// 读入 cdi3.json（参数 / Part 的显示名称与参数分组）
+void  live2DModelApplyDisplayInfo({required BigInt handle , required String cdi3Json }) => RustLib.instance.api.crateApiLive2DModelApiLive2DModelApplyDisplayInfo(handle: handle, cdi3Json: cdi3Json);
+
+/// 读入 userdata3.json（ArtMesh 上的自定义字符串，例如可点击区域标记）
+void  live2DModelApplyUserData({required BigInt handle , required String userdata3Json }) => RustLib.instance.api.crateApiLive2DModelApiLive2DModelApplyUserData(handle: handle, userdata3Json: userdata3Json);
+
+/// 获取所有参数的信息（显示名称、分组、取值范围）
+List<ParameterInfoDto>  live2DModelGetParameterInfos({required BigInt handle }) => RustLib.instance.api.crateApiLive2DModelApiLive2DModelGetParameterInfos(handle: handle);
+
+/// 获取 cdi3.json 中的参数分组；未读入时为空
+List<ParameterGroupInfoDto>  live2DModelGetParameterGroups({required BigInt handle }) => RustLib.instance.api.crateApiLive2DModelApiLive2DModelGetParameterGroups(handle: handle);
+
+/// 获取所有 Part 的信息（显示名称）
+List<PartInfoDto>  live2DModelGetPartInfos({required BigInt handle }) => RustLib.instance.api.crateApiLive2DModelApiLive2DModelGetPartInfos(handle: handle);
+
+/// 获取 userdata3.json 中附加在 drawable 上的字符串；未读入时为空
+List<DrawableUserDataDto>  live2DModelGetDrawableUserData({required BigInt handle }) => RustLib.instance.api.crateApiLive2DModelApiLive2DModelGetDrawableUserData(handle: handle);
 
 /// 获取所有参数 ID
-List<String> live2DModelGetParameterIds({required BigInt handle}) => RustLib
-    .instance
-    .api
-    .crateApiLive2DModelApiLive2DModelGetParameterIds(handle: handle);
+List<String>  live2DModelGetParameterIds({required BigInt handle }) => RustLib.instance.api.crateApiLive2DModelApiLive2DModelGetParameterIds(handle: handle);
 
 /// 获取所有 Part ID
-List<String> live2DModelGetPartIds({required BigInt handle}) => RustLib
-    .instance
-    .api
-    .crateApiLive2DModelApiLive2DModelGetPartIds(handle: handle);
+List<String>  live2DModelGetPartIds({required BigInt handle }) => RustLib.instance.api.crateApiLive2DModelApiLive2DModelGetPartIds(handle: handle);
+
+            /// 动画层
+enum AnimationLayerDto {
+                    motion,
+eyeBlink,
+expression,
+idle,
+lipSync,
+lookAt,
+userOverride,
+                    ;
+                    
+                }
+
+/// 合批格式的一帧数据
+class BatchedFrameDto  {
+                final double canvasWidth;
+final double canvasHeight;
+final double canvasOriginX;
+final double canvasOriginY;
+final double pixelsPerUnit;
+/// 按渲染顺序排列的绘制批次
+final List<DrawBatchDto> batches;
+final int maskTextureCount;
+final List<MaskGroupDto> maskGroups;
+/// 遮罩网格批次，`mask_group` 指明要绘制进哪个遮罩分组
+final List<DrawBatchDto> maskBatches;
+
+                const BatchedFrameDto({required this.canvasWidth ,required this.canvasHeight ,required this.canvasOriginX ,required this.canvasOriginY ,required this.pixelsPerUnit ,required this.batches ,required this.maskTextureCount ,required this.maskGroups ,required this.maskBatches ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => canvasWidth.hashCode^canvasHeight.hashCode^canvasOriginX.hashCode^canvasOriginY.hashCode^pixelsPerUnit.hashCode^batches.hashCode^maskTextureCount.hashCode^maskGroups.hashCode^maskBatches.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is BatchedFrameDto &&
+                runtimeType == other.runtimeType
+                && canvasWidth == other.canvasWidth&& canvasHeight == other.canvasHeight&& canvasOriginX == other.canvasOriginX&& canvasOriginY == other.canvasOriginY&& pixelsPerUnit == other.pixelsPerUnit&& batches == other.batches&& maskTextureCount == other.maskTextureCount&& maskGroups == other.maskGroups&& maskBatches == other.maskBatches;
+        
+            }
+
+/// drawable 的混合模式
+enum BlendModeDto {
+                    normal,
+additive,
+multiplicative,
+                    ;
+                    
+                }
+
+/// 两次眨眼之间的间隔分布
+enum BlinkIntervalDto {
+                    /// 固定间隔 `min_seconds`
+fixed,
+/// 在 [min_seconds, max_seconds) 内均匀分布
+uniform,
+/// 指数分布，均值为 `mean_seconds`，且不短于 `min_seconds`
+exponential,
+                    ;
+                    
+                }
+
+/// 合批后的一次绘制：渲染顺序上相邻、纹理 / 混合模式 / 遮罩分组都相同的 drawable 合并而成
+class DrawBatchDto  {
+                final int textureIndex;
+final BlendModeDto blendMode;
+/// 所属遮罩分组在 `BatchedFrameDto::mask_groups` 中的下标；无遮罩时为 None
+final int? maskGroup;
+/// 是否为反向遮罩
+final bool invertedMask;
+/// 合并进来的 drawable 索引（按渲染顺序）
+final Uint32List drawables;
+/// 展平后的顶点坐标数组：[x0, y0, x1, y1, ...]
+final Float32List vertices;
+/// 展平后的纹理坐标数组：[u0, v0, u1, v1, ...]
+final Float32List uvs;
+/// 已按合并后的顶点重新编号的索引缓冲
+final Uint16List indices;
+/// 每个顶点的乘色 [r, g, b, a, ...]，a 已乘上 drawable 不透明度
+final Float32List multiplyColors;
+/// 每个顶点的屏幕色 [r, g, b, a, ...]
+final Float32List screenColors;
+
+                const DrawBatchDto({required this.textureIndex ,required this.blendMode ,this.maskGroup ,required this.invertedMask ,required this.drawables ,required this.vertices ,required this.uvs ,required this.indices ,required this.multiplyColors ,required this.screenColors ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => textureIndex.hashCode^blendMode.hashCode^maskGroup.hashCode^invertedMask.hashCode^drawables.hashCode^vertices.hashCode^uvs.hashCode^indices.hashCode^multiplyColors.hashCode^screenColors.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is DrawBatchDto &&
+                runtimeType == other.runtimeType
+                && textureIndex == other.textureIndex&& blendMode == other.blendMode&& maskGroup == other.maskGroup&& invertedMask == other.invertedMask&& drawables == other.drawables&& vertices == other.vertices&& uvs == other.uvs&& indices == other.indices&& multiplyColors == other.multiplyColors&& screenColors == other.screenColors;
+        
+            }
+
+/// drawable 排序方式
+enum DrawOrderModeDto {
+                    /// 使用 Core 计算的渲染顺序（已考虑 Part 的绘制顺序）
+renderOrder,
+/// 按 drawable 的 draw order 排序
+drawOrder,
+                    ;
+                    
+                }
 
 /// Dart 侧使用的单个 Drawable 帧数据
-class DrawableFrameDto {
-  /// 原始 drawable 索引（用于遮罩查找）
-  final int index;
-  final int textureIndex;
-
-  /// 展平后的顶点坐标数组：[x0, y0, x1, y1, ...]
-  final Float32List vertices;
-
-  /// 展平后的纹理坐标数组：[u0, v0, u1, v1, ...]
-  final Float32List uvs;
-
-  /// 索引缓冲，指向 `vertices` / `uvs` 中的顶点下标
-  final Uint16List indices;
-
-  /// 遮罩列表，元素为 drawable 索引
-  final Uint16List masks;
-  final double opacity;
-
-  /// 乘色
-  final F32Array4 multiplyColor;
-
-  /// 屏幕色
-  final F32Array4 screenColor;
-
-  /// 当前渲染顺序（越大越后画）
-  final int drawOrder;
-
-  const DrawableFrameDto({
-    required this.index,
-    required this.textureIndex,
-    required this.vertices,
-    required this.uvs,
-    required this.indices,
-    required this.masks,
-    required this.opacity,
-    required this.multiplyColor,
-    required this.screenColor,
-    required this.drawOrder,
-  });
-
-  @override
-  int get hashCode =>
-      index.hashCode ^
-      textureIndex.hashCode ^
-      vertices.hashCode ^
-      uvs.hashCode ^
-      indices.hashCode ^
-      masks.hashCode ^
-      opacity.hashCode ^
-      multiplyColor.hashCode ^
-      screenColor.hashCode ^
-      drawOrder.hashCode;
-
-  @override
-  bool operator ==(Object other) =>
-      identical(this, other) ||
-      other is DrawableFrameDto &&
-          runtimeType == other.runtimeType &&
-          index == other.index &&
-          textureIndex == other.textureIndex &&
-          vertices == other.vertices &&
-          uvs == other.uvs &&
-          indices == other.indices &&
-          masks == other.masks &&
-          opacity == other.opacity &&
-          multiplyColor == other.multiplyColor &&
-          screenColor == other.screenColor &&
-          drawOrder == other.drawOrder;
-}
+class DrawableFrameDto  {
+                /// 对应原始 drawable 的索引（用于遮罩查找）
+final int index;
+final int textureIndex;
+/// 展平后的顶点坐标数组：[x0, y0, x1, y1, ...]
+final Float32List vertices;
+/// 展平后的纹理坐标数组：[u0, v0, u1, v1, ...]
+final Float32List uvs;
+/// 索引缓冲，指向 `vertices` / `uvs` 中的顶点下标
+final Uint16List indices;
+/// 遮罩列表，元素为 drawable 索引
+final Uint16List masks;
+/// 所属遮罩分组在 `FrameDto::mask_groups` 中的下标；无遮罩时为 None
+final int? maskGroup;
+final double opacity;
+/// 乘色
+final F32Array4 multiplyColor;
+/// 屏幕色
+final F32Array4 screenColor;
+/// 当前渲染顺序（越大越后画）
+final int drawOrder;
+
+                const DrawableFrameDto({required this.index ,required this.textureIndex ,required this.vertices ,required this.uvs ,required this.indices ,required this.masks ,this.maskGroup ,required this.opacity ,required this.multiplyColor ,required this.screenColor ,required this.drawOrder ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => index.hashCode^textureIndex.hashCode^vertices.hashCode^uvs.hashCode^indices.hashCode^masks.hashCode^maskGroup.hashCode^opacity.hashCode^multiplyColor.hashCode^screenColor.hashCode^drawOrder.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is DrawableFrameDto &&
+                runtimeType == other.runtimeType
+                && index == other.index&& textureIndex == other.textureIndex&& vertices == other.vertices&& uvs == other.uvs&& indices == other.indices&& masks == other.masks&& maskGroup == other.maskGroup&& opacity == other.opacity&& multiplyColor == other.multiplyColor&& screenColor == other.screenColor&& drawOrder == other.drawOrder;
+        
+            }
+
+/// userdata3.json 中附加在某个 ArtMesh 上的字符串
+class DrawableUserDataDto  {
+                final int drawableIndex;
+final String drawableId;
+final String value;
+
+                const DrawableUserDataDto({required this.drawableIndex ,required this.drawableId ,required this.value ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => drawableIndex.hashCode^drawableId.hashCode^value.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is DrawableUserDataDto &&
+                runtimeType == other.runtimeType
+                && drawableIndex == other.drawableIndex&& drawableId == other.drawableId&& value == other.value;
+        
+            }
+
+/// 设置情绪的结果
+class EmotionResultDto  {
+                /// 映射中为该情绪指定的表情名；已用 `live2d_model_load_expression` 读入的会随情绪一起应用
+final List<String> expressions;
+/// 从映射指定的动作组中随机选出并开始播放的动作名；未指定动作组、
+/// 未读入 model3.json 或组内动作均未读入时为 None
+final String? startedMotion;
+
+                const EmotionResultDto({required this.expressions ,this.startedMotion ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => expressions.hashCode^startedMotion.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is EmotionResultDto &&
+                runtimeType == other.runtimeType
+                && expressions == other.expressions&& startedMotion == other.startedMotion;
+        
+            }
+
+/// 自动眨眼配置
+class EyeBlinkConfigDto  {
+                final bool enabled;
+final BlinkIntervalDto interval;
+final double minSeconds;
+final double maxSeconds;
+final double meanSeconds;
+/// 闭眼 / 保持闭眼 / 睁眼各阶段的时长（秒）
+final double closingSeconds;
+final double closedSeconds;
+final double openingSeconds;
+/// 出现双眨眼的概率
+final double doubleBlinkProbability;
+/// 双眨眼两次之间睁眼的时长（秒）
+final double doubleBlinkGapSeconds;
+/// 驱动的参数 ID；为空时使用 model3.json 的 EyeBlink 分组
+final List<String> parameterIds;
+/// 随机种子；None 时使用系统时间（测试时可固定种子以得到确定结果）
+final BigInt? seed;
+
+                const EyeBlinkConfigDto({required this.enabled ,required this.interval ,required this.minSeconds ,required this.maxSeconds ,required this.meanSeconds ,required this.closingSeconds ,required this.closedSeconds ,required this.openingSeconds ,required this.doubleBlinkProbability ,required this.doubleBlinkGapSeconds ,required this.parameterIds ,this.seed ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => enabled.hashCode^interval.hashCode^minSeconds.hashCode^maxSeconds.hashCode^meanSeconds.hashCode^closingSeconds.hashCode^closedSeconds.hashCode^openingSeconds.hashCode^doubleBlinkProbability.hashCode^doubleBlinkGapSeconds.hashCode^parameterIds.hashCode^seed.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is EyeBlinkConfigDto &&
+                runtimeType == other.runtimeType
+                && enabled == other.enabled&& interval == other.interval&& minSeconds == other.minSeconds&& maxSeconds == other.maxSeconds&& meanSeconds == other.meanSeconds&& closingSeconds == other.closingSeconds&& closedSeconds == other.closedSeconds&& openingSeconds == other.openingSeconds&& doubleBlinkProbability == other.doubleBlinkProbability&& doubleBlinkGapSeconds == other.doubleBlinkGapSeconds&& parameterIds == other.parameterIds&& seed == other.seed;
+        
+            }
+
+
+            class F32Array2 extends NonGrowableListView<double> {
+                static const arraySize = 2;
+
+                @internal
+                Float32List get inner => _inner;
+                final Float32List _inner;
+
+                F32Array2(this._inner)
+                    : assert(_inner.length == arraySize),
+                      super(_inner);
+  
+                F32Array2.init(): this(Float32List(arraySize));
+              }
+            
 
 /// 一帧 Live2D 的整体数据
-class FrameDto {
-  final double canvasWidth;
-  final double canvasHeight;
-  final List<DrawableFrameDto> drawables;
-
-  const FrameDto({
-    required this.canvasWidth,
-    required this.canvasHeight,
-    required this.drawables,
-  });
-
-  @override
-  int get hashCode =>
-      canvasWidth.hashCode ^ canvasHeight.hashCode ^ drawables.hashCode;
-
-  @override
-  bool operator ==(Object other) =>
-      identical(this, other) ||
-      other is FrameDto &&
-          runtimeType == other.runtimeType &&
-          canvasWidth == other.canvasWidth &&
-          canvasHeight == other.canvasHeight &&
-          drawables == other.drawables;
-}
+class FrameDto  {
+                final double canvasWidth;
+final double canvasHeight;
+/// 模型原点在画布中的像素位置（左上角为原点，Y 向下）
+final double canvasOriginX;
+final double canvasOriginY;
+/// 每个模型单位对应的画布像素数
+final double pixelsPerUnit;
+final List<DrawableFrameDto> drawables;
+/// 本帧需要绘制的遮罩图集张数
+final int maskTextureCount;
+/// 本帧用到的遮罩分组，每组遮罩只需绘制一次
+final List<MaskGroupDto> maskGroups;
+
+                const FrameDto({required this.canvasWidth ,required this.canvasHeight ,required this.canvasOriginX ,required this.canvasOriginY ,required this.pixelsPerUnit ,required this.drawables ,required this.maskTextureCount ,required this.maskGroups ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => canvasWidth.hashCode^canvasHeight.hashCode^canvasOriginX.hashCode^canvasOriginY.hashCode^pixelsPerUnit.hashCode^drawables.hashCode^maskTextureCount.hashCode^maskGroups.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is FrameDto &&
+                runtimeType == other.runtimeType
+                && canvasWidth == other.canvasWidth&& canvasHeight == other.canvasHeight&& canvasOriginX == other.canvasOriginX&& canvasOriginY == other.canvasOriginY&& pixelsPerUnit == other.pixelsPerUnit&& drawables == other.drawables&& maskTextureCount == other.maskTextureCount&& maskGroups == other.maskGroups;
+        
+            }
+
+/// 一条待机效果：数值为 `offset + peak * sin(2π t / cycle_seconds)`，按 `weight` 叠加到参数上
+class IdleEffectDto  {
+                final String parameterId;
+final double offset;
+final double peak;
+final double cycleSeconds;
+final double weight;
+
+                const IdleEffectDto({required this.parameterId ,required this.offset ,required this.peak ,required this.cycleSeconds ,required this.weight ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => parameterId.hashCode^offset.hashCode^peak.hashCode^cycleSeconds.hashCode^weight.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is IdleEffectDto &&
+                runtimeType == other.runtimeType
+                && parameterId == other.parameterId&& offset == other.offset&& peak == other.peak&& cycleSeconds == other.cycleSeconds&& weight == other.weight;
+        
+            }
+
+/// 动画层的混合方式
+enum LayerBlendDto {
+                    /// 在下层结果之上计算，按权重混合其改动
+normal,
+/// 从参数默认值开始计算，把相对默认值的偏移按权重叠加
+additive,
+                    ;
+                    
+                }
+
+/// 口型同步参数
+class LipSyncConfigDto  {
+                /// 分析帧长（秒），默认 1/60
+final double frameSeconds;
+/// RMS 到张嘴程度的增益，默认 5.0
+final double gain;
+/// 低于此 RMS 时保持闭嘴，默认 0.01
+final double noiseFloor;
+/// 张嘴 / 闭嘴时的平滑时间常数（秒）
+final double attackSeconds;
+final double releaseSeconds;
+/// 叠加到参数上的权重，默认 0.8
+final double weight;
+
+                const LipSyncConfigDto({required this.frameSeconds ,required this.gain ,required this.noiseFloor ,required this.attackSeconds ,required this.releaseSeconds ,required this.weight ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => frameSeconds.hashCode^gain.hashCode^noiseFloor.hashCode^attackSeconds.hashCode^releaseSeconds.hashCode^weight.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is LipSyncConfigDto &&
+                runtimeType == other.runtimeType
+                && frameSeconds == other.frameSeconds&& gain == other.gain&& noiseFloor == other.noiseFloor&& attackSeconds == other.attackSeconds&& releaseSeconds == other.releaseSeconds&& weight == other.weight;
+        
+            }
+
+/// 预先分析好的口型时间轴：按固定帧长采样的张嘴程度 [0, 1]
+class LipSyncTimelineDto  {
+                final double frameSeconds;
+final Float32List values;
+
+                const LipSyncTimelineDto({required this.frameSeconds ,required this.values ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => frameSeconds.hashCode^values.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is LipSyncTimelineDto &&
+                runtimeType == other.runtimeType
+                && frameSeconds == other.frameSeconds&& values == other.values;
+        
+            }
+
+/// 视线跟随配置
+class LookAtConfigDto  {
+                /// 最大速度（归一化单位 / 秒），默认 4.0
+final double maxSpeed;
+/// 从静止加速到最大速度所需时间（秒），默认 0.15
+final double timeToMaxSpeed;
+/// 参数映射；为空时使用官方示例的映射
+final List<LookAtMappingDto> mappings;
+
+                const LookAtConfigDto({required this.maxSpeed ,required this.timeToMaxSpeed ,required this.mappings ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => maxSpeed.hashCode^timeToMaxSpeed.hashCode^mappings.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is LookAtConfigDto &&
+                runtimeType == other.runtimeType
+                && maxSpeed == other.maxSpeed&& timeToMaxSpeed == other.timeToMaxSpeed&& mappings == other.mappings;
+        
+            }
+
+/// 视线跟随时单个参数的映射：`x * x_weight + y * y_weight + x * y * xy_weight`
+class LookAtMappingDto  {
+                final String parameterId;
+final double xWeight;
+final double yWeight;
+final double xyWeight;
+
+                const LookAtMappingDto({required this.parameterId ,required this.xWeight ,required this.yWeight ,required this.xyWeight ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => parameterId.hashCode^xWeight.hashCode^yWeight.hashCode^xyWeight.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is LookAtMappingDto &&
+                runtimeType == other.runtimeType
+                && parameterId == other.parameterId&& xWeight == other.xWeight&& yWeight == other.yWeight&& xyWeight == other.xyWeight;
+        
+            }
+
+/// 共享同一组遮罩的 drawable 分组，以及它在遮罩图集中的位置
+class MaskGroupDto  {
+                /// 遮罩图集下标
+final int textureSlot;
+/// 写入的颜色通道：0=R, 1=G, 2=B, 3=A
+final int channel;
+/// 图集中的矩形区域 [x, y, width, height]，归一化到 [0, 1]
+final F32Array4 layout;
+/// 作为遮罩绘制的 drawable 索引
+final Uint32List maskDrawables;
+/// 被该组遮罩裁剪的 drawable 索引
+final Uint32List clippedDrawables;
+/// 绘制遮罩时使用：模型坐标 -> 图集 NDC（列主序 4x4）
+final F32Array16 maskMatrix;
+/// 绘制被裁剪 drawable 时使用：模型坐标 -> 图集 UV（列主序 4x4）
+final F32Array16 drawMatrix;
+
+                const MaskGroupDto({required this.textureSlot ,required this.channel ,required this.layout ,required this.maskDrawables ,required this.clippedDrawables ,required this.maskMatrix ,required this.drawMatrix ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => textureSlot.hashCode^channel.hashCode^layout.hashCode^maskDrawables.hashCode^clippedDrawables.hashCode^maskMatrix.hashCode^drawMatrix.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is MaskGroupDto &&
+                runtimeType == other.runtimeType
+                && textureSlot == other.textureSlot&& channel == other.channel&& layout == other.layout&& maskDrawables == other.maskDrawables&& clippedDrawables == other.clippedDrawables&& maskMatrix == other.maskMatrix&& drawMatrix == other.drawMatrix;
+        
+            }
+
+/// 动作播放到 UserData 时间点时发出的事件
+class MotionEventDto  {
+                /// 模型句柄
+final BigInt handle;
+/// `live2d_model_load_motion` 时指定的动作名
+final String motionName;
+/// UserData 中的字符串
+final String value;
+
+                const MotionEventDto({required this.handle ,required this.motionName ,required this.value ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => handle.hashCode^motionName.hashCode^value.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is MotionEventDto &&
+                runtimeType == other.runtimeType
+                && handle == other.handle&& motionName == other.motionName&& value == other.value;
+        
+            }
+
+/// 参数分组（cdi3.json 的 ParameterGroups）
+class ParameterGroupInfoDto  {
+                final String id;
+final String name;
+/// 父分组 id；顶层时为空
+final String parentId;
+
+                const ParameterGroupInfoDto({required this.id ,required this.name ,required this.parentId ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => id.hashCode^name.hashCode^parentId.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is ParameterGroupInfoDto &&
+                runtimeType == other.runtimeType
+                && id == other.id&& name == other.name&& parentId == other.parentId;
+        
+            }
+
+/// 参数信息（含 cdi3.json 中的显示名称）
+class ParameterInfoDto  {
+                final String id;
+/// 显示名称；没有 cdi3.json 或未定义时为 id
+final String name;
+/// 所属参数分组 id；顶层或未知时为空
+final String groupId;
+final double minValue;
+final double maxValue;
+final double defaultValue;
+
+                const ParameterInfoDto({required this.id ,required this.name ,required this.groupId ,required this.minValue ,required this.maxValue ,required this.defaultValue ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => id.hashCode^name.hashCode^groupId.hashCode^minValue.hashCode^maxValue.hashCode^defaultValue.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is ParameterInfoDto &&
+                runtimeType == other.runtimeType
+                && id == other.id&& name == other.name&& groupId == other.groupId&& minValue == other.minValue&& maxValue == other.maxValue&& defaultValue == other.defaultValue;
+        
+            }
+
+/// 单个参数的平滑配置
+class ParameterSmoothingDto  {
+                final SmoothingFilterDto filter;
+final double timeSeconds;
+/// 每秒最大变化量；None 表示不限制
+final double? maxVelocity;
+
+                const ParameterSmoothingDto({required this.filter ,required this.timeSeconds ,this.maxVelocity ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => filter.hashCode^timeSeconds.hashCode^maxVelocity.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is ParameterSmoothingDto &&
+                runtimeType == other.runtimeType
+                && filter == other.filter&& timeSeconds == other.timeSeconds&& maxVelocity == other.maxVelocity;
+        
+            }
+
+/// Part 信息（含 cdi3.json 中的显示名称）
+class PartInfoDto  {
+                final String id;
+final String name;
+
+                const PartInfoDto({required this.id ,required this.name ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => id.hashCode^name.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is PartInfoDto &&
+                runtimeType == other.runtimeType
+                && id == other.id&& name == other.name;
+        
+            }
+
+/// 参数平滑方式
+enum SmoothingFilterDto {
+                    /// 不滤波（仍受最大速度限制）
+none,
+/// 指数平滑，`time_seconds` 为时间常数
+exponential,
+/// 临界阻尼弹簧，约 `time_seconds` 到达目标且不过冲
+spring,
+                    ;
+                    
+                }
+
+/// 画布适配到视口的方式
+enum ViewFitModeDto {
+                    /// 完整显示画布
+contain,
+/// 画布铺满视口（可能裁切）
+cover,
+/// 使用 `ViewportDto::fixed_scale` 作为 视口像素 / 画布像素 的比例
+fixedScale,
+                    ;
+                    
+                }
+
+/// 视图变换结果，矩阵均为列主序 4x4
+class ViewTransformDto  {
+                /// 每个模型单位对应的视口像素数
+final double pixelsPerUnit;
+/// 模型单位 -> 视口像素（左上角为原点，Y 向下）
+final F32Array16 modelToViewport;
+/// 模型单位 -> 标准化设备坐标（[-1, 1]，Y 向上）
+final F32Array16 modelToNdc;
+
+                const ViewTransformDto({required this.pixelsPerUnit ,required this.modelToViewport ,required this.modelToNdc ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => pixelsPerUnit.hashCode^modelToViewport.hashCode^modelToNdc.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is ViewTransformDto &&
+                runtimeType == other.runtimeType
+                && pixelsPerUnit == other.pixelsPerUnit&& modelToViewport == other.modelToViewport&& modelToNdc == other.modelToNdc;
+        
+            }
+
+/// 视口描述：尺寸、适配方式、平移（视口像素）与缩放
+class ViewportDto  {
+                final double width;
+final double height;
+final ViewFitModeDto fitMode;
+final double fixedScale;
+final double panX;
+final double panY;
+final double zoom;
+
+                const ViewportDto({required this.width ,required this.height ,required this.fitMode ,required this.fixedScale ,required this.panX ,required this.panY ,required this.zoom ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => width.hashCode^height.hashCode^fitMode.hashCode^fixedScale.hashCode^panX.hashCode^panY.hashCode^zoom.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is ViewportDto &&
+                runtimeType == other.runtimeType
+                && width == other.width&& height == other.height&& fitMode == other.fitMode&& fixedScale == other.fixedScale&& panX == other.panX&& panY == other.panY&& zoom == other.zoom;
+        
+            }
+
+/// 口型（五个元音 + 闭嘴）
+enum VisemeDto {
+                    a,
+i,
+u,
+e,
+o,
+closed,
+                    ;
+                    
+                }
+
+/// 口型关键帧，相邻关键帧之间线性插值
+class VisemeKeyframeDto  {
+                final double time;
+final VisemeDto viseme;
+/// ParamMouthOpenY 的值
+final double mouthOpen;
+/// ParamMouthForm 的值
+final double mouthForm;
+
+                const VisemeKeyframeDto({required this.time ,required this.viseme ,required this.mouthOpen ,required this.mouthForm ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => time.hashCode^viseme.hashCode^mouthOpen.hashCode^mouthForm.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is VisemeKeyframeDto &&
+                runtimeType == other.runtimeType
+                && time == other.time&& viseme == other.viseme&& mouthOpen == other.mouthOpen&& mouthForm == other.mouthForm;
+        
+            }
+
+/// 由文本生成的口型时间轴
+class VisemeTimelineDto  {
+                final double duration;
+final List<VisemeKeyframeDto> keyframes;
+
+                const VisemeTimelineDto({required this.duration ,required this.keyframes ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => duration.hashCode^keyframes.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is VisemeTimelineDto &&
+                runtimeType == other.runtimeType
+                && duration == other.duration&& keyframes == other.keyframes;
+        
+            }
+            
\ No newline at end of file
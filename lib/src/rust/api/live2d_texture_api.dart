@@ -0,0 +1,111 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `loader`, `texture_dto`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `clone`, `clone`, `fmt`, `fmt`, `fmt`
+
+
+            /// 解码 PNG 字节数组，第 n 张图片对应 texture_index n
+///
+/// Dart 端应使用此函数：Flutter 资源（`rootBundle`）不是文件系统中的文件，
+/// 需先在 Dart 中读取字节再传入。
+Future<List<TextureDto>>  live2DTexturesDecode({required List<Uint8List> pngs , required TextureOptionsDto options }) => RustLib.instance.api.crateApiLive2DTextureApiLive2DTexturesDecode(pngs: pngs, options: options);
+
+/// 读取 model3.json 中列出的全部纹理
+///
+/// 只适用于本地文件系统中的模型目录（桌面端或下载到应用目录的模型），
+/// 无法读取 Flutter 资源，也不支持 Web；其他情况请使用 `live2d_textures_decode`。
+Future<List<TextureDto>>  live2DTexturesLoadFromModel3({required String model3JsonPath , required TextureOptionsDto options }) => RustLib.instance.api.crateApiLive2DTextureApiLive2DTexturesLoadFromModel3(model3JsonPath: model3JsonPath, options: options);
+
+            /// 可直接上传到 GPU 的纹理
+class TextureDto  {
+                /// 对应 drawable 的 `texture_index`
+final int textureIndex;
+final int width;
+final int height;
+final bool premultipliedAlpha;
+/// levels[0] 为原图，其后依次为 mipmap
+final List<TextureLevelDto> levels;
+
+                const TextureDto({required this.textureIndex ,required this.width ,required this.height ,required this.premultipliedAlpha ,required this.levels ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => textureIndex.hashCode^width.hashCode^height.hashCode^premultipliedAlpha.hashCode^levels.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is TextureDto &&
+                runtimeType == other.runtimeType
+                && textureIndex == other.textureIndex&& width == other.width&& height == other.height&& premultipliedAlpha == other.premultipliedAlpha&& levels == other.levels;
+        
+            }
+
+/// 单个 mipmap 层级
+class TextureLevelDto  {
+                final int width;
+final int height;
+/// RGBA8 像素数据，逐行从上到下
+final Uint8List rgba;
+
+                const TextureLevelDto({required this.width ,required this.height ,required this.rgba ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => width.hashCode^height.hashCode^rgba.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is TextureLevelDto &&
+                runtimeType == other.runtimeType
+                && width == other.width&& height == other.height&& rgba == other.rgba;
+        
+            }
+
+/// 纹理加载选项
+class TextureOptionsDto  {
+                /// 是否预乘 alpha（与 Cubism 的预乘混合保持一致）
+final bool premultiplyAlpha;
+/// 是否生成 mipmap
+final bool generateMipmaps;
+/// 所有纹理（含 mipmap）的总字节上限；超出时统一减半缩放，None 表示不限制
+final BigInt? memoryBudgetBytes;
+
+                const TextureOptionsDto({required this.premultiplyAlpha ,required this.generateMipmaps ,this.memoryBudgetBytes ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => premultiplyAlpha.hashCode^generateMipmaps.hashCode^memoryBudgetBytes.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is TextureOptionsDto &&
+                runtimeType == other.runtimeType
+                && premultiplyAlpha == other.premultiplyAlpha&& generateMipmaps == other.generateMipmaps&& memoryBudgetBytes == other.memoryBudgetBytes;
+        
+            }
+            
\ No newline at end of file
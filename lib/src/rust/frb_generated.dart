@@ -3,913 +3,4071 @@
 
 // ignore_for_file: unused_import, unused_element, unnecessary_import, duplicate_ignore, invalid_use_of_internal_member, annotate_overrides, non_constant_identifier_names, curly_braces_in_flow_control_structures, prefer_const_literals_to_create_immutables, unused_field
 
+import 'api/chat_api.dart';
 import 'api/live2d_model_api.dart';
+import 'api/live2d_texture_api.dart';
 import 'api/wise_lover_boot_api.dart';
 import 'dart:async';
 import 'dart:convert';
 import 'frb_generated.dart';
-import 'frb_generated.io.dart'
-    if (dart.library.js_interop) 'frb_generated.web.dart';
+import 'frb_generated.io.dart' if (dart.library.js_interop) 'frb_generated.web.dart';
 import 'lib.dart';
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
 
-/// Main entrypoint of the Rust API
-class RustLib extends BaseEntrypoint<RustLibApi, RustLibApiImpl, RustLibWire> {
-  @internal
-  static final instance = RustLib._();
-
-  RustLib._();
-
-  /// Initialize flutter_rust_bridge
-  static Future<void> init({
-    RustLibApi? api,
-    BaseHandler? handler,
-    ExternalLibrary? externalLibrary,
-    bool forceSameCodegenVersion = true,
-  }) async {
-    await instance.initImpl(
-      api: api,
-      handler: handler,
-      externalLibrary: externalLibrary,
-      forceSameCodegenVersion: forceSameCodegenVersion,
-    );
-  }
-
-  /// Initialize flutter_rust_bridge in mock mode.
-  /// No libraries for FFI are loaded.
-  static void initMock({required RustLibApi api}) {
-    instance.initMockImpl(api: api);
-  }
-
-  /// Dispose flutter_rust_bridge
-  ///
-  /// The call to this function is optional, since flutter_rust_bridge (and everything else)
-  /// is automatically disposed when the app stops.
-  static void dispose() => instance.disposeImpl();
-
-  @override
-  ApiImplConstructor<RustLibApiImpl, RustLibWire> get apiImplConstructor =>
-      RustLibApiImpl.new;
-
-  @override
-  WireConstructor<RustLibWire> get wireConstructor =>
-      RustLibWire.fromExternalLibrary;
-
-  @override
-  Future<void> executeRustInitializers() async {
-    await api.crateApiWiseLoverBootApiWiseLoverBootInitApp();
-  }
-
-  @override
-  ExternalLibraryLoaderConfig get defaultExternalLibraryLoaderConfig =>
-      kDefaultExternalLibraryLoaderConfig;
-
-  @override
-  String get codegenVersion => '2.11.1';
-
-  @override
-  int get rustContentHash => 1822512288;
-
-  static const kDefaultExternalLibraryLoaderConfig =
-      ExternalLibraryLoaderConfig(
-        stem: 'wise_lover_core',
-        ioDirectory: 'rust/Wise_Lover_Core/target/release/',
-        webPrefix: 'pkg/',
-      );
-}
-
-abstract class RustLibApi extends BaseApi {
-  bool crateApiWiseLoverBootApiLive2DCoreCheckMocConsistency({
-    required List<int> mocBytes,
-  });
-
-  String crateApiWiseLoverBootApiLive2DCoreLatestMocVersion();
-
-  String crateApiWiseLoverBootApiLive2DCoreLoader();
-
-  String crateApiWiseLoverBootApiLive2DCoreMocVersion({
-    required List<int> mocBytes,
-  });
-
-  String crateApiWiseLoverBootApiLive2DCoreVersion();
-
-  List<String> crateApiLive2DModelApiLive2DModelGetParameterIds({
-    required BigInt handle,
-  });
-
-  List<String> crateApiLive2DModelApiLive2DModelGetPartIds({
-    required BigInt handle,
-  });
-
-  BigInt crateApiLive2DModelApiLive2DModelLoad({required List<int> mocBytes});
-
-  void crateApiLive2DModelApiLive2DModelSetParameter({
-    required BigInt handle,
-    required String parameterId,
-    required double value,
-  });
-
-  void crateApiLive2DModelApiLive2DModelSetPartOpacity({
-    required BigInt handle,
-    required String partId,
-    required double opacity,
-  });
-
-  FrameDto crateApiLive2DModelApiLive2DModelStep({required BigInt handle});
-
-  void crateApiLive2DModelApiLive2DModelUnload({required BigInt handle});
-
-  Future<void> crateApiWiseLoverBootApiWiseLoverBootInitApp();
-}
-
-class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
-  RustLibApiImpl({
-    required super.handler,
-    required super.wire,
-    required super.generalizedFrbRustBinding,
-    required super.portManager,
-  });
-
-  @override
-  bool crateApiWiseLoverBootApiLive2DCoreCheckMocConsistency({
-    required List<int> mocBytes,
-  }) {
-    return handler.executeSync(
-      SyncTask(
-        callFfi: () {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_list_prim_u_8_loose(mocBytes, serializer);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 1)!;
-        },
-        codec: SseCodec(
+
+                /// Main entrypoint of the Rust API
+                class RustLib extends BaseEntrypoint<RustLibApi, RustLibApiImpl, RustLibWire> {
+                  @internal
+                  static final instance = RustLib._();
+
+                  RustLib._();
+
+                  /// Initialize flutter_rust_bridge
+                  static Future<void> init({
+                    RustLibApi? api,
+                    BaseHandler? handler,
+                    ExternalLibrary? externalLibrary,
+                    bool forceSameCodegenVersion = true,
+                  }) async {
+                    await instance.initImpl(
+                      api: api,
+                      handler: handler,
+                      externalLibrary: externalLibrary,
+                      forceSameCodegenVersion: forceSameCodegenVersion,
+                    );
+                  }
+
+                  /// Initialize flutter_rust_bridge in mock mode.
+                  /// No libraries for FFI are loaded.
+                  static void initMock({
+                    required RustLibApi api,
+                  }) {
+                    instance.initMockImpl(
+                      api: api,
+                    );
+                  }
+
+                  /// Dispose flutter_rust_bridge
+                  ///
+                  /// The call to this function is optional, since flutter_rust_bridge (and everything else)
+                  /// is automatically disposed when the app stops.
+                  static void dispose() => instance.disposeImpl();
+
+                  @override
+                  ApiImplConstructor<RustLibApiImpl, RustLibWire> get apiImplConstructor => RustLibApiImpl.new;
+
+                  @override
+                  WireConstructor<RustLibWire> get wireConstructor => RustLibWire.fromExternalLibrary;
+
+                  @override
+                  Future<void> executeRustInitializers() async {
+                    await api.crateApiWiseLoverBootApiWiseLoverBootInitApp();
+
+                  }
+
+                  @override
+                  ExternalLibraryLoaderConfig get defaultExternalLibraryLoaderConfig => kDefaultExternalLibraryLoaderConfig;
+
+                  @override
+                  String get codegenVersion => '2.11.1';
+
+                  @override
+                  int get rustContentHash => 895375696;
+
+                  static const kDefaultExternalLibraryLoaderConfig = ExternalLibraryLoaderConfig(
+                    stem: 'wise_lover_core',
+                    ioDirectory: 'rust/Wise_Lover_Core/target/release/',
+                    webPrefix: 'pkg/',
+                  );
+                }
+                
+
+                abstract class RustLibApi extends BaseApi {
+                  void crateApiChatApiChatCancel();
+
+Future<ChatCapabilitiesDto> crateApiChatApiChatCapabilities();
+
+Future<void> crateApiChatApiChatCloseSession();
+
+Future<void> crateApiChatApiChatConfigure({required ChatProviderDto provider , required ChatConfigDto config });
+
+Future<void> crateApiChatApiChatConfigureFromFile({required String path , String? profile });
+
+Future<void> crateApiChatApiChatConfigureFromToml({required String configToml , String? profile , String? apiKey });
+
+ChatConfigDto crateApiChatApiChatDefaultConfig();
+
+ChatProviderDto crateApiChatApiChatDefaultProvider({required ChatProviderKindDto kind });
+
+Future<void> crateApiChatApiChatDeleteSession({required String storeDir , required String character , required String sessionId });
+
+Future<List<ChatMessageDto>> crateApiChatApiChatHistory();
+
+Future<List<ChatKeyUsageDto>> crateApiChatApiChatKeyUsage();
+
+Future<List<String>> crateApiChatApiChatListModels();
+
+Future<List<ChatSessionDto>> crateApiChatApiChatListSessions({required String storeDir , required String character });
+
+Future<ChatAppConfigDto> crateApiChatApiChatLoadConfig({required String path });
+
+Future<ChatModelProfileDto> crateApiChatApiChatModelProfileDtoDefault();
+
+Future<String> crateApiChatApiChatOpenSession({required String storeDir , required String character , String? sessionId });
+
+Future<void> crateApiChatApiChatReset();
+
+Future<void> crateApiChatApiChatSaveConfig({required String path , required ChatAppConfigDto config });
+
+Future<ChatReplyDto> crateApiChatApiChatSend({required String message , ChatInvokeModeDto? mode });
+
+Stream<String> crateApiChatApiChatSendStream({required String message });
+
+Future<String?> crateApiChatApiChatSummary();
+
+bool crateApiWiseLoverBootApiLive2DCoreCheckMocConsistency({required List<int> mocBytes });
+
+String crateApiWiseLoverBootApiLive2DCoreLatestMocVersion();
+
+String crateApiWiseLoverBootApiLive2DCoreLoader();
+
+String crateApiWiseLoverBootApiLive2DCoreMocVersion({required List<int> mocBytes });
+
+String crateApiWiseLoverBootApiLive2DCoreVersion();
+
+List<IdleEffectDto> crateApiLive2DModelApiLive2DDefaultIdleEffects();
+
+Future<LipSyncTimelineDto> crateApiLive2DModelApiLive2DLipSyncAnalyzeWav({required List<int> wav , required LipSyncConfigDto config });
+
+void crateApiLive2DModelApiLive2DModelApplyDisplayInfo({required BigInt handle , required String cdi3Json });
+
+void crateApiLive2DModelApiLive2DModelApplySettings({required BigInt handle , required String model3Json });
+
+void crateApiLive2DModelApiLive2DModelApplyUserData({required BigInt handle , required String userdata3Json });
+
+void crateApiLive2DModelApiLive2DModelClearEmotion({required BigInt handle });
+
+void crateApiLive2DModelApiLive2DModelClearParameterSmoothing({required BigInt handle , String? parameterId });
+
+void crateApiLive2DModelApiLive2DModelConfigureEyeBlink({required BigInt handle , required EyeBlinkConfigDto config });
+
+void crateApiLive2DModelApiLive2DModelConfigureLipSync({required BigInt handle , required LipSyncConfigDto config });
+
+void crateApiLive2DModelApiLive2DModelConfigureLookAt({required BigInt handle , required LookAtConfigDto config });
+
+void crateApiLive2DModelApiLive2DModelForceBlink({required BigInt handle });
+
+List<DrawableUserDataDto> crateApiLive2DModelApiLive2DModelGetDrawableUserData({required BigInt handle });
+
+List<ParameterGroupInfoDto> crateApiLive2DModelApiLive2DModelGetParameterGroups({required BigInt handle });
+
+List<String> crateApiLive2DModelApiLive2DModelGetParameterIds({required BigInt handle });
+
+List<ParameterInfoDto> crateApiLive2DModelApiLive2DModelGetParameterInfos({required BigInt handle });
+
+List<String> crateApiLive2DModelApiLive2DModelGetPartIds({required BigInt handle });
+
+List<PartInfoDto> crateApiLive2DModelApiLive2DModelGetPartInfos({required BigInt handle });
+
+void crateApiLive2DModelApiLive2DModelLipSyncPlay({required BigInt handle , required LipSyncTimelineDto timeline });
+
+void crateApiLive2DModelApiLive2DModelLipSyncPlayWav({required BigInt handle , required List<int> wav });
+
+void crateApiLive2DModelApiLive2DModelLipSyncPushPcm({required BigInt handle , required List<double> samples , required int sampleRate , required int channels });
+
+void crateApiLive2DModelApiLive2DModelLipSyncPushPcm16({required BigInt handle , required List<int> samples , required int sampleRate , required int channels });
+
+void crateApiLive2DModelApiLive2DModelLipSyncStop({required BigInt handle });
+
+BigInt crateApiLive2DModelApiLive2DModelLoad({required List<int> mocBytes });
+
+void crateApiLive2DModelApiLive2DModelLoadEmotionMap({required BigInt handle , required String mappingJson , String? modelName });
+
+void crateApiLive2DModelApiLive2DModelLoadExpression({required BigInt handle , required String name , required String exp3Json });
+
+void crateApiLive2DModelApiLive2DModelLoadMotion({required BigInt handle , required String name , required String motion3Json });
+
+VisemeTimelineDto crateApiLive2DModelApiLive2DModelPlayTextVisemes({required BigInt handle , required String text , required double charsPerSecond });
+
+void crateApiLive2DModelApiLive2DModelSetDrawOrderMode({required BigInt handle , required DrawOrderModeDto mode });
+
+EmotionResultDto crateApiLive2DModelApiLive2DModelSetEmotion({required BigInt handle , required String name , required double intensity , double? durationSeconds });
+
+void crateApiLive2DModelApiLive2DModelSetEmotionTiming({required BigInt handle , required double transitionSeconds , required double decaySeconds });
+
+void crateApiLive2DModelApiLive2DModelSetIdleEffects({required BigInt handle , required List<IdleEffectDto> effects });
+
+void crateApiLive2DModelApiLive2DModelSetLayer({required BigInt handle , required AnimationLayerDto layer , required bool enabled , required double weight , required LayerBlendDto blend });
+
+void crateApiLive2DModelApiLive2DModelSetLookTarget({required BigInt handle , required double x , required double y });
+
+void crateApiLive2DModelApiLive2DModelSetParameter({required BigInt handle , required String parameterId , required double value });
+
+void crateApiLive2DModelApiLive2DModelSetParameterOverride({required BigInt handle , required String parameterId , double? value });
+
+void crateApiLive2DModelApiLive2DModelSetParameterSmoothing({required BigInt handle , required String parameterId , required ParameterSmoothingDto smoothing });
+
+void crateApiLive2DModelApiLive2DModelSetPartOpacity({required BigInt handle , required String partId , required double opacity });
+
+void crateApiLive2DModelApiLive2DModelStartMotion({required BigInt handle , required String name , bool? looped });
+
+FrameDto crateApiLive2DModelApiLive2DModelStep({required BigInt handle });
+
+BatchedFrameDto crateApiLive2DModelApiLive2DModelStepBatched({required BigInt handle });
+
+void crateApiLive2DModelApiLive2DModelStopMotions({required BigInt handle , required bool fade });
+
+void crateApiLive2DModelApiLive2DModelUnload({required BigInt handle });
+
+void crateApiLive2DModelApiLive2DModelUpdate({required BigInt handle , required double deltaSeconds });
+
+ViewTransformDto crateApiLive2DModelApiLive2DModelViewTransform({required BigInt handle , required ViewportDto viewport });
+
+F32Array2 crateApiLive2DModelApiLive2DModelViewportToModel({required BigInt handle , required ViewportDto viewport , required double x , required double y });
+
+Stream<MotionEventDto> crateApiLive2DModelApiLive2DMotionEvents();
+
+VisemeTimelineDto crateApiLive2DModelApiLive2DTextVisemes({required String text , required double charsPerSecond });
+
+Future<List<TextureDto>> crateApiLive2DTextureApiLive2DTexturesDecode({required List<Uint8List> pngs , required TextureOptionsDto options });
+
+Future<List<TextureDto>> crateApiLive2DTextureApiLive2DTexturesLoadFromModel3({required String model3JsonPath , required TextureOptionsDto options });
+
+Future<void> crateApiWiseLoverBootApiWiseLoverBootInitApp();
+
+
+                }
+                
+
+                class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
+                  RustLibApiImpl({
+                    required super.handler,
+                    required super.wire,
+                    required super.generalizedFrbRustBinding,
+                    required super.portManager,
+                  });
+
+                  @override void crateApiChatApiChatCancel()  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 1)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiChatApiChatCancelConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiChatApiChatCancelConstMeta => const TaskConstMeta(
+            debugName: "chat_cancel",
+            argNames: [],
+        );
+        
+
+@override Future<ChatCapabilitiesDto> crateApiChatApiChatCapabilities()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 2, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_chat_capabilities_dto,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiChatApiChatCapabilitiesConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiChatApiChatCapabilitiesConstMeta => const TaskConstMeta(
+            debugName: "chat_capabilities",
+            argNames: [],
+        );
+        
+
+@override Future<void> crateApiChatApiChatCloseSession()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 3, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiChatApiChatCloseSessionConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiChatApiChatCloseSessionConstMeta => const TaskConstMeta(
+            debugName: "chat_close_session",
+            argNames: [],
+        );
+        
+
+@override Future<void> crateApiChatApiChatConfigure({required ChatProviderDto provider , required ChatConfigDto config })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_box_autoadd_chat_provider_dto(provider, serializer);
+sse_encode_box_autoadd_chat_config_dto(config, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 4, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiChatApiChatConfigureConstMeta,
+            argValues: [provider, config],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiChatApiChatConfigureConstMeta => const TaskConstMeta(
+            debugName: "chat_configure",
+            argNames: ["provider", "config"],
+        );
+        
+
+@override Future<void> crateApiChatApiChatConfigureFromFile({required String path , String? profile })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(path, serializer);
+sse_encode_opt_String(profile, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 5, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiChatApiChatConfigureFromFileConstMeta,
+            argValues: [path, profile],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiChatApiChatConfigureFromFileConstMeta => const TaskConstMeta(
+            debugName: "chat_configure_from_file",
+            argNames: ["path", "profile"],
+        );
+        
+
+@override Future<void> crateApiChatApiChatConfigureFromToml({required String configToml , String? profile , String? apiKey })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(configToml, serializer);
+sse_encode_opt_String(profile, serializer);
+sse_encode_opt_String(apiKey, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 6, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiChatApiChatConfigureFromTomlConstMeta,
+            argValues: [configToml, profile, apiKey],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiChatApiChatConfigureFromTomlConstMeta => const TaskConstMeta(
+            debugName: "chat_configure_from_toml",
+            argNames: ["configToml", "profile", "apiKey"],
+        );
+        
+
+@override ChatConfigDto crateApiChatApiChatDefaultConfig()  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 7)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_chat_config_dto,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiChatApiChatDefaultConfigConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiChatApiChatDefaultConfigConstMeta => const TaskConstMeta(
+            debugName: "chat_default_config",
+            argNames: [],
+        );
+        
+
+@override ChatProviderDto crateApiChatApiChatDefaultProvider({required ChatProviderKindDto kind })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_chat_provider_kind_dto(kind, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 8)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_chat_provider_dto,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiChatApiChatDefaultProviderConstMeta,
+            argValues: [kind],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiChatApiChatDefaultProviderConstMeta => const TaskConstMeta(
+            debugName: "chat_default_provider",
+            argNames: ["kind"],
+        );
+        
+
+@override Future<void> crateApiChatApiChatDeleteSession({required String storeDir , required String character , required String sessionId })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(storeDir, serializer);
+sse_encode_String(character, serializer);
+sse_encode_String(sessionId, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 9, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiChatApiChatDeleteSessionConstMeta,
+            argValues: [storeDir, character, sessionId],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiChatApiChatDeleteSessionConstMeta => const TaskConstMeta(
+            debugName: "chat_delete_session",
+            argNames: ["storeDir", "character", "sessionId"],
+        );
+        
+
+@override Future<List<ChatMessageDto>> crateApiChatApiChatHistory()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 10, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_chat_message_dto,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiChatApiChatHistoryConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiChatApiChatHistoryConstMeta => const TaskConstMeta(
+            debugName: "chat_history",
+            argNames: [],
+        );
+        
+
+@override Future<List<ChatKeyUsageDto>> crateApiChatApiChatKeyUsage()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 11, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_chat_key_usage_dto,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiChatApiChatKeyUsageConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiChatApiChatKeyUsageConstMeta => const TaskConstMeta(
+            debugName: "chat_key_usage",
+            argNames: [],
+        );
+        
+
+@override Future<List<String>> crateApiChatApiChatListModels()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 12, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_String,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiChatApiChatListModelsConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiChatApiChatListModelsConstMeta => const TaskConstMeta(
+            debugName: "chat_list_models",
+            argNames: [],
+        );
+        
+
+@override Future<List<ChatSessionDto>> crateApiChatApiChatListSessions({required String storeDir , required String character })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(storeDir, serializer);
+sse_encode_String(character, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 13, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_chat_session_dto,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiChatApiChatListSessionsConstMeta,
+            argValues: [storeDir, character],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiChatApiChatListSessionsConstMeta => const TaskConstMeta(
+            debugName: "chat_list_sessions",
+            argNames: ["storeDir", "character"],
+        );
+        
+
+@override Future<ChatAppConfigDto> crateApiChatApiChatLoadConfig({required String path })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(path, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 14, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_chat_app_config_dto,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiChatApiChatLoadConfigConstMeta,
+            argValues: [path],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiChatApiChatLoadConfigConstMeta => const TaskConstMeta(
+            debugName: "chat_load_config",
+            argNames: ["path"],
+        );
+        
+
+@override Future<ChatModelProfileDto> crateApiChatApiChatModelProfileDtoDefault()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 15, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_chat_model_profile_dto,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiChatApiChatModelProfileDtoDefaultConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiChatApiChatModelProfileDtoDefaultConstMeta => const TaskConstMeta(
+            debugName: "chat_model_profile_dto_default",
+            argNames: [],
+        );
+        
+
+@override Future<String> crateApiChatApiChatOpenSession({required String storeDir , required String character , String? sessionId })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(storeDir, serializer);
+sse_encode_String(character, serializer);
+sse_encode_opt_String(sessionId, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 16, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiChatApiChatOpenSessionConstMeta,
+            argValues: [storeDir, character, sessionId],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiChatApiChatOpenSessionConstMeta => const TaskConstMeta(
+            debugName: "chat_open_session",
+            argNames: ["storeDir", "character", "sessionId"],
+        );
+        
+
+@override Future<void> crateApiChatApiChatReset()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 17, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiChatApiChatResetConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiChatApiChatResetConstMeta => const TaskConstMeta(
+            debugName: "chat_reset",
+            argNames: [],
+        );
+        
+
+@override Future<void> crateApiChatApiChatSaveConfig({required String path , required ChatAppConfigDto config })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(path, serializer);
+sse_encode_box_autoadd_chat_app_config_dto(config, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 18, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiChatApiChatSaveConfigConstMeta,
+            argValues: [path, config],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiChatApiChatSaveConfigConstMeta => const TaskConstMeta(
+            debugName: "chat_save_config",
+            argNames: ["path", "config"],
+        );
+        
+
+@override Future<ChatReplyDto> crateApiChatApiChatSend({required String message , ChatInvokeModeDto? mode })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(message, serializer);
+sse_encode_opt_box_autoadd_chat_invoke_mode_dto(mode, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 19, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_chat_reply_dto,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiChatApiChatSendConstMeta,
+            argValues: [message, mode],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiChatApiChatSendConstMeta => const TaskConstMeta(
+            debugName: "chat_send",
+            argNames: ["message", "mode"],
+        );
+        
+
+@override Stream<String> crateApiChatApiChatSendStream({required String message })  { 
+            final sink = RustStreamSink<String>();
+            unawaited(handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(message, serializer);
+sse_encode_StreamSink_String_Sse(sink, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 20, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_chat_reply_dto,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiChatApiChatSendStreamConstMeta,
+            argValues: [message, sink],
+            apiImpl: this,
+        )));
+            return sink.stream;
+             }
+
+
+        TaskConstMeta get kCrateApiChatApiChatSendStreamConstMeta => const TaskConstMeta(
+            debugName: "chat_send_stream",
+            argNames: ["message", "sink"],
+        );
+        
+
+@override Future<String?> crateApiChatApiChatSummary()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 21, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_opt_String,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiChatApiChatSummaryConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiChatApiChatSummaryConstMeta => const TaskConstMeta(
+            debugName: "chat_summary",
+            argNames: [],
+        );
+        
+
+@override bool crateApiWiseLoverBootApiLive2DCoreCheckMocConsistency({required List<int> mocBytes })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_8_loose(mocBytes, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 22)!;
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_bool,
           decodeErrorData: null,
-        ),
-        constMeta:
-            kCrateApiWiseLoverBootApiLive2DCoreCheckMocConsistencyConstMeta,
-        argValues: [mocBytes],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta
-  get kCrateApiWiseLoverBootApiLive2DCoreCheckMocConsistencyConstMeta =>
-      const TaskConstMeta(
-        debugName: "live2d_core_check_moc_consistency",
-        argNames: ["mocBytes"],
-      );
-
-  @override
-  String crateApiWiseLoverBootApiLive2DCoreLatestMocVersion() {
-    return handler.executeSync(
-      SyncTask(
-        callFfi: () {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 2)!;
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiWiseLoverBootApiLive2DCoreCheckMocConsistencyConstMeta,
+            argValues: [mocBytes],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiWiseLoverBootApiLive2DCoreCheckMocConsistencyConstMeta => const TaskConstMeta(
+            debugName: "live2d_core_check_moc_consistency",
+            argNames: ["mocBytes"],
+        );
+        
+
+@override String crateApiWiseLoverBootApiLive2DCoreLatestMocVersion()  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 23)!;
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_String,
           decodeErrorData: null,
-        ),
-        constMeta: kCrateApiWiseLoverBootApiLive2DCoreLatestMocVersionConstMeta,
-        argValues: [],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta
-  get kCrateApiWiseLoverBootApiLive2DCoreLatestMocVersionConstMeta =>
-      const TaskConstMeta(
-        debugName: "live2d_core_latest_moc_version",
-        argNames: [],
-      );
-
-  @override
-  String crateApiWiseLoverBootApiLive2DCoreLoader() {
-    return handler.executeSync(
-      SyncTask(
-        callFfi: () {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 3)!;
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiWiseLoverBootApiLive2DCoreLatestMocVersionConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiWiseLoverBootApiLive2DCoreLatestMocVersionConstMeta => const TaskConstMeta(
+            debugName: "live2d_core_latest_moc_version",
+            argNames: [],
+        );
+        
+
+@override String crateApiWiseLoverBootApiLive2DCoreLoader()  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 24)!;
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_String,
           decodeErrorData: null,
-        ),
-        constMeta: kCrateApiWiseLoverBootApiLive2DCoreLoaderConstMeta,
-        argValues: [],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiWiseLoverBootApiLive2DCoreLoaderConstMeta =>
-      const TaskConstMeta(debugName: "live2d_core_loader", argNames: []);
-
-  @override
-  String crateApiWiseLoverBootApiLive2DCoreMocVersion({
-    required List<int> mocBytes,
-  }) {
-    return handler.executeSync(
-      SyncTask(
-        callFfi: () {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_list_prim_u_8_loose(mocBytes, serializer);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 4)!;
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiWiseLoverBootApiLive2DCoreLoaderConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiWiseLoverBootApiLive2DCoreLoaderConstMeta => const TaskConstMeta(
+            debugName: "live2d_core_loader",
+            argNames: [],
+        );
+        
+
+@override String crateApiWiseLoverBootApiLive2DCoreMocVersion({required List<int> mocBytes })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_8_loose(mocBytes, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 25)!;
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_String,
           decodeErrorData: sse_decode_String,
-        ),
-        constMeta: kCrateApiWiseLoverBootApiLive2DCoreMocVersionConstMeta,
-        argValues: [mocBytes],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiWiseLoverBootApiLive2DCoreMocVersionConstMeta =>
-      const TaskConstMeta(
-        debugName: "live2d_core_moc_version",
-        argNames: ["mocBytes"],
-      );
-
-  @override
-  String crateApiWiseLoverBootApiLive2DCoreVersion() {
-    return handler.executeSync(
-      SyncTask(
-        callFfi: () {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 5)!;
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiWiseLoverBootApiLive2DCoreMocVersionConstMeta,
+            argValues: [mocBytes],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiWiseLoverBootApiLive2DCoreMocVersionConstMeta => const TaskConstMeta(
+            debugName: "live2d_core_moc_version",
+            argNames: ["mocBytes"],
+        );
+        
+
+@override String crateApiWiseLoverBootApiLive2DCoreVersion()  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 26)!;
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_String,
           decodeErrorData: null,
-        ),
-        constMeta: kCrateApiWiseLoverBootApiLive2DCoreVersionConstMeta,
-        argValues: [],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiWiseLoverBootApiLive2DCoreVersionConstMeta =>
-      const TaskConstMeta(debugName: "live2d_core_version", argNames: []);
-
-  @override
-  List<String> crateApiLive2DModelApiLive2DModelGetParameterIds({
-    required BigInt handle,
-  }) {
-    return handler.executeSync(
-      SyncTask(
-        callFfi: () {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_u_64(handle, serializer);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 6)!;
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiWiseLoverBootApiLive2DCoreVersionConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiWiseLoverBootApiLive2DCoreVersionConstMeta => const TaskConstMeta(
+            debugName: "live2d_core_version",
+            argNames: [],
+        );
+        
+
+@override List<IdleEffectDto> crateApiLive2DModelApiLive2DDefaultIdleEffects()  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 27)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_idle_effect_dto,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiLive2DModelApiLive2DDefaultIdleEffectsConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiLive2DModelApiLive2DDefaultIdleEffectsConstMeta => const TaskConstMeta(
+            debugName: "live2d_default_idle_effects",
+            argNames: [],
+        );
+        
+
+@override Future<LipSyncTimelineDto> crateApiLive2DModelApiLive2DLipSyncAnalyzeWav({required List<int> wav , required LipSyncConfigDto config })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_8_loose(wav, serializer);
+sse_encode_box_autoadd_lip_sync_config_dto(config, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 28, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_lip_sync_timeline_dto,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiLive2DModelApiLive2DLipSyncAnalyzeWavConstMeta,
+            argValues: [wav, config],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiLive2DModelApiLive2DLipSyncAnalyzeWavConstMeta => const TaskConstMeta(
+            debugName: "live2d_lip_sync_analyze_wav",
+            argNames: ["wav", "config"],
+        );
+        
+
+@override void crateApiLive2DModelApiLive2DModelApplyDisplayInfo({required BigInt handle , required String cdi3Json })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_u_64(handle, serializer);
+sse_encode_String(cdi3Json, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 29)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiLive2DModelApiLive2DModelApplyDisplayInfoConstMeta,
+            argValues: [handle, cdi3Json],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiLive2DModelApiLive2DModelApplyDisplayInfoConstMeta => const TaskConstMeta(
+            debugName: "live2d_model_apply_display_info",
+            argNames: ["handle", "cdi3Json"],
+        );
+        
+
+@override void crateApiLive2DModelApiLive2DModelApplySettings({required BigInt handle , required String model3Json })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_u_64(handle, serializer);
+sse_encode_String(model3Json, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 30)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiLive2DModelApiLive2DModelApplySettingsConstMeta,
+            argValues: [handle, model3Json],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiLive2DModelApiLive2DModelApplySettingsConstMeta => const TaskConstMeta(
+            debugName: "live2d_model_apply_settings",
+            argNames: ["handle", "model3Json"],
+        );
+        
+
+@override void crateApiLive2DModelApiLive2DModelApplyUserData({required BigInt handle , required String userdata3Json })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_u_64(handle, serializer);
+sse_encode_String(userdata3Json, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 31)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiLive2DModelApiLive2DModelApplyUserDataConstMeta,
+            argValues: [handle, userdata3Json],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiLive2DModelApiLive2DModelApplyUserDataConstMeta => const TaskConstMeta(
+            debugName: "live2d_model_apply_user_data",
+            argNames: ["handle", "userdata3Json"],
+        );
+        
+
+@override void crateApiLive2DModelApiLive2DModelClearEmotion({required BigInt handle })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_u_64(handle, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 32)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiLive2DModelApiLive2DModelClearEmotionConstMeta,
+            argValues: [handle],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiLive2DModelApiLive2DModelClearEmotionConstMeta => const TaskConstMeta(
+            debugName: "live2d_model_clear_emotion",
+            argNames: ["handle"],
+        );
+        
+
+@override void crateApiLive2DModelApiLive2DModelClearParameterSmoothing({required BigInt handle , String? parameterId })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_u_64(handle, serializer);
+sse_encode_opt_String(parameterId, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 33)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiLive2DModelApiLive2DModelClearParameterSmoothingConstMeta,
+            argValues: [handle, parameterId],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiLive2DModelApiLive2DModelClearParameterSmoothingConstMeta => const TaskConstMeta(
+            debugName: "live2d_model_clear_parameter_smoothing",
+            argNames: ["handle", "parameterId"],
+        );
+        
+
+@override void crateApiLive2DModelApiLive2DModelConfigureEyeBlink({required BigInt handle , required EyeBlinkConfigDto config })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_u_64(handle, serializer);
+sse_encode_box_autoadd_eye_blink_config_dto(config, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 34)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiLive2DModelApiLive2DModelConfigureEyeBlinkConstMeta,
+            argValues: [handle, config],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiLive2DModelApiLive2DModelConfigureEyeBlinkConstMeta => const TaskConstMeta(
+            debugName: "live2d_model_configure_eye_blink",
+            argNames: ["handle", "config"],
+        );
+        
+
+@override void crateApiLive2DModelApiLive2DModelConfigureLipSync({required BigInt handle , required LipSyncConfigDto config })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_u_64(handle, serializer);
+sse_encode_box_autoadd_lip_sync_config_dto(config, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 35)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiLive2DModelApiLive2DModelConfigureLipSyncConstMeta,
+            argValues: [handle, config],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiLive2DModelApiLive2DModelConfigureLipSyncConstMeta => const TaskConstMeta(
+            debugName: "live2d_model_configure_lip_sync",
+            argNames: ["handle", "config"],
+        );
+        
+
+@override void crateApiLive2DModelApiLive2DModelConfigureLookAt({required BigInt handle , required LookAtConfigDto config })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_u_64(handle, serializer);
+sse_encode_box_autoadd_look_at_config_dto(config, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 36)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiLive2DModelApiLive2DModelConfigureLookAtConstMeta,
+            argValues: [handle, config],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiLive2DModelApiLive2DModelConfigureLookAtConstMeta => const TaskConstMeta(
+            debugName: "live2d_model_configure_look_at",
+            argNames: ["handle", "config"],
+        );
+        
+
+@override void crateApiLive2DModelApiLive2DModelForceBlink({required BigInt handle })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_u_64(handle, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 37)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiLive2DModelApiLive2DModelForceBlinkConstMeta,
+            argValues: [handle],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiLive2DModelApiLive2DModelForceBlinkConstMeta => const TaskConstMeta(
+            debugName: "live2d_model_force_blink",
+            argNames: ["handle"],
+        );
+        
+
+@override List<DrawableUserDataDto> crateApiLive2DModelApiLive2DModelGetDrawableUserData({required BigInt handle })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_u_64(handle, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 38)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_drawable_user_data_dto,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiLive2DModelApiLive2DModelGetDrawableUserDataConstMeta,
+            argValues: [handle],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiLive2DModelApiLive2DModelGetDrawableUserDataConstMeta => const TaskConstMeta(
+            debugName: "live2d_model_get_drawable_user_data",
+            argNames: ["handle"],
+        );
+        
+
+@override List<ParameterGroupInfoDto> crateApiLive2DModelApiLive2DModelGetParameterGroups({required BigInt handle })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_u_64(handle, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 39)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_parameter_group_info_dto,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiLive2DModelApiLive2DModelGetParameterGroupsConstMeta,
+            argValues: [handle],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiLive2DModelApiLive2DModelGetParameterGroupsConstMeta => const TaskConstMeta(
+            debugName: "live2d_model_get_parameter_groups",
+            argNames: ["handle"],
+        );
+        
+
+@override List<String> crateApiLive2DModelApiLive2DModelGetParameterIds({required BigInt handle })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_u_64(handle, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 40)!;
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_list_String,
           decodeErrorData: sse_decode_String,
-        ),
-        constMeta: kCrateApiLive2DModelApiLive2DModelGetParameterIdsConstMeta,
-        argValues: [handle],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta
-  get kCrateApiLive2DModelApiLive2DModelGetParameterIdsConstMeta =>
-      const TaskConstMeta(
-        debugName: "live2d_model_get_parameter_ids",
-        argNames: ["handle"],
-      );
-
-  @override
-  List<String> crateApiLive2DModelApiLive2DModelGetPartIds({
-    required BigInt handle,
-  }) {
-    return handler.executeSync(
-      SyncTask(
-        callFfi: () {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_u_64(handle, serializer);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 7)!;
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiLive2DModelApiLive2DModelGetParameterIdsConstMeta,
+            argValues: [handle],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiLive2DModelApiLive2DModelGetParameterIdsConstMeta => const TaskConstMeta(
+            debugName: "live2d_model_get_parameter_ids",
+            argNames: ["handle"],
+        );
+        
+
+@override List<ParameterInfoDto> crateApiLive2DModelApiLive2DModelGetParameterInfos({required BigInt handle })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_u_64(handle, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 41)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_parameter_info_dto,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiLive2DModelApiLive2DModelGetParameterInfosConstMeta,
+            argValues: [handle],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiLive2DModelApiLive2DModelGetParameterInfosConstMeta => const TaskConstMeta(
+            debugName: "live2d_model_get_parameter_infos",
+            argNames: ["handle"],
+        );
+        
+
+@override List<String> crateApiLive2DModelApiLive2DModelGetPartIds({required BigInt handle })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_u_64(handle, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 42)!;
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_list_String,
           decodeErrorData: sse_decode_String,
-        ),
-        constMeta: kCrateApiLive2DModelApiLive2DModelGetPartIdsConstMeta,
-        argValues: [handle],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiLive2DModelApiLive2DModelGetPartIdsConstMeta =>
-      const TaskConstMeta(
-        debugName: "live2d_model_get_part_ids",
-        argNames: ["handle"],
-      );
-
-  @override
-  BigInt crateApiLive2DModelApiLive2DModelLoad({required List<int> mocBytes}) {
-    return handler.executeSync(
-      SyncTask(
-        callFfi: () {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_list_prim_u_8_loose(mocBytes, serializer);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 8)!;
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiLive2DModelApiLive2DModelGetPartIdsConstMeta,
+            argValues: [handle],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiLive2DModelApiLive2DModelGetPartIdsConstMeta => const TaskConstMeta(
+            debugName: "live2d_model_get_part_ids",
+            argNames: ["handle"],
+        );
+        
+
+@override List<PartInfoDto> crateApiLive2DModelApiLive2DModelGetPartInfos({required BigInt handle })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_u_64(handle, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 43)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_part_info_dto,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiLive2DModelApiLive2DModelGetPartInfosConstMeta,
+            argValues: [handle],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiLive2DModelApiLive2DModelGetPartInfosConstMeta => const TaskConstMeta(
+            debugName: "live2d_model_get_part_infos",
+            argNames: ["handle"],
+        );
+        
+
+@override void crateApiLive2DModelApiLive2DModelLipSyncPlay({required BigInt handle , required LipSyncTimelineDto timeline })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_u_64(handle, serializer);
+sse_encode_box_autoadd_lip_sync_timeline_dto(timeline, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 44)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiLive2DModelApiLive2DModelLipSyncPlayConstMeta,
+            argValues: [handle, timeline],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiLive2DModelApiLive2DModelLipSyncPlayConstMeta => const TaskConstMeta(
+            debugName: "live2d_model_lip_sync_play",
+            argNames: ["handle", "timeline"],
+        );
+        
+
+@override void crateApiLive2DModelApiLive2DModelLipSyncPlayWav({required BigInt handle , required List<int> wav })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_u_64(handle, serializer);
+sse_encode_list_prim_u_8_loose(wav, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 45)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiLive2DModelApiLive2DModelLipSyncPlayWavConstMeta,
+            argValues: [handle, wav],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiLive2DModelApiLive2DModelLipSyncPlayWavConstMeta => const TaskConstMeta(
+            debugName: "live2d_model_lip_sync_play_wav",
+            argNames: ["handle", "wav"],
+        );
+        
+
+@override void crateApiLive2DModelApiLive2DModelLipSyncPushPcm({required BigInt handle , required List<double> samples , required int sampleRate , required int channels })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_u_64(handle, serializer);
+sse_encode_list_prim_f_32_loose(samples, serializer);
+sse_encode_u_32(sampleRate, serializer);
+sse_encode_u_16(channels, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 46)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiLive2DModelApiLive2DModelLipSyncPushPcmConstMeta,
+            argValues: [handle, samples, sampleRate, channels],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiLive2DModelApiLive2DModelLipSyncPushPcmConstMeta => const TaskConstMeta(
+            debugName: "live2d_model_lip_sync_push_pcm",
+            argNames: ["handle", "samples", "sampleRate", "channels"],
+        );
+        
+
+@override void crateApiLive2DModelApiLive2DModelLipSyncPushPcm16({required BigInt handle , required List<int> samples , required int sampleRate , required int channels })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_u_64(handle, serializer);
+sse_encode_list_prim_i_16_loose(samples, serializer);
+sse_encode_u_32(sampleRate, serializer);
+sse_encode_u_16(channels, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 47)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiLive2DModelApiLive2DModelLipSyncPushPcm16ConstMeta,
+            argValues: [handle, samples, sampleRate, channels],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiLive2DModelApiLive2DModelLipSyncPushPcm16ConstMeta => const TaskConstMeta(
+            debugName: "live2d_model_lip_sync_push_pcm16",
+            argNames: ["handle", "samples", "sampleRate", "channels"],
+        );
+        
+
+@override void crateApiLive2DModelApiLive2DModelLipSyncStop({required BigInt handle })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_u_64(handle, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 48)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiLive2DModelApiLive2DModelLipSyncStopConstMeta,
+            argValues: [handle],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiLive2DModelApiLive2DModelLipSyncStopConstMeta => const TaskConstMeta(
+            debugName: "live2d_model_lip_sync_stop",
+            argNames: ["handle"],
+        );
+        
+
+@override BigInt crateApiLive2DModelApiLive2DModelLoad({required List<int> mocBytes })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_8_loose(mocBytes, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 49)!;
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_u_64,
           decodeErrorData: sse_decode_String,
-        ),
-        constMeta: kCrateApiLive2DModelApiLive2DModelLoadConstMeta,
-        argValues: [mocBytes],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiLive2DModelApiLive2DModelLoadConstMeta =>
-      const TaskConstMeta(
-        debugName: "live2d_model_load",
-        argNames: ["mocBytes"],
-      );
-
-  @override
-  void crateApiLive2DModelApiLive2DModelSetParameter({
-    required BigInt handle,
-    required String parameterId,
-    required double value,
-  }) {
-    return handler.executeSync(
-      SyncTask(
-        callFfi: () {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_u_64(handle, serializer);
-          sse_encode_String(parameterId, serializer);
-          sse_encode_f_32(value, serializer);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 9)!;
-        },
-        codec: SseCodec(
-          decodeSuccessData: sse_decode_unit,
-          decodeErrorData: sse_decode_String,
-        ),
-        constMeta: kCrateApiLive2DModelApiLive2DModelSetParameterConstMeta,
-        argValues: [handle, parameterId, value],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiLive2DModelApiLive2DModelSetParameterConstMeta =>
-      const TaskConstMeta(
-        debugName: "live2d_model_set_parameter",
-        argNames: ["handle", "parameterId", "value"],
-      );
-
-  @override
-  void crateApiLive2DModelApiLive2DModelSetPartOpacity({
-    required BigInt handle,
-    required String partId,
-    required double opacity,
-  }) {
-    return handler.executeSync(
-      SyncTask(
-        callFfi: () {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_u_64(handle, serializer);
-          sse_encode_String(partId, serializer);
-          sse_encode_f_32(opacity, serializer);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 10)!;
-        },
-        codec: SseCodec(
-          decodeSuccessData: sse_decode_unit,
-          decodeErrorData: sse_decode_String,
-        ),
-        constMeta: kCrateApiLive2DModelApiLive2DModelSetPartOpacityConstMeta,
-        argValues: [handle, partId, opacity],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiLive2DModelApiLive2DModelSetPartOpacityConstMeta =>
-      const TaskConstMeta(
-        debugName: "live2d_model_set_part_opacity",
-        argNames: ["handle", "partId", "opacity"],
-      );
-
-  @override
-  FrameDto crateApiLive2DModelApiLive2DModelStep({required BigInt handle}) {
-    return handler.executeSync(
-      SyncTask(
-        callFfi: () {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_u_64(handle, serializer);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 11)!;
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiLive2DModelApiLive2DModelLoadConstMeta,
+            argValues: [mocBytes],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiLive2DModelApiLive2DModelLoadConstMeta => const TaskConstMeta(
+            debugName: "live2d_model_load",
+            argNames: ["mocBytes"],
+        );
+        
+
+@override void crateApiLive2DModelApiLive2DModelLoadEmotionMap({required BigInt handle , required String mappingJson , String? modelName })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_u_64(handle, serializer);
+sse_encode_String(mappingJson, serializer);
+sse_encode_opt_String(modelName, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 50)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiLive2DModelApiLive2DModelLoadEmotionMapConstMeta,
+            argValues: [handle, mappingJson, modelName],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiLive2DModelApiLive2DModelLoadEmotionMapConstMeta => const TaskConstMeta(
+            debugName: "live2d_model_load_emotion_map",
+            argNames: ["handle", "mappingJson", "modelName"],
+        );
+        
+
+@override void crateApiLive2DModelApiLive2DModelLoadExpression({required BigInt handle , required String name , required String exp3Json })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_u_64(handle, serializer);
+sse_encode_String(name, serializer);
+sse_encode_String(exp3Json, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 51)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiLive2DModelApiLive2DModelLoadExpressionConstMeta,
+            argValues: [handle, name, exp3Json],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiLive2DModelApiLive2DModelLoadExpressionConstMeta => const TaskConstMeta(
+            debugName: "live2d_model_load_expression",
+            argNames: ["handle", "name", "exp3Json"],
+        );
+        
+
+@override void crateApiLive2DModelApiLive2DModelLoadMotion({required BigInt handle , required String name , required String motion3Json })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_u_64(handle, serializer);
+sse_encode_String(name, serializer);
+sse_encode_String(motion3Json, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 52)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiLive2DModelApiLive2DModelLoadMotionConstMeta,
+            argValues: [handle, name, motion3Json],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiLive2DModelApiLive2DModelLoadMotionConstMeta => const TaskConstMeta(
+            debugName: "live2d_model_load_motion",
+            argNames: ["handle", "name", "motion3Json"],
+        );
+        
+
+@override VisemeTimelineDto crateApiLive2DModelApiLive2DModelPlayTextVisemes({required BigInt handle , required String text , required double charsPerSecond })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_u_64(handle, serializer);
+sse_encode_String(text, serializer);
+sse_encode_f_32(charsPerSecond, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 53)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_viseme_timeline_dto,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiLive2DModelApiLive2DModelPlayTextVisemesConstMeta,
+            argValues: [handle, text, charsPerSecond],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiLive2DModelApiLive2DModelPlayTextVisemesConstMeta => const TaskConstMeta(
+            debugName: "live2d_model_play_text_visemes",
+            argNames: ["handle", "text", "charsPerSecond"],
+        );
+        
+
+@override void crateApiLive2DModelApiLive2DModelSetDrawOrderMode({required BigInt handle , required DrawOrderModeDto mode })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_u_64(handle, serializer);
+sse_encode_draw_order_mode_dto(mode, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 54)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiLive2DModelApiLive2DModelSetDrawOrderModeConstMeta,
+            argValues: [handle, mode],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiLive2DModelApiLive2DModelSetDrawOrderModeConstMeta => const TaskConstMeta(
+            debugName: "live2d_model_set_draw_order_mode",
+            argNames: ["handle", "mode"],
+        );
+        
+
+@override EmotionResultDto crateApiLive2DModelApiLive2DModelSetEmotion({required BigInt handle , required String name , required double intensity , double? durationSeconds })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_u_64(handle, serializer);
+sse_encode_String(name, serializer);
+sse_encode_f_32(intensity, serializer);
+sse_encode_opt_box_autoadd_f_32(durationSeconds, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 55)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_emotion_result_dto,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiLive2DModelApiLive2DModelSetEmotionConstMeta,
+            argValues: [handle, name, intensity, durationSeconds],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiLive2DModelApiLive2DModelSetEmotionConstMeta => const TaskConstMeta(
+            debugName: "live2d_model_set_emotion",
+            argNames: ["handle", "name", "intensity", "durationSeconds"],
+        );
+        
+
+@override void crateApiLive2DModelApiLive2DModelSetEmotionTiming({required BigInt handle , required double transitionSeconds , required double decaySeconds })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_u_64(handle, serializer);
+sse_encode_f_32(transitionSeconds, serializer);
+sse_encode_f_32(decaySeconds, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 56)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiLive2DModelApiLive2DModelSetEmotionTimingConstMeta,
+            argValues: [handle, transitionSeconds, decaySeconds],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiLive2DModelApiLive2DModelSetEmotionTimingConstMeta => const TaskConstMeta(
+            debugName: "live2d_model_set_emotion_timing",
+            argNames: ["handle", "transitionSeconds", "decaySeconds"],
+        );
+        
+
+@override void crateApiLive2DModelApiLive2DModelSetIdleEffects({required BigInt handle , required List<IdleEffectDto> effects })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_u_64(handle, serializer);
+sse_encode_list_idle_effect_dto(effects, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 57)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiLive2DModelApiLive2DModelSetIdleEffectsConstMeta,
+            argValues: [handle, effects],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiLive2DModelApiLive2DModelSetIdleEffectsConstMeta => const TaskConstMeta(
+            debugName: "live2d_model_set_idle_effects",
+            argNames: ["handle", "effects"],
+        );
+        
+
+@override void crateApiLive2DModelApiLive2DModelSetLayer({required BigInt handle , required AnimationLayerDto layer , required bool enabled , required double weight , required LayerBlendDto blend })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_u_64(handle, serializer);
+sse_encode_animation_layer_dto(layer, serializer);
+sse_encode_bool(enabled, serializer);
+sse_encode_f_32(weight, serializer);
+sse_encode_layer_blend_dto(blend, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 58)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiLive2DModelApiLive2DModelSetLayerConstMeta,
+            argValues: [handle, layer, enabled, weight, blend],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiLive2DModelApiLive2DModelSetLayerConstMeta => const TaskConstMeta(
+            debugName: "live2d_model_set_layer",
+            argNames: ["handle", "layer", "enabled", "weight", "blend"],
+        );
+        
+
+@override void crateApiLive2DModelApiLive2DModelSetLookTarget({required BigInt handle , required double x , required double y })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_u_64(handle, serializer);
+sse_encode_f_32(x, serializer);
+sse_encode_f_32(y, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 59)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiLive2DModelApiLive2DModelSetLookTargetConstMeta,
+            argValues: [handle, x, y],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiLive2DModelApiLive2DModelSetLookTargetConstMeta => const TaskConstMeta(
+            debugName: "live2d_model_set_look_target",
+            argNames: ["handle", "x", "y"],
+        );
+        
+
+@override void crateApiLive2DModelApiLive2DModelSetParameter({required BigInt handle , required String parameterId , required double value })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_u_64(handle, serializer);
+sse_encode_String(parameterId, serializer);
+sse_encode_f_32(value, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 60)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiLive2DModelApiLive2DModelSetParameterConstMeta,
+            argValues: [handle, parameterId, value],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiLive2DModelApiLive2DModelSetParameterConstMeta => const TaskConstMeta(
+            debugName: "live2d_model_set_parameter",
+            argNames: ["handle", "parameterId", "value"],
+        );
+        
+
+@override void crateApiLive2DModelApiLive2DModelSetParameterOverride({required BigInt handle , required String parameterId , double? value })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_u_64(handle, serializer);
+sse_encode_String(parameterId, serializer);
+sse_encode_opt_box_autoadd_f_32(value, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 61)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiLive2DModelApiLive2DModelSetParameterOverrideConstMeta,
+            argValues: [handle, parameterId, value],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiLive2DModelApiLive2DModelSetParameterOverrideConstMeta => const TaskConstMeta(
+            debugName: "live2d_model_set_parameter_override",
+            argNames: ["handle", "parameterId", "value"],
+        );
+        
+
+@override void crateApiLive2DModelApiLive2DModelSetParameterSmoothing({required BigInt handle , required String parameterId , required ParameterSmoothingDto smoothing })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_u_64(handle, serializer);
+sse_encode_String(parameterId, serializer);
+sse_encode_box_autoadd_parameter_smoothing_dto(smoothing, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 62)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiLive2DModelApiLive2DModelSetParameterSmoothingConstMeta,
+            argValues: [handle, parameterId, smoothing],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiLive2DModelApiLive2DModelSetParameterSmoothingConstMeta => const TaskConstMeta(
+            debugName: "live2d_model_set_parameter_smoothing",
+            argNames: ["handle", "parameterId", "smoothing"],
+        );
+        
+
+@override void crateApiLive2DModelApiLive2DModelSetPartOpacity({required BigInt handle , required String partId , required double opacity })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_u_64(handle, serializer);
+sse_encode_String(partId, serializer);
+sse_encode_f_32(opacity, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 63)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiLive2DModelApiLive2DModelSetPartOpacityConstMeta,
+            argValues: [handle, partId, opacity],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiLive2DModelApiLive2DModelSetPartOpacityConstMeta => const TaskConstMeta(
+            debugName: "live2d_model_set_part_opacity",
+            argNames: ["handle", "partId", "opacity"],
+        );
+        
+
+@override void crateApiLive2DModelApiLive2DModelStartMotion({required BigInt handle , required String name , bool? looped })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_u_64(handle, serializer);
+sse_encode_String(name, serializer);
+sse_encode_opt_box_autoadd_bool(looped, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 64)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiLive2DModelApiLive2DModelStartMotionConstMeta,
+            argValues: [handle, name, looped],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiLive2DModelApiLive2DModelStartMotionConstMeta => const TaskConstMeta(
+            debugName: "live2d_model_start_motion",
+            argNames: ["handle", "name", "looped"],
+        );
+        
+
+@override FrameDto crateApiLive2DModelApiLive2DModelStep({required BigInt handle })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_u_64(handle, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 65)!;
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_frame_dto,
           decodeErrorData: sse_decode_String,
-        ),
-        constMeta: kCrateApiLive2DModelApiLive2DModelStepConstMeta,
-        argValues: [handle],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiLive2DModelApiLive2DModelStepConstMeta =>
-      const TaskConstMeta(debugName: "live2d_model_step", argNames: ["handle"]);
-
-  @override
-  void crateApiLive2DModelApiLive2DModelUnload({required BigInt handle}) {
-    return handler.executeSync(
-      SyncTask(
-        callFfi: () {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_u_64(handle, serializer);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 12)!;
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiLive2DModelApiLive2DModelStepConstMeta,
+            argValues: [handle],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiLive2DModelApiLive2DModelStepConstMeta => const TaskConstMeta(
+            debugName: "live2d_model_step",
+            argNames: ["handle"],
+        );
+        
+
+@override BatchedFrameDto crateApiLive2DModelApiLive2DModelStepBatched({required BigInt handle })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_u_64(handle, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 66)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_batched_frame_dto,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiLive2DModelApiLive2DModelStepBatchedConstMeta,
+            argValues: [handle],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiLive2DModelApiLive2DModelStepBatchedConstMeta => const TaskConstMeta(
+            debugName: "live2d_model_step_batched",
+            argNames: ["handle"],
+        );
+        
+
+@override void crateApiLive2DModelApiLive2DModelStopMotions({required BigInt handle , required bool fade })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_u_64(handle, serializer);
+sse_encode_bool(fade, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 67)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiLive2DModelApiLive2DModelStopMotionsConstMeta,
+            argValues: [handle, fade],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiLive2DModelApiLive2DModelStopMotionsConstMeta => const TaskConstMeta(
+            debugName: "live2d_model_stop_motions",
+            argNames: ["handle", "fade"],
+        );
+        
+
+@override void crateApiLive2DModelApiLive2DModelUnload({required BigInt handle })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_u_64(handle, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 68)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiLive2DModelApiLive2DModelUnloadConstMeta,
+            argValues: [handle],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiLive2DModelApiLive2DModelUnloadConstMeta => const TaskConstMeta(
+            debugName: "live2d_model_unload",
+            argNames: ["handle"],
+        );
+        
+
+@override void crateApiLive2DModelApiLive2DModelUpdate({required BigInt handle , required double deltaSeconds })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_u_64(handle, serializer);
+sse_encode_f_32(deltaSeconds, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 69)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiLive2DModelApiLive2DModelUpdateConstMeta,
+            argValues: [handle, deltaSeconds],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiLive2DModelApiLive2DModelUpdateConstMeta => const TaskConstMeta(
+            debugName: "live2d_model_update",
+            argNames: ["handle", "deltaSeconds"],
+        );
+        
+
+@override ViewTransformDto crateApiLive2DModelApiLive2DModelViewTransform({required BigInt handle , required ViewportDto viewport })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_u_64(handle, serializer);
+sse_encode_box_autoadd_viewport_dto(viewport, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 70)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_view_transform_dto,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiLive2DModelApiLive2DModelViewTransformConstMeta,
+            argValues: [handle, viewport],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiLive2DModelApiLive2DModelViewTransformConstMeta => const TaskConstMeta(
+            debugName: "live2d_model_view_transform",
+            argNames: ["handle", "viewport"],
+        );
+        
+
+@override F32Array2 crateApiLive2DModelApiLive2DModelViewportToModel({required BigInt handle , required ViewportDto viewport , required double x , required double y })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_u_64(handle, serializer);
+sse_encode_box_autoadd_viewport_dto(viewport, serializer);
+sse_encode_f_32(x, serializer);
+sse_encode_f_32(y, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 71)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_f_32_array_2,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiLive2DModelApiLive2DModelViewportToModelConstMeta,
+            argValues: [handle, viewport, x, y],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiLive2DModelApiLive2DModelViewportToModelConstMeta => const TaskConstMeta(
+            debugName: "live2d_model_viewport_to_model",
+            argNames: ["handle", "viewport", "x", "y"],
+        );
+        
+
+@override Stream<MotionEventDto> crateApiLive2DModelApiLive2DMotionEvents()  { 
+            final sink = RustStreamSink<MotionEventDto>();
+            unawaited(handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_StreamSink_motion_event_dto_Sse(sink, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 72, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_unit,
           decodeErrorData: null,
-        ),
-        constMeta: kCrateApiLive2DModelApiLive2DModelUnloadConstMeta,
-        argValues: [handle],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiLive2DModelApiLive2DModelUnloadConstMeta =>
-      const TaskConstMeta(
-        debugName: "live2d_model_unload",
-        argNames: ["handle"],
-      );
-
-  @override
-  Future<void> crateApiWiseLoverBootApiWiseLoverBootInitApp() {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 13,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiLive2DModelApiLive2DMotionEventsConstMeta,
+            argValues: [sink],
+            apiImpl: this,
+        )));
+            return sink.stream;
+             }
+
+
+        TaskConstMeta get kCrateApiLive2DModelApiLive2DMotionEventsConstMeta => const TaskConstMeta(
+            debugName: "live2d_motion_events",
+            argNames: ["sink"],
+        );
+        
+
+@override VisemeTimelineDto crateApiLive2DModelApiLive2DTextVisemes({required String text , required double charsPerSecond })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(text, serializer);
+sse_encode_f_32(charsPerSecond, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 73)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_viseme_timeline_dto,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiLive2DModelApiLive2DTextVisemesConstMeta,
+            argValues: [text, charsPerSecond],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiLive2DModelApiLive2DTextVisemesConstMeta => const TaskConstMeta(
+            debugName: "live2d_text_visemes",
+            argNames: ["text", "charsPerSecond"],
+        );
+        
+
+@override Future<List<TextureDto>> crateApiLive2DTextureApiLive2DTexturesDecode({required List<Uint8List> pngs , required TextureOptionsDto options })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_list_prim_u_8_strict(pngs, serializer);
+sse_encode_box_autoadd_texture_options_dto(options, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 74, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_texture_dto,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiLive2DTextureApiLive2DTexturesDecodeConstMeta,
+            argValues: [pngs, options],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiLive2DTextureApiLive2DTexturesDecodeConstMeta => const TaskConstMeta(
+            debugName: "live2d_textures_decode",
+            argNames: ["pngs", "options"],
+        );
+        
+
+@override Future<List<TextureDto>> crateApiLive2DTextureApiLive2DTexturesLoadFromModel3({required String model3JsonPath , required TextureOptionsDto options })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(model3JsonPath, serializer);
+sse_encode_box_autoadd_texture_options_dto(options, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 75, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_texture_dto,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiLive2DTextureApiLive2DTexturesLoadFromModel3ConstMeta,
+            argValues: [model3JsonPath, options],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiLive2DTextureApiLive2DTexturesLoadFromModel3ConstMeta => const TaskConstMeta(
+            debugName: "live2d_textures_load_from_model3",
+            argNames: ["model3JsonPath", "options"],
+        );
+        
+
+@override Future<void> crateApiWiseLoverBootApiWiseLoverBootInitApp()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 76, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_unit,
           decodeErrorData: null,
-        ),
-        constMeta: kCrateApiWiseLoverBootApiWiseLoverBootInitAppConstMeta,
-        argValues: [],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiWiseLoverBootApiWiseLoverBootInitAppConstMeta =>
-      const TaskConstMeta(debugName: "wise_lover_boot_init_app", argNames: []);
-
-  @protected
-  String dco_decode_String(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as String;
-  }
-
-  @protected
-  bool dco_decode_bool(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as bool;
-  }
-
-  @protected
-  DrawableFrameDto dco_decode_drawable_frame_dto(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 10)
-      throw Exception('unexpected arr length: expect 10 but see ${arr.length}');
-    return DrawableFrameDto(
-      index: dco_decode_u_32(arr[0]),
-      textureIndex: dco_decode_u_32(arr[1]),
-      vertices: dco_decode_list_prim_f_32_strict(arr[2]),
-      uvs: dco_decode_list_prim_f_32_strict(arr[3]),
-      indices: dco_decode_list_prim_u_16_strict(arr[4]),
-      masks: dco_decode_list_prim_u_16_strict(arr[5]),
-      opacity: dco_decode_f_32(arr[6]),
-      multiplyColor: dco_decode_f_32_array_4(arr[7]),
-      screenColor: dco_decode_f_32_array_4(arr[8]),
-      drawOrder: dco_decode_i_32(arr[9]),
-    );
-  }
-
-  @protected
-  double dco_decode_f_32(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as double;
-  }
-
-  @protected
-  F32Array4 dco_decode_f_32_array_4(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return F32Array4(dco_decode_list_prim_f_32_strict(raw));
-  }
-
-  @protected
-  FrameDto dco_decode_frame_dto(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 3)
-      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
-    return FrameDto(
-      canvasWidth: dco_decode_f_32(arr[0]),
-      canvasHeight: dco_decode_f_32(arr[1]),
-      drawables: dco_decode_list_drawable_frame_dto(arr[2]),
-    );
-  }
-
-  @protected
-  int dco_decode_i_32(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as int;
-  }
-
-  @protected
-  List<String> dco_decode_list_String(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return (raw as List<dynamic>).map(dco_decode_String).toList();
-  }
-
-  @protected
-  List<DrawableFrameDto> dco_decode_list_drawable_frame_dto(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return (raw as List<dynamic>).map(dco_decode_drawable_frame_dto).toList();
-  }
-
-  @protected
-  Float32List dco_decode_list_prim_f_32_strict(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as Float32List;
-  }
-
-  @protected
-  Uint16List dco_decode_list_prim_u_16_strict(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as Uint16List;
-  }
-
-  @protected
-  List<int> dco_decode_list_prim_u_8_loose(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as List<int>;
-  }
-
-  @protected
-  Uint8List dco_decode_list_prim_u_8_strict(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as Uint8List;
-  }
-
-  @protected
-  int dco_decode_u_16(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as int;
-  }
-
-  @protected
-  int dco_decode_u_32(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as int;
-  }
-
-  @protected
-  BigInt dco_decode_u_64(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return dcoDecodeU64(raw);
-  }
-
-  @protected
-  int dco_decode_u_8(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as int;
-  }
-
-  @protected
-  void dco_decode_unit(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return;
-  }
-
-  @protected
-  String sse_decode_String(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var inner = sse_decode_list_prim_u_8_strict(deserializer);
-    return utf8.decoder.convert(inner);
-  }
-
-  @protected
-  bool sse_decode_bool(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getUint8() != 0;
-  }
-
-  @protected
-  DrawableFrameDto sse_decode_drawable_frame_dto(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_index = sse_decode_u_32(deserializer);
-    var var_textureIndex = sse_decode_u_32(deserializer);
-    var var_vertices = sse_decode_list_prim_f_32_strict(deserializer);
-    var var_uvs = sse_decode_list_prim_f_32_strict(deserializer);
-    var var_indices = sse_decode_list_prim_u_16_strict(deserializer);
-    var var_masks = sse_decode_list_prim_u_16_strict(deserializer);
-    var var_opacity = sse_decode_f_32(deserializer);
-    var var_multiplyColor = sse_decode_f_32_array_4(deserializer);
-    var var_screenColor = sse_decode_f_32_array_4(deserializer);
-    var var_drawOrder = sse_decode_i_32(deserializer);
-    return DrawableFrameDto(
-      index: var_index,
-      textureIndex: var_textureIndex,
-      vertices: var_vertices,
-      uvs: var_uvs,
-      indices: var_indices,
-      masks: var_masks,
-      opacity: var_opacity,
-      multiplyColor: var_multiplyColor,
-      screenColor: var_screenColor,
-      drawOrder: var_drawOrder,
-    );
-  }
-
-  @protected
-  double sse_decode_f_32(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getFloat32();
-  }
-
-  @protected
-  F32Array4 sse_decode_f_32_array_4(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var inner = sse_decode_list_prim_f_32_strict(deserializer);
-    return F32Array4(inner);
-  }
-
-  @protected
-  FrameDto sse_decode_frame_dto(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_canvasWidth = sse_decode_f_32(deserializer);
-    var var_canvasHeight = sse_decode_f_32(deserializer);
-    var var_drawables = sse_decode_list_drawable_frame_dto(deserializer);
-    return FrameDto(
-      canvasWidth: var_canvasWidth,
-      canvasHeight: var_canvasHeight,
-      drawables: var_drawables,
-    );
-  }
-
-  @protected
-  int sse_decode_i_32(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getInt32();
-  }
-
-  @protected
-  List<String> sse_decode_list_String(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <String>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_String(deserializer));
-    }
-    return ans_;
-  }
-
-  @protected
-  List<DrawableFrameDto> sse_decode_list_drawable_frame_dto(
-    SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <DrawableFrameDto>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_drawable_frame_dto(deserializer));
-    }
-    return ans_;
-  }
-
-  @protected
-  Float32List sse_decode_list_prim_f_32_strict(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var len_ = sse_decode_i_32(deserializer);
-    return deserializer.buffer.getFloat32List(len_);
-  }
-
-  @protected
-  Uint16List sse_decode_list_prim_u_16_strict(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var len_ = sse_decode_i_32(deserializer);
-    return deserializer.buffer.getUint16List(len_);
-  }
-
-  @protected
-  List<int> sse_decode_list_prim_u_8_loose(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var len_ = sse_decode_i_32(deserializer);
-    return deserializer.buffer.getUint8List(len_);
-  }
-
-  @protected
-  Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var len_ = sse_decode_i_32(deserializer);
-    return deserializer.buffer.getUint8List(len_);
-  }
-
-  @protected
-  int sse_decode_u_16(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getUint16();
-  }
-
-  @protected
-  int sse_decode_u_32(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getUint32();
-  }
-
-  @protected
-  BigInt sse_decode_u_64(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getBigUint64();
-  }
-
-  @protected
-  int sse_decode_u_8(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getUint8();
-  }
-
-  @protected
-  void sse_decode_unit(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-  }
-
-  @protected
-  void sse_encode_String(String self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_list_prim_u_8_strict(utf8.encoder.convert(self), serializer);
-  }
-
-  @protected
-  void sse_encode_bool(bool self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putUint8(self ? 1 : 0);
-  }
-
-  @protected
-  void sse_encode_drawable_frame_dto(
-    DrawableFrameDto self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_u_32(self.index, serializer);
-    sse_encode_u_32(self.textureIndex, serializer);
-    sse_encode_list_prim_f_32_strict(self.vertices, serializer);
-    sse_encode_list_prim_f_32_strict(self.uvs, serializer);
-    sse_encode_list_prim_u_16_strict(self.indices, serializer);
-    sse_encode_list_prim_u_16_strict(self.masks, serializer);
-    sse_encode_f_32(self.opacity, serializer);
-    sse_encode_f_32_array_4(self.multiplyColor, serializer);
-    sse_encode_f_32_array_4(self.screenColor, serializer);
-    sse_encode_i_32(self.drawOrder, serializer);
-  }
-
-  @protected
-  void sse_encode_f_32(double self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putFloat32(self);
-  }
-
-  @protected
-  void sse_encode_f_32_array_4(F32Array4 self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_list_prim_f_32_strict(self.inner, serializer);
-  }
-
-  @protected
-  void sse_encode_frame_dto(FrameDto self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_f_32(self.canvasWidth, serializer);
-    sse_encode_f_32(self.canvasHeight, serializer);
-    sse_encode_list_drawable_frame_dto(self.drawables, serializer);
-  }
-
-  @protected
-  void sse_encode_i_32(int self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putInt32(self);
-  }
-
-  @protected
-  void sse_encode_list_String(List<String> self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_String(item, serializer);
-    }
-  }
-
-  @protected
-  void sse_encode_list_drawable_frame_dto(
-    List<DrawableFrameDto> self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_drawable_frame_dto(item, serializer);
-    }
-  }
-
-  @protected
-  void sse_encode_list_prim_f_32_strict(
-    Float32List self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    serializer.buffer.putFloat32List(self);
-  }
-
-  @protected
-  void sse_encode_list_prim_u_16_strict(
-    Uint16List self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    serializer.buffer.putUint16List(self);
-  }
-
-  @protected
-  void sse_encode_list_prim_u_8_loose(
-    List<int> self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    serializer.buffer.putUint8List(
-      self is Uint8List ? self : Uint8List.fromList(self),
-    );
-  }
-
-  @protected
-  void sse_encode_list_prim_u_8_strict(
-    Uint8List self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    serializer.buffer.putUint8List(self);
-  }
-
-  @protected
-  void sse_encode_u_16(int self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putUint16(self);
-  }
-
-  @protected
-  void sse_encode_u_32(int self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putUint32(self);
-  }
-
-  @protected
-  void sse_encode_u_64(BigInt self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putBigUint64(self);
-  }
-
-  @protected
-  void sse_encode_u_8(int self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putUint8(self);
-  }
-
-  @protected
-  void sse_encode_unit(void self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-  }
-}
+        )
+        ,
+            constMeta: kCrateApiWiseLoverBootApiWiseLoverBootInitAppConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiWiseLoverBootApiWiseLoverBootInitAppConstMeta => const TaskConstMeta(
+            debugName: "wise_lover_boot_init_app",
+            argNames: [],
+        );
+        
+
+
+
+                  @protected AnyhowException dco_decode_AnyhowException(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return AnyhowException(raw as String); }
+
+@protected RustStreamSink<String> dco_decode_StreamSink_String_Sse(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+throw UnimplementedError(); }
+
+@protected RustStreamSink<MotionEventDto> dco_decode_StreamSink_motion_event_dto_Sse(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+throw UnimplementedError(); }
+
+@protected String dco_decode_String(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as String; }
+
+@protected AnimationLayerDto dco_decode_animation_layer_dto(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return AnimationLayerDto.values[raw as int]; }
+
+@protected BatchedFrameDto dco_decode_batched_frame_dto(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 9) throw Exception('unexpected arr length: expect 9 but see ${arr.length}');
+                return BatchedFrameDto(canvasWidth: dco_decode_f_32(arr[0]),
+canvasHeight: dco_decode_f_32(arr[1]),
+canvasOriginX: dco_decode_f_32(arr[2]),
+canvasOriginY: dco_decode_f_32(arr[3]),
+pixelsPerUnit: dco_decode_f_32(arr[4]),
+batches: dco_decode_list_draw_batch_dto(arr[5]),
+maskTextureCount: dco_decode_u_32(arr[6]),
+maskGroups: dco_decode_list_mask_group_dto(arr[7]),
+maskBatches: dco_decode_list_draw_batch_dto(arr[8]),); }
+
+@protected BlendModeDto dco_decode_blend_mode_dto(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return BlendModeDto.values[raw as int]; }
+
+@protected BlinkIntervalDto dco_decode_blink_interval_dto(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return BlinkIntervalDto.values[raw as int]; }
+
+@protected bool dco_decode_bool(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as bool; }
+
+@protected bool dco_decode_box_autoadd_bool(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as bool; }
+
+@protected ChatAppConfigDto dco_decode_box_autoadd_chat_app_config_dto(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_chat_app_config_dto(raw); }
+
+@protected ChatConfigDto dco_decode_box_autoadd_chat_config_dto(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_chat_config_dto(raw); }
+
+@protected ChatInvokeModeDto dco_decode_box_autoadd_chat_invoke_mode_dto(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_chat_invoke_mode_dto(raw); }
+
+@protected ChatProviderDto dco_decode_box_autoadd_chat_provider_dto(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_chat_provider_dto(raw); }
+
+@protected EyeBlinkConfigDto dco_decode_box_autoadd_eye_blink_config_dto(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_eye_blink_config_dto(raw); }
+
+@protected double dco_decode_box_autoadd_f_32(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as double; }
+
+@protected double dco_decode_box_autoadd_f_64(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as double; }
+
+@protected PlatformInt64 dco_decode_box_autoadd_i_64(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_i_64(raw); }
+
+@protected LipSyncConfigDto dco_decode_box_autoadd_lip_sync_config_dto(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_lip_sync_config_dto(raw); }
+
+@protected LipSyncTimelineDto dco_decode_box_autoadd_lip_sync_timeline_dto(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_lip_sync_timeline_dto(raw); }
+
+@protected LookAtConfigDto dco_decode_box_autoadd_look_at_config_dto(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_look_at_config_dto(raw); }
+
+@protected ParameterSmoothingDto dco_decode_box_autoadd_parameter_smoothing_dto(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_parameter_smoothing_dto(raw); }
+
+@protected TextureOptionsDto dco_decode_box_autoadd_texture_options_dto(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_texture_options_dto(raw); }
+
+@protected int dco_decode_box_autoadd_u_32(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as int; }
+
+@protected BigInt dco_decode_box_autoadd_u_64(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_u_64(raw); }
+
+@protected ViewportDto dco_decode_box_autoadd_viewport_dto(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_viewport_dto(raw); }
+
+@protected ChatAppConfigDto dco_decode_chat_app_config_dto(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 4) throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+                return ChatAppConfigDto(defaultProfile: dco_decode_opt_String(arr[0]),
+provider: dco_decode_chat_provider_dto(arr[1]),
+defaults: dco_decode_chat_model_profile_dto(arr[2]),
+profiles: dco_decode_list_chat_profile_dto(arr[3]),); }
+
+@protected ChatCapabilitiesDto dco_decode_chat_capabilities_dto(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+                return ChatCapabilitiesDto(streaming: dco_decode_bool(arr[0]),
+asyncTask: dco_decode_bool(arr[1]),
+listModels: dco_decode_bool(arr[2]),); }
+
+@protected ChatConfigDto dco_decode_chat_config_dto(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 8) throw Exception('unexpected arr length: expect 8 but see ${arr.length}');
+                return ChatConfigDto(languageModel: dco_decode_String(arr[0]),
+systemContent: dco_decode_opt_String(arr[1]),
+temperature: dco_decode_f_64(arr[2]),
+topP: dco_decode_f_64(arr[3]),
+maxHistory: dco_decode_u_32(arr[4]),
+contextTokens: dco_decode_opt_box_autoadd_u_32(arr[5]),
+replyTokens: dco_decode_opt_box_autoadd_u_32(arr[6]),
+summarize: dco_decode_bool(arr[7]),); }
+
+@protected ChatInvokeModeDto dco_decode_chat_invoke_mode_dto(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return ChatInvokeModeDto.values[raw as int]; }
+
+@protected ChatKeySelectionDto dco_decode_chat_key_selection_dto(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return ChatKeySelectionDto.values[raw as int]; }
+
+@protected ChatKeyUsageDto dco_decode_chat_key_usage_dto(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 7) throw Exception('unexpected arr length: expect 7 but see ${arr.length}');
+                return ChatKeyUsageDto(label: dco_decode_String(arr[0]),
+requests: dco_decode_u_64(arr[1]),
+successes: dco_decode_u_64(arr[2]),
+failures: dco_decode_u_64(arr[3]),
+rateLimited: dco_decode_u_64(arr[4]),
+unauthorized: dco_decode_u_64(arr[5]),
+disabledForSeconds: dco_decode_opt_box_autoadd_u_32(arr[6]),); }
+
+@protected ChatMessageDto dco_decode_chat_message_dto(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+                return ChatMessageDto(role: dco_decode_chat_role_dto(arr[0]),
+content: dco_decode_String(arr[1]),); }
+
+@protected ChatModelProfileDto dco_decode_chat_model_profile_dto(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 8) throw Exception('unexpected arr length: expect 8 but see ${arr.length}');
+                return ChatModelProfileDto(languageModel: dco_decode_opt_String(arr[0]),
+systemContent: dco_decode_opt_String(arr[1]),
+temperature: dco_decode_opt_box_autoadd_f_64(arr[2]),
+topP: dco_decode_opt_box_autoadd_f_64(arr[3]),
+maxHistory: dco_decode_opt_box_autoadd_u_32(arr[4]),
+contextTokens: dco_decode_opt_box_autoadd_u_32(arr[5]),
+replyTokens: dco_decode_opt_box_autoadd_u_32(arr[6]),
+summarize: dco_decode_opt_box_autoadd_bool(arr[7]),); }
+
+@protected ChatProfileDto dco_decode_chat_profile_dto(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+                return ChatProfileDto(name: dco_decode_String(arr[0]),
+profile: dco_decode_chat_model_profile_dto(arr[1]),); }
+
+@protected ChatProviderDto dco_decode_chat_provider_dto(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 10) throw Exception('unexpected arr length: expect 10 but see ${arr.length}');
+                return ChatProviderDto(kind: dco_decode_chat_provider_kind_dto(arr[0]),
+baseUrl: dco_decode_opt_String(arr[1]),
+apiKey: dco_decode_opt_String(arr[2]),
+apiKeys: dco_decode_list_String(arr[3]),
+keySelection: dco_decode_chat_key_selection_dto(arr[4]),
+pollIntervalMs: dco_decode_u_32(arr[5]),
+maxPollIntervalMs: dco_decode_u_32(arr[6]),
+taskTimeoutSeconds: dco_decode_u_32(arr[7]),
+pollMultiplier: dco_decode_f_64(arr[8]),
+pollJitter: dco_decode_f_64(arr[9]),); }
+
+@protected ChatProviderKindDto dco_decode_chat_provider_kind_dto(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return ChatProviderKindDto.values[raw as int]; }
+
+@protected ChatReplyDto dco_decode_chat_reply_dto(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+                return ChatReplyDto(content: dco_decode_String(arr[0]),
+warnings: dco_decode_list_String(arr[1]),); }
+
+@protected ChatRoleDto dco_decode_chat_role_dto(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return ChatRoleDto.values[raw as int]; }
+
+@protected ChatSessionDto dco_decode_chat_session_dto(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 5) throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+                return ChatSessionDto(id: dco_decode_String(arr[0]),
+messageCount: dco_decode_u_32(arr[1]),
+tokens: dco_decode_u_64(arr[2]),
+createdAtMs: dco_decode_opt_box_autoadd_i_64(arr[3]),
+updatedAtMs: dco_decode_opt_box_autoadd_i_64(arr[4]),); }
+
+@protected DrawBatchDto dco_decode_draw_batch_dto(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 10) throw Exception('unexpected arr length: expect 10 but see ${arr.length}');
+                return DrawBatchDto(textureIndex: dco_decode_u_32(arr[0]),
+blendMode: dco_decode_blend_mode_dto(arr[1]),
+maskGroup: dco_decode_opt_box_autoadd_u_32(arr[2]),
+invertedMask: dco_decode_bool(arr[3]),
+drawables: dco_decode_list_prim_u_32_strict(arr[4]),
+vertices: dco_decode_list_prim_f_32_strict(arr[5]),
+uvs: dco_decode_list_prim_f_32_strict(arr[6]),
+indices: dco_decode_list_prim_u_16_strict(arr[7]),
+multiplyColors: dco_decode_list_prim_f_32_strict(arr[8]),
+screenColors: dco_decode_list_prim_f_32_strict(arr[9]),); }
+
+@protected DrawOrderModeDto dco_decode_draw_order_mode_dto(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return DrawOrderModeDto.values[raw as int]; }
+
+@protected DrawableFrameDto dco_decode_drawable_frame_dto(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 11) throw Exception('unexpected arr length: expect 11 but see ${arr.length}');
+                return DrawableFrameDto(index: dco_decode_u_32(arr[0]),
+textureIndex: dco_decode_u_32(arr[1]),
+vertices: dco_decode_list_prim_f_32_strict(arr[2]),
+uvs: dco_decode_list_prim_f_32_strict(arr[3]),
+indices: dco_decode_list_prim_u_16_strict(arr[4]),
+masks: dco_decode_list_prim_u_16_strict(arr[5]),
+maskGroup: dco_decode_opt_box_autoadd_u_32(arr[6]),
+opacity: dco_decode_f_32(arr[7]),
+multiplyColor: dco_decode_f_32_array_4(arr[8]),
+screenColor: dco_decode_f_32_array_4(arr[9]),
+drawOrder: dco_decode_i_32(arr[10]),); }
+
+@protected DrawableUserDataDto dco_decode_drawable_user_data_dto(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+                return DrawableUserDataDto(drawableIndex: dco_decode_u_32(arr[0]),
+drawableId: dco_decode_String(arr[1]),
+value: dco_decode_String(arr[2]),); }
+
+@protected EmotionResultDto dco_decode_emotion_result_dto(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+                return EmotionResultDto(expressions: dco_decode_list_String(arr[0]),
+startedMotion: dco_decode_opt_String(arr[1]),); }
+
+@protected EyeBlinkConfigDto dco_decode_eye_blink_config_dto(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 12) throw Exception('unexpected arr length: expect 12 but see ${arr.length}');
+                return EyeBlinkConfigDto(enabled: dco_decode_bool(arr[0]),
+interval: dco_decode_blink_interval_dto(arr[1]),
+minSeconds: dco_decode_f_32(arr[2]),
+maxSeconds: dco_decode_f_32(arr[3]),
+meanSeconds: dco_decode_f_32(arr[4]),
+closingSeconds: dco_decode_f_32(arr[5]),
+closedSeconds: dco_decode_f_32(arr[6]),
+openingSeconds: dco_decode_f_32(arr[7]),
+doubleBlinkProbability: dco_decode_f_32(arr[8]),
+doubleBlinkGapSeconds: dco_decode_f_32(arr[9]),
+parameterIds: dco_decode_list_String(arr[10]),
+seed: dco_decode_opt_box_autoadd_u_64(arr[11]),); }
+
+@protected double dco_decode_f_32(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as double; }
+
+@protected F32Array16 dco_decode_f_32_array_16(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return F32Array16(dco_decode_list_prim_f_32_strict(raw)); }
+
+@protected F32Array2 dco_decode_f_32_array_2(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return F32Array2(dco_decode_list_prim_f_32_strict(raw)); }
+
+@protected F32Array4 dco_decode_f_32_array_4(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return F32Array4(dco_decode_list_prim_f_32_strict(raw)); }
+
+@protected double dco_decode_f_64(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as double; }
+
+@protected FrameDto dco_decode_frame_dto(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 8) throw Exception('unexpected arr length: expect 8 but see ${arr.length}');
+                return FrameDto(canvasWidth: dco_decode_f_32(arr[0]),
+canvasHeight: dco_decode_f_32(arr[1]),
+canvasOriginX: dco_decode_f_32(arr[2]),
+canvasOriginY: dco_decode_f_32(arr[3]),
+pixelsPerUnit: dco_decode_f_32(arr[4]),
+drawables: dco_decode_list_drawable_frame_dto(arr[5]),
+maskTextureCount: dco_decode_u_32(arr[6]),
+maskGroups: dco_decode_list_mask_group_dto(arr[7]),); }
+
+@protected int dco_decode_i_16(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as int; }
+
+@protected int dco_decode_i_32(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as int; }
+
+@protected PlatformInt64 dco_decode_i_64(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dcoDecodeI64(raw); }
+
+@protected IdleEffectDto dco_decode_idle_effect_dto(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 5) throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+                return IdleEffectDto(parameterId: dco_decode_String(arr[0]),
+offset: dco_decode_f_32(arr[1]),
+peak: dco_decode_f_32(arr[2]),
+cycleSeconds: dco_decode_f_32(arr[3]),
+weight: dco_decode_f_32(arr[4]),); }
+
+@protected LayerBlendDto dco_decode_layer_blend_dto(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return LayerBlendDto.values[raw as int]; }
+
+@protected LipSyncConfigDto dco_decode_lip_sync_config_dto(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 6) throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
+                return LipSyncConfigDto(frameSeconds: dco_decode_f_32(arr[0]),
+gain: dco_decode_f_32(arr[1]),
+noiseFloor: dco_decode_f_32(arr[2]),
+attackSeconds: dco_decode_f_32(arr[3]),
+releaseSeconds: dco_decode_f_32(arr[4]),
+weight: dco_decode_f_32(arr[5]),); }
+
+@protected LipSyncTimelineDto dco_decode_lip_sync_timeline_dto(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+                return LipSyncTimelineDto(frameSeconds: dco_decode_f_32(arr[0]),
+values: dco_decode_list_prim_f_32_strict(arr[1]),); }
+
+@protected List<String> dco_decode_list_String(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_String).toList(); }
+
+@protected List<ChatKeyUsageDto> dco_decode_list_chat_key_usage_dto(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_chat_key_usage_dto).toList(); }
+
+@protected List<ChatMessageDto> dco_decode_list_chat_message_dto(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_chat_message_dto).toList(); }
+
+@protected List<ChatProfileDto> dco_decode_list_chat_profile_dto(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_chat_profile_dto).toList(); }
+
+@protected List<ChatSessionDto> dco_decode_list_chat_session_dto(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_chat_session_dto).toList(); }
+
+@protected List<DrawBatchDto> dco_decode_list_draw_batch_dto(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_draw_batch_dto).toList(); }
+
+@protected List<DrawableFrameDto> dco_decode_list_drawable_frame_dto(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_drawable_frame_dto).toList(); }
+
+@protected List<DrawableUserDataDto> dco_decode_list_drawable_user_data_dto(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_drawable_user_data_dto).toList(); }
+
+@protected List<IdleEffectDto> dco_decode_list_idle_effect_dto(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_idle_effect_dto).toList(); }
+
+@protected List<Uint8List> dco_decode_list_list_prim_u_8_strict(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_list_prim_u_8_strict).toList(); }
+
+@protected List<LookAtMappingDto> dco_decode_list_look_at_mapping_dto(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_look_at_mapping_dto).toList(); }
+
+@protected List<MaskGroupDto> dco_decode_list_mask_group_dto(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_mask_group_dto).toList(); }
+
+@protected List<ParameterGroupInfoDto> dco_decode_list_parameter_group_info_dto(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_parameter_group_info_dto).toList(); }
+
+@protected List<ParameterInfoDto> dco_decode_list_parameter_info_dto(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_parameter_info_dto).toList(); }
+
+@protected List<PartInfoDto> dco_decode_list_part_info_dto(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_part_info_dto).toList(); }
+
+@protected List<double> dco_decode_list_prim_f_32_loose(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as List<double>; }
+
+@protected Float32List dco_decode_list_prim_f_32_strict(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as Float32List; }
+
+@protected List<int> dco_decode_list_prim_i_16_loose(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as List<int>; }
+
+@protected Int16List dco_decode_list_prim_i_16_strict(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as Int16List; }
+
+@protected Uint16List dco_decode_list_prim_u_16_strict(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as Uint16List; }
+
+@protected Uint32List dco_decode_list_prim_u_32_strict(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as Uint32List; }
+
+@protected List<int> dco_decode_list_prim_u_8_loose(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as List<int>; }
+
+@protected Uint8List dco_decode_list_prim_u_8_strict(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as Uint8List; }
+
+@protected List<TextureDto> dco_decode_list_texture_dto(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_texture_dto).toList(); }
+
+@protected List<TextureLevelDto> dco_decode_list_texture_level_dto(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_texture_level_dto).toList(); }
+
+@protected List<VisemeKeyframeDto> dco_decode_list_viseme_keyframe_dto(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_viseme_keyframe_dto).toList(); }
+
+@protected LookAtConfigDto dco_decode_look_at_config_dto(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+                return LookAtConfigDto(maxSpeed: dco_decode_f_32(arr[0]),
+timeToMaxSpeed: dco_decode_f_32(arr[1]),
+mappings: dco_decode_list_look_at_mapping_dto(arr[2]),); }
+
+@protected LookAtMappingDto dco_decode_look_at_mapping_dto(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 4) throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+                return LookAtMappingDto(parameterId: dco_decode_String(arr[0]),
+xWeight: dco_decode_f_32(arr[1]),
+yWeight: dco_decode_f_32(arr[2]),
+xyWeight: dco_decode_f_32(arr[3]),); }
+
+@protected MaskGroupDto dco_decode_mask_group_dto(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 7) throw Exception('unexpected arr length: expect 7 but see ${arr.length}');
+                return MaskGroupDto(textureSlot: dco_decode_u_32(arr[0]),
+channel: dco_decode_u_32(arr[1]),
+layout: dco_decode_f_32_array_4(arr[2]),
+maskDrawables: dco_decode_list_prim_u_32_strict(arr[3]),
+clippedDrawables: dco_decode_list_prim_u_32_strict(arr[4]),
+maskMatrix: dco_decode_f_32_array_16(arr[5]),
+drawMatrix: dco_decode_f_32_array_16(arr[6]),); }
+
+@protected MotionEventDto dco_decode_motion_event_dto(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+                return MotionEventDto(handle: dco_decode_u_64(arr[0]),
+motionName: dco_decode_String(arr[1]),
+value: dco_decode_String(arr[2]),); }
+
+@protected String? dco_decode_opt_String(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_String(raw); }
+
+@protected bool? dco_decode_opt_box_autoadd_bool(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_bool(raw); }
+
+@protected ChatInvokeModeDto? dco_decode_opt_box_autoadd_chat_invoke_mode_dto(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_chat_invoke_mode_dto(raw); }
+
+@protected double? dco_decode_opt_box_autoadd_f_32(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_f_32(raw); }
+
+@protected double? dco_decode_opt_box_autoadd_f_64(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_f_64(raw); }
+
+@protected PlatformInt64? dco_decode_opt_box_autoadd_i_64(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_i_64(raw); }
+
+@protected int? dco_decode_opt_box_autoadd_u_32(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_u_32(raw); }
+
+@protected BigInt? dco_decode_opt_box_autoadd_u_64(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_u_64(raw); }
+
+@protected ParameterGroupInfoDto dco_decode_parameter_group_info_dto(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+                return ParameterGroupInfoDto(id: dco_decode_String(arr[0]),
+name: dco_decode_String(arr[1]),
+parentId: dco_decode_String(arr[2]),); }
+
+@protected ParameterInfoDto dco_decode_parameter_info_dto(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 6) throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
+                return ParameterInfoDto(id: dco_decode_String(arr[0]),
+name: dco_decode_String(arr[1]),
+groupId: dco_decode_String(arr[2]),
+minValue: dco_decode_f_32(arr[3]),
+maxValue: dco_decode_f_32(arr[4]),
+defaultValue: dco_decode_f_32(arr[5]),); }
+
+@protected ParameterSmoothingDto dco_decode_parameter_smoothing_dto(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+                return ParameterSmoothingDto(filter: dco_decode_smoothing_filter_dto(arr[0]),
+timeSeconds: dco_decode_f_32(arr[1]),
+maxVelocity: dco_decode_opt_box_autoadd_f_32(arr[2]),); }
+
+@protected PartInfoDto dco_decode_part_info_dto(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+                return PartInfoDto(id: dco_decode_String(arr[0]),
+name: dco_decode_String(arr[1]),); }
+
+@protected SmoothingFilterDto dco_decode_smoothing_filter_dto(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return SmoothingFilterDto.values[raw as int]; }
+
+@protected TextureDto dco_decode_texture_dto(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 5) throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+                return TextureDto(textureIndex: dco_decode_u_32(arr[0]),
+width: dco_decode_u_32(arr[1]),
+height: dco_decode_u_32(arr[2]),
+premultipliedAlpha: dco_decode_bool(arr[3]),
+levels: dco_decode_list_texture_level_dto(arr[4]),); }
+
+@protected TextureLevelDto dco_decode_texture_level_dto(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+                return TextureLevelDto(width: dco_decode_u_32(arr[0]),
+height: dco_decode_u_32(arr[1]),
+rgba: dco_decode_list_prim_u_8_strict(arr[2]),); }
+
+@protected TextureOptionsDto dco_decode_texture_options_dto(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+                return TextureOptionsDto(premultiplyAlpha: dco_decode_bool(arr[0]),
+generateMipmaps: dco_decode_bool(arr[1]),
+memoryBudgetBytes: dco_decode_opt_box_autoadd_u_64(arr[2]),); }
+
+@protected int dco_decode_u_16(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as int; }
+
+@protected int dco_decode_u_32(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as int; }
+
+@protected BigInt dco_decode_u_64(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dcoDecodeU64(raw); }
+
+@protected int dco_decode_u_8(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as int; }
+
+@protected void dco_decode_unit(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return; }
+
+@protected ViewFitModeDto dco_decode_view_fit_mode_dto(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return ViewFitModeDto.values[raw as int]; }
+
+@protected ViewTransformDto dco_decode_view_transform_dto(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+                return ViewTransformDto(pixelsPerUnit: dco_decode_f_32(arr[0]),
+modelToViewport: dco_decode_f_32_array_16(arr[1]),
+modelToNdc: dco_decode_f_32_array_16(arr[2]),); }
+
+@protected ViewportDto dco_decode_viewport_dto(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 7) throw Exception('unexpected arr length: expect 7 but see ${arr.length}');
+                return ViewportDto(width: dco_decode_f_32(arr[0]),
+height: dco_decode_f_32(arr[1]),
+fitMode: dco_decode_view_fit_mode_dto(arr[2]),
+fixedScale: dco_decode_f_32(arr[3]),
+panX: dco_decode_f_32(arr[4]),
+panY: dco_decode_f_32(arr[5]),
+zoom: dco_decode_f_32(arr[6]),); }
+
+@protected VisemeDto dco_decode_viseme_dto(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return VisemeDto.values[raw as int]; }
+
+@protected VisemeKeyframeDto dco_decode_viseme_keyframe_dto(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 4) throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+                return VisemeKeyframeDto(time: dco_decode_f_32(arr[0]),
+viseme: dco_decode_viseme_dto(arr[1]),
+mouthOpen: dco_decode_f_32(arr[2]),
+mouthForm: dco_decode_f_32(arr[3]),); }
+
+@protected VisemeTimelineDto dco_decode_viseme_timeline_dto(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+                return VisemeTimelineDto(duration: dco_decode_f_32(arr[0]),
+keyframes: dco_decode_list_viseme_keyframe_dto(arr[1]),); }
+
+@protected AnyhowException sse_decode_AnyhowException(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_String(deserializer);
+        return AnyhowException(inner); }
+
+@protected RustStreamSink<String> sse_decode_StreamSink_String_Sse(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+throw UnimplementedError('Unreachable ()'); }
+
+@protected RustStreamSink<MotionEventDto> sse_decode_StreamSink_motion_event_dto_Sse(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+throw UnimplementedError('Unreachable ()'); }
+
+@protected String sse_decode_String(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_list_prim_u_8_strict(deserializer);
+        return utf8.decoder.convert(inner); }
+
+@protected AnimationLayerDto sse_decode_animation_layer_dto(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return AnimationLayerDto.values[inner]; }
+
+@protected BatchedFrameDto sse_decode_batched_frame_dto(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_canvasWidth = sse_decode_f_32(deserializer);
+var var_canvasHeight = sse_decode_f_32(deserializer);
+var var_canvasOriginX = sse_decode_f_32(deserializer);
+var var_canvasOriginY = sse_decode_f_32(deserializer);
+var var_pixelsPerUnit = sse_decode_f_32(deserializer);
+var var_batches = sse_decode_list_draw_batch_dto(deserializer);
+var var_maskTextureCount = sse_decode_u_32(deserializer);
+var var_maskGroups = sse_decode_list_mask_group_dto(deserializer);
+var var_maskBatches = sse_decode_list_draw_batch_dto(deserializer);
+return BatchedFrameDto(canvasWidth: var_canvasWidth, canvasHeight: var_canvasHeight, canvasOriginX: var_canvasOriginX, canvasOriginY: var_canvasOriginY, pixelsPerUnit: var_pixelsPerUnit, batches: var_batches, maskTextureCount: var_maskTextureCount, maskGroups: var_maskGroups, maskBatches: var_maskBatches); }
+
+@protected BlendModeDto sse_decode_blend_mode_dto(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return BlendModeDto.values[inner]; }
+
+@protected BlinkIntervalDto sse_decode_blink_interval_dto(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return BlinkIntervalDto.values[inner]; }
+
+@protected bool sse_decode_bool(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return deserializer.buffer.getUint8() != 0; }
+
+@protected bool sse_decode_box_autoadd_bool(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_bool(deserializer)); }
+
+@protected ChatAppConfigDto sse_decode_box_autoadd_chat_app_config_dto(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_chat_app_config_dto(deserializer)); }
+
+@protected ChatConfigDto sse_decode_box_autoadd_chat_config_dto(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_chat_config_dto(deserializer)); }
+
+@protected ChatInvokeModeDto sse_decode_box_autoadd_chat_invoke_mode_dto(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_chat_invoke_mode_dto(deserializer)); }
+
+@protected ChatProviderDto sse_decode_box_autoadd_chat_provider_dto(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_chat_provider_dto(deserializer)); }
+
+@protected EyeBlinkConfigDto sse_decode_box_autoadd_eye_blink_config_dto(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_eye_blink_config_dto(deserializer)); }
+
+@protected double sse_decode_box_autoadd_f_32(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_f_32(deserializer)); }
+
+@protected double sse_decode_box_autoadd_f_64(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_f_64(deserializer)); }
+
+@protected PlatformInt64 sse_decode_box_autoadd_i_64(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_i_64(deserializer)); }
+
+@protected LipSyncConfigDto sse_decode_box_autoadd_lip_sync_config_dto(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_lip_sync_config_dto(deserializer)); }
+
+@protected LipSyncTimelineDto sse_decode_box_autoadd_lip_sync_timeline_dto(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_lip_sync_timeline_dto(deserializer)); }
+
+@protected LookAtConfigDto sse_decode_box_autoadd_look_at_config_dto(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_look_at_config_dto(deserializer)); }
+
+@protected ParameterSmoothingDto sse_decode_box_autoadd_parameter_smoothing_dto(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_parameter_smoothing_dto(deserializer)); }
+
+@protected TextureOptionsDto sse_decode_box_autoadd_texture_options_dto(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_texture_options_dto(deserializer)); }
+
+@protected int sse_decode_box_autoadd_u_32(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_u_32(deserializer)); }
+
+@protected BigInt sse_decode_box_autoadd_u_64(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_u_64(deserializer)); }
+
+@protected ViewportDto sse_decode_box_autoadd_viewport_dto(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_viewport_dto(deserializer)); }
+
+@protected ChatAppConfigDto sse_decode_chat_app_config_dto(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_defaultProfile = sse_decode_opt_String(deserializer);
+var var_provider = sse_decode_chat_provider_dto(deserializer);
+var var_defaults = sse_decode_chat_model_profile_dto(deserializer);
+var var_profiles = sse_decode_list_chat_profile_dto(deserializer);
+return ChatAppConfigDto(defaultProfile: var_defaultProfile, provider: var_provider, defaults: var_defaults, profiles: var_profiles); }
+
+@protected ChatCapabilitiesDto sse_decode_chat_capabilities_dto(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_streaming = sse_decode_bool(deserializer);
+var var_asyncTask = sse_decode_bool(deserializer);
+var var_listModels = sse_decode_bool(deserializer);
+return ChatCapabilitiesDto(streaming: var_streaming, asyncTask: var_asyncTask, listModels: var_listModels); }
+
+@protected ChatConfigDto sse_decode_chat_config_dto(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_languageModel = sse_decode_String(deserializer);
+var var_systemContent = sse_decode_opt_String(deserializer);
+var var_temperature = sse_decode_f_64(deserializer);
+var var_topP = sse_decode_f_64(deserializer);
+var var_maxHistory = sse_decode_u_32(deserializer);
+var var_contextTokens = sse_decode_opt_box_autoadd_u_32(deserializer);
+var var_replyTokens = sse_decode_opt_box_autoadd_u_32(deserializer);
+var var_summarize = sse_decode_bool(deserializer);
+return ChatConfigDto(languageModel: var_languageModel, systemContent: var_systemContent, temperature: var_temperature, topP: var_topP, maxHistory: var_maxHistory, contextTokens: var_contextTokens, replyTokens: var_replyTokens, summarize: var_summarize); }
+
+@protected ChatInvokeModeDto sse_decode_chat_invoke_mode_dto(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return ChatInvokeModeDto.values[inner]; }
+
+@protected ChatKeySelectionDto sse_decode_chat_key_selection_dto(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return ChatKeySelectionDto.values[inner]; }
+
+@protected ChatKeyUsageDto sse_decode_chat_key_usage_dto(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_label = sse_decode_String(deserializer);
+var var_requests = sse_decode_u_64(deserializer);
+var var_successes = sse_decode_u_64(deserializer);
+var var_failures = sse_decode_u_64(deserializer);
+var var_rateLimited = sse_decode_u_64(deserializer);
+var var_unauthorized = sse_decode_u_64(deserializer);
+var var_disabledForSeconds = sse_decode_opt_box_autoadd_u_32(deserializer);
+return ChatKeyUsageDto(label: var_label, requests: var_requests, successes: var_successes, failures: var_failures, rateLimited: var_rateLimited, unauthorized: var_unauthorized, disabledForSeconds: var_disabledForSeconds); }
+
+@protected ChatMessageDto sse_decode_chat_message_dto(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_role = sse_decode_chat_role_dto(deserializer);
+var var_content = sse_decode_String(deserializer);
+return ChatMessageDto(role: var_role, content: var_content); }
+
+@protected ChatModelProfileDto sse_decode_chat_model_profile_dto(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_languageModel = sse_decode_opt_String(deserializer);
+var var_systemContent = sse_decode_opt_String(deserializer);
+var var_temperature = sse_decode_opt_box_autoadd_f_64(deserializer);
+var var_topP = sse_decode_opt_box_autoadd_f_64(deserializer);
+var var_maxHistory = sse_decode_opt_box_autoadd_u_32(deserializer);
+var var_contextTokens = sse_decode_opt_box_autoadd_u_32(deserializer);
+var var_replyTokens = sse_decode_opt_box_autoadd_u_32(deserializer);
+var var_summarize = sse_decode_opt_box_autoadd_bool(deserializer);
+return ChatModelProfileDto(languageModel: var_languageModel, systemContent: var_systemContent, temperature: var_temperature, topP: var_topP, maxHistory: var_maxHistory, contextTokens: var_contextTokens, replyTokens: var_replyTokens, summarize: var_summarize); }
+
+@protected ChatProfileDto sse_decode_chat_profile_dto(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_name = sse_decode_String(deserializer);
+var var_profile = sse_decode_chat_model_profile_dto(deserializer);
+return ChatProfileDto(name: var_name, profile: var_profile); }
+
+@protected ChatProviderDto sse_decode_chat_provider_dto(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_kind = sse_decode_chat_provider_kind_dto(deserializer);
+var var_baseUrl = sse_decode_opt_String(deserializer);
+var var_apiKey = sse_decode_opt_String(deserializer);
+var var_apiKeys = sse_decode_list_String(deserializer);
+var var_keySelection = sse_decode_chat_key_selection_dto(deserializer);
+var var_pollIntervalMs = sse_decode_u_32(deserializer);
+var var_maxPollIntervalMs = sse_decode_u_32(deserializer);
+var var_taskTimeoutSeconds = sse_decode_u_32(deserializer);
+var var_pollMultiplier = sse_decode_f_64(deserializer);
+var var_pollJitter = sse_decode_f_64(deserializer);
+return ChatProviderDto(kind: var_kind, baseUrl: var_baseUrl, apiKey: var_apiKey, apiKeys: var_apiKeys, keySelection: var_keySelection, pollIntervalMs: var_pollIntervalMs, maxPollIntervalMs: var_maxPollIntervalMs, taskTimeoutSeconds: var_taskTimeoutSeconds, pollMultiplier: var_pollMultiplier, pollJitter: var_pollJitter); }
+
+@protected ChatProviderKindDto sse_decode_chat_provider_kind_dto(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return ChatProviderKindDto.values[inner]; }
+
+@protected ChatReplyDto sse_decode_chat_reply_dto(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_content = sse_decode_String(deserializer);
+var var_warnings = sse_decode_list_String(deserializer);
+return ChatReplyDto(content: var_content, warnings: var_warnings); }
+
+@protected ChatRoleDto sse_decode_chat_role_dto(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return ChatRoleDto.values[inner]; }
+
+@protected ChatSessionDto sse_decode_chat_session_dto(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_id = sse_decode_String(deserializer);
+var var_messageCount = sse_decode_u_32(deserializer);
+var var_tokens = sse_decode_u_64(deserializer);
+var var_createdAtMs = sse_decode_opt_box_autoadd_i_64(deserializer);
+var var_updatedAtMs = sse_decode_opt_box_autoadd_i_64(deserializer);
+return ChatSessionDto(id: var_id, messageCount: var_messageCount, tokens: var_tokens, createdAtMs: var_createdAtMs, updatedAtMs: var_updatedAtMs); }
+
+@protected DrawBatchDto sse_decode_draw_batch_dto(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_textureIndex = sse_decode_u_32(deserializer);
+var var_blendMode = sse_decode_blend_mode_dto(deserializer);
+var var_maskGroup = sse_decode_opt_box_autoadd_u_32(deserializer);
+var var_invertedMask = sse_decode_bool(deserializer);
+var var_drawables = sse_decode_list_prim_u_32_strict(deserializer);
+var var_vertices = sse_decode_list_prim_f_32_strict(deserializer);
+var var_uvs = sse_decode_list_prim_f_32_strict(deserializer);
+var var_indices = sse_decode_list_prim_u_16_strict(deserializer);
+var var_multiplyColors = sse_decode_list_prim_f_32_strict(deserializer);
+var var_screenColors = sse_decode_list_prim_f_32_strict(deserializer);
+return DrawBatchDto(textureIndex: var_textureIndex, blendMode: var_blendMode, maskGroup: var_maskGroup, invertedMask: var_invertedMask, drawables: var_drawables, vertices: var_vertices, uvs: var_uvs, indices: var_indices, multiplyColors: var_multiplyColors, screenColors: var_screenColors); }
+
+@protected DrawOrderModeDto sse_decode_draw_order_mode_dto(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return DrawOrderModeDto.values[inner]; }
+
+@protected DrawableFrameDto sse_decode_drawable_frame_dto(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_index = sse_decode_u_32(deserializer);
+var var_textureIndex = sse_decode_u_32(deserializer);
+var var_vertices = sse_decode_list_prim_f_32_strict(deserializer);
+var var_uvs = sse_decode_list_prim_f_32_strict(deserializer);
+var var_indices = sse_decode_list_prim_u_16_strict(deserializer);
+var var_masks = sse_decode_list_prim_u_16_strict(deserializer);
+var var_maskGroup = sse_decode_opt_box_autoadd_u_32(deserializer);
+var var_opacity = sse_decode_f_32(deserializer);
+var var_multiplyColor = sse_decode_f_32_array_4(deserializer);
+var var_screenColor = sse_decode_f_32_array_4(deserializer);
+var var_drawOrder = sse_decode_i_32(deserializer);
+return DrawableFrameDto(index: var_index, textureIndex: var_textureIndex, vertices: var_vertices, uvs: var_uvs, indices: var_indices, masks: var_masks, maskGroup: var_maskGroup, opacity: var_opacity, multiplyColor: var_multiplyColor, screenColor: var_screenColor, drawOrder: var_drawOrder); }
+
+@protected DrawableUserDataDto sse_decode_drawable_user_data_dto(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_drawableIndex = sse_decode_u_32(deserializer);
+var var_drawableId = sse_decode_String(deserializer);
+var var_value = sse_decode_String(deserializer);
+return DrawableUserDataDto(drawableIndex: var_drawableIndex, drawableId: var_drawableId, value: var_value); }
+
+@protected EmotionResultDto sse_decode_emotion_result_dto(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_expressions = sse_decode_list_String(deserializer);
+var var_startedMotion = sse_decode_opt_String(deserializer);
+return EmotionResultDto(expressions: var_expressions, startedMotion: var_startedMotion); }
+
+@protected EyeBlinkConfigDto sse_decode_eye_blink_config_dto(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_enabled = sse_decode_bool(deserializer);
+var var_interval = sse_decode_blink_interval_dto(deserializer);
+var var_minSeconds = sse_decode_f_32(deserializer);
+var var_maxSeconds = sse_decode_f_32(deserializer);
+var var_meanSeconds = sse_decode_f_32(deserializer);
+var var_closingSeconds = sse_decode_f_32(deserializer);
+var var_closedSeconds = sse_decode_f_32(deserializer);
+var var_openingSeconds = sse_decode_f_32(deserializer);
+var var_doubleBlinkProbability = sse_decode_f_32(deserializer);
+var var_doubleBlinkGapSeconds = sse_decode_f_32(deserializer);
+var var_parameterIds = sse_decode_list_String(deserializer);
+var var_seed = sse_decode_opt_box_autoadd_u_64(deserializer);
+return EyeBlinkConfigDto(enabled: var_enabled, interval: var_interval, minSeconds: var_minSeconds, maxSeconds: var_maxSeconds, meanSeconds: var_meanSeconds, closingSeconds: var_closingSeconds, closedSeconds: var_closedSeconds, openingSeconds: var_openingSeconds, doubleBlinkProbability: var_doubleBlinkProbability, doubleBlinkGapSeconds: var_doubleBlinkGapSeconds, parameterIds: var_parameterIds, seed: var_seed); }
+
+@protected double sse_decode_f_32(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return deserializer.buffer.getFloat32(); }
+
+@protected F32Array16 sse_decode_f_32_array_16(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_list_prim_f_32_strict(deserializer);
+        return F32Array16(inner); }
+
+@protected F32Array2 sse_decode_f_32_array_2(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_list_prim_f_32_strict(deserializer);
+        return F32Array2(inner); }
+
+@protected F32Array4 sse_decode_f_32_array_4(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_list_prim_f_32_strict(deserializer);
+        return F32Array4(inner); }
+
+@protected double sse_decode_f_64(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return deserializer.buffer.getFloat64(); }
+
+@protected FrameDto sse_decode_frame_dto(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_canvasWidth = sse_decode_f_32(deserializer);
+var var_canvasHeight = sse_decode_f_32(deserializer);
+var var_canvasOriginX = sse_decode_f_32(deserializer);
+var var_canvasOriginY = sse_decode_f_32(deserializer);
+var var_pixelsPerUnit = sse_decode_f_32(deserializer);
+var var_drawables = sse_decode_list_drawable_frame_dto(deserializer);
+var var_maskTextureCount = sse_decode_u_32(deserializer);
+var var_maskGroups = sse_decode_list_mask_group_dto(deserializer);
+return FrameDto(canvasWidth: var_canvasWidth, canvasHeight: var_canvasHeight, canvasOriginX: var_canvasOriginX, canvasOriginY: var_canvasOriginY, pixelsPerUnit: var_pixelsPerUnit, drawables: var_drawables, maskTextureCount: var_maskTextureCount, maskGroups: var_maskGroups); }
+
+@protected int sse_decode_i_16(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return deserializer.buffer.getInt16(); }
+
+@protected int sse_decode_i_32(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return deserializer.buffer.getInt32(); }
+
+@protected PlatformInt64 sse_decode_i_64(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return deserializer.buffer.getPlatformInt64(); }
+
+@protected IdleEffectDto sse_decode_idle_effect_dto(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_parameterId = sse_decode_String(deserializer);
+var var_offset = sse_decode_f_32(deserializer);
+var var_peak = sse_decode_f_32(deserializer);
+var var_cycleSeconds = sse_decode_f_32(deserializer);
+var var_weight = sse_decode_f_32(deserializer);
+return IdleEffectDto(parameterId: var_parameterId, offset: var_offset, peak: var_peak, cycleSeconds: var_cycleSeconds, weight: var_weight); }
+
+@protected LayerBlendDto sse_decode_layer_blend_dto(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return LayerBlendDto.values[inner]; }
+
+@protected LipSyncConfigDto sse_decode_lip_sync_config_dto(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_frameSeconds = sse_decode_f_32(deserializer);
+var var_gain = sse_decode_f_32(deserializer);
+var var_noiseFloor = sse_decode_f_32(deserializer);
+var var_attackSeconds = sse_decode_f_32(deserializer);
+var var_releaseSeconds = sse_decode_f_32(deserializer);
+var var_weight = sse_decode_f_32(deserializer);
+return LipSyncConfigDto(frameSeconds: var_frameSeconds, gain: var_gain, noiseFloor: var_noiseFloor, attackSeconds: var_attackSeconds, releaseSeconds: var_releaseSeconds, weight: var_weight); }
+
+@protected LipSyncTimelineDto sse_decode_lip_sync_timeline_dto(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_frameSeconds = sse_decode_f_32(deserializer);
+var var_values = sse_decode_list_prim_f_32_strict(deserializer);
+return LipSyncTimelineDto(frameSeconds: var_frameSeconds, values: var_values); }
+
+@protected List<String> sse_decode_list_String(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <String>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_String(deserializer)); }
+        return ans_;
+         }
+
+@protected List<ChatKeyUsageDto> sse_decode_list_chat_key_usage_dto(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <ChatKeyUsageDto>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_chat_key_usage_dto(deserializer)); }
+        return ans_;
+         }
+
+@protected List<ChatMessageDto> sse_decode_list_chat_message_dto(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <ChatMessageDto>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_chat_message_dto(deserializer)); }
+        return ans_;
+         }
+
+@protected List<ChatProfileDto> sse_decode_list_chat_profile_dto(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <ChatProfileDto>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_chat_profile_dto(deserializer)); }
+        return ans_;
+         }
+
+@protected List<ChatSessionDto> sse_decode_list_chat_session_dto(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <ChatSessionDto>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_chat_session_dto(deserializer)); }
+        return ans_;
+         }
+
+@protected List<DrawBatchDto> sse_decode_list_draw_batch_dto(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <DrawBatchDto>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_draw_batch_dto(deserializer)); }
+        return ans_;
+         }
+
+@protected List<DrawableFrameDto> sse_decode_list_drawable_frame_dto(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <DrawableFrameDto>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_drawable_frame_dto(deserializer)); }
+        return ans_;
+         }
+
+@protected List<DrawableUserDataDto> sse_decode_list_drawable_user_data_dto(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <DrawableUserDataDto>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_drawable_user_data_dto(deserializer)); }
+        return ans_;
+         }
+
+@protected List<IdleEffectDto> sse_decode_list_idle_effect_dto(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <IdleEffectDto>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_idle_effect_dto(deserializer)); }
+        return ans_;
+         }
+
+@protected List<Uint8List> sse_decode_list_list_prim_u_8_strict(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <Uint8List>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_list_prim_u_8_strict(deserializer)); }
+        return ans_;
+         }
+
+@protected List<LookAtMappingDto> sse_decode_list_look_at_mapping_dto(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <LookAtMappingDto>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_look_at_mapping_dto(deserializer)); }
+        return ans_;
+         }
+
+@protected List<MaskGroupDto> sse_decode_list_mask_group_dto(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <MaskGroupDto>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_mask_group_dto(deserializer)); }
+        return ans_;
+         }
+
+@protected List<ParameterGroupInfoDto> sse_decode_list_parameter_group_info_dto(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <ParameterGroupInfoDto>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_parameter_group_info_dto(deserializer)); }
+        return ans_;
+         }
+
+@protected List<ParameterInfoDto> sse_decode_list_parameter_info_dto(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <ParameterInfoDto>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_parameter_info_dto(deserializer)); }
+        return ans_;
+         }
+
+@protected List<PartInfoDto> sse_decode_list_part_info_dto(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <PartInfoDto>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_part_info_dto(deserializer)); }
+        return ans_;
+         }
+
+@protected List<double> sse_decode_list_prim_f_32_loose(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var len_ = sse_decode_i_32(deserializer);
+                return deserializer.buffer.getFloat32List(len_); }
+
+@protected Float32List sse_decode_list_prim_f_32_strict(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var len_ = sse_decode_i_32(deserializer);
+                return deserializer.buffer.getFloat32List(len_); }
+
+@protected List<int> sse_decode_list_prim_i_16_loose(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var len_ = sse_decode_i_32(deserializer);
+                return deserializer.buffer.getInt16List(len_); }
+
+@protected Int16List sse_decode_list_prim_i_16_strict(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var len_ = sse_decode_i_32(deserializer);
+                return deserializer.buffer.getInt16List(len_); }
+
+@protected Uint16List sse_decode_list_prim_u_16_strict(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var len_ = sse_decode_i_32(deserializer);
+                return deserializer.buffer.getUint16List(len_); }
+
+@protected Uint32List sse_decode_list_prim_u_32_strict(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var len_ = sse_decode_i_32(deserializer);
+                return deserializer.buffer.getUint32List(len_); }
+
+@protected List<int> sse_decode_list_prim_u_8_loose(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var len_ = sse_decode_i_32(deserializer);
+                return deserializer.buffer.getUint8List(len_); }
+
+@protected Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var len_ = sse_decode_i_32(deserializer);
+                return deserializer.buffer.getUint8List(len_); }
+
+@protected List<TextureDto> sse_decode_list_texture_dto(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <TextureDto>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_texture_dto(deserializer)); }
+        return ans_;
+         }
+
+@protected List<TextureLevelDto> sse_decode_list_texture_level_dto(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <TextureLevelDto>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_texture_level_dto(deserializer)); }
+        return ans_;
+         }
+
+@protected List<VisemeKeyframeDto> sse_decode_list_viseme_keyframe_dto(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <VisemeKeyframeDto>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_viseme_keyframe_dto(deserializer)); }
+        return ans_;
+         }
+
+@protected LookAtConfigDto sse_decode_look_at_config_dto(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_maxSpeed = sse_decode_f_32(deserializer);
+var var_timeToMaxSpeed = sse_decode_f_32(deserializer);
+var var_mappings = sse_decode_list_look_at_mapping_dto(deserializer);
+return LookAtConfigDto(maxSpeed: var_maxSpeed, timeToMaxSpeed: var_timeToMaxSpeed, mappings: var_mappings); }
+
+@protected LookAtMappingDto sse_decode_look_at_mapping_dto(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_parameterId = sse_decode_String(deserializer);
+var var_xWeight = sse_decode_f_32(deserializer);
+var var_yWeight = sse_decode_f_32(deserializer);
+var var_xyWeight = sse_decode_f_32(deserializer);
+return LookAtMappingDto(parameterId: var_parameterId, xWeight: var_xWeight, yWeight: var_yWeight, xyWeight: var_xyWeight); }
+
+@protected MaskGroupDto sse_decode_mask_group_dto(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_textureSlot = sse_decode_u_32(deserializer);
+var var_channel = sse_decode_u_32(deserializer);
+var var_layout = sse_decode_f_32_array_4(deserializer);
+var var_maskDrawables = sse_decode_list_prim_u_32_strict(deserializer);
+var var_clippedDrawables = sse_decode_list_prim_u_32_strict(deserializer);
+var var_maskMatrix = sse_decode_f_32_array_16(deserializer);
+var var_drawMatrix = sse_decode_f_32_array_16(deserializer);
+return MaskGroupDto(textureSlot: var_textureSlot, channel: var_channel, layout: var_layout, maskDrawables: var_maskDrawables, clippedDrawables: var_clippedDrawables, maskMatrix: var_maskMatrix, drawMatrix: var_drawMatrix); }
+
+@protected MotionEventDto sse_decode_motion_event_dto(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_handle = sse_decode_u_64(deserializer);
+var var_motionName = sse_decode_String(deserializer);
+var var_value = sse_decode_String(deserializer);
+return MotionEventDto(handle: var_handle, motionName: var_motionName, value: var_value); }
+
+@protected String? sse_decode_opt_String(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_String(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected bool? sse_decode_opt_box_autoadd_bool(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_bool(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected ChatInvokeModeDto? sse_decode_opt_box_autoadd_chat_invoke_mode_dto(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_chat_invoke_mode_dto(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected double? sse_decode_opt_box_autoadd_f_32(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_f_32(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected double? sse_decode_opt_box_autoadd_f_64(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_f_64(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected PlatformInt64? sse_decode_opt_box_autoadd_i_64(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_i_64(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected int? sse_decode_opt_box_autoadd_u_32(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_u_32(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected BigInt? sse_decode_opt_box_autoadd_u_64(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_u_64(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected ParameterGroupInfoDto sse_decode_parameter_group_info_dto(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_id = sse_decode_String(deserializer);
+var var_name = sse_decode_String(deserializer);
+var var_parentId = sse_decode_String(deserializer);
+return ParameterGroupInfoDto(id: var_id, name: var_name, parentId: var_parentId); }
+
+@protected ParameterInfoDto sse_decode_parameter_info_dto(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_id = sse_decode_String(deserializer);
+var var_name = sse_decode_String(deserializer);
+var var_groupId = sse_decode_String(deserializer);
+var var_minValue = sse_decode_f_32(deserializer);
+var var_maxValue = sse_decode_f_32(deserializer);
+var var_defaultValue = sse_decode_f_32(deserializer);
+return ParameterInfoDto(id: var_id, name: var_name, groupId: var_groupId, minValue: var_minValue, maxValue: var_maxValue, defaultValue: var_defaultValue); }
+
+@protected ParameterSmoothingDto sse_decode_parameter_smoothing_dto(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_filter = sse_decode_smoothing_filter_dto(deserializer);
+var var_timeSeconds = sse_decode_f_32(deserializer);
+var var_maxVelocity = sse_decode_opt_box_autoadd_f_32(deserializer);
+return ParameterSmoothingDto(filter: var_filter, timeSeconds: var_timeSeconds, maxVelocity: var_maxVelocity); }
+
+@protected PartInfoDto sse_decode_part_info_dto(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_id = sse_decode_String(deserializer);
+var var_name = sse_decode_String(deserializer);
+return PartInfoDto(id: var_id, name: var_name); }
+
+@protected SmoothingFilterDto sse_decode_smoothing_filter_dto(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return SmoothingFilterDto.values[inner]; }
+
+@protected TextureDto sse_decode_texture_dto(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_textureIndex = sse_decode_u_32(deserializer);
+var var_width = sse_decode_u_32(deserializer);
+var var_height = sse_decode_u_32(deserializer);
+var var_premultipliedAlpha = sse_decode_bool(deserializer);
+var var_levels = sse_decode_list_texture_level_dto(deserializer);
+return TextureDto(textureIndex: var_textureIndex, width: var_width, height: var_height, premultipliedAlpha: var_premultipliedAlpha, levels: var_levels); }
+
+@protected TextureLevelDto sse_decode_texture_level_dto(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_width = sse_decode_u_32(deserializer);
+var var_height = sse_decode_u_32(deserializer);
+var var_rgba = sse_decode_list_prim_u_8_strict(deserializer);
+return TextureLevelDto(width: var_width, height: var_height, rgba: var_rgba); }
+
+@protected TextureOptionsDto sse_decode_texture_options_dto(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_premultiplyAlpha = sse_decode_bool(deserializer);
+var var_generateMipmaps = sse_decode_bool(deserializer);
+var var_memoryBudgetBytes = sse_decode_opt_box_autoadd_u_64(deserializer);
+return TextureOptionsDto(premultiplyAlpha: var_premultiplyAlpha, generateMipmaps: var_generateMipmaps, memoryBudgetBytes: var_memoryBudgetBytes); }
+
+@protected int sse_decode_u_16(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return deserializer.buffer.getUint16(); }
+
+@protected int sse_decode_u_32(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return deserializer.buffer.getUint32(); }
+
+@protected BigInt sse_decode_u_64(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return deserializer.buffer.getBigUint64(); }
+
+@protected int sse_decode_u_8(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return deserializer.buffer.getUint8(); }
+
+@protected void sse_decode_unit(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+ }
+
+@protected ViewFitModeDto sse_decode_view_fit_mode_dto(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return ViewFitModeDto.values[inner]; }
+
+@protected ViewTransformDto sse_decode_view_transform_dto(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_pixelsPerUnit = sse_decode_f_32(deserializer);
+var var_modelToViewport = sse_decode_f_32_array_16(deserializer);
+var var_modelToNdc = sse_decode_f_32_array_16(deserializer);
+return ViewTransformDto(pixelsPerUnit: var_pixelsPerUnit, modelToViewport: var_modelToViewport, modelToNdc: var_modelToNdc); }
+
+@protected ViewportDto sse_decode_viewport_dto(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_width = sse_decode_f_32(deserializer);
+var var_height = sse_decode_f_32(deserializer);
+var var_fitMode = sse_decode_view_fit_mode_dto(deserializer);
+var var_fixedScale = sse_decode_f_32(deserializer);
+var var_panX = sse_decode_f_32(deserializer);
+var var_panY = sse_decode_f_32(deserializer);
+var var_zoom = sse_decode_f_32(deserializer);
+return ViewportDto(width: var_width, height: var_height, fitMode: var_fitMode, fixedScale: var_fixedScale, panX: var_panX, panY: var_panY, zoom: var_zoom); }
+
+@protected VisemeDto sse_decode_viseme_dto(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return VisemeDto.values[inner]; }
+
+@protected VisemeKeyframeDto sse_decode_viseme_keyframe_dto(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_time = sse_decode_f_32(deserializer);
+var var_viseme = sse_decode_viseme_dto(deserializer);
+var var_mouthOpen = sse_decode_f_32(deserializer);
+var var_mouthForm = sse_decode_f_32(deserializer);
+return VisemeKeyframeDto(time: var_time, viseme: var_viseme, mouthOpen: var_mouthOpen, mouthForm: var_mouthForm); }
+
+@protected VisemeTimelineDto sse_decode_viseme_timeline_dto(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_duration = sse_decode_f_32(deserializer);
+var var_keyframes = sse_decode_list_viseme_keyframe_dto(deserializer);
+return VisemeTimelineDto(duration: var_duration, keyframes: var_keyframes); }
+
+@protected void sse_encode_AnyhowException(AnyhowException self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.message, serializer); }
+
+@protected void sse_encode_StreamSink_String_Sse(RustStreamSink<String> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.setupAndSerialize(codec: SseCodec(
+            decodeSuccessData: sse_decode_String,
+            decodeErrorData: sse_decode_AnyhowException,
+        )), serializer); }
+
+@protected void sse_encode_StreamSink_motion_event_dto_Sse(RustStreamSink<MotionEventDto> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.setupAndSerialize(codec: SseCodec(
+            decodeSuccessData: sse_decode_motion_event_dto,
+            decodeErrorData: sse_decode_AnyhowException,
+        )), serializer); }
+
+@protected void sse_encode_String(String self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_list_prim_u_8_strict(utf8.encoder.convert(self), serializer); }
+
+@protected void sse_encode_animation_layer_dto(AnimationLayerDto self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_batched_frame_dto(BatchedFrameDto self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_f_32(self.canvasWidth, serializer);
+sse_encode_f_32(self.canvasHeight, serializer);
+sse_encode_f_32(self.canvasOriginX, serializer);
+sse_encode_f_32(self.canvasOriginY, serializer);
+sse_encode_f_32(self.pixelsPerUnit, serializer);
+sse_encode_list_draw_batch_dto(self.batches, serializer);
+sse_encode_u_32(self.maskTextureCount, serializer);
+sse_encode_list_mask_group_dto(self.maskGroups, serializer);
+sse_encode_list_draw_batch_dto(self.maskBatches, serializer);
+ }
+
+@protected void sse_encode_blend_mode_dto(BlendModeDto self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_blink_interval_dto(BlinkIntervalDto self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_bool(bool self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+serializer.buffer.putUint8(self ? 1 : 0); }
+
+@protected void sse_encode_box_autoadd_bool(bool self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_bool(self, serializer); }
+
+@protected void sse_encode_box_autoadd_chat_app_config_dto(ChatAppConfigDto self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_chat_app_config_dto(self, serializer); }
+
+@protected void sse_encode_box_autoadd_chat_config_dto(ChatConfigDto self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_chat_config_dto(self, serializer); }
+
+@protected void sse_encode_box_autoadd_chat_invoke_mode_dto(ChatInvokeModeDto self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_chat_invoke_mode_dto(self, serializer); }
+
+@protected void sse_encode_box_autoadd_chat_provider_dto(ChatProviderDto self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_chat_provider_dto(self, serializer); }
+
+@protected void sse_encode_box_autoadd_eye_blink_config_dto(EyeBlinkConfigDto self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_eye_blink_config_dto(self, serializer); }
+
+@protected void sse_encode_box_autoadd_f_32(double self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_f_32(self, serializer); }
+
+@protected void sse_encode_box_autoadd_f_64(double self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_f_64(self, serializer); }
+
+@protected void sse_encode_box_autoadd_i_64(PlatformInt64 self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_64(self, serializer); }
+
+@protected void sse_encode_box_autoadd_lip_sync_config_dto(LipSyncConfigDto self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_lip_sync_config_dto(self, serializer); }
+
+@protected void sse_encode_box_autoadd_lip_sync_timeline_dto(LipSyncTimelineDto self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_lip_sync_timeline_dto(self, serializer); }
+
+@protected void sse_encode_box_autoadd_look_at_config_dto(LookAtConfigDto self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_look_at_config_dto(self, serializer); }
+
+@protected void sse_encode_box_autoadd_parameter_smoothing_dto(ParameterSmoothingDto self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_parameter_smoothing_dto(self, serializer); }
+
+@protected void sse_encode_box_autoadd_texture_options_dto(TextureOptionsDto self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_texture_options_dto(self, serializer); }
+
+@protected void sse_encode_box_autoadd_u_32(int self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_u_32(self, serializer); }
+
+@protected void sse_encode_box_autoadd_u_64(BigInt self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_u_64(self, serializer); }
+
+@protected void sse_encode_box_autoadd_viewport_dto(ViewportDto self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_viewport_dto(self, serializer); }
+
+@protected void sse_encode_chat_app_config_dto(ChatAppConfigDto self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_opt_String(self.defaultProfile, serializer);
+sse_encode_chat_provider_dto(self.provider, serializer);
+sse_encode_chat_model_profile_dto(self.defaults, serializer);
+sse_encode_list_chat_profile_dto(self.profiles, serializer);
+ }
+
+@protected void sse_encode_chat_capabilities_dto(ChatCapabilitiesDto self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_bool(self.streaming, serializer);
+sse_encode_bool(self.asyncTask, serializer);
+sse_encode_bool(self.listModels, serializer);
+ }
+
+@protected void sse_encode_chat_config_dto(ChatConfigDto self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.languageModel, serializer);
+sse_encode_opt_String(self.systemContent, serializer);
+sse_encode_f_64(self.temperature, serializer);
+sse_encode_f_64(self.topP, serializer);
+sse_encode_u_32(self.maxHistory, serializer);
+sse_encode_opt_box_autoadd_u_32(self.contextTokens, serializer);
+sse_encode_opt_box_autoadd_u_32(self.replyTokens, serializer);
+sse_encode_bool(self.summarize, serializer);
+ }
+
+@protected void sse_encode_chat_invoke_mode_dto(ChatInvokeModeDto self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_chat_key_selection_dto(ChatKeySelectionDto self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_chat_key_usage_dto(ChatKeyUsageDto self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.label, serializer);
+sse_encode_u_64(self.requests, serializer);
+sse_encode_u_64(self.successes, serializer);
+sse_encode_u_64(self.failures, serializer);
+sse_encode_u_64(self.rateLimited, serializer);
+sse_encode_u_64(self.unauthorized, serializer);
+sse_encode_opt_box_autoadd_u_32(self.disabledForSeconds, serializer);
+ }
+
+@protected void sse_encode_chat_message_dto(ChatMessageDto self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_chat_role_dto(self.role, serializer);
+sse_encode_String(self.content, serializer);
+ }
+
+@protected void sse_encode_chat_model_profile_dto(ChatModelProfileDto self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_opt_String(self.languageModel, serializer);
+sse_encode_opt_String(self.systemContent, serializer);
+sse_encode_opt_box_autoadd_f_64(self.temperature, serializer);
+sse_encode_opt_box_autoadd_f_64(self.topP, serializer);
+sse_encode_opt_box_autoadd_u_32(self.maxHistory, serializer);
+sse_encode_opt_box_autoadd_u_32(self.contextTokens, serializer);
+sse_encode_opt_box_autoadd_u_32(self.replyTokens, serializer);
+sse_encode_opt_box_autoadd_bool(self.summarize, serializer);
+ }
+
+@protected void sse_encode_chat_profile_dto(ChatProfileDto self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.name, serializer);
+sse_encode_chat_model_profile_dto(self.profile, serializer);
+ }
+
+@protected void sse_encode_chat_provider_dto(ChatProviderDto self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_chat_provider_kind_dto(self.kind, serializer);
+sse_encode_opt_String(self.baseUrl, serializer);
+sse_encode_opt_String(self.apiKey, serializer);
+sse_encode_list_String(self.apiKeys, serializer);
+sse_encode_chat_key_selection_dto(self.keySelection, serializer);
+sse_encode_u_32(self.pollIntervalMs, serializer);
+sse_encode_u_32(self.maxPollIntervalMs, serializer);
+sse_encode_u_32(self.taskTimeoutSeconds, serializer);
+sse_encode_f_64(self.pollMultiplier, serializer);
+sse_encode_f_64(self.pollJitter, serializer);
+ }
+
+@protected void sse_encode_chat_provider_kind_dto(ChatProviderKindDto self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_chat_reply_dto(ChatReplyDto self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.content, serializer);
+sse_encode_list_String(self.warnings, serializer);
+ }
+
+@protected void sse_encode_chat_role_dto(ChatRoleDto self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_chat_session_dto(ChatSessionDto self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.id, serializer);
+sse_encode_u_32(self.messageCount, serializer);
+sse_encode_u_64(self.tokens, serializer);
+sse_encode_opt_box_autoadd_i_64(self.createdAtMs, serializer);
+sse_encode_opt_box_autoadd_i_64(self.updatedAtMs, serializer);
+ }
+
+@protected void sse_encode_draw_batch_dto(DrawBatchDto self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_u_32(self.textureIndex, serializer);
+sse_encode_blend_mode_dto(self.blendMode, serializer);
+sse_encode_opt_box_autoadd_u_32(self.maskGroup, serializer);
+sse_encode_bool(self.invertedMask, serializer);
+sse_encode_list_prim_u_32_strict(self.drawables, serializer);
+sse_encode_list_prim_f_32_strict(self.vertices, serializer);
+sse_encode_list_prim_f_32_strict(self.uvs, serializer);
+sse_encode_list_prim_u_16_strict(self.indices, serializer);
+sse_encode_list_prim_f_32_strict(self.multiplyColors, serializer);
+sse_encode_list_prim_f_32_strict(self.screenColors, serializer);
+ }
+
+@protected void sse_encode_draw_order_mode_dto(DrawOrderModeDto self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_drawable_frame_dto(DrawableFrameDto self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_u_32(self.index, serializer);
+sse_encode_u_32(self.textureIndex, serializer);
+sse_encode_list_prim_f_32_strict(self.vertices, serializer);
+sse_encode_list_prim_f_32_strict(self.uvs, serializer);
+sse_encode_list_prim_u_16_strict(self.indices, serializer);
+sse_encode_list_prim_u_16_strict(self.masks, serializer);
+sse_encode_opt_box_autoadd_u_32(self.maskGroup, serializer);
+sse_encode_f_32(self.opacity, serializer);
+sse_encode_f_32_array_4(self.multiplyColor, serializer);
+sse_encode_f_32_array_4(self.screenColor, serializer);
+sse_encode_i_32(self.drawOrder, serializer);
+ }
+
+@protected void sse_encode_drawable_user_data_dto(DrawableUserDataDto self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_u_32(self.drawableIndex, serializer);
+sse_encode_String(self.drawableId, serializer);
+sse_encode_String(self.value, serializer);
+ }
+
+@protected void sse_encode_emotion_result_dto(EmotionResultDto self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_list_String(self.expressions, serializer);
+sse_encode_opt_String(self.startedMotion, serializer);
+ }
+
+@protected void sse_encode_eye_blink_config_dto(EyeBlinkConfigDto self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_bool(self.enabled, serializer);
+sse_encode_blink_interval_dto(self.interval, serializer);
+sse_encode_f_32(self.minSeconds, serializer);
+sse_encode_f_32(self.maxSeconds, serializer);
+sse_encode_f_32(self.meanSeconds, serializer);
+sse_encode_f_32(self.closingSeconds, serializer);
+sse_encode_f_32(self.closedSeconds, serializer);
+sse_encode_f_32(self.openingSeconds, serializer);
+sse_encode_f_32(self.doubleBlinkProbability, serializer);
+sse_encode_f_32(self.doubleBlinkGapSeconds, serializer);
+sse_encode_list_String(self.parameterIds, serializer);
+sse_encode_opt_box_autoadd_u_64(self.seed, serializer);
+ }
+
+@protected void sse_encode_f_32(double self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+serializer.buffer.putFloat32(self); }
+
+@protected void sse_encode_f_32_array_16(F32Array16 self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_list_prim_f_32_strict(self.inner, serializer); }
+
+@protected void sse_encode_f_32_array_2(F32Array2 self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_list_prim_f_32_strict(self.inner, serializer); }
+
+@protected void sse_encode_f_32_array_4(F32Array4 self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_list_prim_f_32_strict(self.inner, serializer); }
+
+@protected void sse_encode_f_64(double self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+serializer.buffer.putFloat64(self); }
+
+@protected void sse_encode_frame_dto(FrameDto self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_f_32(self.canvasWidth, serializer);
+sse_encode_f_32(self.canvasHeight, serializer);
+sse_encode_f_32(self.canvasOriginX, serializer);
+sse_encode_f_32(self.canvasOriginY, serializer);
+sse_encode_f_32(self.pixelsPerUnit, serializer);
+sse_encode_list_drawable_frame_dto(self.drawables, serializer);
+sse_encode_u_32(self.maskTextureCount, serializer);
+sse_encode_list_mask_group_dto(self.maskGroups, serializer);
+ }
+
+@protected void sse_encode_i_16(int self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+serializer.buffer.putInt16(self); }
+
+@protected void sse_encode_i_32(int self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+serializer.buffer.putInt32(self); }
+
+@protected void sse_encode_i_64(PlatformInt64 self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+serializer.buffer.putPlatformInt64(self); }
+
+@protected void sse_encode_idle_effect_dto(IdleEffectDto self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.parameterId, serializer);
+sse_encode_f_32(self.offset, serializer);
+sse_encode_f_32(self.peak, serializer);
+sse_encode_f_32(self.cycleSeconds, serializer);
+sse_encode_f_32(self.weight, serializer);
+ }
+
+@protected void sse_encode_layer_blend_dto(LayerBlendDto self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_lip_sync_config_dto(LipSyncConfigDto self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_f_32(self.frameSeconds, serializer);
+sse_encode_f_32(self.gain, serializer);
+sse_encode_f_32(self.noiseFloor, serializer);
+sse_encode_f_32(self.attackSeconds, serializer);
+sse_encode_f_32(self.releaseSeconds, serializer);
+sse_encode_f_32(self.weight, serializer);
+ }
+
+@protected void sse_encode_lip_sync_timeline_dto(LipSyncTimelineDto self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_f_32(self.frameSeconds, serializer);
+sse_encode_list_prim_f_32_strict(self.values, serializer);
+ }
+
+@protected void sse_encode_list_String(List<String> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_String(item, serializer); } }
+
+@protected void sse_encode_list_chat_key_usage_dto(List<ChatKeyUsageDto> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_chat_key_usage_dto(item, serializer); } }
+
+@protected void sse_encode_list_chat_message_dto(List<ChatMessageDto> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_chat_message_dto(item, serializer); } }
+
+@protected void sse_encode_list_chat_profile_dto(List<ChatProfileDto> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_chat_profile_dto(item, serializer); } }
+
+@protected void sse_encode_list_chat_session_dto(List<ChatSessionDto> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_chat_session_dto(item, serializer); } }
+
+@protected void sse_encode_list_draw_batch_dto(List<DrawBatchDto> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_draw_batch_dto(item, serializer); } }
+
+@protected void sse_encode_list_drawable_frame_dto(List<DrawableFrameDto> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_drawable_frame_dto(item, serializer); } }
+
+@protected void sse_encode_list_drawable_user_data_dto(List<DrawableUserDataDto> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_drawable_user_data_dto(item, serializer); } }
+
+@protected void sse_encode_list_idle_effect_dto(List<IdleEffectDto> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_idle_effect_dto(item, serializer); } }
+
+@protected void sse_encode_list_list_prim_u_8_strict(List<Uint8List> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_list_prim_u_8_strict(item, serializer); } }
+
+@protected void sse_encode_list_look_at_mapping_dto(List<LookAtMappingDto> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_look_at_mapping_dto(item, serializer); } }
+
+@protected void sse_encode_list_mask_group_dto(List<MaskGroupDto> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_mask_group_dto(item, serializer); } }
+
+@protected void sse_encode_list_parameter_group_info_dto(List<ParameterGroupInfoDto> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_parameter_group_info_dto(item, serializer); } }
+
+@protected void sse_encode_list_parameter_info_dto(List<ParameterInfoDto> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_parameter_info_dto(item, serializer); } }
+
+@protected void sse_encode_list_part_info_dto(List<PartInfoDto> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_part_info_dto(item, serializer); } }
+
+@protected void sse_encode_list_prim_f_32_loose(List<double> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+                    serializer.buffer.putFloat32List(self is Float32List ? self : Float32List.fromList(self)); }
+
+@protected void sse_encode_list_prim_f_32_strict(Float32List self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+                    serializer.buffer.putFloat32List(self); }
+
+@protected void sse_encode_list_prim_i_16_loose(List<int> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+                    serializer.buffer.putInt16List(self is Int16List ? self : Int16List.fromList(self)); }
+
+@protected void sse_encode_list_prim_i_16_strict(Int16List self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+                    serializer.buffer.putInt16List(self); }
+
+@protected void sse_encode_list_prim_u_16_strict(Uint16List self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+                    serializer.buffer.putUint16List(self); }
+
+@protected void sse_encode_list_prim_u_32_strict(Uint32List self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+                    serializer.buffer.putUint32List(self); }
+
+@protected void sse_encode_list_prim_u_8_loose(List<int> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+                    serializer.buffer.putUint8List(self is Uint8List ? self : Uint8List.fromList(self)); }
+
+@protected void sse_encode_list_prim_u_8_strict(Uint8List self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+                    serializer.buffer.putUint8List(self); }
+
+@protected void sse_encode_list_texture_dto(List<TextureDto> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_texture_dto(item, serializer); } }
+
+@protected void sse_encode_list_texture_level_dto(List<TextureLevelDto> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_texture_level_dto(item, serializer); } }
+
+@protected void sse_encode_list_viseme_keyframe_dto(List<VisemeKeyframeDto> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_viseme_keyframe_dto(item, serializer); } }
+
+@protected void sse_encode_look_at_config_dto(LookAtConfigDto self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_f_32(self.maxSpeed, serializer);
+sse_encode_f_32(self.timeToMaxSpeed, serializer);
+sse_encode_list_look_at_mapping_dto(self.mappings, serializer);
+ }
+
+@protected void sse_encode_look_at_mapping_dto(LookAtMappingDto self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.parameterId, serializer);
+sse_encode_f_32(self.xWeight, serializer);
+sse_encode_f_32(self.yWeight, serializer);
+sse_encode_f_32(self.xyWeight, serializer);
+ }
+
+@protected void sse_encode_mask_group_dto(MaskGroupDto self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_u_32(self.textureSlot, serializer);
+sse_encode_u_32(self.channel, serializer);
+sse_encode_f_32_array_4(self.layout, serializer);
+sse_encode_list_prim_u_32_strict(self.maskDrawables, serializer);
+sse_encode_list_prim_u_32_strict(self.clippedDrawables, serializer);
+sse_encode_f_32_array_16(self.maskMatrix, serializer);
+sse_encode_f_32_array_16(self.drawMatrix, serializer);
+ }
+
+@protected void sse_encode_motion_event_dto(MotionEventDto self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_u_64(self.handle, serializer);
+sse_encode_String(self.motionName, serializer);
+sse_encode_String(self.value, serializer);
+ }
+
+@protected void sse_encode_opt_String(String? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_String(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_bool(bool? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_bool(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_chat_invoke_mode_dto(ChatInvokeModeDto? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_chat_invoke_mode_dto(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_f_32(double? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_f_32(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_f_64(double? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_f_64(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_i_64(PlatformInt64? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_i_64(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_u_32(int? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_u_32(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_u_64(BigInt? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_u_64(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_parameter_group_info_dto(ParameterGroupInfoDto self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.id, serializer);
+sse_encode_String(self.name, serializer);
+sse_encode_String(self.parentId, serializer);
+ }
+
+@protected void sse_encode_parameter_info_dto(ParameterInfoDto self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.id, serializer);
+sse_encode_String(self.name, serializer);
+sse_encode_String(self.groupId, serializer);
+sse_encode_f_32(self.minValue, serializer);
+sse_encode_f_32(self.maxValue, serializer);
+sse_encode_f_32(self.defaultValue, serializer);
+ }
+
+@protected void sse_encode_parameter_smoothing_dto(ParameterSmoothingDto self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_smoothing_filter_dto(self.filter, serializer);
+sse_encode_f_32(self.timeSeconds, serializer);
+sse_encode_opt_box_autoadd_f_32(self.maxVelocity, serializer);
+ }
+
+@protected void sse_encode_part_info_dto(PartInfoDto self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.id, serializer);
+sse_encode_String(self.name, serializer);
+ }
+
+@protected void sse_encode_smoothing_filter_dto(SmoothingFilterDto self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_texture_dto(TextureDto self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_u_32(self.textureIndex, serializer);
+sse_encode_u_32(self.width, serializer);
+sse_encode_u_32(self.height, serializer);
+sse_encode_bool(self.premultipliedAlpha, serializer);
+sse_encode_list_texture_level_dto(self.levels, serializer);
+ }
+
+@protected void sse_encode_texture_level_dto(TextureLevelDto self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_u_32(self.width, serializer);
+sse_encode_u_32(self.height, serializer);
+sse_encode_list_prim_u_8_strict(self.rgba, serializer);
+ }
+
+@protected void sse_encode_texture_options_dto(TextureOptionsDto self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_bool(self.premultiplyAlpha, serializer);
+sse_encode_bool(self.generateMipmaps, serializer);
+sse_encode_opt_box_autoadd_u_64(self.memoryBudgetBytes, serializer);
+ }
+
+@protected void sse_encode_u_16(int self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+serializer.buffer.putUint16(self); }
+
+@protected void sse_encode_u_32(int self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+serializer.buffer.putUint32(self); }
+
+@protected void sse_encode_u_64(BigInt self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+serializer.buffer.putBigUint64(self); }
+
+@protected void sse_encode_u_8(int self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+serializer.buffer.putUint8(self); }
+
+@protected void sse_encode_unit(void self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+ }
+
+@protected void sse_encode_view_fit_mode_dto(ViewFitModeDto self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_view_transform_dto(ViewTransformDto self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_f_32(self.pixelsPerUnit, serializer);
+sse_encode_f_32_array_16(self.modelToViewport, serializer);
+sse_encode_f_32_array_16(self.modelToNdc, serializer);
+ }
+
+@protected void sse_encode_viewport_dto(ViewportDto self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_f_32(self.width, serializer);
+sse_encode_f_32(self.height, serializer);
+sse_encode_view_fit_mode_dto(self.fitMode, serializer);
+sse_encode_f_32(self.fixedScale, serializer);
+sse_encode_f_32(self.panX, serializer);
+sse_encode_f_32(self.panY, serializer);
+sse_encode_f_32(self.zoom, serializer);
+ }
+
+@protected void sse_encode_viseme_dto(VisemeDto self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_viseme_keyframe_dto(VisemeKeyframeDto self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_f_32(self.time, serializer);
+sse_encode_viseme_dto(self.viseme, serializer);
+sse_encode_f_32(self.mouthOpen, serializer);
+sse_encode_f_32(self.mouthForm, serializer);
+ }
+
+@protected void sse_encode_viseme_timeline_dto(VisemeTimelineDto self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_f_32(self.duration, serializer);
+sse_encode_list_viseme_keyframe_dto(self.keyframes, serializer);
+ }
+                }
+                
@@ -3,10 +3,13 @@
 
 // ignore_for_file: unused_import, unused_element, unnecessary_import, duplicate_ignore, invalid_use_of_internal_member, annotate_overrides, non_constant_identifier_names, curly_braces_in_flow_control_structures, prefer_const_literals_to_create_immutables, unused_field
 
+
 // Static analysis wrongly picks the IO variant, thus ignore this
 // ignore_for_file: argument_type_not_assignable
 
+import 'api/chat_api.dart';
 import 'api/live2d_model_api.dart';
+import 'api/live2d_texture_api.dart';
 import 'api/wise_lover_boot_api.dart';
 import 'dart:async';
 import 'dart:convert';
@@ -14,203 +17,728 @@ import 'frb_generated.dart';
 import 'lib.dart';
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated_web.dart';
 
-abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
-  RustLibApiImplPlatform({
-    required super.handler,
-    required super.wire,
-    required super.generalizedFrbRustBinding,
-    required super.portManager,
-  });
 
-  @protected
-  String dco_decode_String(dynamic raw);
 
-  @protected
-  bool dco_decode_bool(dynamic raw);
 
-  @protected
-  DrawableFrameDto dco_decode_drawable_frame_dto(dynamic raw);
+                abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
+                  RustLibApiImplPlatform({
+                    required super.handler,
+                    required super.wire,
+                    required super.generalizedFrbRustBinding,
+                    required super.portManager,
+                  });
+
+                  
+
+                  @protected AnyhowException dco_decode_AnyhowException(dynamic raw);
+
+@protected RustStreamSink<String> dco_decode_StreamSink_String_Sse(dynamic raw);
+
+@protected RustStreamSink<MotionEventDto> dco_decode_StreamSink_motion_event_dto_Sse(dynamic raw);
+
+@protected String dco_decode_String(dynamic raw);
+
+@protected AnimationLayerDto dco_decode_animation_layer_dto(dynamic raw);
+
+@protected BatchedFrameDto dco_decode_batched_frame_dto(dynamic raw);
+
+@protected BlendModeDto dco_decode_blend_mode_dto(dynamic raw);
+
+@protected BlinkIntervalDto dco_decode_blink_interval_dto(dynamic raw);
+
+@protected bool dco_decode_bool(dynamic raw);
+
+@protected bool dco_decode_box_autoadd_bool(dynamic raw);
+
+@protected ChatAppConfigDto dco_decode_box_autoadd_chat_app_config_dto(dynamic raw);
+
+@protected ChatConfigDto dco_decode_box_autoadd_chat_config_dto(dynamic raw);
+
+@protected ChatInvokeModeDto dco_decode_box_autoadd_chat_invoke_mode_dto(dynamic raw);
+
+@protected ChatProviderDto dco_decode_box_autoadd_chat_provider_dto(dynamic raw);
+
+@protected EyeBlinkConfigDto dco_decode_box_autoadd_eye_blink_config_dto(dynamic raw);
+
+@protected double dco_decode_box_autoadd_f_32(dynamic raw);
+
+@protected double dco_decode_box_autoadd_f_64(dynamic raw);
+
+@protected PlatformInt64 dco_decode_box_autoadd_i_64(dynamic raw);
+
+@protected LipSyncConfigDto dco_decode_box_autoadd_lip_sync_config_dto(dynamic raw);
+
+@protected LipSyncTimelineDto dco_decode_box_autoadd_lip_sync_timeline_dto(dynamic raw);
+
+@protected LookAtConfigDto dco_decode_box_autoadd_look_at_config_dto(dynamic raw);
+
+@protected ParameterSmoothingDto dco_decode_box_autoadd_parameter_smoothing_dto(dynamic raw);
+
+@protected TextureOptionsDto dco_decode_box_autoadd_texture_options_dto(dynamic raw);
+
+@protected int dco_decode_box_autoadd_u_32(dynamic raw);
+
+@protected BigInt dco_decode_box_autoadd_u_64(dynamic raw);
+
+@protected ViewportDto dco_decode_box_autoadd_viewport_dto(dynamic raw);
+
+@protected ChatAppConfigDto dco_decode_chat_app_config_dto(dynamic raw);
+
+@protected ChatCapabilitiesDto dco_decode_chat_capabilities_dto(dynamic raw);
+
+@protected ChatConfigDto dco_decode_chat_config_dto(dynamic raw);
+
+@protected ChatInvokeModeDto dco_decode_chat_invoke_mode_dto(dynamic raw);
+
+@protected ChatKeySelectionDto dco_decode_chat_key_selection_dto(dynamic raw);
+
+@protected ChatKeyUsageDto dco_decode_chat_key_usage_dto(dynamic raw);
+
+@protected ChatMessageDto dco_decode_chat_message_dto(dynamic raw);
+
+@protected ChatModelProfileDto dco_decode_chat_model_profile_dto(dynamic raw);
+
+@protected ChatProfileDto dco_decode_chat_profile_dto(dynamic raw);
+
+@protected ChatProviderDto dco_decode_chat_provider_dto(dynamic raw);
+
+@protected ChatProviderKindDto dco_decode_chat_provider_kind_dto(dynamic raw);
+
+@protected ChatReplyDto dco_decode_chat_reply_dto(dynamic raw);
+
+@protected ChatRoleDto dco_decode_chat_role_dto(dynamic raw);
+
+@protected ChatSessionDto dco_decode_chat_session_dto(dynamic raw);
+
+@protected DrawBatchDto dco_decode_draw_batch_dto(dynamic raw);
+
+@protected DrawOrderModeDto dco_decode_draw_order_mode_dto(dynamic raw);
+
+@protected DrawableFrameDto dco_decode_drawable_frame_dto(dynamic raw);
+
+@protected DrawableUserDataDto dco_decode_drawable_user_data_dto(dynamic raw);
+
+@protected EmotionResultDto dco_decode_emotion_result_dto(dynamic raw);
+
+@protected EyeBlinkConfigDto dco_decode_eye_blink_config_dto(dynamic raw);
+
+@protected double dco_decode_f_32(dynamic raw);
+
+@protected F32Array16 dco_decode_f_32_array_16(dynamic raw);
+
+@protected F32Array2 dco_decode_f_32_array_2(dynamic raw);
+
+@protected F32Array4 dco_decode_f_32_array_4(dynamic raw);
+
+@protected double dco_decode_f_64(dynamic raw);
+
+@protected FrameDto dco_decode_frame_dto(dynamic raw);
+
+@protected int dco_decode_i_16(dynamic raw);
+
+@protected int dco_decode_i_32(dynamic raw);
+
+@protected PlatformInt64 dco_decode_i_64(dynamic raw);
+
+@protected IdleEffectDto dco_decode_idle_effect_dto(dynamic raw);
+
+@protected LayerBlendDto dco_decode_layer_blend_dto(dynamic raw);
+
+@protected LipSyncConfigDto dco_decode_lip_sync_config_dto(dynamic raw);
+
+@protected LipSyncTimelineDto dco_decode_lip_sync_timeline_dto(dynamic raw);
+
+@protected List<String> dco_decode_list_String(dynamic raw);
+
+@protected List<ChatKeyUsageDto> dco_decode_list_chat_key_usage_dto(dynamic raw);
+
+@protected List<ChatMessageDto> dco_decode_list_chat_message_dto(dynamic raw);
+
+@protected List<ChatProfileDto> dco_decode_list_chat_profile_dto(dynamic raw);
+
+@protected List<ChatSessionDto> dco_decode_list_chat_session_dto(dynamic raw);
+
+@protected List<DrawBatchDto> dco_decode_list_draw_batch_dto(dynamic raw);
+
+@protected List<DrawableFrameDto> dco_decode_list_drawable_frame_dto(dynamic raw);
+
+@protected List<DrawableUserDataDto> dco_decode_list_drawable_user_data_dto(dynamic raw);
+
+@protected List<IdleEffectDto> dco_decode_list_idle_effect_dto(dynamic raw);
+
+@protected List<Uint8List> dco_decode_list_list_prim_u_8_strict(dynamic raw);
+
+@protected List<LookAtMappingDto> dco_decode_list_look_at_mapping_dto(dynamic raw);
+
+@protected List<MaskGroupDto> dco_decode_list_mask_group_dto(dynamic raw);
+
+@protected List<ParameterGroupInfoDto> dco_decode_list_parameter_group_info_dto(dynamic raw);
+
+@protected List<ParameterInfoDto> dco_decode_list_parameter_info_dto(dynamic raw);
+
+@protected List<PartInfoDto> dco_decode_list_part_info_dto(dynamic raw);
+
+@protected List<double> dco_decode_list_prim_f_32_loose(dynamic raw);
+
+@protected Float32List dco_decode_list_prim_f_32_strict(dynamic raw);
+
+@protected List<int> dco_decode_list_prim_i_16_loose(dynamic raw);
+
+@protected Int16List dco_decode_list_prim_i_16_strict(dynamic raw);
+
+@protected Uint16List dco_decode_list_prim_u_16_strict(dynamic raw);
+
+@protected Uint32List dco_decode_list_prim_u_32_strict(dynamic raw);
+
+@protected List<int> dco_decode_list_prim_u_8_loose(dynamic raw);
+
+@protected Uint8List dco_decode_list_prim_u_8_strict(dynamic raw);
+
+@protected List<TextureDto> dco_decode_list_texture_dto(dynamic raw);
+
+@protected List<TextureLevelDto> dco_decode_list_texture_level_dto(dynamic raw);
+
+@protected List<VisemeKeyframeDto> dco_decode_list_viseme_keyframe_dto(dynamic raw);
+
+@protected LookAtConfigDto dco_decode_look_at_config_dto(dynamic raw);
+
+@protected LookAtMappingDto dco_decode_look_at_mapping_dto(dynamic raw);
+
+@protected MaskGroupDto dco_decode_mask_group_dto(dynamic raw);
+
+@protected MotionEventDto dco_decode_motion_event_dto(dynamic raw);
+
+@protected String? dco_decode_opt_String(dynamic raw);
+
+@protected bool? dco_decode_opt_box_autoadd_bool(dynamic raw);
+
+@protected ChatInvokeModeDto? dco_decode_opt_box_autoadd_chat_invoke_mode_dto(dynamic raw);
+
+@protected double? dco_decode_opt_box_autoadd_f_32(dynamic raw);
+
+@protected double? dco_decode_opt_box_autoadd_f_64(dynamic raw);
+
+@protected PlatformInt64? dco_decode_opt_box_autoadd_i_64(dynamic raw);
+
+@protected int? dco_decode_opt_box_autoadd_u_32(dynamic raw);
+
+@protected BigInt? dco_decode_opt_box_autoadd_u_64(dynamic raw);
+
+@protected ParameterGroupInfoDto dco_decode_parameter_group_info_dto(dynamic raw);
+
+@protected ParameterInfoDto dco_decode_parameter_info_dto(dynamic raw);
+
+@protected ParameterSmoothingDto dco_decode_parameter_smoothing_dto(dynamic raw);
+
+@protected PartInfoDto dco_decode_part_info_dto(dynamic raw);
+
+@protected SmoothingFilterDto dco_decode_smoothing_filter_dto(dynamic raw);
+
+@protected TextureDto dco_decode_texture_dto(dynamic raw);
+
+@protected TextureLevelDto dco_decode_texture_level_dto(dynamic raw);
+
+@protected TextureOptionsDto dco_decode_texture_options_dto(dynamic raw);
+
+@protected int dco_decode_u_16(dynamic raw);
+
+@protected int dco_decode_u_32(dynamic raw);
+
+@protected BigInt dco_decode_u_64(dynamic raw);
+
+@protected int dco_decode_u_8(dynamic raw);
+
+@protected void dco_decode_unit(dynamic raw);
+
+@protected ViewFitModeDto dco_decode_view_fit_mode_dto(dynamic raw);
+
+@protected ViewTransformDto dco_decode_view_transform_dto(dynamic raw);
+
+@protected ViewportDto dco_decode_viewport_dto(dynamic raw);
+
+@protected VisemeDto dco_decode_viseme_dto(dynamic raw);
+
+@protected VisemeKeyframeDto dco_decode_viseme_keyframe_dto(dynamic raw);
+
+@protected VisemeTimelineDto dco_decode_viseme_timeline_dto(dynamic raw);
+
+@protected AnyhowException sse_decode_AnyhowException(SseDeserializer deserializer);
+
+@protected RustStreamSink<String> sse_decode_StreamSink_String_Sse(SseDeserializer deserializer);
+
+@protected RustStreamSink<MotionEventDto> sse_decode_StreamSink_motion_event_dto_Sse(SseDeserializer deserializer);
+
+@protected String sse_decode_String(SseDeserializer deserializer);
+
+@protected AnimationLayerDto sse_decode_animation_layer_dto(SseDeserializer deserializer);
+
+@protected BatchedFrameDto sse_decode_batched_frame_dto(SseDeserializer deserializer);
+
+@protected BlendModeDto sse_decode_blend_mode_dto(SseDeserializer deserializer);
+
+@protected BlinkIntervalDto sse_decode_blink_interval_dto(SseDeserializer deserializer);
+
+@protected bool sse_decode_bool(SseDeserializer deserializer);
+
+@protected bool sse_decode_box_autoadd_bool(SseDeserializer deserializer);
+
+@protected ChatAppConfigDto sse_decode_box_autoadd_chat_app_config_dto(SseDeserializer deserializer);
+
+@protected ChatConfigDto sse_decode_box_autoadd_chat_config_dto(SseDeserializer deserializer);
+
+@protected ChatInvokeModeDto sse_decode_box_autoadd_chat_invoke_mode_dto(SseDeserializer deserializer);
+
+@protected ChatProviderDto sse_decode_box_autoadd_chat_provider_dto(SseDeserializer deserializer);
+
+@protected EyeBlinkConfigDto sse_decode_box_autoadd_eye_blink_config_dto(SseDeserializer deserializer);
+
+@protected double sse_decode_box_autoadd_f_32(SseDeserializer deserializer);
+
+@protected double sse_decode_box_autoadd_f_64(SseDeserializer deserializer);
+
+@protected PlatformInt64 sse_decode_box_autoadd_i_64(SseDeserializer deserializer);
+
+@protected LipSyncConfigDto sse_decode_box_autoadd_lip_sync_config_dto(SseDeserializer deserializer);
+
+@protected LipSyncTimelineDto sse_decode_box_autoadd_lip_sync_timeline_dto(SseDeserializer deserializer);
+
+@protected LookAtConfigDto sse_decode_box_autoadd_look_at_config_dto(SseDeserializer deserializer);
+
+@protected ParameterSmoothingDto sse_decode_box_autoadd_parameter_smoothing_dto(SseDeserializer deserializer);
+
+@protected TextureOptionsDto sse_decode_box_autoadd_texture_options_dto(SseDeserializer deserializer);
+
+@protected int sse_decode_box_autoadd_u_32(SseDeserializer deserializer);
+
+@protected BigInt sse_decode_box_autoadd_u_64(SseDeserializer deserializer);
+
+@protected ViewportDto sse_decode_box_autoadd_viewport_dto(SseDeserializer deserializer);
+
+@protected ChatAppConfigDto sse_decode_chat_app_config_dto(SseDeserializer deserializer);
+
+@protected ChatCapabilitiesDto sse_decode_chat_capabilities_dto(SseDeserializer deserializer);
+
+@protected ChatConfigDto sse_decode_chat_config_dto(SseDeserializer deserializer);
+
+@protected ChatInvokeModeDto sse_decode_chat_invoke_mode_dto(SseDeserializer deserializer);
+
+@protected ChatKeySelectionDto sse_decode_chat_key_selection_dto(SseDeserializer deserializer);
+
+@protected ChatKeyUsageDto sse_decode_chat_key_usage_dto(SseDeserializer deserializer);
+
+@protected ChatMessageDto sse_decode_chat_message_dto(SseDeserializer deserializer);
+
+@protected ChatModelProfileDto sse_decode_chat_model_profile_dto(SseDeserializer deserializer);
+
+@protected ChatProfileDto sse_decode_chat_profile_dto(SseDeserializer deserializer);
+
+@protected ChatProviderDto sse_decode_chat_provider_dto(SseDeserializer deserializer);
+
+@protected ChatProviderKindDto sse_decode_chat_provider_kind_dto(SseDeserializer deserializer);
+
+@protected ChatReplyDto sse_decode_chat_reply_dto(SseDeserializer deserializer);
+
+@protected ChatRoleDto sse_decode_chat_role_dto(SseDeserializer deserializer);
+
+@protected ChatSessionDto sse_decode_chat_session_dto(SseDeserializer deserializer);
+
+@protected DrawBatchDto sse_decode_draw_batch_dto(SseDeserializer deserializer);
+
+@protected DrawOrderModeDto sse_decode_draw_order_mode_dto(SseDeserializer deserializer);
+
+@protected DrawableFrameDto sse_decode_drawable_frame_dto(SseDeserializer deserializer);
+
+@protected DrawableUserDataDto sse_decode_drawable_user_data_dto(SseDeserializer deserializer);
+
+@protected EmotionResultDto sse_decode_emotion_result_dto(SseDeserializer deserializer);
+
+@protected EyeBlinkConfigDto sse_decode_eye_blink_config_dto(SseDeserializer deserializer);
+
+@protected double sse_decode_f_32(SseDeserializer deserializer);
+
+@protected F32Array16 sse_decode_f_32_array_16(SseDeserializer deserializer);
+
+@protected F32Array2 sse_decode_f_32_array_2(SseDeserializer deserializer);
+
+@protected F32Array4 sse_decode_f_32_array_4(SseDeserializer deserializer);
+
+@protected double sse_decode_f_64(SseDeserializer deserializer);
+
+@protected FrameDto sse_decode_frame_dto(SseDeserializer deserializer);
+
+@protected int sse_decode_i_16(SseDeserializer deserializer);
+
+@protected int sse_decode_i_32(SseDeserializer deserializer);
+
+@protected PlatformInt64 sse_decode_i_64(SseDeserializer deserializer);
+
+@protected IdleEffectDto sse_decode_idle_effect_dto(SseDeserializer deserializer);
+
+@protected LayerBlendDto sse_decode_layer_blend_dto(SseDeserializer deserializer);
+
+@protected LipSyncConfigDto sse_decode_lip_sync_config_dto(SseDeserializer deserializer);
+
+@protected LipSyncTimelineDto sse_decode_lip_sync_timeline_dto(SseDeserializer deserializer);
+
+@protected List<String> sse_decode_list_String(SseDeserializer deserializer);
+
+@protected List<ChatKeyUsageDto> sse_decode_list_chat_key_usage_dto(SseDeserializer deserializer);
+
+@protected List<ChatMessageDto> sse_decode_list_chat_message_dto(SseDeserializer deserializer);
+
+@protected List<ChatProfileDto> sse_decode_list_chat_profile_dto(SseDeserializer deserializer);
+
+@protected List<ChatSessionDto> sse_decode_list_chat_session_dto(SseDeserializer deserializer);
+
+@protected List<DrawBatchDto> sse_decode_list_draw_batch_dto(SseDeserializer deserializer);
+
+@protected List<DrawableFrameDto> sse_decode_list_drawable_frame_dto(SseDeserializer deserializer);
+
+@protected List<DrawableUserDataDto> sse_decode_list_drawable_user_data_dto(SseDeserializer deserializer);
+
+@protected List<IdleEffectDto> sse_decode_list_idle_effect_dto(SseDeserializer deserializer);
+
+@protected List<Uint8List> sse_decode_list_list_prim_u_8_strict(SseDeserializer deserializer);
+
+@protected List<LookAtMappingDto> sse_decode_list_look_at_mapping_dto(SseDeserializer deserializer);
+
+@protected List<MaskGroupDto> sse_decode_list_mask_group_dto(SseDeserializer deserializer);
+
+@protected List<ParameterGroupInfoDto> sse_decode_list_parameter_group_info_dto(SseDeserializer deserializer);
+
+@protected List<ParameterInfoDto> sse_decode_list_parameter_info_dto(SseDeserializer deserializer);
+
+@protected List<PartInfoDto> sse_decode_list_part_info_dto(SseDeserializer deserializer);
+
+@protected List<double> sse_decode_list_prim_f_32_loose(SseDeserializer deserializer);
+
+@protected Float32List sse_decode_list_prim_f_32_strict(SseDeserializer deserializer);
+
+@protected List<int> sse_decode_list_prim_i_16_loose(SseDeserializer deserializer);
+
+@protected Int16List sse_decode_list_prim_i_16_strict(SseDeserializer deserializer);
+
+@protected Uint16List sse_decode_list_prim_u_16_strict(SseDeserializer deserializer);
+
+@protected Uint32List sse_decode_list_prim_u_32_strict(SseDeserializer deserializer);
+
+@protected List<int> sse_decode_list_prim_u_8_loose(SseDeserializer deserializer);
+
+@protected Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer);
+
+@protected List<TextureDto> sse_decode_list_texture_dto(SseDeserializer deserializer);
+
+@protected List<TextureLevelDto> sse_decode_list_texture_level_dto(SseDeserializer deserializer);
+
+@protected List<VisemeKeyframeDto> sse_decode_list_viseme_keyframe_dto(SseDeserializer deserializer);
+
+@protected LookAtConfigDto sse_decode_look_at_config_dto(SseDeserializer deserializer);
+
+@protected LookAtMappingDto sse_decode_look_at_mapping_dto(SseDeserializer deserializer);
+
+@protected MaskGroupDto sse_decode_mask_group_dto(SseDeserializer deserializer);
+
+@protected MotionEventDto sse_decode_motion_event_dto(SseDeserializer deserializer);
+
+@protected String? sse_decode_opt_String(SseDeserializer deserializer);
+
+@protected bool? sse_decode_opt_box_autoadd_bool(SseDeserializer deserializer);
+
+@protected ChatInvokeModeDto? sse_decode_opt_box_autoadd_chat_invoke_mode_dto(SseDeserializer deserializer);
+
+@protected double? sse_decode_opt_box_autoadd_f_32(SseDeserializer deserializer);
+
+@protected double? sse_decode_opt_box_autoadd_f_64(SseDeserializer deserializer);
+
+@protected PlatformInt64? sse_decode_opt_box_autoadd_i_64(SseDeserializer deserializer);
+
+@protected int? sse_decode_opt_box_autoadd_u_32(SseDeserializer deserializer);
+
+@protected BigInt? sse_decode_opt_box_autoadd_u_64(SseDeserializer deserializer);
+
+@protected ParameterGroupInfoDto sse_decode_parameter_group_info_dto(SseDeserializer deserializer);
+
+@protected ParameterInfoDto sse_decode_parameter_info_dto(SseDeserializer deserializer);
+
+@protected ParameterSmoothingDto sse_decode_parameter_smoothing_dto(SseDeserializer deserializer);
+
+@protected PartInfoDto sse_decode_part_info_dto(SseDeserializer deserializer);
+
+@protected SmoothingFilterDto sse_decode_smoothing_filter_dto(SseDeserializer deserializer);
+
+@protected TextureDto sse_decode_texture_dto(SseDeserializer deserializer);
+
+@protected TextureLevelDto sse_decode_texture_level_dto(SseDeserializer deserializer);
+
+@protected TextureOptionsDto sse_decode_texture_options_dto(SseDeserializer deserializer);
+
+@protected int sse_decode_u_16(SseDeserializer deserializer);
+
+@protected int sse_decode_u_32(SseDeserializer deserializer);
+
+@protected BigInt sse_decode_u_64(SseDeserializer deserializer);
+
+@protected int sse_decode_u_8(SseDeserializer deserializer);
+
+@protected void sse_decode_unit(SseDeserializer deserializer);
+
+@protected ViewFitModeDto sse_decode_view_fit_mode_dto(SseDeserializer deserializer);
+
+@protected ViewTransformDto sse_decode_view_transform_dto(SseDeserializer deserializer);
+
+@protected ViewportDto sse_decode_viewport_dto(SseDeserializer deserializer);
+
+@protected VisemeDto sse_decode_viseme_dto(SseDeserializer deserializer);
+
+@protected VisemeKeyframeDto sse_decode_viseme_keyframe_dto(SseDeserializer deserializer);
+
+@protected VisemeTimelineDto sse_decode_viseme_timeline_dto(SseDeserializer deserializer);
+
+@protected void sse_encode_AnyhowException(AnyhowException self, SseSerializer serializer);
+
+@protected void sse_encode_StreamSink_String_Sse(RustStreamSink<String> self, SseSerializer serializer);
+
+@protected void sse_encode_StreamSink_motion_event_dto_Sse(RustStreamSink<MotionEventDto> self, SseSerializer serializer);
+
+@protected void sse_encode_String(String self, SseSerializer serializer);
+
+@protected void sse_encode_animation_layer_dto(AnimationLayerDto self, SseSerializer serializer);
+
+@protected void sse_encode_batched_frame_dto(BatchedFrameDto self, SseSerializer serializer);
+
+@protected void sse_encode_blend_mode_dto(BlendModeDto self, SseSerializer serializer);
+
+@protected void sse_encode_blink_interval_dto(BlinkIntervalDto self, SseSerializer serializer);
+
+@protected void sse_encode_bool(bool self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_bool(bool self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_chat_app_config_dto(ChatAppConfigDto self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_chat_config_dto(ChatConfigDto self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_chat_invoke_mode_dto(ChatInvokeModeDto self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_chat_provider_dto(ChatProviderDto self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_eye_blink_config_dto(EyeBlinkConfigDto self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_f_32(double self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_f_64(double self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_i_64(PlatformInt64 self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_lip_sync_config_dto(LipSyncConfigDto self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_lip_sync_timeline_dto(LipSyncTimelineDto self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_look_at_config_dto(LookAtConfigDto self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_parameter_smoothing_dto(ParameterSmoothingDto self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_texture_options_dto(TextureOptionsDto self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_u_32(int self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_u_64(BigInt self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_viewport_dto(ViewportDto self, SseSerializer serializer);
+
+@protected void sse_encode_chat_app_config_dto(ChatAppConfigDto self, SseSerializer serializer);
+
+@protected void sse_encode_chat_capabilities_dto(ChatCapabilitiesDto self, SseSerializer serializer);
+
+@protected void sse_encode_chat_config_dto(ChatConfigDto self, SseSerializer serializer);
+
+@protected void sse_encode_chat_invoke_mode_dto(ChatInvokeModeDto self, SseSerializer serializer);
+
+@protected void sse_encode_chat_key_selection_dto(ChatKeySelectionDto self, SseSerializer serializer);
+
+@protected void sse_encode_chat_key_usage_dto(ChatKeyUsageDto self, SseSerializer serializer);
+
+@protected void sse_encode_chat_message_dto(ChatMessageDto self, SseSerializer serializer);
+
+@protected void sse_encode_chat_model_profile_dto(ChatModelProfileDto self, SseSerializer serializer);
+
+@protected void sse_encode_chat_profile_dto(ChatProfileDto self, SseSerializer serializer);
+
+@protected void sse_encode_chat_provider_dto(ChatProviderDto self, SseSerializer serializer);
+
+@protected void sse_encode_chat_provider_kind_dto(ChatProviderKindDto self, SseSerializer serializer);
+
+@protected void sse_encode_chat_reply_dto(ChatReplyDto self, SseSerializer serializer);
+
+@protected void sse_encode_chat_role_dto(ChatRoleDto self, SseSerializer serializer);
+
+@protected void sse_encode_chat_session_dto(ChatSessionDto self, SseSerializer serializer);
+
+@protected void sse_encode_draw_batch_dto(DrawBatchDto self, SseSerializer serializer);
+
+@protected void sse_encode_draw_order_mode_dto(DrawOrderModeDto self, SseSerializer serializer);
+
+@protected void sse_encode_drawable_frame_dto(DrawableFrameDto self, SseSerializer serializer);
+
+@protected void sse_encode_drawable_user_data_dto(DrawableUserDataDto self, SseSerializer serializer);
+
+@protected void sse_encode_emotion_result_dto(EmotionResultDto self, SseSerializer serializer);
+
+@protected void sse_encode_eye_blink_config_dto(EyeBlinkConfigDto self, SseSerializer serializer);
+
+@protected void sse_encode_f_32(double self, SseSerializer serializer);
+
+@protected void sse_encode_f_32_array_16(F32Array16 self, SseSerializer serializer);
+
+@protected void sse_encode_f_32_array_2(F32Array2 self, SseSerializer serializer);
+
+@protected void sse_encode_f_32_array_4(F32Array4 self, SseSerializer serializer);
+
+@protected void sse_encode_f_64(double self, SseSerializer serializer);
+
+@protected void sse_encode_frame_dto(FrameDto self, SseSerializer serializer);
+
+@protected void sse_encode_i_16(int self, SseSerializer serializer);
+
+@protected void sse_encode_i_32(int self, SseSerializer serializer);
+
+@protected void sse_encode_i_64(PlatformInt64 self, SseSerializer serializer);
+
+@protected void sse_encode_idle_effect_dto(IdleEffectDto self, SseSerializer serializer);
+
+@protected void sse_encode_layer_blend_dto(LayerBlendDto self, SseSerializer serializer);
+
+@protected void sse_encode_lip_sync_config_dto(LipSyncConfigDto self, SseSerializer serializer);
+
+@protected void sse_encode_lip_sync_timeline_dto(LipSyncTimelineDto self, SseSerializer serializer);
+
+@protected void sse_encode_list_String(List<String> self, SseSerializer serializer);
+
+@protected void sse_encode_list_chat_key_usage_dto(List<ChatKeyUsageDto> self, SseSerializer serializer);
+
+@protected void sse_encode_list_chat_message_dto(List<ChatMessageDto> self, SseSerializer serializer);
+
+@protected void sse_encode_list_chat_profile_dto(List<ChatProfileDto> self, SseSerializer serializer);
+
+@protected void sse_encode_list_chat_session_dto(List<ChatSessionDto> self, SseSerializer serializer);
+
+@protected void sse_encode_list_draw_batch_dto(List<DrawBatchDto> self, SseSerializer serializer);
+
+@protected void sse_encode_list_drawable_frame_dto(List<DrawableFrameDto> self, SseSerializer serializer);
 
-  @protected
-  double dco_decode_f_32(dynamic raw);
+@protected void sse_encode_list_drawable_user_data_dto(List<DrawableUserDataDto> self, SseSerializer serializer);
 
-  @protected
-  F32Array4 dco_decode_f_32_array_4(dynamic raw);
+@protected void sse_encode_list_idle_effect_dto(List<IdleEffectDto> self, SseSerializer serializer);
 
-  @protected
-  FrameDto dco_decode_frame_dto(dynamic raw);
+@protected void sse_encode_list_list_prim_u_8_strict(List<Uint8List> self, SseSerializer serializer);
 
-  @protected
-  int dco_decode_i_32(dynamic raw);
+@protected void sse_encode_list_look_at_mapping_dto(List<LookAtMappingDto> self, SseSerializer serializer);
 
-  @protected
-  List<String> dco_decode_list_String(dynamic raw);
+@protected void sse_encode_list_mask_group_dto(List<MaskGroupDto> self, SseSerializer serializer);
 
-  @protected
-  List<DrawableFrameDto> dco_decode_list_drawable_frame_dto(dynamic raw);
+@protected void sse_encode_list_parameter_group_info_dto(List<ParameterGroupInfoDto> self, SseSerializer serializer);
 
-  @protected
-  Float32List dco_decode_list_prim_f_32_strict(dynamic raw);
+@protected void sse_encode_list_parameter_info_dto(List<ParameterInfoDto> self, SseSerializer serializer);
 
-  @protected
-  Uint16List dco_decode_list_prim_u_16_strict(dynamic raw);
+@protected void sse_encode_list_part_info_dto(List<PartInfoDto> self, SseSerializer serializer);
 
-  @protected
-  List<int> dco_decode_list_prim_u_8_loose(dynamic raw);
+@protected void sse_encode_list_prim_f_32_loose(List<double> self, SseSerializer serializer);
 
-  @protected
-  Uint8List dco_decode_list_prim_u_8_strict(dynamic raw);
+@protected void sse_encode_list_prim_f_32_strict(Float32List self, SseSerializer serializer);
 
-  @protected
-  int dco_decode_u_16(dynamic raw);
+@protected void sse_encode_list_prim_i_16_loose(List<int> self, SseSerializer serializer);
 
-  @protected
-  int dco_decode_u_32(dynamic raw);
+@protected void sse_encode_list_prim_i_16_strict(Int16List self, SseSerializer serializer);
 
-  @protected
-  BigInt dco_decode_u_64(dynamic raw);
+@protected void sse_encode_list_prim_u_16_strict(Uint16List self, SseSerializer serializer);
 
-  @protected
-  int dco_decode_u_8(dynamic raw);
+@protected void sse_encode_list_prim_u_32_strict(Uint32List self, SseSerializer serializer);
 
-  @protected
-  void dco_decode_unit(dynamic raw);
+@protected void sse_encode_list_prim_u_8_loose(List<int> self, SseSerializer serializer);
 
-  @protected
-  String sse_decode_String(SseDeserializer deserializer);
+@protected void sse_encode_list_prim_u_8_strict(Uint8List self, SseSerializer serializer);
 
-  @protected
-  bool sse_decode_bool(SseDeserializer deserializer);
+@protected void sse_encode_list_texture_dto(List<TextureDto> self, SseSerializer serializer);
 
-  @protected
-  DrawableFrameDto sse_decode_drawable_frame_dto(SseDeserializer deserializer);
+@protected void sse_encode_list_texture_level_dto(List<TextureLevelDto> self, SseSerializer serializer);
 
-  @protected
-  double sse_decode_f_32(SseDeserializer deserializer);
+@protected void sse_encode_list_viseme_keyframe_dto(List<VisemeKeyframeDto> self, SseSerializer serializer);
 
-  @protected
-  F32Array4 sse_decode_f_32_array_4(SseDeserializer deserializer);
+@protected void sse_encode_look_at_config_dto(LookAtConfigDto self, SseSerializer serializer);
 
-  @protected
-  FrameDto sse_decode_frame_dto(SseDeserializer deserializer);
+@protected void sse_encode_look_at_mapping_dto(LookAtMappingDto self, SseSerializer serializer);
 
-  @protected
-  int sse_decode_i_32(SseDeserializer deserializer);
+@protected void sse_encode_mask_group_dto(MaskGroupDto self, SseSerializer serializer);
 
-  @protected
-  List<String> sse_decode_list_String(SseDeserializer deserializer);
+@protected void sse_encode_motion_event_dto(MotionEventDto self, SseSerializer serializer);
 
-  @protected
-  List<DrawableFrameDto> sse_decode_list_drawable_frame_dto(
-    SseDeserializer deserializer,
-  );
+@protected void sse_encode_opt_String(String? self, SseSerializer serializer);
 
-  @protected
-  Float32List sse_decode_list_prim_f_32_strict(SseDeserializer deserializer);
+@protected void sse_encode_opt_box_autoadd_bool(bool? self, SseSerializer serializer);
 
-  @protected
-  Uint16List sse_decode_list_prim_u_16_strict(SseDeserializer deserializer);
+@protected void sse_encode_opt_box_autoadd_chat_invoke_mode_dto(ChatInvokeModeDto? self, SseSerializer serializer);
 
-  @protected
-  List<int> sse_decode_list_prim_u_8_loose(SseDeserializer deserializer);
+@protected void sse_encode_opt_box_autoadd_f_32(double? self, SseSerializer serializer);
 
-  @protected
-  Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer);
+@protected void sse_encode_opt_box_autoadd_f_64(double? self, SseSerializer serializer);
 
-  @protected
-  int sse_decode_u_16(SseDeserializer deserializer);
+@protected void sse_encode_opt_box_autoadd_i_64(PlatformInt64? self, SseSerializer serializer);
 
-  @protected
-  int sse_decode_u_32(SseDeserializer deserializer);
+@protected void sse_encode_opt_box_autoadd_u_32(int? self, SseSerializer serializer);
 
-  @protected
-  BigInt sse_decode_u_64(SseDeserializer deserializer);
+@protected void sse_encode_opt_box_autoadd_u_64(BigInt? self, SseSerializer serializer);
 
-  @protected
-  int sse_decode_u_8(SseDeserializer deserializer);
+@protected void sse_encode_parameter_group_info_dto(ParameterGroupInfoDto self, SseSerializer serializer);
 
-  @protected
-  void sse_decode_unit(SseDeserializer deserializer);
+@protected void sse_encode_parameter_info_dto(ParameterInfoDto self, SseSerializer serializer);
 
-  @protected
-  void sse_encode_String(String self, SseSerializer serializer);
+@protected void sse_encode_parameter_smoothing_dto(ParameterSmoothingDto self, SseSerializer serializer);
 
-  @protected
-  void sse_encode_bool(bool self, SseSerializer serializer);
+@protected void sse_encode_part_info_dto(PartInfoDto self, SseSerializer serializer);
 
-  @protected
-  void sse_encode_drawable_frame_dto(
-    DrawableFrameDto self,
-    SseSerializer serializer,
-  );
+@protected void sse_encode_smoothing_filter_dto(SmoothingFilterDto self, SseSerializer serializer);
 
-  @protected
-  void sse_encode_f_32(double self, SseSerializer serializer);
+@protected void sse_encode_texture_dto(TextureDto self, SseSerializer serializer);
 
-  @protected
-  void sse_encode_f_32_array_4(F32Array4 self, SseSerializer serializer);
+@protected void sse_encode_texture_level_dto(TextureLevelDto self, SseSerializer serializer);
 
-  @protected
-  void sse_encode_frame_dto(FrameDto self, SseSerializer serializer);
+@protected void sse_encode_texture_options_dto(TextureOptionsDto self, SseSerializer serializer);
 
-  @protected
-  void sse_encode_i_32(int self, SseSerializer serializer);
+@protected void sse_encode_u_16(int self, SseSerializer serializer);
 
-  @protected
-  void sse_encode_list_String(List<String> self, SseSerializer serializer);
+@protected void sse_encode_u_32(int self, SseSerializer serializer);
 
-  @protected
-  void sse_encode_list_drawable_frame_dto(
-    List<DrawableFrameDto> self,
-    SseSerializer serializer,
-  );
+@protected void sse_encode_u_64(BigInt self, SseSerializer serializer);
 
-  @protected
-  void sse_encode_list_prim_f_32_strict(
-    Float32List self,
-    SseSerializer serializer,
-  );
+@protected void sse_encode_u_8(int self, SseSerializer serializer);
 
-  @protected
-  void sse_encode_list_prim_u_16_strict(
-    Uint16List self,
-    SseSerializer serializer,
-  );
+@protected void sse_encode_unit(void self, SseSerializer serializer);
 
-  @protected
-  void sse_encode_list_prim_u_8_loose(List<int> self, SseSerializer serializer);
+@protected void sse_encode_view_fit_mode_dto(ViewFitModeDto self, SseSerializer serializer);
 
-  @protected
-  void sse_encode_list_prim_u_8_strict(
-    Uint8List self,
-    SseSerializer serializer,
-  );
+@protected void sse_encode_view_transform_dto(ViewTransformDto self, SseSerializer serializer);
 
-  @protected
-  void sse_encode_u_16(int self, SseSerializer serializer);
+@protected void sse_encode_viewport_dto(ViewportDto self, SseSerializer serializer);
 
-  @protected
-  void sse_encode_u_32(int self, SseSerializer serializer);
+@protected void sse_encode_viseme_dto(VisemeDto self, SseSerializer serializer);
 
-  @protected
-  void sse_encode_u_64(BigInt self, SseSerializer serializer);
+@protected void sse_encode_viseme_keyframe_dto(VisemeKeyframeDto self, SseSerializer serializer);
 
-  @protected
-  void sse_encode_u_8(int self, SseSerializer serializer);
+@protected void sse_encode_viseme_timeline_dto(VisemeTimelineDto self, SseSerializer serializer);
+                }
+                
 
-  @protected
-  void sse_encode_unit(void self, SseSerializer serializer);
-}
 
 // Section: wire_class
 
 class RustLibWire implements BaseWire {
-  RustLibWire.fromExternalLibrary(ExternalLibrary lib);
-}
+            RustLibWire.fromExternalLibrary(ExternalLibrary lib);
 
-@JS('wasm_bindgen')
-external RustLibWasmModule get wasmModule;
+            
+        }
+        @JS('wasm_bindgen') external RustLibWasmModule get wasmModule;
 
-@JS()
-@anonymous
-extension type RustLibWasmModule._(JSObject _) implements JSObject {}
+        @JS() @anonymous extension type RustLibWasmModule._(JSObject _) implements JSObject {
+            
+        }
+        
\ No newline at end of file
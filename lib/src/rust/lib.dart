@@ -7,14 +7,40 @@ import 'frb_generated.dart';
 import 'package:collection/collection.dart';
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
 
-class F32Array4 extends NonGrowableListView<double> {
-  static const arraySize = 4;
 
-  @internal
-  Float32List get inner => _inner;
-  final Float32List _inner;
+            
 
-  F32Array4(this._inner) : assert(_inner.length == arraySize), super(_inner);
+            
 
-  F32Array4.init() : this(Float32List(arraySize));
-}
+            
+            class F32Array16 extends NonGrowableListView<double> {
+                static const arraySize = 16;
+
+                @internal
+                Float32List get inner => _inner;
+                final Float32List _inner;
+
+                F32Array16(this._inner)
+                    : assert(_inner.length == arraySize),
+                      super(_inner);
+  
+                F32Array16.init(): this(Float32List(arraySize));
+              }
+            
+
+
+            class F32Array4 extends NonGrowableListView<double> {
+                static const arraySize = 4;
+
+                @internal
+                Float32List get inner => _inner;
+                final Float32List _inner;
+
+                F32Array4(this._inner)
+                    : assert(_inner.length == arraySize),
+                      super(_inner);
+  
+                F32Array4.init(): this(Float32List(arraySize));
+              }
+            
+            
\ No newline at end of file
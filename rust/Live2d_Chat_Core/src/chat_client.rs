@@ -0,0 +1,530 @@
+use std::sync::Arc;
+
+use serde::{Deserialize, Serialize};
+
+use crate::config::AppConfig;
+use crate::context::{summarize_transcript, ContextBudget, ContextBuilder, SUMMARIZE_INSTRUCTION};
+use crate::conversation_store::Session;
+use crate::key_pool::KeyUsage;
+use crate::provider::{ChatGlmProvider, ChatProvider, ChatRequest, ProviderCapabilities};
+use crate::task_poll::CancelToken;
+
+#[derive(Debug, thiserror::Error)]
+pub enum ChatError {
+    #[error("Your API Key is Invalid")]
+    InvalidApiKey,
+    #[error("Unknown profile: {0}")]
+    UnknownProfile(String),
+    #[error("Error reading config: {0}")]
+    Config(#[from] toml::de::Error),
+    #[error("Error writing config: {0}")]
+    SerializeConfig(#[from] toml::ser::Error),
+    #[error("Invalid config `{key}`: {message}")]
+    InvalidConfig { key: String, message: String },
+    #[error("Missing config: {0}")]
+    MissingConfig(&'static str),
+    #[error("Config file error: {0}")]
+    Io(#[from] std::io::Error),
+    #[error("HTTP request failure: {0}")]
+    Http(#[from] reqwest::Error),
+    #[error("HTTP request failure, Code: {status}, {message}")]
+    Api { status: u16, message: String },
+    #[error("Invalid response: {0}")]
+    InvalidResponse(String),
+    #[error("Async task {task_id} failed, Status: {status}, {}", message.as_deref().unwrap_or("no message"))]
+    TaskFailed {
+        task_id: String,
+        status: String,
+        message: Option<String>,
+    },
+    #[error("Async task {0} timed out")]
+    TaskTimeout(String),
+    #[error("Async task {0} cancelled")]
+    Cancelled(String),
+    #[error("Not supported by this provider: {0}")]
+    Unsupported(&'static str),
+    #[error("All API keys are temporarily disabled")]
+    NoAvailableKey,
+}
+
+impl ChatError {
+    /// 网络错误与服务端 5xx，重试可能成功
+    pub fn is_transient(&self) -> bool {
+        match self {
+            ChatError::Http(err) => err.is_timeout() || err.is_connect() || err.is_request(),
+            ChatError::Api { status, .. } => *status >= 500,
+            _ => false,
+        }
+    }
+}
+
+/// 不影响本次回复、但调用方应当知道的失败
+#[derive(Debug, thiserror::Error)]
+pub enum ChatWarning {
+    /// 早期对话未能概括，已直接移出上下文
+    #[error("Failed to summarize history: {0}")]
+    Summarize(ChatError),
+    #[error("Failed to write to session {session_id}: {error}")]
+    SessionWrite {
+        session_id: String,
+        error: std::io::Error,
+    },
+}
+
+/// 一次发送的回复
+#[derive(Debug)]
+pub struct ChatReply {
+    pub content: String,
+    pub warnings: Vec<ChatWarning>,
+}
+
+/// 每次发送时选择的调用方式
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum InvokeMode {
+    /// 直接请求并等待完整回复，适合短回复
+    #[default]
+    Sync,
+    /// 提交异步任务后轮询结果，只有 ChatGLM 支持
+    AsyncTask,
+    /// 流式返回增量文本
+    Stream,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ChatRole {
+    System,
+    User,
+    Assistant,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ChatMessage {
+    pub role: ChatRole,
+    pub content: String,
+}
+
+impl ChatMessage {
+    pub fn new(role: ChatRole, content: impl Into<String>) -> Self {
+        Self {
+            role,
+            content: content.into(),
+        }
+    }
+}
+
+/// 对话参数
+#[derive(Debug, Clone, PartialEq)]
+pub struct ChatConfig {
+    pub language_model: String,
+    /// 角色设定，作为每次请求的第一条 system 消息
+    pub system_content: Option<String>,
+    pub temperature: f64,
+    pub top_p: f64,
+    /// 每次请求最多带上的历史消息条数（不含 system 消息）；更早的一问一答与超出预算的一样，
+    /// 按 `summarize` 概括为摘要或丢弃
+    pub max_history: usize,
+    /// 上下文预算，None 时按模型选择，见 [`ContextBudget::for_model`]
+    pub context_budget: Option<ContextBudget>,
+    /// 历史超出预算时，让模型把移出上下文的对话概括为摘要，而不是直接丢弃
+    pub summarize: bool,
+}
+
+impl Default for ChatConfig {
+    fn default() -> Self {
+        Self {
+            language_model: "glm-4-flash".to_string(),
+            system_content: None,
+            temperature: 0.9,
+            top_p: 0.7,
+            max_history: 20,
+            context_budget: None,
+            summarize: true,
+        }
+    }
+}
+
+impl ChatConfig {
+    pub fn context_budget(&self) -> ContextBudget {
+        self.context_budget.unwrap_or_else(|| ContextBudget::for_model(&self.language_model))
+    }
+
+    /// 从 TOML 配置中读取名为 `profile` 的模型档案，见 [`AppConfig`]
+    pub fn from_toml(content: &str, profile: &str) -> Result<Self, ChatError> {
+        AppConfig::from_toml(content)?.chat_config(Some(profile))
+    }
+}
+
+/// [`ChatClient::request`] 组装的请求，以及回复成功后才应用的历史变化
+struct PreparedRequest {
+    request: ChatRequest,
+    /// 移出上下文的最早的历史消息条数
+    dropped: usize,
+    /// 新的摘要，None 时不变
+    summary: Option<String>,
+    warnings: Vec<ChatWarning>,
+}
+
+/// 带对话历史的聊天客户端，请求由 [`ChatProvider`] 发送
+#[derive(Debug, Clone)]
+pub struct ChatClient {
+    provider: Arc<dyn ChatProvider>,
+    config: ChatConfig,
+    history: Vec<ChatMessage>,
+    /// 已移出上下文的早期对话的摘要
+    summary: Option<String>,
+    session: Option<Session>,
+}
+
+impl ChatClient {
+    pub fn new(provider: Arc<dyn ChatProvider>, config: ChatConfig) -> Self {
+        Self {
+            provider,
+            config,
+            history: Vec::new(),
+            summary: None,
+            session: None,
+        }
+    }
+
+    /// 使用 `id.secret` 格式的 API Key 连接 ChatGLM
+    pub fn chatglm(api_key: &str, config: ChatConfig) -> Result<Self, ChatError> {
+        Ok(Self::new(Arc::new(ChatGlmProvider::new(api_key)?), config))
+    }
+
+    pub fn provider(&self) -> &dyn ChatProvider {
+        self.provider.as_ref()
+    }
+
+    pub fn capabilities(&self) -> ProviderCapabilities {
+        self.provider.capabilities()
+    }
+
+    pub async fn list_models(&self) -> Result<Vec<String>, ChatError> {
+        self.provider.list_models().await
+    }
+
+    pub fn key_usage(&self) -> Vec<KeyUsage> {
+        self.provider.key_usage()
+    }
+
+    pub fn config(&self) -> &ChatConfig {
+        &self.config
+    }
+    pub fn set_config(&mut self, config: ChatConfig) {
+        self.config = config;
+    }
+
+    /// 用户与助手的历史消息，不含 system 消息
+    pub fn history(&self) -> &[ChatMessage] {
+        &self.history
+    }
+
+    /// 早期对话的摘要
+    pub fn summary(&self) -> Option<&str> {
+        self.summary.as_deref()
+    }
+
+    /// 清空内存中的上下文与摘要；已写入会话文件的消息不受影响
+    pub fn reset(&mut self) {
+        self.history.clear();
+        self.summary = None;
+    }
+
+    /// 从会话中恢复最近的历史，之后的每一问一答都追加写入该会话
+    pub fn attach_session(&mut self, session: Session) -> std::io::Result<()> {
+        self.history = session.recent_chat_messages(self.config.max_history)?;
+        self.summary = None;
+        self.session = Some(session);
+        Ok(())
+    }
+
+    /// 停止写入会话，返回原来的会话
+    pub fn detach_session(&mut self) -> Option<Session> {
+        self.session.take()
+    }
+
+    pub fn session(&self) -> Option<&Session> {
+        self.session.as_ref()
+    }
+
+    /// 发送一条用户消息并返回回复；成功时两者都会记入历史
+    pub async fn send(&mut self, message: &str) -> Result<ChatReply, ChatError> {
+        let prepared = self.request(message).await;
+        let reply = self.provider.chat(&prepared.request).await?;
+        Ok(self.record(message, reply, prepared))
+    }
+
+    /// 以 `mode` 指定的方式发送；流式方式下收集完整回复后返回
+    ///
+    /// `cancel` 只用于停止异步任务的轮询。
+    pub async fn send_with(
+        &mut self,
+        message: &str,
+        mode: InvokeMode,
+        cancel: Option<&CancelToken>,
+    ) -> Result<ChatReply, ChatError> {
+        match mode {
+            InvokeMode::Sync => self.send(message).await,
+            InvokeMode::AsyncTask => self.send_async_task(message, cancel).await,
+            InvokeMode::Stream => self.send_stream(message, |_| {}).await,
+        }
+    }
+
+    /// 提交异步任务并轮询结果，直到完成、失败、超时或被 `cancel` 取消
+    pub async fn send_async_task(
+        &mut self,
+        message: &str,
+        cancel: Option<&CancelToken>,
+    ) -> Result<ChatReply, ChatError> {
+        if !self.provider.capabilities().async_task {
+            return Err(ChatError::Unsupported("async task"));
+        }
+        let prepared = self.request(message).await;
+        let reply = self.provider.chat_async_task(&prepared.request, cancel).await?;
+        Ok(self.record(message, reply, prepared))
+    }
+
+    /// 以流式响应发送消息，每收到一段增量文本就调用 `on_delta`，结束后返回完整回复
+    ///
+    /// 只有完整收到回复时才记入历史。
+    pub async fn send_stream(
+        &mut self,
+        message: &str,
+        mut on_delta: impl FnMut(&str) + Send,
+    ) -> Result<ChatReply, ChatError> {
+        let prepared = self.request(message).await;
+        let reply = if self.provider.capabilities().streaming {
+            self.provider.chat_stream(&prepared.request, &mut on_delta).await?
+        } else {
+            let reply = self.provider.chat(&prepared.request).await?;
+            on_delta(&reply);
+            reply
+        };
+        Ok(self.record(message, reply, prepared))
+    }
+
+    /// 在上下文预算内组装请求；放不下的早期对话按配置概括为摘要或丢弃
+    ///
+    /// 不修改历史与摘要，两者在回复成功后由 [`ChatClient::record`] 更新。
+    async fn request(&self, message: &str) -> PreparedRequest {
+        let builder = ContextBuilder::new(self.config.context_budget()).with_max_history(self.config.max_history);
+        let mut context = builder.build(
+            self.config.system_content.as_deref(),
+            self.summary.as_deref(),
+            &self.history,
+            message,
+        );
+
+        let mut dropped = context.dropped;
+        let mut summary = None;
+        let mut warnings = Vec::new();
+        if dropped > 0 {
+            if self.config.summarize {
+                // 概括失败时直接丢弃，不影响本次对话
+                match self.summarize(&self.history[..dropped], builder.budget()).await {
+                    Ok(new_summary) => summary = Some(new_summary),
+                    Err(err) => warnings.push(ChatWarning::Summarize(err)),
+                }
+            }
+            context = builder.build(
+                self.config.system_content.as_deref(),
+                summary.as_deref().or(self.summary.as_deref()),
+                &self.history[dropped..],
+                message,
+            );
+            // 摘要本身也占用预算，可能又挤出更早的一问一答，这部分不再概括
+            dropped += context.dropped;
+        }
+
+        PreparedRequest {
+            request: ChatRequest {
+                model: self.config.language_model.clone(),
+                messages: context.messages,
+                temperature: self.config.temperature,
+                top_p: self.config.top_p,
+            },
+            dropped,
+            summary,
+            warnings,
+        }
+    }
+
+    async fn summarize(&self, dropped: &[ChatMessage], budget: ContextBudget) -> Result<String, ChatError> {
+        let transcript = summarize_transcript(self.summary.as_deref(), dropped, budget.prompt_tokens() / 2);
+        let request = ChatRequest {
+            model: self.config.language_model.clone(),
+            messages: vec![
+                ChatMessage::new(ChatRole::System, SUMMARIZE_INSTRUCTION),
+                ChatMessage::new(ChatRole::User, transcript),
+            ],
+            temperature: 0.3,
+            top_p: self.config.top_p,
+        };
+        Ok(self.provider.chat(&request).await?.trim().to_string())
+    }
+
+    fn record(&mut self, message: &str, reply: String, prepared: PreparedRequest) -> ChatReply {
+        let mut warnings = prepared.warnings;
+        self.history.drain(..prepared.dropped);
+        if let Some(summary) = prepared.summary {
+            self.summary = Some(summary);
+        }
+        self.history.push(ChatMessage::new(ChatRole::User, message));
+        self.history.push(ChatMessage::new(ChatRole::Assistant, reply.as_str()));
+
+        if let Some(session) = self.session.as_mut() {
+            let appended = session
+                .append(ChatRole::User, message)
+                .and_then(|_| session.append(ChatRole::Assistant, &reply));
+            if let Err(error) = appended {
+                warnings.push(ChatWarning::SessionWrite {
+                    session_id: session.id().to_string(),
+                    error,
+                });
+            }
+        }
+
+        ChatReply {
+            content: reply,
+            warnings,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::VecDeque;
+    use std::sync::Mutex;
+
+    use super::*;
+    use crate::provider::BoxFuture;
+
+    /// 按顺序返回预设回复（None 表示 500 错误）并记录收到的请求
+    #[derive(Debug, Default)]
+    struct ScriptedProvider {
+        replies: Mutex<VecDeque<Option<&'static str>>>,
+        requests: Mutex<Vec<ChatRequest>>,
+    }
+
+    impl ScriptedProvider {
+        fn new(replies: impl IntoIterator<Item = Option<&'static str>>) -> Arc<Self> {
+            Arc::new(Self {
+                replies: Mutex::new(replies.into_iter().collect()),
+                requests: Mutex::default(),
+            })
+        }
+
+        fn contents(&self, request: usize) -> Vec<String> {
+            let requests = self.requests.lock().unwrap();
+            requests[request].messages.iter().map(|m| m.content.clone()).collect()
+        }
+    }
+
+    impl ChatProvider for ScriptedProvider {
+        fn name(&self) -> &str {
+            "scripted"
+        }
+
+        fn capabilities(&self) -> ProviderCapabilities {
+            ProviderCapabilities::default()
+        }
+
+        fn chat<'a>(&'a self, request: &'a ChatRequest) -> BoxFuture<'a, Result<String, ChatError>> {
+            self.requests.lock().unwrap().push(request.clone());
+            let reply = self.replies.lock().unwrap().pop_front().expect("unexpected request");
+            Box::pin(async move {
+                reply.map(str::to_string).ok_or_else(|| ChatError::Api {
+                    status: 500,
+                    message: "scripted failure".to_string(),
+                })
+            })
+        }
+
+        fn chat_stream<'a>(
+            &'a self,
+            request: &'a ChatRequest,
+            _on_delta: &'a mut (dyn FnMut(&str) + Send),
+        ) -> BoxFuture<'a, Result<String, ChatError>> {
+            self.chat(request)
+        }
+
+        fn list_models(&self) -> BoxFuture<'_, Result<Vec<String>, ChatError>> {
+            Box::pin(async { Ok(Vec::new()) })
+        }
+    }
+
+    fn client(provider: Arc<ScriptedProvider>, max_history: usize, prompt_tokens: u32) -> ChatClient {
+        ChatClient::new(
+            provider,
+            ChatConfig {
+                max_history,
+                context_budget: Some(ContextBudget {
+                    context_tokens: prompt_tokens + 100,
+                    reply_tokens: 100,
+                }),
+                ..ChatConfig::default()
+            },
+        )
+    }
+
+    fn history(client: &ChatClient) -> Vec<&str> {
+        client.history().iter().map(|m| m.content.as_str()).collect()
+    }
+
+    #[tokio::test]
+    async fn pairs_over_the_count_cap_are_summarized() {
+        let provider = ScriptedProvider::new([Some("a1"), Some("a2"), Some("sum"), Some("a3")]);
+        let mut client = client(provider.clone(), 2, 10_000);
+
+        client.send("q1").await.unwrap();
+        client.send("q2").await.unwrap();
+        assert_eq!(history(&client), ["q1", "a1", "q2", "a2"]);
+
+        let reply = client.send("q3").await.unwrap();
+        assert_eq!(reply.content, "a3");
+        assert!(reply.warnings.is_empty());
+        assert_eq!(client.summary(), Some("sum"));
+        assert_eq!(history(&client), ["q2", "a2", "q3", "a3"]);
+
+        // 第三次请求先概括 q1/a1，再带着摘要发送
+        assert!(provider.contents(2)[1].contains("用户：q1\n角色：a1"));
+        assert_eq!(provider.contents(3), ["以下是之前对话的摘要：\nsum", "q2", "a2", "q3"]);
+    }
+
+    #[tokio::test]
+    async fn failed_summary_drops_the_pairs_with_a_warning() {
+        let provider = ScriptedProvider::new([Some("a1"), None, Some("a2")]);
+        let mut client = client(provider.clone(), 0, 10_000);
+
+        client.send("q1").await.unwrap();
+        let reply = client.send("q2").await.unwrap();
+        assert!(matches!(reply.warnings[..], [ChatWarning::Summarize(_)]));
+        assert_eq!(client.summary(), None);
+        assert_eq!(history(&client), ["q2", "a2"]);
+        assert_eq!(provider.contents(2), ["q2"]);
+    }
+
+    #[tokio::test]
+    async fn pairs_pushed_out_by_the_summary_leave_the_history() {
+        // 每组一问一答 12 个 token，新消息 6 个：预算 36 放得下两组，
+        // 加上 17 个 token 的摘要后只剩一组
+        let provider = ScriptedProvider::new([
+            Some("answer 1"),
+            Some("answer 2"),
+            Some("answer 3"),
+            Some("short sum"),
+            Some("answer 4"),
+        ]);
+        let mut client = client(provider.clone(), 100, 36);
+        for question in ["quest 1?", "quest 2?", "quest 3?"] {
+            client.send(question).await.unwrap();
+        }
+
+        client.send("quest 4?").await.unwrap();
+        let sent = provider.contents(4);
+        assert_eq!(sent[1..], ["quest 3?", "answer 3", "quest 4?"]);
+        // 记录的历史与实际发送的一致
+        assert_eq!(history(&client), ["quest 3?", "answer 3", "quest 4?", "answer 4"]);
+    }
+}
@@ -1,32 +1,7 @@
 use std::error::Error;
-use once_cell::sync::OnceCell;
-use std::io::BufRead;
-use serde_derive::{Deserialize, Serialize};
-use serde_json::Value;
 
-#[derive(Serialize, Deserialize, Debug)]
-struct ChatApiConfig {
-    api_key: Option<String>,
-}
-
-#[derive(Serialize, Deserialize, Debug)]
-struct AiConfig {
-    chatglm_api_key: Vec<ChatApiConfig>,
-}
-
-pub async fn chatglm_api_read_config(file_path: &str, glm: &str) -> Result<String, Box<dyn Error>> {
-    let file_content = tokio::fs::read_to_string(file_path).await?;
-    let config: AiConfig = toml::from_str(&file_content)?;
-
-    let response = match glm {
-        "chatglm_api_key" => config.chatglm_api_key,
-        _ => return Err("Invalid ChatGLM API".into()),
-    };
-
-    let json_string = serde_json::to_string(&response)?;
-
-    Ok(json_string)
-}
+use crate::chat_client::ChatError;
+use crate::config::AppConfig;
 
 pub struct APIKeys {
     user_id: String,
@@ -41,17 +16,14 @@ impl APIKeys {
         }
     }
 
-    pub fn get_instance(api: &str) -> &APIKeys {
-        static INSTANCE: OnceCell<APIKeys> = OnceCell::new();
-
-        INSTANCE.get_or_init(|| {
-            let parts: Vec<&str> = api.trim().split('.').collect();
-            if parts.len() == 2 {
-                APIKeys::new(parts[0], parts[1])
-            } else {
-                panic!("Your API Key is Invalid");
-            }
-        })
+    /// 解析 `id.secret` 格式的 Key
+    pub fn parse(api: &str) -> Result<APIKeys, ChatError> {
+        let (user_id, user_secret) = api
+            .trim()
+            .split_once('.')
+            .filter(|(id, secret)| !id.is_empty() && !secret.is_empty() && !secret.contains('.'))
+            .ok_or(ChatError::InvalidApiKey)?;
+        Ok(APIKeys::new(user_id, user_secret))
     }
 
     pub fn get_user_id(&self) -> &str {
@@ -62,20 +34,27 @@ impl APIKeys {
         &self.user_secret
     }
 
+    /// 配置文件中的第一个 Key，见 [`APIKeys::load_api_keys`]
     pub async fn load_api_key(user_config: &str) -> Result<String, Box<dyn Error>> {
-        let json_string = match chatglm_api_read_config(user_config, "chatglm_api_key").await {
-            Ok(final_json_string) => final_json_string,
-            Err(err) => return Err(format!("Error reading config file: {}", err).into()),
-        };
-
-        let api_key: Value = serde_json::from_str(&json_string)
-            .map_err(|err| format!("Failed to parse JSON: {}", err))?;
-
-        let glm_key = api_key[0]["api_key"]
-            .as_str()
-            .ok_or_else(|| "Failed to get api_key")?
-            .to_string();
+        let glm_key = Self::load_api_keys(user_config)
+            .await?
+            .into_iter()
+            .next()
+            .ok_or("Failed to get api_key")?;
 
         Ok(glm_key)
     }
-}
\ No newline at end of file
+
+    /// 配置文件中 `provider.api_key` 与 `provider.api_keys`（或旧格式的 `chatglm_api_key`）中的所有 Key
+    ///
+    /// 可被环境变量覆盖，见 [`AppConfig::apply_env_overrides`]。
+    pub async fn load_api_keys(user_config: &str) -> Result<Vec<String>, Box<dyn Error>> {
+        let file_content = tokio::fs::read_to_string(user_config)
+            .await
+            .map_err(|err| format!("Error reading config file: {}", err))?;
+        let mut config = AppConfig::from_toml(&file_content)?;
+        config.apply_env_overrides();
+
+        Ok(config.provider.keys().into_iter().map(str::to_string).collect())
+    }
+}
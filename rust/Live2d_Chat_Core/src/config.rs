@@ -0,0 +1,543 @@
+use std::collections::{BTreeMap, HashSet};
+use std::fs;
+use std::io;
+use std::path::Path;
+
+use serde::{Deserialize, Serialize};
+
+use crate::chat_client::{ChatConfig, ChatError};
+use crate::context::ContextBudget;
+use crate::provider::{ProviderConfig, ProviderKind};
+
+/// 覆盖任意后端 API Key 的环境变量，优先于各后端自己的环境变量与配置文件
+pub const API_KEY_ENV: &str = "WISE_LOVER_API_KEY";
+
+/// 旧格式中各模型的配置节与对应的档案名
+const LEGACY_PROFILES: [(&str, &str); 4] = [
+    ("ai_config_glm4_plus", "glm-4-plus"),
+    ("ai_config_glm4_air", "glm-4-air"),
+    ("ai_config_glm4_flash", "glm-4-flash"),
+    ("ai_config_glm4_long", "glm-4-long"),
+];
+
+/// 整个 TOML 配置文件
+///
+/// ```toml
+/// default_profile = "glm-4-flash"
+///
+/// [provider]
+/// kind = "chatglm"
+/// api_key = "..."
+///
+/// [defaults]              # 各档案未设置的项取这里的值
+/// temperature = 0.9
+/// summarize = true
+///
+/// [profiles.glm-4-flash]  # 档案名默认也是模型名称
+/// system_content = "..."
+///
+/// [profiles.local]
+/// language_model = "qwen2.5:7b"
+/// context_tokens = 32768
+/// ```
+///
+/// 仍可读取旧格式的 `[[ai_config_glm4_*]]` 与 `[[chatglm_api_key]]`，保存时写为新格式。
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct AppConfig {
+    /// 未指定档案时使用
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub default_profile: Option<String>,
+    pub provider: ProviderConfig,
+    #[serde(skip_serializing_if = "ModelProfile::is_empty")]
+    pub defaults: ModelProfile,
+    pub profiles: BTreeMap<String, ModelProfile>,
+}
+
+/// 一个命名的模型档案，未设置的项依次取 `[defaults]` 与 [`ChatConfig::default`] 的值
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ModelProfile {
+    /// 省略时为档案名
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub language_model: Option<String>,
+    /// 角色设定
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub system_content: Option<String>,
+    #[serde(alias = "temp_float", skip_serializing_if = "Option::is_none")]
+    pub temperature: Option<f64>,
+    #[serde(alias = "top_p_float", skip_serializing_if = "Option::is_none")]
+    pub top_p: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_history: Option<usize>,
+    /// 模型的上下文窗口，省略时按模型名称选择
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub context_tokens: Option<u32>,
+    /// 为回复预留的 token 数，只在设置了 `context_tokens` 时使用
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reply_tokens: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub summarize: Option<bool>,
+}
+
+impl ModelProfile {
+    pub fn is_empty(&self) -> bool {
+        *self == Self::default()
+    }
+
+    /// 未设置的项取 `fallback` 中的值
+    pub fn or(self, fallback: &ModelProfile) -> Self {
+        Self {
+            language_model: self.language_model.or_else(|| fallback.language_model.clone()),
+            system_content: self.system_content.or_else(|| fallback.system_content.clone()),
+            temperature: self.temperature.or(fallback.temperature),
+            top_p: self.top_p.or(fallback.top_p),
+            max_history: self.max_history.or(fallback.max_history),
+            context_tokens: self.context_tokens.or(fallback.context_tokens),
+            reply_tokens: self.reply_tokens.or(fallback.reply_tokens),
+            summarize: self.summarize.or(fallback.summarize),
+        }
+    }
+
+    fn validate(&self, table: &str) -> Result<(), ChatError> {
+        if self.language_model.as_deref().is_some_and(|model| model.trim().is_empty()) {
+            return Err(invalid(table, "language_model", "must not be empty"));
+        }
+        if self.temperature.is_some_and(|t| !(0.0..=2.0).contains(&t)) {
+            return Err(invalid(table, "temperature", "must be between 0 and 2"));
+        }
+        if self.top_p.is_some_and(|p| !(p > 0.0 && p <= 1.0)) {
+            return Err(invalid(table, "top_p", "must be greater than 0 and at most 1"));
+        }
+        if self.context_tokens == Some(0) {
+            return Err(invalid(table, "context_tokens", "must be greater than 0"));
+        }
+        if let (Some(context), Some(reply)) = (self.context_tokens, self.reply_tokens) {
+            if reply >= context {
+                return Err(invalid(table, "reply_tokens", "must be less than context_tokens"));
+            }
+        }
+        Ok(())
+    }
+}
+
+/// 旧格式中只在迁移时读取的部分
+#[derive(Default, Deserialize)]
+#[serde(default)]
+struct LegacyConfig {
+    chatglm_api_key: Vec<LegacyApiKey>,
+    ai_config_glm4_plus: Vec<ModelProfile>,
+    ai_config_glm4_air: Vec<ModelProfile>,
+    ai_config_glm4_flash: Vec<ModelProfile>,
+    ai_config_glm4_long: Vec<ModelProfile>,
+}
+
+#[derive(Deserialize)]
+struct LegacyApiKey {
+    api_key: Option<String>,
+}
+
+impl LegacyConfig {
+    fn section(&mut self, key: &str) -> Vec<ModelProfile> {
+        std::mem::take(match key {
+            "ai_config_glm4_plus" => &mut self.ai_config_glm4_plus,
+            "ai_config_glm4_air" => &mut self.ai_config_glm4_air,
+            "ai_config_glm4_flash" => &mut self.ai_config_glm4_flash,
+            _ => &mut self.ai_config_glm4_long,
+        })
+    }
+}
+
+impl AppConfig {
+    /// 解析并校验配置文本；不读取环境变量，见 [`AppConfig::apply_env_overrides`]
+    ///
+    /// 新格式中的未知配置项（多半是拼写错误）视为错误，旧格式的配置节中的不检查。
+    pub fn from_toml(content: &str) -> Result<Self, ChatError> {
+        let mut unknown = None;
+        let mut config: AppConfig = serde_ignored::deserialize(toml::Deserializer::new(content), |path| {
+            let keys = key_path(&path);
+            if unknown.is_none() && !keys.first().is_some_and(|root| is_legacy_section(root)) {
+                unknown = Some(keys.join("."));
+            }
+        })?;
+        if let Some(key) = unknown {
+            return Err(ChatError::InvalidConfig {
+                key,
+                message: "unknown key".to_string(),
+            });
+        }
+
+        let mut legacy: LegacyConfig = toml::from_str(content)?;
+        for (section, name) in LEGACY_PROFILES {
+            if let Some(profile) = legacy.section(section).into_iter().next() {
+                config.insert_profile(name.to_string(), profile)?;
+            }
+        }
+        if config.provider.api_key.is_none() && config.provider.api_keys.is_empty() {
+            let mut keys = legacy.chatglm_api_key.into_iter().filter_map(|key| key.api_key);
+            config.provider.api_key = keys.next();
+            config.provider.api_keys = keys.collect();
+        }
+
+        config.validate()?;
+        Ok(config)
+    }
+
+    /// 添加档案；与已有档案重名（忽略首尾空白）时返回错误，而不是覆盖
+    pub fn insert_profile(&mut self, name: String, profile: ModelProfile) -> Result<(), ChatError> {
+        if self.profiles.keys().any(|existing| existing.trim() == name.trim()) {
+            return Err(duplicate_profile(&name));
+        }
+        self.profiles.insert(name, profile);
+        Ok(())
+    }
+
+    /// 读取配置文件，文件不存在时为默认配置
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, ChatError> {
+        match fs::read_to_string(path) {
+            Ok(content) => Self::from_toml(&content),
+            Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(err) => Err(err.into()),
+        }
+    }
+
+    pub fn to_toml(&self) -> Result<String, ChatError> {
+        self.validate()?;
+        Ok(toml::to_string_pretty(self)?)
+    }
+
+    /// 校验后写入 `path`；先写临时文件再替换，中途失败不会损坏原文件
+    pub fn save(&self, path: impl AsRef<Path>) -> Result<(), ChatError> {
+        let path = path.as_ref();
+        let content = self.to_toml()?;
+        if let Some(parent) = path.parent().filter(|parent| !parent.as_os_str().is_empty()) {
+            fs::create_dir_all(parent)?;
+        }
+        let temp = path.with_extension("toml.tmp");
+        fs::write(&temp, content)?;
+        fs::rename(&temp, path)?;
+        Ok(())
+    }
+
+    /// 用环境变量覆盖 API Key：先看 [`API_KEY_ENV`]，再看后端自己的变量，
+    /// 如 ChatGLM 的 `ZHIPUAI_API_KEY`、OpenAI 的 `OPENAI_API_KEY`
+    ///
+    /// 变量中可以用逗号分隔多个 Key；覆盖后不再使用配置文件中的 `api_keys`。
+    pub fn apply_env_overrides(&mut self) {
+        let provider_env = match self.provider.kind {
+            ProviderKind::ChatGlm => Some("ZHIPUAI_API_KEY"),
+            ProviderKind::OpenAi => Some("OPENAI_API_KEY"),
+            ProviderKind::Ollama => None,
+        };
+        let api_key = [Some(API_KEY_ENV), provider_env]
+            .into_iter()
+            .flatten()
+            .filter_map(|name| std::env::var(name).ok())
+            .find(|key| !key.trim().is_empty());
+        if let Some(api_key) = api_key {
+            let mut keys = api_key.split(',').map(str::trim).filter(|key| !key.is_empty());
+            self.provider.api_key = keys.next().map(str::to_string);
+            self.provider.api_keys = keys.map(str::to_string).collect();
+        }
+    }
+
+    /// 名为 `profile` 的档案对应的对话参数，为 None 时使用 `default_profile`
+    ///
+    /// 没有任何档案时只使用 `[defaults]`。
+    pub fn chat_config(&self, profile: Option<&str>) -> Result<ChatConfig, ChatError> {
+        let (name, profile) = match profile.or(self.default_profile.as_deref()) {
+            Some(name) => {
+                let profile = self
+                    .profiles
+                    .get(name)
+                    .ok_or_else(|| ChatError::UnknownProfile(name.to_string()))?;
+                (Some(name), profile.clone().or(&self.defaults))
+            }
+            None if self.profiles.is_empty() => (None, self.defaults.clone()),
+            None => return Err(ChatError::MissingConfig("default_profile")),
+        };
+
+        let default = ChatConfig::default();
+        Ok(ChatConfig {
+            language_model: profile
+                .language_model
+                .or_else(|| name.map(str::to_string))
+                .unwrap_or(default.language_model),
+            system_content: profile
+                .system_content
+                .map(|content| content.trim().to_string())
+                .filter(|content| !content.is_empty()),
+            temperature: profile.temperature.unwrap_or(default.temperature),
+            top_p: profile.top_p.unwrap_or(default.top_p),
+            max_history: profile.max_history.unwrap_or(default.max_history),
+            context_budget: profile.context_tokens.map(|context_tokens| {
+                let mut budget = ContextBudget::with_context_tokens(context_tokens);
+                if let Some(reply_tokens) = profile.reply_tokens {
+                    budget.reply_tokens = reply_tokens.min(context_tokens);
+                }
+                budget
+            }),
+            summarize: profile.summarize.unwrap_or(default.summarize),
+        })
+    }
+
+    /// 出错时指出对应的配置项，如 `profiles.glm-4-flash.top_p`
+    pub fn validate(&self) -> Result<(), ChatError> {
+        if let Some(name) = &self.default_profile {
+            if !self.profiles.contains_key(name) {
+                return Err(ChatError::InvalidConfig {
+                    key: "default_profile".to_string(),
+                    message: format!("no profile named `{}`", name),
+                });
+            }
+        }
+
+        let provider = &self.provider;
+        match provider.base_url.as_deref().map(str::trim) {
+            Some("") => return Err(invalid("provider", "base_url", "must not be empty")),
+            Some(url) if !url.starts_with("http://") && !url.starts_with("https://") => {
+                return Err(invalid("provider", "base_url", "must start with http:// or https://"))
+            }
+            None if provider.kind == ProviderKind::OpenAi => {
+                return Err(invalid("provider", "base_url", "is required for the openai provider"))
+            }
+            _ => {}
+        }
+
+        if provider.api_keys.iter().any(|key| key.trim().is_empty()) {
+            return Err(invalid("provider", "api_keys", "must not contain empty keys"));
+        }
+
+        let poll = &provider.poll;
+        if poll.timeout.is_zero() {
+            return Err(invalid("provider.poll", "timeout_seconds", "must be greater than 0"));
+        }
+        if poll.initial_interval.is_zero() {
+            return Err(invalid("provider.poll", "initial_interval_ms", "must be greater than 0"));
+        }
+        if poll.max_interval < poll.initial_interval {
+            return Err(invalid(
+                "provider.poll",
+                "max_interval_ms",
+                "must not be less than initial_interval_ms",
+            ));
+        }
+        if poll.multiplier < 1.0 {
+            return Err(invalid("provider.poll", "multiplier", "must be at least 1"));
+        }
+        if !(0.0..=1.0).contains(&poll.jitter) {
+            return Err(invalid("provider.poll", "jitter", "must be between 0 and 1"));
+        }
+
+        self.defaults.validate("defaults")?;
+        let mut names = HashSet::new();
+        for (name, profile) in &self.profiles {
+            if name.trim().is_empty() {
+                return Err(ChatError::InvalidConfig {
+                    key: "profiles".to_string(),
+                    message: "profile names must not be empty".to_string(),
+                });
+            }
+            if !names.insert(name.trim()) {
+                return Err(duplicate_profile(name));
+            }
+            profile.validate(&format!("profiles.{}", toml_key(name)))?;
+        }
+        Ok(())
+    }
+}
+
+fn invalid(table: &str, key: &str, message: &str) -> ChatError {
+    ChatError::InvalidConfig {
+        key: format!("{}.{}", table, key),
+        message: message.to_string(),
+    }
+}
+
+fn duplicate_profile(name: &str) -> ChatError {
+    ChatError::InvalidConfig {
+        key: format!("profiles.{}", toml_key(name)),
+        message: "duplicate profile name".to_string(),
+    }
+}
+
+fn is_legacy_section(key: &str) -> bool {
+    key == "chatglm_api_key" || LEGACY_PROFILES.iter().any(|(section, _)| *section == key)
+}
+
+/// 从根开始的各级键名，不是裸键的加上引号
+fn key_path(path: &serde_ignored::Path) -> Vec<String> {
+    let mut keys = Vec::new();
+    let mut path = path;
+    loop {
+        path = match path {
+            serde_ignored::Path::Root => break,
+            serde_ignored::Path::Seq { parent, index } => {
+                keys.push(index.to_string());
+                parent
+            }
+            serde_ignored::Path::Map { parent, key } => {
+                keys.push(toml_key(key));
+                parent
+            }
+            serde_ignored::Path::Some { parent }
+            | serde_ignored::Path::NewtypeStruct { parent }
+            | serde_ignored::Path::NewtypeVariant { parent } => parent,
+        };
+    }
+    keys.reverse();
+    keys
+}
+
+/// 不是裸键的名称加上引号，如 `"qwen2.5"`
+fn toml_key(name: &str) -> String {
+    if name.chars().all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_') {
+        name.to_string()
+    } else {
+        format!("{:?}", name)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// 配置出错时指出的配置项
+    fn error_key(content: &str) -> String {
+        match AppConfig::from_toml(content) {
+            Err(ChatError::InvalidConfig { key, .. }) => key,
+            other => panic!("expected an invalid config, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn legacy_sections_are_migrated() {
+        let config = AppConfig::from_toml(
+            r#"
+            [[chatglm_api_key]]
+            api_key = "id.secret"
+
+            [[ai_config_glm4_flash]]
+            language_model = "glm-4-flash"
+            system_content = "persona"
+            temp_float = 0.5
+            top_p_float = 0.8
+            max_history = 10
+            unrelated = "ignored"
+            "#,
+        )
+        .unwrap();
+
+        assert_eq!(config.provider.api_key.as_deref(), Some("id.secret"));
+        let profile = &config.profiles["glm-4-flash"];
+        assert_eq!(profile.temperature, Some(0.5));
+        assert_eq!(profile.top_p, Some(0.8));
+        assert_eq!(profile.max_history, Some(10));
+
+        // 保存为新格式后读回的结果相同
+        let saved = config.to_toml().unwrap();
+        assert!(!saved.contains("ai_config"));
+        assert_eq!(AppConfig::from_toml(&saved).unwrap(), config);
+    }
+
+    #[test]
+    fn legacy_and_new_profiles_must_not_collide() {
+        let content = r#"
+            [profiles.glm-4-flash]
+            temperature = 0.5
+
+            [[ai_config_glm4_flash]]
+            temp_float = 0.7
+            "#;
+        assert_eq!(error_key(content), "profiles.glm-4-flash");
+    }
+
+    #[test]
+    fn unknown_keys_are_reported_with_their_path() {
+        assert_eq!(error_key("[profiles.local]\ntemprature = 1.0"), "profiles.local.temprature");
+        assert_eq!(error_key("[profiles.\"qwen2.5\"]\nfoo = 1"), "profiles.\"qwen2.5\".foo");
+        assert_eq!(error_key("[provider.poll]\ntimeout = 1"), "provider.poll.timeout");
+        assert_eq!(error_key("defualt_profile = \"x\""), "defualt_profile");
+    }
+
+    #[test]
+    fn invalid_values_are_reported_with_their_path() {
+        assert_eq!(error_key("[profiles.\"qwen2.5\"]\ntop_p = 0.0"), "profiles.\"qwen2.5\".top_p");
+        assert_eq!(error_key("[defaults]\ntemperature = 3.0"), "defaults.temperature");
+        assert_eq!(
+            error_key("[profiles.local]\ncontext_tokens = 100\nreply_tokens = 100"),
+            "profiles.local.reply_tokens"
+        );
+        assert_eq!(error_key("default_profile = \"missing\""), "default_profile");
+        assert_eq!(error_key("[provider]\nkind = \"openai\""), "provider.base_url");
+        assert_eq!(error_key("[provider.poll]\nmultiplier = 0.5"), "provider.poll.multiplier");
+    }
+
+    #[test]
+    fn duplicate_profile_names_are_rejected() {
+        let mut config = AppConfig::default();
+        config.insert_profile("local".to_string(), ModelProfile::default()).unwrap();
+        let err = config.insert_profile(" local ".to_string(), ModelProfile::default());
+        assert!(matches!(err, Err(ChatError::InvalidConfig { key, .. }) if key == "profiles.\" local \""));
+
+        config.profiles.insert("local ".to_string(), ModelProfile::default());
+        assert!(matches!(config.validate(), Err(ChatError::InvalidConfig { .. })));
+    }
+
+    #[test]
+    fn profiles_fall_back_to_defaults() {
+        let config = AppConfig::from_toml(
+            r#"
+            default_profile = "glm-4-flash"
+
+            [defaults]
+            temperature = 0.3
+            max_history = 8
+
+            [profiles.glm-4-flash]
+            system_content = "  persona  "
+
+            [profiles.local]
+            language_model = "qwen2.5:7b"
+            temperature = 0.6
+            context_tokens = 32768
+            reply_tokens = 1000
+            "#,
+        )
+        .unwrap();
+
+        let flash = config.chat_config(None).unwrap();
+        assert_eq!(flash.language_model, "glm-4-flash");
+        assert_eq!(flash.system_content.as_deref(), Some("persona"));
+        assert_eq!((flash.temperature, flash.max_history), (0.3, 8));
+        assert_eq!(flash.top_p, ChatConfig::default().top_p);
+        assert_eq!(flash.context_budget, None);
+
+        let local = config.chat_config(Some("local")).unwrap();
+        assert_eq!(local.language_model, "qwen2.5:7b");
+        assert_eq!(local.temperature, 0.6);
+        assert_eq!(
+            local.context_budget,
+            Some(ContextBudget {
+                context_tokens: 32768,
+                reply_tokens: 1000,
+            })
+        );
+
+        assert!(matches!(config.chat_config(Some("other")), Err(ChatError::UnknownProfile(_))));
+    }
+
+    #[test]
+    fn environment_overrides_the_api_key() {
+        let mut config = AppConfig::from_toml("[provider]\nkind = \"ollama\"\napi_key = \"file\"").unwrap();
+
+        std::env::set_var(API_KEY_ENV, "  ");
+        config.apply_env_overrides();
+        assert_eq!(config.provider.api_key.as_deref(), Some("file"));
+
+        std::env::set_var(API_KEY_ENV, "env");
+        config.apply_env_overrides();
+        std::env::remove_var(API_KEY_ENV);
+        assert_eq!(config.provider.api_key.as_deref(), Some("env"));
+    }
+}
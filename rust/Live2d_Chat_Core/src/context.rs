@@ -0,0 +1,259 @@
+use crate::chat_client::{ChatMessage, ChatRole};
+use crate::conversation_store::estimate_tokens;
+
+/// 每条消息在正文之外的格式开销（角色、分隔符等）
+const MESSAGE_OVERHEAD_TOKENS: u32 = 4;
+
+/// 一次请求可用的上下文长度
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ContextBudget {
+    /// 模型的上下文窗口
+    pub context_tokens: u32,
+    /// 为回复预留的部分
+    pub reply_tokens: u32,
+}
+
+impl ContextBudget {
+    /// 按模型名称给出的默认值；未知模型（如本地模型）按 8K 处理
+    pub fn for_model(model: &str) -> Self {
+        let model = model.to_ascii_lowercase();
+        let context_tokens = if model.starts_with("glm-4-long") {
+            1_000_000
+        } else if model.starts_with("glm-4") || model.starts_with("gpt-4") || model.starts_with("deepseek") {
+            128_000
+        } else {
+            8_192
+        };
+        Self::with_context_tokens(context_tokens)
+    }
+
+    /// 上下文窗口为 `context_tokens` 时的默认值，回复预留窗口的 1/8，最多 4096
+    pub fn with_context_tokens(context_tokens: u32) -> Self {
+        Self {
+            context_tokens,
+            reply_tokens: (context_tokens / 8).min(4_096),
+        }
+    }
+
+    /// 可用于消息的 token 数
+    pub fn prompt_tokens(&self) -> u32 {
+        self.context_tokens.saturating_sub(self.reply_tokens)
+    }
+}
+
+pub fn message_tokens(message: &ChatMessage) -> u32 {
+    estimate_tokens(&message.content) + MESSAGE_OVERHEAD_TOKENS
+}
+
+/// [`ContextBuilder::build`] 的结果
+#[derive(Debug, Clone, PartialEq)]
+pub struct BuiltContext {
+    pub messages: Vec<ChatMessage>,
+    /// 估算的总 token 数
+    pub tokens: u32,
+    /// 因超出预算或条数上限而未放入的最早的历史消息条数，总是一问一答成对
+    pub dropped: usize,
+}
+
+/// 在预算内组装请求的 `messages`：角色设定、早期对话摘要、尽可能多的最近历史与新消息
+///
+/// 角色设定与新消息总会保留，即使它们本身已超出预算。
+#[derive(Debug, Clone, Copy)]
+pub struct ContextBuilder {
+    budget: ContextBudget,
+    /// 最多放入的历史消息条数
+    max_history: Option<usize>,
+}
+
+impl ContextBuilder {
+    pub fn new(budget: ContextBudget) -> Self {
+        Self {
+            budget,
+            max_history: None,
+        }
+    }
+
+    /// 除预算外，最多放入 `max_history` 条历史消息
+    pub fn with_max_history(mut self, max_history: usize) -> Self {
+        self.max_history = Some(max_history);
+        self
+    }
+
+    pub fn budget(&self) -> ContextBudget {
+        self.budget
+    }
+
+    pub fn build(
+        &self,
+        persona: Option<&str>,
+        summary: Option<&str>,
+        history: &[ChatMessage],
+        message: &str,
+    ) -> BuiltContext {
+        let persona = persona.map(|content| ChatMessage::new(ChatRole::System, content));
+        let summary = summary.map(|content| ChatMessage::new(ChatRole::System, summary_prompt(content)));
+        let user = ChatMessage::new(ChatRole::User, message);
+
+        let mut tokens = message_tokens(&user);
+        tokens += persona.as_ref().map_or(0, message_tokens);
+        tokens += summary.as_ref().map_or(0, message_tokens);
+
+        // 从最新的一问一答往前放，直到超出预算或条数上限
+        let available = self.budget.prompt_tokens();
+        let max_history = self.max_history.unwrap_or(usize::MAX);
+        let mut start = history.len();
+        for pair_start in (0..history.len()).rev() {
+            if history[pair_start].role != ChatRole::User {
+                continue;
+            }
+            let pair_tokens: u32 = history[pair_start..start].iter().map(message_tokens).sum();
+            if tokens + pair_tokens > available || history.len() - pair_start > max_history {
+                break;
+            }
+            tokens += pair_tokens;
+            start = pair_start;
+        }
+
+        let mut messages = Vec::with_capacity(history.len() - start + 3);
+        messages.extend(persona);
+        messages.extend(summary);
+        messages.extend(history[start..].iter().cloned());
+        messages.push(user);
+
+        BuiltContext {
+            messages,
+            tokens,
+            dropped: start,
+        }
+    }
+}
+
+fn summary_prompt(summary: &str) -> String {
+    format!("以下是之前对话的摘要：\n{}", summary)
+}
+
+/// 请模型概括早期对话时使用的指令
+pub const SUMMARIZE_INSTRUCTION: &str =
+    "请将以下对话概括为简短的摘要，保留人物关系、重要事实、约定与用户的偏好，使用第三人称，不超过 300 字。";
+
+/// 概括请求的正文：之前的摘要与被移出上下文的对话，超出 `max_tokens` 时只保留末尾
+pub fn summarize_transcript(previous: Option<&str>, dropped: &[ChatMessage], max_tokens: u32) -> String {
+    let mut lines = Vec::with_capacity(dropped.len() + 1);
+    if let Some(previous) = previous {
+        lines.push(format!("（之前的摘要）{}", previous));
+    }
+    for message in dropped {
+        let speaker = match message.role {
+            ChatRole::System => "系统",
+            ChatRole::User => "用户",
+            ChatRole::Assistant => "角色",
+        };
+        lines.push(format!("{}：{}", speaker, message.content));
+    }
+
+    let mut tokens = 0;
+    let mut start = lines.len();
+    while start > 0 {
+        let line_tokens = estimate_tokens(&lines[start - 1]) + 1;
+        if tokens + line_tokens > max_tokens && start < lines.len() {
+            break;
+        }
+        tokens += line_tokens;
+        start -= 1;
+    }
+    lines[start..].join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// `n` 组一问一答，每条消息 8 个 ASCII 字符，即 2 + 4 个 token
+    fn history(pairs: usize) -> Vec<ChatMessage> {
+        (0..pairs)
+            .flat_map(|i| {
+                [
+                    ChatMessage::new(ChatRole::User, format!("user {:03}", i)),
+                    ChatMessage::new(ChatRole::Assistant, format!("char {:03}", i)),
+                ]
+            })
+            .collect()
+    }
+
+    fn budget(prompt_tokens: u32) -> ContextBudget {
+        ContextBudget {
+            context_tokens: prompt_tokens + 100,
+            reply_tokens: 100,
+        }
+    }
+
+    fn contents(context: &BuiltContext) -> Vec<&str> {
+        context.messages.iter().map(|m| m.content.as_str()).collect()
+    }
+
+    #[test]
+    fn budget_depends_on_the_model() {
+        assert_eq!(ContextBudget::for_model("GLM-4-Flash").context_tokens, 128_000);
+        assert_eq!(ContextBudget::for_model("glm-4-long").context_tokens, 1_000_000);
+        let local = ContextBudget::for_model("qwen2.5:7b");
+        assert_eq!((local.context_tokens, local.reply_tokens), (8_192, 1_024));
+        assert_eq!(local.prompt_tokens(), 7_168);
+    }
+
+    #[test]
+    fn everything_fits_in_a_large_budget() {
+        let context = ContextBuilder::new(budget(1_000)).build(Some("persona"), None, &history(2), "hi");
+        assert_eq!(context.dropped, 0);
+        assert_eq!(
+            contents(&context),
+            ["persona", "user 000", "char 000", "user 001", "char 001", "hi"]
+        );
+        assert_eq!(context.tokens, 6 + 4 * 6 + 5);
+    }
+
+    #[test]
+    fn oldest_pairs_are_dropped_to_fit_the_budget() {
+        // 新消息 5 个 token，每组一问一答 12 个：放得下两组
+        let context = ContextBuilder::new(budget(30)).build(None, None, &history(3), "hi");
+        assert_eq!(context.dropped, 2);
+        assert_eq!(contents(&context), ["user 001", "char 001", "user 002", "char 002", "hi"]);
+        assert_eq!(context.tokens, 29);
+    }
+
+    #[test]
+    fn history_is_capped_by_message_count() {
+        let builder = ContextBuilder::new(budget(1_000)).with_max_history(3);
+        let context = builder.build(None, Some("earlier"), &history(3), "hi");
+        // 三条放不下两组，只保留最近一组
+        assert_eq!(context.dropped, 4);
+        assert_eq!(
+            contents(&context),
+            ["以下是之前对话的摘要：\nearlier", "user 002", "char 002", "hi"]
+        );
+    }
+
+    #[test]
+    fn persona_and_message_are_kept_over_budget() {
+        let context = ContextBuilder::new(budget(1)).build(Some("persona"), None, &history(1), "hi");
+        assert_eq!(context.dropped, 2);
+        assert_eq!(contents(&context), ["persona", "hi"]);
+    }
+
+    #[test]
+    fn dangling_assistant_messages_are_dropped_with_their_pair() {
+        let mut history = history(2);
+        history.remove(0);
+        let context = ContextBuilder::new(budget(1_000)).build(None, None, &history, "hi");
+        assert_eq!(context.dropped, 1);
+        assert_eq!(contents(&context), ["user 001", "char 001", "hi"]);
+    }
+
+    #[test]
+    fn transcript_keeps_the_latest_lines() {
+        let transcript = summarize_transcript(Some("旧摘要"), &history(1), 1_000);
+        assert_eq!(transcript, "（之前的摘要）旧摘要\n用户：user 000\n角色：char 000");
+
+        let transcript = summarize_transcript(Some("旧摘要"), &history(1), 8);
+        assert_eq!(transcript, "角色：char 000");
+    }
+}
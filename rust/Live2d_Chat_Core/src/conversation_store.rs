@@ -0,0 +1,371 @@
+use std::fs::{self, File, OpenOptions};
+use std::io::{self, BufRead, BufReader, Write};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+
+use chrono::Utc;
+use serde::{Deserialize, Serialize};
+
+use crate::chat_client::{ChatMessage, ChatRole};
+
+const SESSION_EXTENSION: &str = "jsonl";
+
+/// 会话文件中的一行
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct StoredMessage {
+    /// 会话内从 1 开始递增
+    pub id: u64,
+    pub role: ChatRole,
+    pub content: String,
+    /// 毫秒时间戳
+    pub timestamp: i64,
+    /// 估算的 token 数，见 [`estimate_tokens`]
+    pub tokens: u32,
+}
+
+impl From<&StoredMessage> for ChatMessage {
+    fn from(message: &StoredMessage) -> Self {
+        ChatMessage::new(message.role, message.content.as_str())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct SessionInfo {
+    pub id: String,
+    pub message_count: usize,
+    pub tokens: u64,
+    /// 第一条与最后一条消息的时间戳，空会话为 None
+    pub created_at: Option<i64>,
+    pub updated_at: Option<i64>,
+}
+
+/// 按角色分目录、每个会话一个只追加 JSONL 文件的对话存储
+///
+/// 目录结构为 `<root>/<character>/<session>.jsonl`。
+#[derive(Debug, Clone)]
+pub struct ConversationStore {
+    root: PathBuf,
+}
+
+impl ConversationStore {
+    pub fn new(root: impl Into<PathBuf>) -> Self {
+        Self { root: root.into() }
+    }
+
+    pub fn root(&self) -> &Path {
+        &self.root
+    }
+
+    fn character_dir(&self, character: &str) -> PathBuf {
+        self.root.join(sanitize(character))
+    }
+
+    fn session_path(&self, character: &str, session_id: &str) -> PathBuf {
+        self.character_dir(character)
+            .join(format!("{}.{}", sanitize(session_id), SESSION_EXTENSION))
+    }
+
+    /// `character` 的所有会话，最近更新的在前
+    pub fn sessions(&self, character: &str) -> io::Result<Vec<SessionInfo>> {
+        let dir = self.character_dir(character);
+        if !dir.exists() {
+            return Ok(Vec::new());
+        }
+
+        let mut sessions = Vec::new();
+        for entry in fs::read_dir(dir)? {
+            let path = entry?.path();
+            if path.extension().and_then(|e| e.to_str()) != Some(SESSION_EXTENSION) {
+                continue;
+            }
+            let Some(id) = path.file_stem().and_then(|s| s.to_str()) else {
+                continue;
+            };
+            let messages = read_messages(&path)?;
+            sessions.push(SessionInfo {
+                id: id.to_string(),
+                message_count: messages.len(),
+                tokens: messages.iter().map(|m| u64::from(m.tokens)).sum(),
+                created_at: messages.first().map(|m| m.timestamp),
+                updated_at: messages.last().map(|m| m.timestamp),
+            });
+        }
+        sessions.sort_by(|a, b| b.updated_at.cmp(&a.updated_at).then_with(|| b.id.cmp(&a.id)));
+        Ok(sessions)
+    }
+
+    /// 以当前时间命名创建新会话
+    pub fn create_session(&self, character: &str) -> io::Result<Session> {
+        fs::create_dir_all(self.character_dir(character))?;
+
+        let base = Utc::now().format("%Y%m%d-%H%M%S-%3f").to_string();
+        let mut id = base.clone();
+        let mut suffix = 1;
+        loop {
+            let path = self.session_path(character, &id);
+            match OpenOptions::new().write(true).create_new(true).open(&path) {
+                Ok(_) => {
+                    return Ok(Session {
+                        path,
+                        character: character.to_string(),
+                        id,
+                        next_id: Arc::new(AtomicU64::new(1)),
+                    })
+                }
+                Err(err) if err.kind() == io::ErrorKind::AlreadyExists => {
+                    suffix += 1;
+                    id = format!("{}-{}", base, suffix);
+                }
+                Err(err) => return Err(err),
+            }
+        }
+    }
+
+    /// 打开已有会话；会话不存在时返回 `NotFound`
+    pub fn open_session(&self, character: &str, session_id: &str) -> io::Result<Session> {
+        let path = self.session_path(character, session_id);
+        if !path.is_file() {
+            return Err(io::Error::new(
+                io::ErrorKind::NotFound,
+                format!("Session not found: {}/{}", character, session_id),
+            ));
+        }
+        let next_id = read_messages(&path)?.last().map_or(1, |m| m.id + 1);
+        Ok(Session {
+            path,
+            character: character.to_string(),
+            id: sanitize(session_id),
+            next_id: Arc::new(AtomicU64::new(next_id)),
+        })
+    }
+
+    pub fn delete_session(&self, character: &str, session_id: &str) -> io::Result<()> {
+        fs::remove_file(self.session_path(character, session_id))
+    }
+}
+
+/// 一个会话文件，只追加写入
+///
+/// 克隆出的 `Session` 共享消息 id 计数，写入同一文件时 id 不会重复。
+#[derive(Debug, Clone)]
+pub struct Session {
+    path: PathBuf,
+    character: String,
+    id: String,
+    next_id: Arc<AtomicU64>,
+}
+
+impl Session {
+    pub fn id(&self) -> &str {
+        &self.id
+    }
+
+    pub fn character(&self) -> &str {
+        &self.character
+    }
+
+    pub fn messages(&self) -> io::Result<Vec<StoredMessage>> {
+        read_messages(&self.path)
+    }
+
+    /// 最近的 `max_messages` 条消息，按一问一答对齐，可直接作为请求的历史部分
+    pub fn recent_chat_messages(&self, max_messages: usize) -> io::Result<Vec<ChatMessage>> {
+        let messages = self.messages()?;
+        let mut start = messages.len().saturating_sub(max_messages);
+        // 不以助手的回复开头
+        while messages.get(start).is_some_and(|m| m.role != ChatRole::User) {
+            start += 1;
+        }
+        Ok(messages[start..]
+            .iter()
+            .filter(|m| m.role != ChatRole::System)
+            .map(ChatMessage::from)
+            .collect())
+    }
+
+    pub fn append(&mut self, role: ChatRole, content: &str) -> io::Result<StoredMessage> {
+        let message = StoredMessage {
+            id: self.next_id.fetch_add(1, Ordering::SeqCst),
+            role,
+            content: content.to_string(),
+            timestamp: Utc::now().timestamp_millis(),
+            tokens: estimate_tokens(content),
+        };
+        let line = serde_json::to_string(&message).map_err(io::Error::other)?;
+
+        let mut file = OpenOptions::new().append(true).create(true).open(&self.path)?;
+        writeln!(file, "{}", line)?;
+        Ok(message)
+    }
+}
+
+/// 读取会话文件；无法解析的行（如写入中断留下的半行）被跳过
+fn read_messages(path: &Path) -> io::Result<Vec<StoredMessage>> {
+    let file = match File::open(path) {
+        Ok(file) => file,
+        Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(Vec::new()),
+        Err(err) => return Err(err),
+    };
+    let mut messages = Vec::new();
+    for line in BufReader::new(file).lines() {
+        let line = line?;
+        if let Ok(message) = serde_json::from_str::<StoredMessage>(line.trim()) {
+            messages.push(message);
+        }
+    }
+    Ok(messages)
+}
+
+/// 粗略估算 token 数：中日韩字符各计 1 个，其它字符约 4 个计 1 个
+pub fn estimate_tokens(text: &str) -> u32 {
+    let (cjk, other) = text.chars().fold((0u32, 0u32), |(cjk, other), c| {
+        if is_cjk(c) {
+            (cjk + 1, other)
+        } else {
+            (cjk, other + 1)
+        }
+    });
+    cjk + other.div_ceil(4)
+}
+
+fn is_cjk(c: char) -> bool {
+    matches!(c,
+        '\u{3040}'..='\u{30FF}' // 平假名、片假名
+        | '\u{3400}'..='\u{4DBF}'
+        | '\u{4E00}'..='\u{9FFF}'
+        | '\u{AC00}'..='\u{D7AF}' // 谚文
+        | '\u{F900}'..='\u{FAFF}'
+        | '\u{20000}'..='\u{2FFFF}')
+}
+
+/// 角色名与会话 id 用作文件名，去掉路径分隔符等字符
+fn sanitize(name: &str) -> String {
+    let name: String = name
+        .trim()
+        .chars()
+        .map(|c| match c {
+            '/' | '\\' | ':' | '*' | '?' | '"' | '<' | '>' | '|' => '_',
+            c if c.is_control() => '_',
+            c => c,
+        })
+        .collect();
+    match name.trim_matches('.') {
+        "" => "_".to_string(),
+        _ => name,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A store in a fresh directory under the system temp dir, removed on drop.
+    struct TempStore {
+        store: ConversationStore,
+    }
+
+    impl TempStore {
+        fn new(name: &str) -> Self {
+            let root = std::env::temp_dir().join(format!("conversation_store_{}_{}", name, std::process::id()));
+            let _ = fs::remove_dir_all(&root);
+            Self {
+                store: ConversationStore::new(root),
+            }
+        }
+    }
+
+    impl Drop for TempStore {
+        fn drop(&mut self) {
+            let _ = fs::remove_dir_all(self.store.root());
+        }
+    }
+
+    #[test]
+    fn appended_messages_survive_a_reopen() {
+        let temp = TempStore::new("reopen");
+        let mut session = temp.store.create_session("Hiyori").unwrap();
+        session.append(ChatRole::User, "你好").unwrap();
+        session.append(ChatRole::Assistant, "hello there").unwrap();
+
+        let mut reopened = temp.store.open_session("Hiyori", session.id()).unwrap();
+        let third = reopened.append(ChatRole::User, "again").unwrap();
+        assert_eq!(third.id, 3);
+
+        let messages = reopened.messages().unwrap();
+        let contents: Vec<(u64, &str)> = messages.iter().map(|m| (m.id, m.content.as_str())).collect();
+        assert_eq!(contents, [(1, "你好"), (2, "hello there"), (3, "again")]);
+        assert_eq!(messages[0].tokens, 2);
+
+        let sessions = temp.store.sessions("Hiyori").unwrap();
+        assert_eq!(sessions.len(), 1);
+        assert_eq!(sessions[0].id, session.id());
+        assert_eq!(sessions[0].message_count, 3);
+    }
+
+    #[test]
+    fn opening_a_missing_session_fails() {
+        let temp = TempStore::new("missing");
+        let err = temp.store.open_session("Hiyori", "nope").unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::NotFound);
+        assert!(temp.store.sessions("Hiyori").unwrap().is_empty());
+    }
+
+    #[test]
+    fn cloned_sessions_share_message_ids() {
+        let temp = TempStore::new("clone");
+        let mut session = temp.store.create_session("Hiyori").unwrap();
+        let mut clone = session.clone();
+        assert_eq!(session.append(ChatRole::User, "a").unwrap().id, 1);
+        assert_eq!(clone.append(ChatRole::Assistant, "b").unwrap().id, 2);
+    }
+
+    #[test]
+    fn truncated_lines_are_skipped() {
+        let temp = TempStore::new("truncated");
+        let mut session = temp.store.create_session("Hiyori").unwrap();
+        session.append(ChatRole::User, "kept").unwrap();
+        let path = temp.store.session_path("Hiyori", session.id());
+        OpenOptions::new()
+            .append(true)
+            .open(&path)
+            .and_then(|mut file| write!(file, "{{\"id\":2,\"ro"))
+            .unwrap();
+
+        let reopened = temp.store.open_session("Hiyori", session.id()).unwrap();
+        assert_eq!(reopened.messages().unwrap().len(), 1);
+    }
+
+    #[test]
+    fn recent_messages_start_with_the_user() {
+        let temp = TempStore::new("recent");
+        let mut session = temp.store.create_session("Hiyori").unwrap();
+        for (role, content) in [
+            (ChatRole::User, "q1"),
+            (ChatRole::Assistant, "a1"),
+            (ChatRole::User, "q2"),
+            (ChatRole::Assistant, "a2"),
+        ] {
+            session.append(role, content).unwrap();
+        }
+
+        let recent = session.recent_chat_messages(3).unwrap();
+        let contents: Vec<&str> = recent.iter().map(|m| m.content.as_str()).collect();
+        assert_eq!(contents, ["q2", "a2"]);
+    }
+
+    #[test]
+    fn names_are_sanitised_into_single_path_components() {
+        assert_eq!(sanitize("../../etc/passwd"), ".._.._etc_passwd");
+        assert_eq!(sanitize(r"a\b:c*d?"), "a_b_c_d_");
+        assert_eq!(sanitize(" .. "), "_");
+        assert_eq!(sanitize(""), "_");
+        assert_eq!(sanitize("Hiyori 桃瀬"), "Hiyori 桃瀬");
+
+        let store = ConversationStore::new("/store");
+        assert_eq!(
+            store.session_path("../x", "../../y"),
+            Path::new("/store").join(".._x").join(".._.._y.jsonl")
+        );
+    }
+}
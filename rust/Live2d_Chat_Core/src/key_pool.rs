@@ -0,0 +1,501 @@
+use std::future::Future;
+use std::sync::{Arc, Mutex, PoisonError};
+use std::time::Duration;
+
+use reqwest::StatusCode;
+use serde::{Deserialize, Serialize};
+use tokio::time::Instant;
+
+use crate::chat_client::ChatError;
+use crate::provider::check_status;
+
+/// 被限流且响应中没有 `Retry-After` 时停用的时长
+pub const DEFAULT_RATE_LIMIT_COOLDOWN: Duration = Duration::from_secs(60);
+
+/// 鉴权失败后停用的时长
+pub const DEFAULT_UNAUTHORIZED_COOLDOWN: Duration = Duration::from_secs(10 * 60);
+
+/// 有多个 API Key 时每次请求选用哪一个
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum KeySelection {
+    /// 轮流使用，分摊用量
+    #[default]
+    RoundRobin,
+    /// 总是使用排在最前的可用 Key，其余作为备用
+    Priority,
+}
+
+/// 一个 Key 的用量统计
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct KeyUsage {
+    /// 只显示首尾几位的 Key，见 [`mask_key`]
+    pub label: String,
+    pub requests: u64,
+    pub successes: u64,
+    /// 其它错误，包括网络错误，不会停用 Key
+    pub failures: u64,
+    pub rate_limited: u64,
+    pub unauthorized: u64,
+    /// 停用时剩余的时长
+    pub disabled_for: Option<Duration>,
+}
+
+/// 一次请求的结果
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum KeyOutcome {
+    Success,
+    Failed,
+    /// 429，`retry_after` 为响应中的 `Retry-After`
+    RateLimited { retry_after: Option<Duration> },
+    /// 401 或 403
+    Unauthorized,
+}
+
+/// Key 对应的凭据
+pub trait Credential: Clone {
+    /// 该 Key 鉴权失败时调用，丢弃由它派生并缓存的凭据，如 ChatGLM 的 JWT
+    fn invalidate(&self) {}
+}
+
+impl Credential for String {}
+
+struct KeyEntry<K> {
+    key: K,
+    usage: KeyUsage,
+    disabled_until: Option<Instant>,
+}
+
+struct PoolState<K> {
+    entries: Vec<KeyEntry<K>>,
+    next: usize,
+}
+
+/// 同一后端的多个 API Key：按 [`KeySelection`] 选用，被限流或鉴权失败时暂时停用并换下一个
+///
+/// `K` 是每个 Key 对应的凭据，如 ChatGLM 的 `TokenManager`。克隆出的实例共用同一个状态。
+pub struct KeyPool<K> {
+    state: Arc<Mutex<PoolState<K>>>,
+    selection: KeySelection,
+    rate_limit_cooldown: Duration,
+    unauthorized_cooldown: Duration,
+}
+
+impl<K> Clone for KeyPool<K> {
+    fn clone(&self) -> Self {
+        Self {
+            state: Arc::clone(&self.state),
+            selection: self.selection,
+            rate_limit_cooldown: self.rate_limit_cooldown,
+            unauthorized_cooldown: self.unauthorized_cooldown,
+        }
+    }
+}
+
+impl<K: Clone> std::fmt::Debug for KeyPool<K> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("KeyPool")
+            .field("keys", &self.usage())
+            .field("selection", &self.selection)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<K: Clone> KeyPool<K> {
+    /// `keys` 为 Key 的原文与对应的凭据
+    pub fn new<'a>(keys: impl IntoIterator<Item = (&'a str, K)>, selection: KeySelection) -> Self {
+        let entries = keys
+            .into_iter()
+            .map(|(raw, key)| KeyEntry {
+                key,
+                usage: KeyUsage {
+                    label: mask_key(raw),
+                    ..KeyUsage::default()
+                },
+                disabled_until: None,
+            })
+            .collect();
+        Self {
+            state: Arc::new(Mutex::new(PoolState { entries, next: 0 })),
+            selection,
+            rate_limit_cooldown: DEFAULT_RATE_LIMIT_COOLDOWN,
+            unauthorized_cooldown: DEFAULT_UNAUTHORIZED_COOLDOWN,
+        }
+    }
+
+    /// 被限流（没有 `Retry-After` 时）与鉴权失败后停用的时长
+    pub fn with_cooldowns(mut self, rate_limited: Duration, unauthorized: Duration) -> Self {
+        self.rate_limit_cooldown = rate_limited;
+        self.unauthorized_cooldown = unauthorized;
+        self
+    }
+
+    /// 转换每个 Key 的凭据，用量统计重新开始
+    pub fn map<U: Clone>(&self, mut f: impl FnMut(&K) -> U) -> KeyPool<U> {
+        let state = self.lock();
+        KeyPool {
+            state: Arc::new(Mutex::new(PoolState {
+                entries: state
+                    .entries
+                    .iter()
+                    .map(|entry| KeyEntry {
+                        key: f(&entry.key),
+                        usage: KeyUsage {
+                            label: entry.usage.label.clone(),
+                            ..KeyUsage::default()
+                        },
+                        disabled_until: None,
+                    })
+                    .collect(),
+                next: 0,
+            })),
+            selection: self.selection,
+            rate_limit_cooldown: self.rate_limit_cooldown,
+            unauthorized_cooldown: self.unauthorized_cooldown,
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.lock().entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    pub fn selection(&self) -> KeySelection {
+        self.selection
+    }
+
+    /// 选出下一个可用的 Key，返回其序号与凭据；全部停用时为 None
+    pub fn acquire(&self) -> Option<(usize, K)> {
+        self.acquire_except(&[])
+    }
+
+    /// 同 [`Self::acquire`]，但跳过 `tried` 中的 Key
+    fn acquire_except(&self, tried: &[usize]) -> Option<(usize, K)> {
+        let now = Instant::now();
+        let mut state = self.lock();
+        let len = state.entries.len();
+        let start = match self.selection {
+            KeySelection::RoundRobin => state.next,
+            KeySelection::Priority => 0,
+        };
+        let index = (0..len)
+            .map(|offset| (start + offset) % len)
+            .filter(|index| !tried.contains(index))
+            .find(|&index| state.entries[index].disabled_until.is_none_or(|until| until <= now))?;
+
+        state.next = (index + 1) % len;
+        let entry = &mut state.entries[index];
+        entry.disabled_until = None;
+        entry.usage.requests += 1;
+        Some((index, entry.key.clone()))
+    }
+
+    /// 记录 `index` 号 Key 的请求结果，被限流或鉴权失败时停用一段时间
+    pub fn report(&self, index: usize, outcome: KeyOutcome) {
+        let mut state = self.lock();
+        let Some(entry) = state.entries.get_mut(index) else {
+            return;
+        };
+        let cooldown = match outcome {
+            KeyOutcome::Success => {
+                entry.usage.successes += 1;
+                None
+            }
+            KeyOutcome::Failed => {
+                entry.usage.failures += 1;
+                None
+            }
+            KeyOutcome::RateLimited { retry_after } => {
+                entry.usage.rate_limited += 1;
+                Some(retry_after.unwrap_or(self.rate_limit_cooldown))
+            }
+            KeyOutcome::Unauthorized => {
+                entry.usage.unauthorized += 1;
+                Some(self.unauthorized_cooldown)
+            }
+        };
+        if let Some(cooldown) = cooldown {
+            entry.disabled_until = Some(Instant::now() + cooldown);
+        }
+    }
+
+    /// 每个 Key 的用量，顺序与配置相同
+    pub fn usage(&self) -> Vec<KeyUsage> {
+        let now = Instant::now();
+        self.lock()
+            .entries
+            .iter()
+            .map(|entry| KeyUsage {
+                disabled_for: entry
+                    .disabled_until
+                    .filter(|&until| until > now)
+                    .map(|until| until - now),
+                ..entry.usage.clone()
+            })
+            .collect()
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, PoolState<K>> {
+        self.state.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+impl<K: Credential> KeyPool<K> {
+    /// 依次用每个可用的 Key 调用 `send`，遇到 401、403 或 429 时停用该 Key 并换下一个，网络错误时只换下一个
+    ///
+    /// 鉴权失败时还会调用 [`Credential::invalidate`]，Key 恢复使用时不会再发送被拒绝的凭据。
+    /// 返回所用 Key 的凭据与已通过 `check_status` 的响应；所有 Key 都不可用时返回最后一次的错误。
+    pub(crate) async fn send<F, Fut>(&self, mut send: F) -> Result<(K, reqwest::Response), ChatError>
+    where
+        F: FnMut(K) -> Fut,
+        Fut: Future<Output = Result<reqwest::Response, reqwest::Error>>,
+    {
+        let mut last_error = None;
+        let mut tried = Vec::new();
+        while let Some((index, key)) = self.acquire_except(&tried) {
+            tried.push(index);
+            let response = match send(key.clone()).await {
+                Ok(response) => response,
+                Err(err) => {
+                    // 连接失败或超时不一定是这个 Key 的问题，不停用，但换下一个 Key 重试
+                    self.report(index, KeyOutcome::Failed);
+                    last_error = Some(err.into());
+                    continue;
+                }
+            };
+
+            let outcome = outcome(&response);
+            self.report(index, outcome);
+            if outcome == KeyOutcome::Unauthorized {
+                key.invalidate();
+            }
+            match outcome {
+                KeyOutcome::Success | KeyOutcome::Failed => return Ok((key, check_status(response).await?)),
+                _ => last_error = check_status(response).await.err(),
+            }
+        }
+        Err(last_error.unwrap_or(ChatError::NoAvailableKey))
+    }
+}
+
+/// 按响应状态码判断本次请求的结果
+fn outcome(response: &reqwest::Response) -> KeyOutcome {
+    match response.status() {
+        StatusCode::UNAUTHORIZED | StatusCode::FORBIDDEN => KeyOutcome::Unauthorized,
+        StatusCode::TOO_MANY_REQUESTS => KeyOutcome::RateLimited {
+            retry_after: retry_after(response),
+        },
+        status if status.is_success() => KeyOutcome::Success,
+        _ => KeyOutcome::Failed,
+    }
+}
+
+/// `Retry-After` 中的秒数，不支持 HTTP 日期格式
+fn retry_after(response: &reqwest::Response) -> Option<Duration> {
+    response
+        .headers()
+        .get(reqwest::header::RETRY_AFTER)?
+        .to_str()
+        .ok()?
+        .trim()
+        .parse()
+        .ok()
+        .map(Duration::from_secs)
+}
+
+/// 只保留首尾各 4 位，用于日志与界面显示
+pub fn mask_key(key: &str) -> String {
+    let chars: Vec<char> = key.trim().chars().collect();
+    if chars.len() <= 12 {
+        return "****".to_string();
+    }
+    let head: String = chars[..4].iter().collect();
+    let tail: String = chars[chars.len() - 4..].iter().collect();
+    format!("{}…{}", head, tail)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    use super::*;
+
+    #[derive(Debug, Clone)]
+    struct TestKey {
+        name: &'static str,
+        invalidated: Arc<AtomicUsize>,
+    }
+
+    impl Credential for TestKey {
+        fn invalidate(&self) {
+            self.invalidated.fetch_add(1, Ordering::SeqCst);
+        }
+    }
+
+    fn pool(selection: KeySelection) -> KeyPool<&'static str> {
+        KeyPool::new([("key-a", "a"), ("key-b", "b"), ("key-c", "c")], selection)
+    }
+
+    fn test_pool(names: &[&'static str]) -> KeyPool<TestKey> {
+        let keys = names.iter().map(|&name| {
+            let key = TestKey {
+                name,
+                invalidated: Arc::default(),
+            };
+            (name, key)
+        });
+        KeyPool::new(keys, KeySelection::Priority)
+    }
+
+    fn response(status: u16, retry_after: Option<&str>) -> reqwest::Response {
+        let mut builder = http::Response::builder().status(status);
+        if let Some(retry_after) = retry_after {
+            builder = builder.header(reqwest::header::RETRY_AFTER, retry_after);
+        }
+        builder.body("{}").unwrap().into()
+    }
+
+    /// 连接一个没有监听的端口，得到一个网络错误
+    async fn transport_error() -> reqwest::Error {
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let address = listener.local_addr().unwrap();
+        drop(listener);
+        reqwest::get(format!("http://{}", address)).await.unwrap_err()
+    }
+
+    fn acquired(pool: &KeyPool<&'static str>) -> &'static str {
+        pool.acquire().unwrap().1
+    }
+
+    #[test]
+    fn round_robin_rotates_and_priority_prefers_the_first_key() {
+        let pool = pool(KeySelection::RoundRobin);
+        let order: Vec<_> = (0..4).map(|_| acquired(&pool)).collect();
+        assert_eq!(order, ["a", "b", "c", "a"]);
+
+        let pool = self::pool(KeySelection::Priority);
+        let order: Vec<_> = (0..3).map(|_| acquired(&pool)).collect();
+        assert_eq!(order, ["a", "a", "a"]);
+        assert_eq!(pool.usage()[0].requests, 3);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn disabled_keys_are_skipped_until_the_cooldown_ends() {
+        let pool = pool(KeySelection::Priority).with_cooldowns(Duration::from_secs(5), Duration::from_secs(60));
+        pool.report(0, KeyOutcome::RateLimited { retry_after: None });
+        pool.report(1, KeyOutcome::Unauthorized);
+        assert_eq!(acquired(&pool), "c");
+        assert_eq!(pool.usage()[0].disabled_for, Some(Duration::from_secs(5)));
+
+        tokio::time::advance(Duration::from_secs(5)).await;
+        assert_eq!(acquired(&pool), "a");
+        assert_eq!(pool.usage()[0].disabled_for, None);
+
+        pool.report(0, KeyOutcome::Unauthorized);
+        pool.report(2, KeyOutcome::Unauthorized);
+        assert!(pool.acquire().is_none());
+
+        tokio::time::advance(Duration::from_secs(55)).await;
+        assert_eq!(acquired(&pool), "b");
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn retry_after_overrides_the_rate_limit_cooldown() {
+        let pool = pool(KeySelection::Priority);
+        let limited = outcome(&response(429, Some(" 30 ")));
+        assert_eq!(
+            limited,
+            KeyOutcome::RateLimited {
+                retry_after: Some(Duration::from_secs(30))
+            }
+        );
+        pool.report(0, limited);
+        assert_eq!(pool.usage()[0].disabled_for, Some(Duration::from_secs(30)));
+        assert_eq!(pool.usage()[0].rate_limited, 1);
+
+        // HTTP 日期格式不支持，按默认时长停用
+        let date = "Wed, 21 Oct 2015 07:28:00 GMT";
+        assert_eq!(outcome(&response(429, Some(date))), KeyOutcome::RateLimited { retry_after: None });
+        pool.report(1, KeyOutcome::RateLimited { retry_after: None });
+        assert_eq!(pool.usage()[1].disabled_for, Some(DEFAULT_RATE_LIMIT_COOLDOWN));
+    }
+
+    #[tokio::test]
+    async fn send_invalidates_rejected_keys_and_fails_over() {
+        for status in [401, 403] {
+            let pool = test_pool(&["first-api-key", "second-api-key"]);
+            let (key, response) = pool
+                .send(|key| async move { Ok(response(if key.name == "first-api-key" { status } else { 200 }, None)) })
+                .await
+                .unwrap();
+            assert_eq!(key.name, "second-api-key");
+            assert_eq!(response.status(), 200);
+
+            let next = pool.acquire().unwrap().1;
+            assert_eq!(next.name, "second-api-key", "the rejected key stays disabled");
+            let usage = pool.usage();
+            assert_eq!(usage[0].unauthorized, 1);
+            assert!(usage[0].disabled_for.is_some());
+            assert_eq!(usage[1].successes, 1);
+            assert_eq!(key.invalidated.load(Ordering::SeqCst), 0);
+        }
+    }
+
+    #[tokio::test]
+    async fn send_calls_invalidate_on_the_rejected_key() {
+        let pool = test_pool(&["only-api-key-here"]);
+        let invalidated = Arc::clone(&pool.acquire().unwrap().1.invalidated);
+        let err = pool.send(|_| async { Ok(response(401, None)) }).await.unwrap_err();
+        assert!(matches!(err, ChatError::Api { status: 401, .. }), "{err:?}");
+        assert_eq!(invalidated.load(Ordering::SeqCst), 1);
+        assert!(matches!(pool.send(|_| async { Ok(response(200, None)) }).await, Err(ChatError::NoAvailableKey)));
+    }
+
+    #[tokio::test]
+    async fn send_tries_the_next_key_after_a_transport_error() {
+        let pool = test_pool(&["first-api-key", "second-api-key"]);
+        let error = transport_error().await;
+        let mut error = Some(error);
+        let (key, _) = pool
+            .send(|key| {
+                let result = match key.name {
+                    "first-api-key" => Err(error.take().unwrap()),
+                    _ => Ok(response(200, None)),
+                };
+                async move { result }
+            })
+            .await
+            .unwrap();
+        assert_eq!(key.name, "second-api-key");
+
+        let usage = pool.usage();
+        assert_eq!((usage[0].failures, usage[0].disabled_for), (1, None));
+        assert_eq!(usage[1].successes, 1);
+    }
+
+    #[tokio::test]
+    async fn send_returns_the_last_transport_error_when_every_key_fails() {
+        let pool = test_pool(&["first-api-key", "second-api-key"]);
+        let mut errors = vec![transport_error().await, transport_error().await];
+        let mut tried = Vec::new();
+        let err = pool
+            .send(|key| {
+                tried.push(key.name);
+                let err = errors.pop().unwrap();
+                async move { Err(err) }
+            })
+            .await
+            .unwrap_err();
+        assert!(matches!(err, ChatError::Http(_)), "{err:?}");
+        assert_eq!(tried, ["first-api-key", "second-api-key"]);
+    }
+
+    #[test]
+    fn mask_key_keeps_only_both_ends() {
+        assert_eq!(mask_key(" abcd1234567890wxyz "), "abcd…wxyz");
+        assert_eq!(mask_key("short-key"), "****");
+    }
+}
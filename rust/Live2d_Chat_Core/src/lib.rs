@@ -1 +1,18 @@
-pub mod utils;
\ No newline at end of file
+pub mod chat_client;
+pub mod chatglm_api;
+pub mod config;
+pub mod context;
+pub mod conversation_store;
+pub mod key_pool;
+pub mod provider;
+pub mod sse;
+pub mod task_poll;
+pub mod utils;
+
+pub use chat_client::{ChatClient, ChatConfig, ChatError, ChatMessage, ChatReply, ChatRole, ChatWarning, InvokeMode};
+pub use config::{AppConfig, ModelProfile};
+pub use context::{ContextBudget, ContextBuilder};
+pub use conversation_store::{ConversationStore, Session, SessionInfo, StoredMessage};
+pub use key_pool::{Credential, KeyPool, KeySelection, KeyUsage};
+pub use provider::{ChatProvider, ProviderCapabilities, ProviderConfig, ProviderKind};
+pub use task_poll::{CancelToken, PollConfig};
@@ -0,0 +1,187 @@
+use std::time::Duration;
+
+use serde::Deserialize;
+
+use super::{
+    check_status, completion_body, first_choice, join_url, parse_response, read_completion, read_completion_stream,
+    BoxFuture, ChatProvider, ChatRequest, CompletionChoice, ProviderCapabilities,
+};
+use crate::chat_client::ChatError;
+use crate::key_pool::{KeyPool, KeySelection, KeyUsage};
+use crate::task_poll::{poll_task, CancelToken, PollConfig, PollError};
+use crate::utils::token_manager::TokenManager;
+
+pub const DEFAULT_BASE_URL: &str = "https://open.bigmodel.cn/api/paas/v4/";
+
+/// ChatGLM 没有模型列表接口，使用配置文件支持的模型
+const MODELS: &[&str] = &["glm-4-plus", "glm-4-air", "glm-4-flash", "glm-4-long"];
+
+#[derive(Deserialize)]
+struct TaskResponse {
+    #[serde(default)]
+    id: Option<String>,
+    #[serde(default)]
+    choices: Vec<CompletionChoice>,
+}
+
+/// 智谱 ChatGLM 开放平台
+#[derive(Debug, Clone)]
+pub struct ChatGlmProvider {
+    keys: KeyPool<TokenManager>,
+    base_url: String,
+    poll: PollConfig,
+    http: reqwest::Client,
+}
+
+impl ChatGlmProvider {
+    /// `api_key` 为 `id.secret` 格式
+    pub fn new(api_key: &str) -> Result<Self, ChatError> {
+        Self::with_api_keys(&[api_key], KeySelection::default())
+    }
+
+    /// 使用多个 `id.secret` 格式的 Key，被限流或鉴权失败时换下一个
+    pub fn with_api_keys(api_keys: &[&str], selection: KeySelection) -> Result<Self, ChatError> {
+        if api_keys.is_empty() {
+            return Err(ChatError::InvalidApiKey);
+        }
+        let keys = api_keys
+            .iter()
+            .map(|api_key| {
+                let (user_id, user_secret) = api_key
+                    .trim()
+                    .split_once('.')
+                    .filter(|(id, secret)| !id.is_empty() && !secret.is_empty() && !secret.contains('.'))
+                    .ok_or(ChatError::InvalidApiKey)?;
+                Ok((*api_key, TokenManager::new(user_id, user_secret)))
+            })
+            .collect::<Result<Vec<_>, ChatError>>()?;
+
+        Ok(Self {
+            keys: KeyPool::new(keys, selection),
+            base_url: DEFAULT_BASE_URL.to_string(),
+            poll: PollConfig::default(),
+            http: reqwest::Client::new(),
+        })
+    }
+
+    pub fn with_base_url(mut self, base_url: &str) -> Self {
+        self.base_url = base_url.to_string();
+        self
+    }
+
+    /// 异步任务结果的轮询策略
+    pub fn with_poll(mut self, poll: PollConfig) -> Self {
+        self.poll = poll;
+        self
+    }
+
+    /// 鉴权令牌的有效期，默认 30 分钟
+    pub fn with_token_ttl(mut self, ttl: Duration) -> Self {
+        self.keys = self.keys.map(|tokens| tokens.clone().with_ttl(ttl));
+        self
+    }
+
+    /// 返回所用 Key 的令牌与响应
+    async fn post(
+        &self,
+        path: &str,
+        request: &ChatRequest,
+        stream: bool,
+    ) -> Result<(TokenManager, reqwest::Response), ChatError> {
+        let url = join_url(&self.base_url, path);
+        let body = completion_body(request, stream);
+        self.keys
+            .send(|tokens| {
+                self.http
+                    .post(&url)
+                    .header("Accept", if stream { "text/event-stream" } else { "application/json" })
+                    .header("Authorization", format!("Bearer {}", tokens.token()))
+                    .json(&body)
+                    .send()
+            })
+            .await
+    }
+
+    async fn async_task(&self, request: &ChatRequest, cancel: Option<&CancelToken>) -> Result<String, ChatError> {
+        let (tokens, response) = self.post("async/chat/completions", request, false).await?;
+        let task: TaskResponse = parse_response(&response.text().await?)?;
+        let task_id = task
+            .id
+            .ok_or_else(|| ChatError::InvalidResponse("Task id not found in response".to_string()))?;
+
+        // 任务结果只能用提交任务的 Key 查询
+        let url = join_url(&self.base_url, &format!("async-result/{}", task_id));
+        let fetch = || async {
+            let token = tokens.token();
+            let response = self
+                .http
+                .get(&url)
+                .header("Accept", "application/json")
+                .header("Authorization", format!("Bearer {}", token))
+                .send()
+                .await?;
+            Ok::<_, ChatError>(check_status(response).await?.text().await?)
+        };
+        let body = poll_task(&self.poll, cancel, fetch, ChatError::is_transient)
+            .await
+            .map_err(|err| match err {
+                PollError::Failed { status, message } => ChatError::TaskFailed {
+                    task_id: task_id.clone(),
+                    status,
+                    message,
+                },
+                PollError::Timeout => ChatError::TaskTimeout(task_id.clone()),
+                PollError::Cancelled => ChatError::Cancelled(task_id.clone()),
+                PollError::InvalidResponse(message) => ChatError::InvalidResponse(message),
+                PollError::Request(err) => err,
+            })?;
+
+        let result: TaskResponse = parse_response(&body)?;
+        first_choice(result.choices)
+    }
+}
+
+impl ChatProvider for ChatGlmProvider {
+    fn name(&self) -> &str {
+        "chatglm"
+    }
+
+    fn capabilities(&self) -> ProviderCapabilities {
+        ProviderCapabilities {
+            streaming: true,
+            async_task: true,
+            list_models: true,
+        }
+    }
+
+    fn chat<'a>(&'a self, request: &'a ChatRequest) -> BoxFuture<'a, Result<String, ChatError>> {
+        Box::pin(async move { read_completion(self.post("chat/completions", request, false).await?.1).await })
+    }
+
+    fn chat_stream<'a>(
+        &'a self,
+        request: &'a ChatRequest,
+        on_delta: &'a mut (dyn FnMut(&str) + Send),
+    ) -> BoxFuture<'a, Result<String, ChatError>> {
+        Box::pin(async move {
+            let (_, response) = self.post("chat/completions", request, true).await?;
+            read_completion_stream(response, on_delta).await
+        })
+    }
+
+    fn chat_async_task<'a>(
+        &'a self,
+        request: &'a ChatRequest,
+        cancel: Option<&'a CancelToken>,
+    ) -> BoxFuture<'a, Result<String, ChatError>> {
+        Box::pin(self.async_task(request, cancel))
+    }
+
+    fn list_models(&self) -> BoxFuture<'_, Result<Vec<String>, ChatError>> {
+        Box::pin(async { Ok(MODELS.iter().map(|model| model.to_string()).collect()) })
+    }
+
+    fn key_usage(&self) -> Vec<KeyUsage> {
+        self.keys.usage()
+    }
+}
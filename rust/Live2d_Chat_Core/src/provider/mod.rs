@@ -0,0 +1,276 @@
+pub mod chatglm;
+pub mod ollama;
+pub mod openai;
+
+use std::fmt::Debug;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::Arc;
+
+use serde::{Deserialize, Serialize};
+
+use crate::chat_client::{ChatError, ChatMessage};
+use crate::config::AppConfig;
+use crate::key_pool::{KeySelection, KeyUsage};
+use crate::sse::SseDecoder;
+use crate::task_poll::{CancelToken, PollConfig};
+
+pub use chatglm::ChatGlmProvider;
+pub use ollama::OllamaProvider;
+pub use openai::OpenAiCompatibleProvider;
+
+pub type BoxFuture<'a, T> = Pin<Box<dyn Future<Output = T> + Send + 'a>>;
+
+/// 一次对话请求
+#[derive(Debug, Clone, PartialEq)]
+pub struct ChatRequest {
+    pub model: String,
+    pub messages: Vec<ChatMessage>,
+    pub temperature: f64,
+    pub top_p: f64,
+}
+
+/// 后端支持的功能
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ProviderCapabilities {
+    pub streaming: bool,
+    /// 提交异步任务并轮询结果
+    pub async_task: bool,
+    pub list_models: bool,
+}
+
+/// 大语言模型后端
+pub trait ChatProvider: Debug + Send + Sync {
+    fn name(&self) -> &str;
+
+    fn capabilities(&self) -> ProviderCapabilities;
+
+    /// 请求完整回复
+    fn chat<'a>(&'a self, request: &'a ChatRequest) -> BoxFuture<'a, Result<String, ChatError>>;
+
+    /// 流式请求，每段增量文本调用一次 `on_delta`，返回完整回复
+    fn chat_stream<'a>(
+        &'a self,
+        request: &'a ChatRequest,
+        on_delta: &'a mut (dyn FnMut(&str) + Send),
+    ) -> BoxFuture<'a, Result<String, ChatError>>;
+
+    /// 提交异步任务并等待结果，只有 `capabilities().async_task` 为 true 的后端支持
+    fn chat_async_task<'a>(
+        &'a self,
+        request: &'a ChatRequest,
+        cancel: Option<&'a CancelToken>,
+    ) -> BoxFuture<'a, Result<String, ChatError>> {
+        let _ = (request, cancel);
+        Box::pin(async { Err(ChatError::Unsupported("async task")) })
+    }
+
+    /// 可用的模型名称
+    fn list_models(&self) -> BoxFuture<'_, Result<Vec<String>, ChatError>>;
+
+    /// 每个 API Key 的用量，不使用 Key 的后端为空
+    fn key_usage(&self) -> Vec<KeyUsage> {
+        Vec::new()
+    }
+}
+
+/// 后端类型
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ProviderKind {
+    #[default]
+    ChatGlm,
+    /// 任意 OpenAI 兼容接口，包括 llama.cpp server、vLLM 等
+    #[serde(alias = "openai-compatible", alias = "llamacpp")]
+    OpenAi,
+    Ollama,
+}
+
+/// TOML 配置中的 `[provider]` 一节
+///
+/// ```toml
+/// [provider]
+/// kind = "ollama"                     # chatglm / openai / ollama
+/// base_url = "http://localhost:11434" # 省略时使用各后端的默认地址
+/// api_key = "..."                     # ChatGLM 为 id.secret 格式，本地后端可省略
+/// api_keys = ["...", "..."]            # 多个 Key 时与 api_key 一起按 key_selection 选用
+/// key_selection = "round-robin"        # round-robin / priority
+///
+/// [provider.poll]                      # 异步任务的轮询策略，只用于 ChatGLM，均可省略
+/// timeout_seconds = 120
+/// initial_interval_ms = 100
+/// max_interval_ms = 2000
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ProviderConfig {
+    #[serde(default)]
+    pub kind: ProviderKind,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub base_url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub api_key: Option<String>,
+    /// 额外的 Key，排在 `api_key` 之后
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub api_keys: Vec<String>,
+    #[serde(default)]
+    pub key_selection: KeySelection,
+    #[serde(default)]
+    pub poll: PollConfig,
+}
+
+impl ProviderConfig {
+    /// 读取 `[provider]` 一节，没有时为 ChatGLM，见 [`AppConfig`](crate::config::AppConfig)
+    pub fn from_toml(content: &str) -> Result<Self, ChatError> {
+        Ok(AppConfig::from_toml(content)?.provider)
+    }
+
+    /// `api_key` 与 `api_keys` 中所有不为空的 Key，去掉重复的
+    pub fn keys(&self) -> Vec<&str> {
+        let mut keys: Vec<&str> = Vec::new();
+        for key in self.api_key.iter().chain(&self.api_keys) {
+            let key = key.trim();
+            if !key.is_empty() && !keys.contains(&key) {
+                keys.push(key);
+            }
+        }
+        keys
+    }
+
+    pub fn build(&self) -> Result<Arc<dyn ChatProvider>, ChatError> {
+        let api_keys = self.keys();
+        Ok(match self.kind {
+            ProviderKind::ChatGlm => {
+                let mut provider = ChatGlmProvider::with_api_keys(&api_keys, self.key_selection)?;
+                if let Some(base_url) = &self.base_url {
+                    provider = provider.with_base_url(base_url);
+                }
+                Arc::new(provider.with_poll(self.poll.clone()))
+            }
+            ProviderKind::OpenAi => {
+                let base_url = self
+                    .base_url
+                    .as_deref()
+                    .ok_or(ChatError::MissingConfig("provider.base_url"))?;
+                Arc::new(OpenAiCompatibleProvider::with_api_keys(base_url, &api_keys, self.key_selection))
+            }
+            ProviderKind::Ollama => Arc::new(OllamaProvider::new(
+                self.base_url.as_deref().unwrap_or(ollama::DEFAULT_BASE_URL),
+            )),
+        })
+    }
+}
+
+/// `base` 与 `path` 之间恰好一个斜杠
+pub(crate) fn join_url(base: &str, path: &str) -> String {
+    format!("{}/{}", base.trim_end_matches('/'), path.trim_start_matches('/'))
+}
+
+pub(crate) async fn check_status(response: reqwest::Response) -> Result<reqwest::Response, ChatError> {
+    let status = response.status();
+    if status.is_success() {
+        return Ok(response);
+    }
+    let text = response.text().await?;
+    let message = serde_json::from_str::<serde_json::Value>(&text)
+        .ok()
+        .and_then(|error| {
+            let error = &error["error"];
+            error["message"].as_str().or_else(|| error.as_str()).map(str::to_string)
+        })
+        .unwrap_or(text);
+    Err(ChatError::Api {
+        status: status.as_u16(),
+        message,
+    })
+}
+
+pub(crate) fn parse_response<T: serde::de::DeserializeOwned>(text: &str) -> Result<T, ChatError> {
+    serde_json::from_str(text).map_err(|err| ChatError::InvalidResponse(err.to_string()))
+}
+
+#[derive(Deserialize)]
+struct CompletionResponse {
+    choices: Vec<CompletionChoice>,
+}
+
+#[derive(Deserialize)]
+pub(crate) struct CompletionChoice {
+    message: ChatMessage,
+}
+
+pub(crate) fn first_choice(choices: Vec<CompletionChoice>) -> Result<String, ChatError> {
+    choices
+        .into_iter()
+        .next()
+        .map(|choice| choice.message.content)
+        .ok_or_else(|| ChatError::InvalidResponse("Choices not found in response".to_string()))
+}
+
+/// OpenAI 格式的 `chat/completions` 请求正文，ChatGLM 也使用这一格式
+pub(crate) fn completion_body(request: &ChatRequest, stream: bool) -> serde_json::Value {
+    serde_json::json!({
+        "model": request.model,
+        "messages": request.messages,
+        "stream": stream,
+        "temperature": request.temperature,
+        "top_p": request.top_p,
+    })
+}
+
+/// 读取 OpenAI 格式的非流式回复
+pub(crate) async fn read_completion(response: reqwest::Response) -> Result<String, ChatError> {
+    let completion: CompletionResponse = parse_response(&response.text().await?)?;
+    first_choice(completion.choices)
+}
+
+#[derive(Deserialize)]
+struct StreamChunk {
+    #[serde(default)]
+    choices: Vec<StreamChoice>,
+}
+
+#[derive(Deserialize)]
+struct StreamChoice {
+    #[serde(default)]
+    delta: StreamDelta,
+}
+
+#[derive(Default, Deserialize)]
+struct StreamDelta {
+    content: Option<String>,
+}
+
+/// 流式响应的结束标记
+const STREAM_DONE: &str = "[DONE]";
+
+/// 读取 OpenAI 格式的 SSE 流式回复，直到 `[DONE]`；连接在此之前结束时返回错误
+pub(crate) async fn read_completion_stream(
+    mut response: reqwest::Response,
+    on_delta: &mut (dyn FnMut(&str) + Send),
+) -> Result<String, ChatError> {
+    let mut decoder = SseDecoder::new();
+    let mut reply = String::new();
+    'stream: loop {
+        let events = match response.chunk().await? {
+            Some(chunk) => decoder.push(&chunk),
+            // 连接在 `[DONE]` 之前结束，回复可能被截断
+            None => match decoder.finish() {
+                Some(data) if data.trim() == STREAM_DONE => break,
+                _ => return Err(ChatError::InvalidResponse("Stream ended before [DONE]".to_string())),
+            },
+        };
+        for data in events {
+            if data.trim() == STREAM_DONE {
+                break 'stream;
+            }
+            let chunk: StreamChunk = parse_response(&data)?;
+            for content in chunk.choices.into_iter().filter_map(|choice| choice.delta.content) {
+                if !content.is_empty() {
+                    on_delta(&content);
+                    reply.push_str(&content);
+                }
+            }
+        }
+    }
+    Ok(reply)
+}
@@ -0,0 +1,146 @@
+use serde::Deserialize;
+use serde_json::json;
+
+use super::{check_status, join_url, parse_response, BoxFuture, ChatProvider, ChatRequest, ProviderCapabilities};
+use crate::chat_client::ChatError;
+
+pub const DEFAULT_BASE_URL: &str = "http://localhost:11434";
+
+#[derive(Deserialize)]
+struct ChatResponse {
+    #[serde(default)]
+    message: Option<ResponseMessage>,
+    #[serde(default)]
+    done: bool,
+    #[serde(default)]
+    error: Option<String>,
+}
+
+#[derive(Deserialize)]
+struct ResponseMessage {
+    #[serde(default)]
+    content: String,
+}
+
+#[derive(Deserialize)]
+struct TagList {
+    models: Vec<TagEntry>,
+}
+
+#[derive(Deserialize)]
+struct TagEntry {
+    name: String,
+}
+
+/// 本地 Ollama 服务的原生接口，可离线使用
+#[derive(Debug, Clone)]
+pub struct OllamaProvider {
+    base_url: String,
+    http: reqwest::Client,
+}
+
+impl OllamaProvider {
+    pub fn new(base_url: &str) -> Self {
+        Self {
+            base_url: base_url.to_string(),
+            http: reqwest::Client::new(),
+        }
+    }
+
+    async fn post(&self, request: &ChatRequest, stream: bool) -> Result<reqwest::Response, ChatError> {
+        let body = json!({
+            "model": request.model,
+            "messages": request.messages,
+            "stream": stream,
+            "options": {
+                "temperature": request.temperature,
+                "top_p": request.top_p,
+            },
+        });
+        let response = self
+            .http
+            .post(join_url(&self.base_url, "api/chat"))
+            .json(&body)
+            .send()
+            .await?;
+        check_status(response).await
+    }
+}
+
+/// 解析一行响应，返回其中的文本与是否结束
+fn parse_line(line: &str) -> Result<(String, bool), ChatError> {
+    let response: ChatResponse = parse_response(line)?;
+    if let Some(error) = response.error {
+        return Err(ChatError::InvalidResponse(error));
+    }
+    Ok((response.message.map(|m| m.content).unwrap_or_default(), response.done))
+}
+
+impl ChatProvider for OllamaProvider {
+    fn name(&self) -> &str {
+        "ollama"
+    }
+
+    fn capabilities(&self) -> ProviderCapabilities {
+        ProviderCapabilities {
+            streaming: true,
+            async_task: false,
+            list_models: true,
+        }
+    }
+
+    fn chat<'a>(&'a self, request: &'a ChatRequest) -> BoxFuture<'a, Result<String, ChatError>> {
+        Box::pin(async move {
+            let response = self.post(request, false).await?;
+            Ok(parse_line(&response.text().await?)?.0)
+        })
+    }
+
+    fn chat_stream<'a>(
+        &'a self,
+        request: &'a ChatRequest,
+        on_delta: &'a mut (dyn FnMut(&str) + Send),
+    ) -> BoxFuture<'a, Result<String, ChatError>> {
+        Box::pin(async move {
+            // 流式响应为逐行的 JSON（NDJSON），不是 SSE；以 `done: true` 的一行结束
+            let mut response = self.post(request, true).await?;
+            let mut buffer = Vec::new();
+            let mut reply = String::new();
+            loop {
+                let chunk = response.chunk().await?;
+                let finished = chunk.is_none();
+                buffer.extend_from_slice(chunk.as_deref().unwrap_or_default());
+                if finished {
+                    buffer.push(b'\n');
+                }
+
+                while let Some(end) = buffer.iter().position(|&b| b == b'\n') {
+                    let line: Vec<u8> = buffer.drain(..=end).collect();
+                    let line = String::from_utf8_lossy(&line);
+                    if line.trim().is_empty() {
+                        continue;
+                    }
+                    let (content, done) = parse_line(line.trim())?;
+                    if !content.is_empty() {
+                        on_delta(&content);
+                        reply.push_str(&content);
+                    }
+                    if done {
+                        return Ok(reply);
+                    }
+                }
+                if finished {
+                    return Err(ChatError::InvalidResponse("Stream ended before done".to_string()));
+                }
+            }
+        })
+    }
+
+    fn list_models(&self) -> BoxFuture<'_, Result<Vec<String>, ChatError>> {
+        Box::pin(async move {
+            let response = self.http.get(join_url(&self.base_url, "api/tags")).send().await?;
+            let tags: TagList = parse_response(&check_status(response).await?.text().await?)?;
+            Ok(tags.models.into_iter().map(|model| model.name).collect())
+        })
+    }
+}
@@ -0,0 +1,105 @@
+use serde::Deserialize;
+
+use super::{
+    check_status, completion_body, join_url, parse_response, read_completion, read_completion_stream, BoxFuture,
+    ChatProvider, ChatRequest, ProviderCapabilities,
+};
+use crate::chat_client::ChatError;
+use crate::key_pool::{KeyPool, KeySelection, KeyUsage};
+
+#[derive(Deserialize)]
+struct ModelList {
+    data: Vec<ModelEntry>,
+}
+
+#[derive(Deserialize)]
+struct ModelEntry {
+    id: String,
+}
+
+/// 任意 OpenAI 兼容接口，如 OpenAI、DeepSeek、llama.cpp server（`http://localhost:8080/v1`）
+#[derive(Debug, Clone)]
+pub struct OpenAiCompatibleProvider {
+    /// 包含版本前缀，如 `https://api.openai.com/v1`
+    base_url: String,
+    /// 本地服务可以没有
+    keys: Option<KeyPool<String>>,
+    http: reqwest::Client,
+}
+
+impl OpenAiCompatibleProvider {
+    /// 本地服务不需要 `api_key`
+    pub fn new(base_url: &str, api_key: Option<&str>) -> Self {
+        Self::with_api_keys(base_url, api_key.as_slice(), KeySelection::default())
+    }
+
+    /// 使用多个 Key，被限流或鉴权失败时换下一个；`api_keys` 为空时不带鉴权
+    pub fn with_api_keys(base_url: &str, api_keys: &[&str], selection: KeySelection) -> Self {
+        let keys = (!api_keys.is_empty())
+            .then(|| KeyPool::new(api_keys.iter().map(|key| (*key, key.to_string())), selection));
+        Self {
+            base_url: base_url.to_string(),
+            keys,
+            http: reqwest::Client::new(),
+        }
+    }
+
+    /// 有 Key 时依次尝试，没有时直接发送
+    async fn send(&self, build: impl Fn() -> reqwest::RequestBuilder) -> Result<reqwest::Response, ChatError> {
+        match &self.keys {
+            Some(keys) => Ok(keys.send(|api_key| build().bearer_auth(api_key).send()).await?.1),
+            None => check_status(build().send().await?).await,
+        }
+    }
+
+    async fn post(&self, request: &ChatRequest, stream: bool) -> Result<reqwest::Response, ChatError> {
+        let url = join_url(&self.base_url, "chat/completions");
+        let body = completion_body(request, stream);
+        self.send(|| {
+            self.http
+                .post(&url)
+                .header("Accept", if stream { "text/event-stream" } else { "application/json" })
+                .json(&body)
+        })
+        .await
+    }
+}
+
+impl ChatProvider for OpenAiCompatibleProvider {
+    fn name(&self) -> &str {
+        "openai"
+    }
+
+    fn capabilities(&self) -> ProviderCapabilities {
+        ProviderCapabilities {
+            streaming: true,
+            async_task: false,
+            list_models: true,
+        }
+    }
+
+    fn chat<'a>(&'a self, request: &'a ChatRequest) -> BoxFuture<'a, Result<String, ChatError>> {
+        Box::pin(async move { read_completion(self.post(request, false).await?).await })
+    }
+
+    fn chat_stream<'a>(
+        &'a self,
+        request: &'a ChatRequest,
+        on_delta: &'a mut (dyn FnMut(&str) + Send),
+    ) -> BoxFuture<'a, Result<String, ChatError>> {
+        Box::pin(async move { read_completion_stream(self.post(request, true).await?, on_delta).await })
+    }
+
+    fn list_models(&self) -> BoxFuture<'_, Result<Vec<String>, ChatError>> {
+        Box::pin(async move {
+            let url = join_url(&self.base_url, "models");
+            let response = self.send(|| self.http.get(&url)).await?;
+            let models: ModelList = parse_response(&response.text().await?)?;
+            Ok(models.data.into_iter().map(|model| model.id).collect())
+        })
+    }
+
+    fn key_usage(&self) -> Vec<KeyUsage> {
+        self.keys.as_ref().map(KeyPool::usage).unwrap_or_default()
+    }
+}
@@ -0,0 +1,107 @@
+/// 增量解析 `text/event-stream` 响应，按事件返回 `data:` 字段内容
+#[derive(Debug, Default)]
+pub struct SseDecoder {
+    buffer: Vec<u8>,
+    data: Option<String>,
+}
+
+impl SseDecoder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 写入收到的字节，返回其中已完整结束的事件的数据；多行 `data:` 以换行拼接
+    pub fn push(&mut self, chunk: &[u8]) -> Vec<String> {
+        self.buffer.extend_from_slice(chunk);
+
+        let mut events = Vec::new();
+        while let Some(end) = self.buffer.iter().position(|&b| b == b'\n') {
+            let line: Vec<u8> = self.buffer.drain(..=end).collect();
+            let line = String::from_utf8_lossy(&line);
+            self.process_line(line.trim_end_matches(['\n', '\r']), &mut events);
+        }
+        events
+    }
+
+    /// 连接结束时调用，返回缺少结尾空行的最后一个事件
+    pub fn finish(&mut self) -> Option<String> {
+        if !self.buffer.is_empty() {
+            let line = String::from_utf8_lossy(&std::mem::take(&mut self.buffer)).into_owned();
+            let mut events = Vec::new();
+            self.process_line(line.trim_end_matches('\r'), &mut events);
+        }
+        self.data.take()
+    }
+
+    fn process_line(&mut self, line: &str, events: &mut Vec<String>) {
+        if line.is_empty() {
+            events.extend(self.data.take());
+            return;
+        }
+        // 以冒号开头的是注释，其它字段（event、id、retry）不需要
+        let Some(value) = line.strip_prefix("data:") else {
+            return;
+        };
+        let value = value.strip_prefix(' ').unwrap_or(value);
+        match &mut self.data {
+            Some(data) => {
+                data.push('\n');
+                data.push_str(value);
+            }
+            None => self.data = Some(value.to_string()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn events_split_across_chunks_are_joined() {
+        let mut decoder = SseDecoder::new();
+        assert!(decoder.push(b"da").is_empty());
+        assert!(decoder.push(b"ta: {\"a\":").is_empty());
+        assert!(decoder.push(b" 1}\n").is_empty());
+        assert_eq!(decoder.push(b"\ndata: second\n\nda"), ["{\"a\": 1}", "second"]);
+        assert_eq!(decoder.push(b"ta: third\n\n"), ["third"]);
+    }
+
+    #[test]
+    fn multi_line_data_is_joined_with_newlines() {
+        let mut decoder = SseDecoder::new();
+        assert_eq!(decoder.push(b"data: first\ndata:second\ndata: \n\n"), ["first\nsecond\n"]);
+    }
+
+    #[test]
+    fn comments_and_other_fields_are_ignored() {
+        let mut decoder = SseDecoder::new();
+        let events = decoder.push(b": keep-alive\n\nevent: message\nid: 7\nretry: 1000\ndata: text\n\n");
+        assert_eq!(events, ["text"]);
+    }
+
+    #[test]
+    fn crlf_line_endings_are_accepted() {
+        let mut decoder = SseDecoder::new();
+        assert!(decoder.push(b"data: one\r").is_empty());
+        assert_eq!(decoder.push(b"\n\r\ndata: two\r\n\r\n"), ["one", "two"]);
+    }
+
+    #[test]
+    fn done_marker_is_returned_like_any_other_event() {
+        let mut decoder = SseDecoder::new();
+        assert_eq!(decoder.push(b"data: {}\n\ndata: [DONE]\n\n"), ["{}", "[DONE]"]);
+        assert_eq!(decoder.finish(), None);
+    }
+
+    #[test]
+    fn finish_returns_an_unterminated_event() {
+        let mut decoder = SseDecoder::new();
+        assert!(decoder.push(b"data: [DONE]").is_empty());
+        assert_eq!(decoder.finish().as_deref(), Some("[DONE]"));
+
+        assert!(decoder.push(b"data: partial\n").is_empty());
+        assert_eq!(decoder.finish().as_deref(), Some("partial"));
+        assert_eq!(decoder.finish(), None);
+    }
+}
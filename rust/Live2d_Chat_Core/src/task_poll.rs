@@ -0,0 +1,367 @@
+use std::collections::hash_map::RandomState;
+use std::future::Future;
+use std::hash::{BuildHasher, Hasher};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use tokio::sync::Notify;
+use tokio::time::Instant;
+
+/// 异步任务结果的轮询策略，对应 TOML 配置中的 `[provider.poll]`
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct PollConfig {
+    /// 从开始轮询算起的最长等待时间
+    #[serde(rename = "timeout_seconds", with = "duration_secs")]
+    pub timeout: Duration,
+    #[serde(rename = "initial_interval_ms", with = "duration_ms")]
+    pub initial_interval: Duration,
+    #[serde(rename = "max_interval_ms", with = "duration_ms")]
+    pub max_interval: Duration,
+    /// 每次轮询后间隔的增长倍数
+    pub multiplier: f64,
+    /// 间隔的随机抖动比例，0.2 表示在 ±20% 范围内浮动
+    pub jitter: f64,
+}
+
+impl Default for PollConfig {
+    fn default() -> Self {
+        Self {
+            timeout: Duration::from_secs(120),
+            initial_interval: Duration::from_millis(100),
+            max_interval: Duration::from_secs(2),
+            multiplier: 1.5,
+            jitter: 0.2,
+        }
+    }
+}
+
+impl PollConfig {
+    /// 第 `attempt` 次（从 0 开始）轮询后的等待时间
+    fn interval(&self, attempt: u32) -> Duration {
+        let base = self.initial_interval.as_secs_f64() * self.multiplier.max(1.0).powi(attempt.min(64) as i32);
+        let base = base.min(self.max_interval.as_secs_f64());
+        let jitter = self.jitter.clamp(0.0, 1.0);
+        Duration::from_secs_f64((base * (1.0 + jitter * (2.0 * random_unit() - 1.0))).max(0.0))
+    }
+}
+
+mod duration_secs {
+    use std::time::Duration;
+
+    use serde::{Deserialize, Deserializer, Serializer};
+
+    pub fn serialize<S: Serializer>(duration: &Duration, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_u64(duration.as_secs())
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Duration, D::Error> {
+        u64::deserialize(deserializer).map(Duration::from_secs)
+    }
+}
+
+mod duration_ms {
+    use std::time::Duration;
+
+    use serde::{Deserialize, Deserializer, Serializer};
+
+    pub fn serialize<S: Serializer>(duration: &Duration, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_u64(duration.as_millis() as u64)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Duration, D::Error> {
+        u64::deserialize(deserializer).map(Duration::from_millis)
+    }
+}
+
+/// [0, 1) 内的随机数，只用于抖动
+fn random_unit() -> f64 {
+    // 每个 RandomState 的种子都不同
+    let hasher = RandomState::new().build_hasher();
+    (hasher.finish() >> 11) as f64 / (1u64 << 53) as f64
+}
+
+/// 可在其它线程或任务中取消轮询
+#[derive(Debug, Clone, Default)]
+pub struct CancelToken {
+    inner: Arc<CancelState>,
+}
+
+#[derive(Debug, Default)]
+struct CancelState {
+    cancelled: AtomicBool,
+    notify: Notify,
+}
+
+impl CancelToken {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn cancel(&self) {
+        self.inner.cancelled.store(true, Ordering::SeqCst);
+        self.inner.notify.notify_waiters();
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.inner.cancelled.load(Ordering::SeqCst)
+    }
+
+    /// 取消时完成
+    pub async fn cancelled(&self) {
+        loop {
+            let notified = self.inner.notify.notified();
+            if self.is_cancelled() {
+                return;
+            }
+            notified.await;
+        }
+    }
+}
+
+/// `async-result/` 返回的任务状态
+#[derive(Debug, Clone, PartialEq)]
+pub enum TaskStatus {
+    Processing,
+    Success,
+    /// `FAIL` 或无法识别的状态，附带服务端的错误信息
+    Failed { status: String, message: Option<String> },
+}
+
+impl TaskStatus {
+    /// 响应中没有 `task_status` 时返回 None
+    pub fn from_response(response: &Value) -> Option<Self> {
+        let status = response.get("task_status").and_then(Value::as_str)?;
+        let status = if status.eq_ignore_ascii_case("SUCCESS") {
+            TaskStatus::Success
+        } else if status.eq_ignore_ascii_case("PROCESSING") {
+            TaskStatus::Processing
+        } else {
+            let message = response
+                .get("error")
+                .and_then(|error| error.get("message"))
+                .or_else(|| response.get("message"))
+                .and_then(Value::as_str)
+                .map(str::to_string);
+            TaskStatus::Failed {
+                status: status.to_string(),
+                message,
+            }
+        };
+        Some(status)
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum PollError<E> {
+    #[error("Task failed, Status: {status}, {}", message.as_deref().unwrap_or("no message"))]
+    Failed { status: String, message: Option<String> },
+    #[error("Task timed out")]
+    Timeout,
+    #[error("Task polling cancelled")]
+    Cancelled,
+    #[error("Invalid task response: {0}")]
+    InvalidResponse(String),
+    #[error("{0}")]
+    Request(E),
+}
+
+/// 反复调用 `fetch` 获取任务结果，直到 `SUCCESS` 时返回响应正文
+///
+/// 每次之间按指数退避并加入抖动；`is_transient` 判定为临时性的错误（网络错误、5xx 等）
+/// 按同样的退避重试，其它错误、任务失败、超时或取消时停止。
+pub async fn poll_task<F, Fut, E>(
+    config: &PollConfig,
+    cancel: Option<&CancelToken>,
+    mut fetch: F,
+    is_transient: impl Fn(&E) -> bool,
+) -> Result<String, PollError<E>>
+where
+    F: FnMut() -> Fut,
+    Fut: Future<Output = Result<String, E>>,
+{
+    let deadline = Instant::now() + config.timeout;
+    let never_cancelled = CancelToken::new();
+    let cancel = cancel.unwrap_or(&never_cancelled);
+
+    let mut attempt = 0;
+    loop {
+        if cancel.is_cancelled() {
+            return Err(PollError::Cancelled);
+        }
+
+        let fetched = tokio::select! {
+            fetched = fetch() => fetched,
+            _ = cancel.cancelled() => return Err(PollError::Cancelled),
+            _ = tokio::time::sleep_until(deadline) => return Err(PollError::Timeout),
+        };
+        match fetched {
+            Ok(body) => {
+                let response: Value =
+                    serde_json::from_str(&body).map_err(|err| PollError::InvalidResponse(err.to_string()))?;
+                match TaskStatus::from_response(&response) {
+                    Some(TaskStatus::Success) => return Ok(body),
+                    Some(TaskStatus::Processing) => {}
+                    Some(TaskStatus::Failed { status, message }) => return Err(PollError::Failed { status, message }),
+                    None => return Err(PollError::InvalidResponse("task_status not found in response".to_string())),
+                }
+            }
+            Err(err) if is_transient(&err) => {}
+            Err(err) => return Err(PollError::Request(err)),
+        }
+
+        let wait = config.interval(attempt);
+        attempt = attempt.saturating_add(1);
+        if Instant::now() + wait >= deadline {
+            return Err(PollError::Timeout);
+        }
+        tokio::select! {
+            _ = tokio::time::sleep(wait) => {}
+            _ = cancel.cancelled() => return Err(PollError::Cancelled),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::{Cell, RefCell};
+
+    use super::*;
+
+    #[derive(Debug, PartialEq)]
+    enum FetchError {
+        Transient,
+        Fatal,
+    }
+
+    fn config() -> PollConfig {
+        PollConfig {
+            timeout: Duration::from_secs(10),
+            initial_interval: Duration::from_secs(1),
+            max_interval: Duration::from_secs(4),
+            multiplier: 2.0,
+            jitter: 0.0,
+        }
+    }
+
+    fn processing() -> Result<String, FetchError> {
+        Ok(r#"{"task_status":"PROCESSING"}"#.to_string())
+    }
+
+    /// Polls with the given responses in turn, recording when each fetch happens.
+    async fn poll(
+        config: &PollConfig,
+        cancel: Option<&CancelToken>,
+        responses: Vec<Result<String, FetchError>>,
+    ) -> (Result<String, PollError<FetchError>>, Vec<Duration>) {
+        let start = tokio::time::Instant::now();
+        let responses = RefCell::new(responses.into_iter());
+        let times = RefCell::new(Vec::new());
+        let result = poll_task(
+            config,
+            cancel,
+            || {
+                times.borrow_mut().push(start.elapsed());
+                let response = responses.borrow_mut().next().unwrap_or_else(processing);
+                async move { response }
+            },
+            |err| *err == FetchError::Transient,
+        )
+        .await;
+        (result, times.into_inner())
+    }
+
+    #[test]
+    fn interval_grows_up_to_the_maximum() {
+        let config = config();
+        let intervals: Vec<u64> = (0..5).map(|attempt| config.interval(attempt).as_secs()).collect();
+        assert_eq!(intervals, [1, 2, 4, 4, 4]);
+
+        let jittered = PollConfig { jitter: 0.5, ..config };
+        for _ in 0..20 {
+            let interval = jittered.interval(0).as_secs_f64();
+            assert!((0.5..=1.5).contains(&interval), "{interval}");
+        }
+    }
+
+    #[test]
+    fn status_is_read_case_insensitively() {
+        let status = |json: &str| TaskStatus::from_response(&serde_json::from_str(json).unwrap());
+        assert_eq!(status(r#"{"task_status":"success"}"#), Some(TaskStatus::Success));
+        assert_eq!(status(r#"{"task_status":"PROCESSING"}"#), Some(TaskStatus::Processing));
+        assert_eq!(
+            status(r#"{"task_status":"FAIL","error":{"message":"quota"}}"#),
+            Some(TaskStatus::Failed {
+                status: "FAIL".to_string(),
+                message: Some("quota".to_string()),
+            })
+        );
+        assert_eq!(status(r#"{"id":"1"}"#), None);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn backs_off_and_retries_transient_errors() {
+        let success = r#"{"task_status":"SUCCESS","choices":[]}"#;
+        let responses = vec![processing(), Err(FetchError::Transient), processing(), Ok(success.to_string())];
+        let (result, times) = poll(&config(), None, responses).await;
+
+        assert_eq!(result.unwrap(), success);
+        let seconds: Vec<u64> = times.iter().map(Duration::as_secs).collect();
+        assert_eq!(seconds, [0, 1, 3, 7]);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn stops_on_fatal_errors_and_missing_status() {
+        let (result, times) = poll(&config(), None, vec![processing(), Err(FetchError::Fatal)]).await;
+        assert!(matches!(result, Err(PollError::Request(FetchError::Fatal))));
+        assert_eq!(times.len(), 2);
+
+        let (result, _) = poll(&config(), None, vec![Ok("{}".to_string())]).await;
+        assert!(matches!(result, Err(PollError::InvalidResponse(_))));
+
+        let failed = r#"{"task_status":"FAIL"}"#.to_string();
+        let (result, _) = poll(&config(), None, vec![Ok(failed)]).await;
+        assert!(matches!(result, Err(PollError::Failed { .. })));
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn times_out_when_the_next_poll_would_pass_the_deadline() {
+        let responses = (0..10).map(|_| Err(FetchError::Transient)).collect();
+        let (result, times) = poll(&config(), None, responses).await;
+
+        assert!(matches!(result, Err(PollError::Timeout)));
+        // Polls at 0, 1, 3 and 7 seconds; the next one would be at 11.
+        assert_eq!(times.len(), 4);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn cancellation_interrupts_the_wait() {
+        let config = config();
+        let cancel = CancelToken::new();
+        let fetches = Cell::new(0);
+        let canceller = async {
+            tokio::time::sleep(Duration::from_millis(1500)).await;
+            cancel.cancel();
+        };
+        let polling = poll_task(
+            &config,
+            Some(&cancel),
+            || {
+                fetches.set(fetches.get() + 1);
+                async { processing() }
+            },
+            |_| false,
+        );
+
+        let (result, ()) = tokio::join!(polling, canceller);
+        assert!(matches!(result, Err(PollError::Cancelled)));
+        assert_eq!(fetches.get(), 2);
+
+        let (result, times) = poll(&config, Some(&cancel), Vec::new()).await;
+        assert!(matches!(result, Err(PollError::Cancelled)));
+        assert!(times.is_empty());
+    }
+}
@@ -1,24 +1,60 @@
-use crate::utils::base64url::encode;
+use std::time::Duration;
+
+use serde::Deserialize;
+use serde_json::json;
+
+use crate::utils::base64url::{decode, encode};
 use crate::utils::hmac256::HmacSha256;
-use crate::utils::time_stamp::time_sync;
+use crate::utils::time_stamp::time_now_cached;
+
+/// 默认有效期
+pub const DEFAULT_TTL: Duration = Duration::from_secs(30 * 60);
+
+/// 校验时允许的本地与签发方的时间误差
+pub const DEFAULT_CLOCK_SKEW: Duration = Duration::from_secs(60);
+
+#[derive(Deserialize)]
+struct Claims {
+    exp: i64,
+    timestamp: i64,
+}
 
 pub struct CustomJwt {
     secret: String,
     header: String,
     payload: String,
+    expires_at: i64,
 }
 
 impl CustomJwt {
+    /// 以本地缓存的校准时间签发，有效期为 [`DEFAULT_TTL`]；不会访问网络
     pub fn new(user_id: &str, user_secret: &str) -> CustomJwt {
+        CustomJwt::with_timestamp(user_id, user_secret, time_now_cached())
+    }
+
+    /// 使用给定的毫秒时间戳创建，异步代码中可配合 `time_sync_async` 使用
+    pub fn with_timestamp(user_id: &str, user_secret: &str, time_now: i64) -> CustomJwt {
+        CustomJwt::with_ttl(user_id, user_secret, time_now, DEFAULT_TTL)
+    }
+
+    /// 在毫秒时间戳 `time_now` 签发，`ttl` 之后过期
+    pub fn with_ttl(user_id: &str, user_secret: &str, time_now: i64, ttl: Duration) -> CustomJwt {
         let header = "{\"alg\":\"HS256\",\"sign_type\":\"SIGN\"}".to_string();
-        let payload = CustomJwt::jwt_payload(user_id);
+        let expires_at = time_now.saturating_add(ttl.as_millis().min(i64::MAX as u128) as i64);
+        let payload = CustomJwt::jwt_payload(user_id, time_now, expires_at);
         CustomJwt {
             secret: user_secret.to_string(),
             header,
             payload,
+            expires_at,
         }
     }
 
+    /// 过期时刻的毫秒时间戳
+    pub fn expires_at(&self) -> i64 {
+        self.expires_at
+    }
+
     pub fn create_jwt(&self) -> String {
         let encoded_header = encode(self.header.as_bytes());
         let encoded_payload = encode(self.payload.as_bytes());
@@ -29,32 +65,45 @@ impl CustomJwt {
         format!("{}.{}", to_sign, calculated_signature)
     }
 
+    /// 按本地缓存的校准时间校验，允许 [`DEFAULT_CLOCK_SKEW`] 的误差
     pub fn verify_jwt(&self, jwt: &str) -> bool {
-        let jwt = jwt.trim();
+        self.verify_jwt_at(jwt, time_now_cached(), DEFAULT_CLOCK_SKEW)
+    }
 
-        let parts: Vec<&str> = jwt.split('.').collect();
-        if parts.len() != 3 {
+    /// 校验签名，以及 `now`（毫秒时间戳）是否在 `timestamp` 与 `exp` 之间，两端各放宽 `skew`
+    pub fn verify_jwt_at(&self, jwt: &str, now: i64, skew: Duration) -> bool {
+        let mut parts = jwt.trim().split('.');
+        let (Some(encoded_header), Some(encoded_payload), Some(signature), None) =
+            (parts.next(), parts.next(), parts.next(), parts.next())
+        else {
             return false;
-        }
-
-        let encoded_header = parts[0];
-        let encoded_payload = parts[1];
-        let signature = parts[2];
+        };
 
+        let Ok(signature) = decode(signature) else {
+            return false;
+        };
         let to_verify = format!("{}.{}", encoded_header, encoded_payload);
-        let calculated_signature_bytes = self.generate_signature(&to_verify);
-        let calculated_signature = encode(&calculated_signature_bytes);
+        if !constant_time_eq(&self.generate_signature(&to_verify), &signature) {
+            return false;
+        }
 
-        calculated_signature == signature
+        let claims = decode(encoded_payload)
+            .ok()
+            .and_then(|payload| serde_json::from_slice::<Claims>(&payload).ok());
+        let Some(claims) = claims else {
+            return false;
+        };
+        let skew = skew.as_millis().min(i64::MAX as u128) as i64;
+        claims.timestamp.saturating_sub(skew) <= now && now < claims.exp.saturating_add(skew)
     }
 
-    fn jwt_payload(user_id: &str) -> String {
-        let time_now = time_sync(); 
-        let exp_time = time_now * 2;
-        format!(
-            "{{\"api_key\":\"{}\",\"exp\":{},\"timestamp\":{:?}}}",
-            user_id, exp_time, time_now
-        )
+    fn jwt_payload(user_id: &str, time_now: i64, exp_time: i64) -> String {
+        json!({
+            "api_key": user_id,
+            "exp": exp_time,
+            "timestamp": time_now,
+        })
+        .to_string()
     }
 
     fn generate_signature(&self, data: &str) -> Vec<u8> {
@@ -62,4 +111,86 @@ impl CustomJwt {
         mac.update(data.as_bytes());
         mac.finalize().to_vec()
     }
-}
\ No newline at end of file
+}
+
+/// 比较耗时只与长度有关，不因第一个不同的字节位置而变化
+fn constant_time_eq(a: &[u8], b: &[u8]) -> bool {
+    if a.len() != b.len() {
+        return false;
+    }
+    a.iter().zip(b).fold(0u8, |diff, (x, y)| diff | (x ^ y)) == 0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ISSUED: i64 = 1_700_000_000_000;
+    const TTL: Duration = Duration::from_secs(60);
+    const SKEW: Duration = Duration::from_secs(5);
+
+    fn jwt() -> CustomJwt {
+        CustomJwt::with_ttl("id", "secret", ISSUED, TTL)
+    }
+
+    #[test]
+    fn token_is_valid_between_timestamp_and_exp() {
+        let jwt = jwt();
+        let token = jwt.create_jwt();
+        assert_eq!(jwt.expires_at(), ISSUED + 60_000);
+
+        let valid = |now| jwt.verify_jwt_at(&token, now, Duration::ZERO);
+        assert!(!valid(ISSUED - 1));
+        assert!(valid(ISSUED));
+        assert!(valid(ISSUED + 59_999));
+        assert!(!valid(ISSUED + 60_000));
+    }
+
+    #[test]
+    fn skew_widens_both_ends() {
+        let jwt = jwt();
+        let token = jwt.create_jwt();
+
+        let valid = |now| jwt.verify_jwt_at(&token, now, SKEW);
+        assert!(!valid(ISSUED - 5_001));
+        assert!(valid(ISSUED - 5_000));
+        assert!(valid(ISSUED + 64_999));
+        assert!(!valid(ISSUED + 65_000));
+    }
+
+    #[test]
+    fn tampered_tokens_are_rejected() {
+        let jwt = jwt();
+        let token = jwt.create_jwt();
+        let valid = |token: &str| jwt.verify_jwt_at(token, ISSUED, SKEW);
+        assert!(valid(&token));
+
+        // 改动签名的最后一个字符
+        let mut tampered = token.clone();
+        let last = tampered.pop().unwrap();
+        tampered.push(if last == 'A' { 'B' } else { 'A' });
+        assert!(!valid(&tampered));
+
+        // 换成延长了有效期的载荷，沿用原签名
+        let parts: Vec<&str> = token.split('.').collect();
+        let forged = CustomJwt::jwt_payload("id", ISSUED, ISSUED + 3_600_000);
+        let forged = format!("{}.{}.{}", parts[0], encode(forged.as_bytes()), parts[2]);
+        assert!(!valid(&forged));
+
+        // 其它密钥签发的令牌
+        let other = CustomJwt::with_ttl("id", "other", ISSUED, TTL).create_jwt();
+        assert!(!valid(&other));
+
+        assert!(!valid(&format!("{}.{}", parts[0], parts[1])));
+        assert!(!valid(&format!("{}.extra", token)));
+        assert!(!valid(&format!("{}.{}.!!", parts[0], parts[1])));
+    }
+
+    #[test]
+    fn constant_time_eq_compares_whole_slices() {
+        assert!(constant_time_eq(b"abc", b"abc"));
+        assert!(!constant_time_eq(b"abc", b"abd"));
+        assert!(!constant_time_eq(b"abc", b"ab"));
+        assert!(constant_time_eq(b"", b""));
+    }
+}
@@ -1,4 +1,6 @@
 pub mod base64url;
+pub mod customjwt;
 pub mod hmac256;
 pub mod sha256;
 pub mod time_stamp;
+pub mod token_manager;
@@ -1,6 +1,5 @@
-use once_cell::sync::Lazy;
 use rsntp::SntpClient;
-use std::sync::atomic::{AtomicI64, Ordering};
+use std::sync::atomic::{AtomicBool, AtomicI64, Ordering};
 use std::time::{Duration, SystemTime, UNIX_EPOCH};
 
 const NTP_SERVERS: &[&str] = &[
@@ -19,6 +18,8 @@ static TIME_OFFSET: AtomicI64 = AtomicI64::new(0);
 
 static LAST_SYNC_TIME: AtomicI64 = AtomicI64::new(0);
 
+static SYNCING: AtomicBool = AtomicBool::new(false);
+
 const SYNC_INTERVAL_MS: i64 = 3600 * 1000;
 
 const RETRY_INTERVAL_MS: i64 = 60 * 1000;
@@ -27,18 +28,6 @@ const NTP_TIMEOUT_SECS: u64 = 3;
 
 const MAX_TIME_OFFSET_MS: i64 = 24 * 3600 * 1000;
 
-static RUNTIME: Lazy<tokio::runtime::Runtime> = Lazy::new(|| {
-    tokio::runtime::Runtime::new().expect("Failed to create Tokio runtime for time synchronization")
-});
-
-pub fn time_sync() -> i64 {
-    if let Ok(handle) = tokio::runtime::Handle::try_current() {
-        handle.block_on(time_sync_async())
-    } else {
-        RUNTIME.block_on(time_sync_async())
-    }
-}
-
 pub async fn time_sync_async() -> i64 {
     // 获取当前系统时间
     let now_sys = match get_system_time_millis() {
@@ -55,10 +44,7 @@ pub async fn time_sync_async() -> i64 {
         }
     };
 
-    let last_sync = LAST_SYNC_TIME.load(Ordering::Relaxed);
-    let needs_sync = last_sync == 0 || (now_sys - last_sync).abs() > SYNC_INTERVAL_MS;
-
-    if needs_sync {
+    if sync_due(now_sys) {
         if let Some(ntp_millis) = fetch_ntp_time_async().await {
             let offset = ntp_millis - now_sys;
 
@@ -66,7 +52,6 @@ pub async fn time_sync_async() -> i64 {
                 TIME_OFFSET.store(offset, Ordering::Relaxed);
                 LAST_SYNC_TIME.store(now_sys, Ordering::Relaxed);
                 return ntp_millis;
-            } else {
             }
         } else {
             let backoff_time = now_sys - (SYNC_INTERVAL_MS - RETRY_INTERVAL_MS);
@@ -78,6 +63,37 @@ pub async fn time_sync_async() -> i64 {
     now_sys + offset
 }
 
+/// 系统时间加上最近一次同步得到的偏移量，不访问网络
+pub fn time_now_cached() -> i64 {
+    get_system_time_millis().unwrap_or_default() + TIME_OFFSET.load(Ordering::Relaxed)
+}
+
+/// 需要同步时在后台任务中与 NTP 服务器同步并立即返回；不在 Tokio 运行时中时不做任何事
+pub fn time_sync_in_background() {
+    let Some(now_sys) = get_system_time_millis() else {
+        return;
+    };
+    if !sync_due(now_sys) {
+        return;
+    }
+    let Ok(handle) = tokio::runtime::Handle::try_current() else {
+        return;
+    };
+    // 同一时间只进行一次同步
+    if SYNCING.swap(true, Ordering::AcqRel) {
+        return;
+    }
+    handle.spawn(async {
+        time_sync_async().await;
+        SYNCING.store(false, Ordering::Release);
+    });
+}
+
+fn sync_due(now_sys: i64) -> bool {
+    let last_sync = LAST_SYNC_TIME.load(Ordering::Relaxed);
+    last_sync == 0 || (now_sys - last_sync).abs() > SYNC_INTERVAL_MS
+}
+
 fn get_system_time_millis() -> Option<i64> {
     SystemTime::now()
         .duration_since(UNIX_EPOCH)
@@ -0,0 +1,156 @@
+use std::fmt;
+use std::sync::{Arc, Mutex, PoisonError};
+use std::time::Duration;
+
+use crate::key_pool::Credential;
+use crate::utils::customjwt::{CustomJwt, DEFAULT_TTL};
+use crate::utils::time_stamp::{time_now_cached, time_sync_in_background};
+
+/// 默认在过期前 1 分钟换新
+pub const DEFAULT_REFRESH_MARGIN: Duration = Duration::from_secs(60);
+
+struct CachedToken {
+    token: String,
+    expires_at: i64,
+}
+
+/// 签发并缓存 ChatGLM 的 JWT，快过期时换新
+///
+/// 时间取本地缓存的校准时间，需要时在后台与 NTP 服务器同步，不会阻塞异步运行时。
+/// 克隆出的实例共用同一个缓存。
+#[derive(Clone)]
+pub struct TokenManager {
+    user_id: String,
+    user_secret: String,
+    ttl: Duration,
+    refresh_margin: Duration,
+    cached: Arc<Mutex<Option<CachedToken>>>,
+}
+
+impl TokenManager {
+    pub fn new(user_id: &str, user_secret: &str) -> Self {
+        Self {
+            user_id: user_id.to_string(),
+            user_secret: user_secret.to_string(),
+            ttl: DEFAULT_TTL,
+            refresh_margin: DEFAULT_REFRESH_MARGIN,
+            cached: Arc::default(),
+        }
+    }
+
+    /// 令牌的有效期
+    pub fn with_ttl(mut self, ttl: Duration) -> Self {
+        self.ttl = ttl;
+        self.cached = Arc::default();
+        self
+    }
+
+    /// 距过期不足 `margin` 时换新；最多为有效期的一半
+    pub fn with_refresh_margin(mut self, margin: Duration) -> Self {
+        self.refresh_margin = margin;
+        self
+    }
+
+    pub fn ttl(&self) -> Duration {
+        self.ttl
+    }
+
+    /// 当前可用的令牌，没有或快过期时签发新的
+    pub fn token(&self) -> String {
+        time_sync_in_background();
+        self.token_at(time_now_cached())
+    }
+
+    /// 毫秒时间戳 `now` 时可用的令牌
+    fn token_at(&self, now: i64) -> String {
+        let margin = self.refresh_margin.min(self.ttl / 2).as_millis() as i64;
+
+        let mut cached = self.cached.lock().unwrap_or_else(PoisonError::into_inner);
+        if let Some(cached) = cached.as_ref().filter(|cached| now < cached.expires_at - margin) {
+            return cached.token.clone();
+        }
+
+        let jwt = CustomJwt::with_ttl(&self.user_id, &self.user_secret, now, self.ttl);
+        let token = jwt.create_jwt();
+        *cached = Some(CachedToken {
+            token: token.clone(),
+            expires_at: jwt.expires_at(),
+        });
+        token
+    }
+
+    /// 丢弃缓存的令牌，下次使用时重新签发
+    pub fn invalidate(&self) {
+        *self.cached.lock().unwrap_or_else(PoisonError::into_inner) = None;
+    }
+}
+
+impl Credential for TokenManager {
+    fn invalidate(&self) {
+        TokenManager::invalidate(self);
+    }
+}
+
+impl fmt::Debug for TokenManager {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // 不输出密钥与令牌
+        f.debug_struct("TokenManager")
+            .field("user_id", &self.user_id)
+            .field("ttl", &self.ttl)
+            .field("refresh_margin", &self.refresh_margin)
+            .finish_non_exhaustive()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MINUTE: i64 = 60_000;
+
+    fn manager() -> TokenManager {
+        TokenManager::new("id", "secret")
+            .with_ttl(Duration::from_secs(10 * 60))
+            .with_refresh_margin(Duration::from_secs(60))
+    }
+
+    fn cached_expiry(manager: &TokenManager) -> Option<i64> {
+        manager.cached.lock().unwrap().as_ref().map(|cached| cached.expires_at)
+    }
+
+    #[test]
+    fn token_is_reused_until_the_refresh_margin() {
+        let manager = manager();
+        let first = manager.token_at(0);
+        assert_eq!(cached_expiry(&manager), Some(10 * MINUTE));
+        assert_eq!(manager.token_at(5 * MINUTE), first);
+        assert_eq!(manager.token_at(9 * MINUTE - 1), first);
+
+        let refreshed = manager.token_at(9 * MINUTE);
+        assert_ne!(refreshed, first);
+        assert_eq!(cached_expiry(&manager), Some(19 * MINUTE));
+        let verifier = CustomJwt::with_ttl("id", "secret", 0, manager.ttl());
+        assert!(verifier.verify_jwt_at(&refreshed, 9 * MINUTE, Duration::ZERO));
+    }
+
+    #[test]
+    fn clones_share_the_cache_and_invalidate_clears_it() {
+        let manager = manager();
+        let clone = manager.clone();
+        let token = manager.token_at(0);
+        assert_eq!(clone.token_at(MINUTE), token);
+
+        clone.invalidate();
+        assert_eq!(cached_expiry(&manager), None);
+        assert_ne!(manager.token_at(MINUTE), token);
+        assert_eq!(cached_expiry(&clone), Some(11 * MINUTE));
+    }
+
+    #[test]
+    fn refresh_margin_is_at_most_half_the_ttl() {
+        let manager = manager().with_refresh_margin(Duration::from_secs(60 * 60));
+        let token = manager.token_at(0);
+        assert_eq!(manager.token_at(5 * MINUTE - 1), token);
+        assert_ne!(manager.token_at(5 * MINUTE), token);
+    }
+}
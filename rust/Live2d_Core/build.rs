@@ -1,4 +1,4 @@
-use std::{env, path::PathBuf};
+use std::{env, path::{Path, PathBuf}};
 
 fn main() {
     let target_arch = env::var("CARGO_CFG_TARGET_ARCH").unwrap();
@@ -59,7 +59,7 @@ fn emit_dummy_bindings() {
     std::fs::write(&bindings_path, dummy_bindings).unwrap();
 }
 
-fn calc_lib_dir(cubism_core_dir: &PathBuf, target_os: &str) -> PathBuf {
+fn calc_lib_dir(cubism_core_dir: &Path, target_os: &str) -> PathBuf {
     let target_arch = env::var("CARGO_CFG_TARGET_ARCH").unwrap();
 
     let arch_dir_name = match target_os {
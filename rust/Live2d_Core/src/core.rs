@@ -52,6 +52,10 @@ impl CubismCore {
         Self { inner }
     }
 
+    /// Installs a callback receiving log messages from Cubism Core.
+    ///
+    /// # Safety
+    /// Replaces the process-wide Core log hook; must not race with Core calls on other threads.
     #[cfg(not(target_arch = "wasm32"))]
     pub unsafe fn set_log_function<F>(f: F)
     where
@@ -1,8 +1,3 @@
-#[allow(non_upper_case_globals)]
-#[allow(non_camel_case_types)]
-#[allow(non_snake_case)]
-#[allow(dead_code)]
-
 use std::os::raw::{c_char, c_int, c_uint, c_void};
 
 pub type csmVector2 = [f32; 2];
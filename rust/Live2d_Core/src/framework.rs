@@ -0,0 +1,63 @@
+#![cfg(feature = "framework")]
+
+//! Runtime helpers built on top of [`crate::core`], modelled after the official Cubism Framework.
+
+use crate::core::ModelStatic;
+
+pub mod batching;
+pub mod clipping;
+pub mod compositor;
+pub mod display_info;
+pub mod emotion;
+pub mod eye_blink;
+pub mod idle;
+pub mod lip_sync;
+pub mod look_at;
+pub mod model_settings;
+pub mod motion;
+pub mod random;
+pub mod render_order;
+pub mod smoothing;
+pub mod texture;
+pub mod user_data;
+pub mod view_transform;
+pub mod viseme;
+
+pub use batching::{BatchKey, BlendMode, DrawBatch, DrawBatcher};
+pub use clipping::{ClippingContext, ClippingFramePlan, ClippingManager, MaskChannel, MaskGroupPlan, Rect};
+pub use compositor::{Compositor, LayerBlend, LayerKind, LayerSettings};
+pub use display_info::DisplayInfo;
+pub use emotion::{
+    EmotionBlend, EmotionDefinition, EmotionEngine, EmotionMap, EmotionTarget, EmotionTiming, Expression,
+    ExpressionParameter,
+};
+pub use eye_blink::{BlinkInterval, BlinkState, EyeBlink, EyeBlinkConfig};
+pub use idle::{IdleEffect, IdleEffects};
+pub use lip_sync::{LipSync, LipSyncConfig, LipSyncError, LipSyncTimeline, PcmFormat, WavClip};
+pub use look_at::{LookAt, LookAtMapping, TargetPoint};
+pub use model_settings::{ModelSettings, ModelSettingsError};
+pub use motion::{Motion, MotionCurve, MotionError, MotionEvent, MotionPlayer};
+pub use random::Rng;
+pub use render_order::{DrawOrderMode, RenderOrderCache};
+pub use smoothing::{ParameterSmoother, SmoothingConfig, SmoothingFilter};
+pub use texture::{LoadedTexture, TextureError, TextureImage, TextureLoadOptions, TextureLoader};
+pub use user_data::ModelUserData;
+pub use view_transform::{FitMode, ViewTransform};
+pub use viseme::{Viseme, VisemeKeyframe, VisemeTimeline};
+
+/// Indices of the parameters with the given ids, skipping ids the model does not have.
+pub(crate) fn parameter_indices(model_static: &ModelStatic, ids: &[String]) -> Vec<usize> {
+    ids.iter()
+        .filter_map(|id| model_static.parameters().iter().position(|p| p.id() == id))
+        .collect()
+}
+
+/// Column-major 4x4 matrix of a 2D scale followed by a translation.
+pub(crate) fn axis_aligned_matrix(scale_x: f32, scale_y: f32, translate_x: f32, translate_y: f32) -> [f32; 16] {
+    [
+        scale_x, 0.0, 0.0, 0.0,
+        0.0, scale_y, 0.0, 0.0,
+        0.0, 0.0, 1.0, 0.0,
+        translate_x, translate_y, 0.0, 1.0,
+    ]
+}
@@ -0,0 +1,313 @@
+use super::clipping::{ClippingContext, ClippingManager};
+use crate::core::{
+    ConstantDrawableFlagSet, ConstantDrawableFlags, DrawableIndex, DynamicDrawableFlags,
+    ModelDynamic, ModelStatic, TextureIndex, Vector2, Vector4,
+};
+
+/// Largest vertex count addressable by a `u16` index buffer.
+pub const MAX_BATCH_VERTICES: usize = u16::MAX as usize + 1;
+
+/// Color blending of a drawable, from its constant flags.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum BlendMode {
+    Normal,
+    Additive,
+    Multiplicative,
+}
+
+impl BlendMode {
+    pub fn of(flagset: ConstantDrawableFlagSet) -> Self {
+        if flagset.contains(ConstantDrawableFlags::BlendAdditive) {
+            BlendMode::Additive
+        } else if flagset.contains(ConstantDrawableFlags::BlendMultiplicative) {
+            BlendMode::Multiplicative
+        } else {
+            BlendMode::Normal
+        }
+    }
+}
+
+/// Render state shared by every drawable of a batch.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct BatchKey {
+    pub texture_index: TextureIndex,
+    pub blend_mode: BlendMode,
+    /// Index into [`ClippingManager::contexts`] of the mask clipping the batch.
+    pub mask_context: Option<usize>,
+    pub inverted_mask: bool,
+}
+
+/// Consecutive drawables merged into one draw call.
+///
+/// Per-drawable opacity and colors are baked into per-vertex colors so they survive merging.
+#[derive(Debug, Clone)]
+pub struct DrawBatch {
+    pub key: BatchKey,
+    pub drawables: Vec<DrawableIndex>,
+    /// Flattened positions `[x0, y0, x1, y1, ...]` in model units.
+    pub positions: Vec<f32>,
+    /// Flattened texture coordinates `[u0, v0, u1, v1, ...]`.
+    pub uvs: Vec<f32>,
+    /// Triangle indices into this batch's vertices.
+    pub indices: Vec<u16>,
+    /// Per-vertex multiply color `[r, g, b, a, ...]`, alpha holding the drawable opacity.
+    pub multiply_colors: Vec<f32>,
+    /// Per-vertex screen color `[r, g, b, a, ...]`.
+    pub screen_colors: Vec<f32>,
+}
+
+impl DrawBatch {
+    fn new(key: BatchKey) -> Self {
+        Self {
+            key,
+            drawables: Vec::new(),
+            positions: Vec::new(),
+            uvs: Vec::new(),
+            indices: Vec::new(),
+            multiply_colors: Vec::new(),
+            screen_colors: Vec::new(),
+        }
+    }
+
+    pub fn vertex_count(&self) -> usize {
+        self.positions.len() / 2
+    }
+}
+
+/// Merges drawables into [`DrawBatch`]es to reduce draw calls.
+#[derive(Debug, Clone, Copy)]
+pub struct DrawBatcher {
+    max_vertices: usize,
+}
+
+impl Default for DrawBatcher {
+    fn default() -> Self {
+        Self {
+            max_vertices: MAX_BATCH_VERTICES,
+        }
+    }
+}
+
+impl DrawBatcher {
+    /// Caps the number of vertices per batch; clamped to [`MAX_BATCH_VERTICES`].
+    pub fn with_max_vertices(max_vertices: usize) -> Self {
+        Self {
+            max_vertices: max_vertices.clamp(1, MAX_BATCH_VERTICES),
+        }
+    }
+
+    /// Batches drawables in the given render order; only consecutive drawables with equal
+    /// [`BatchKey`]s are merged, so the visual result is unchanged. Invisible drawables are skipped.
+    pub fn batch(
+        &self,
+        order: impl IntoIterator<Item = DrawableIndex>,
+        model_static: &ModelStatic,
+        model_dynamic: &ModelDynamic,
+        clipping: Option<&ClippingManager>,
+    ) -> Vec<DrawBatch> {
+        let mut batches: Vec<DrawBatch> = Vec::new();
+
+        for index in order {
+            let Some(drawable) = model_static.get_drawable(index) else {
+                continue;
+            };
+            if !is_drawn(model_dynamic, index) {
+                continue;
+            }
+
+            let flagset = drawable.constant_flagset();
+            let key = BatchKey {
+                texture_index: drawable.texture_index(),
+                blend_mode: BlendMode::of(flagset),
+                mask_context: clipping.and_then(|c| c.context_index_of(index)),
+                inverted_mask: flagset.contains(ConstantDrawableFlags::IsInvertedMask),
+            };
+
+            self.append(&mut batches, key, index, Geometry::of(index, model_static, model_dynamic));
+        }
+
+        batches
+    }
+
+    /// Batches the mask drawables of a clipping context by texture. Masks accumulate into a single
+    /// channel, so their order does not matter and non-consecutive drawables may be merged.
+    pub fn batch_masks(
+        &self,
+        context: &ClippingContext,
+        model_static: &ModelStatic,
+        model_dynamic: &ModelDynamic,
+    ) -> Vec<DrawBatch> {
+        let mut by_texture: Vec<DrawableIndex> = context
+            .mask_drawables()
+            .iter()
+            .copied()
+            .filter(|&index| model_static.get_drawable(index).is_some())
+            .collect();
+        by_texture.sort_by_key(|&index| model_static.get_drawable(index).map(|d| d.texture_index()));
+
+        let mut batches: Vec<DrawBatch> = Vec::new();
+        for index in by_texture {
+            let drawable = &model_static.drawables()[index.as_usize()];
+            let key = BatchKey {
+                texture_index: drawable.texture_index(),
+                blend_mode: BlendMode::Normal,
+                mask_context: None,
+                inverted_mask: false,
+            };
+            self.append(&mut batches, key, index, Geometry::of(index, model_static, model_dynamic));
+        }
+
+        batches
+    }
+
+    fn append(&self, batches: &mut Vec<DrawBatch>, key: BatchKey, index: DrawableIndex, geometry: Geometry) {
+        let Geometry {
+            positions,
+            uvs,
+            triangle_indices,
+            multiply,
+            screen,
+            opacity,
+        } = geometry;
+        let vertex_count = positions.len().min(uvs.len());
+
+        let fits = |batch: &DrawBatch| {
+            batch.key == key && batch.vertex_count() + vertex_count <= self.max_vertices
+        };
+        if !batches.last().is_some_and(fits) {
+            batches.push(DrawBatch::new(key));
+        }
+        let batch = batches.last_mut().expect("a batch was just ensured");
+
+        let base = batch.vertex_count() as u16;
+        batch.drawables.push(index);
+        batch
+            .indices
+            .extend(triangle_indices.iter().map(|&t| base + t));
+
+        for (position, uv) in positions.iter().zip(uvs).take(vertex_count) {
+            batch.positions.extend([position.x, position.y]);
+            batch.uvs.extend([uv.x, uv.y]);
+            batch
+                .multiply_colors
+                .extend([multiply.x, multiply.y, multiply.z, multiply.w * opacity]);
+            batch
+                .screen_colors
+                .extend([screen.x, screen.y, screen.z, screen.w]);
+        }
+    }
+}
+
+/// Vertex data and colors of one drawable for the current frame.
+#[derive(Clone, Copy)]
+struct Geometry<'a> {
+    positions: &'a [Vector2],
+    uvs: &'a [Vector2],
+    triangle_indices: &'a [u16],
+    multiply: Vector4,
+    screen: Vector4,
+    opacity: f32,
+}
+
+impl<'a> Geometry<'a> {
+    fn of(index: DrawableIndex, model_static: &'a ModelStatic, model_dynamic: &'a ModelDynamic) -> Self {
+        let i = index.as_usize();
+        let drawable = &model_static.drawables()[i];
+        Self {
+            positions: model_dynamic.drawable_vertex_position_containers()[i],
+            uvs: drawable.vertex_uvs(),
+            triangle_indices: drawable.triangle_indices(),
+            multiply: model_dynamic.drawable_multiply_colors()[i],
+            screen: model_dynamic.drawable_screen_colors()[i],
+            opacity: model_dynamic.drawable_opacities()[i],
+        }
+    }
+}
+
+fn is_drawn(model_dynamic: &ModelDynamic, index: DrawableIndex) -> bool {
+    let i = index.as_usize();
+    model_dynamic.drawable_dynamic_flagsets()[i].contains(DynamicDrawableFlags::IsVisible)
+        && model_dynamic.drawable_opacities()[i] > 0.0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const QUAD: [Vector2; 4] = [
+        Vector2 { x: 0.0, y: 0.0 },
+        Vector2 { x: 1.0, y: 0.0 },
+        Vector2 { x: 1.0, y: 1.0 },
+        Vector2 { x: 0.0, y: 1.0 },
+    ];
+    const QUAD_TRIANGLES: [u16; 6] = [0, 1, 2, 0, 2, 3];
+
+    fn key(texture: u64) -> BatchKey {
+        BatchKey {
+            texture_index: TextureIndex(texture),
+            blend_mode: BlendMode::Normal,
+            mask_context: None,
+            inverted_mask: false,
+        }
+    }
+
+    fn quad(opacity: f32) -> Geometry<'static> {
+        Geometry {
+            positions: &QUAD,
+            uvs: &QUAD,
+            triangle_indices: &QUAD_TRIANGLES,
+            multiply: Vector4 { x: 1.0, y: 1.0, z: 1.0, w: 1.0 },
+            screen: Vector4 { x: 0.0, y: 0.0, z: 0.0, w: 1.0 },
+            opacity,
+        }
+    }
+
+    /// Appends one quad per key, in order.
+    fn batch(batcher: DrawBatcher, keys: &[BatchKey]) -> Vec<DrawBatch> {
+        let mut batches = Vec::new();
+        for (i, &key) in keys.iter().enumerate() {
+            batcher.append(&mut batches, key, DrawableIndex::from(i), quad(0.5));
+        }
+        batches
+    }
+
+    #[test]
+    fn only_consecutive_equal_keys_merge() {
+        let batches = batch(DrawBatcher::default(), &[key(0), key(0), key(1), key(0)]);
+
+        let drawables: Vec<&[DrawableIndex]> = batches.iter().map(|b| &b.drawables[..]).collect();
+        assert_eq!(drawables, [&[DrawableIndex(0), DrawableIndex(1)][..], &[DrawableIndex(2)], &[DrawableIndex(3)]]);
+    }
+
+    #[test]
+    fn merged_indices_are_rebased_and_opacity_is_baked() {
+        let batches = batch(DrawBatcher::default(), &[key(0), key(0)]);
+
+        let batch = &batches[0];
+        assert_eq!(batch.vertex_count(), 8);
+        assert_eq!(batch.indices, [0, 1, 2, 0, 2, 3, 4, 5, 6, 4, 6, 7]);
+        assert_eq!(batch.multiply_colors.len(), 8 * 4);
+        assert!(batch.multiply_colors.chunks(4).all(|color| color == [1.0, 1.0, 1.0, 0.5]));
+    }
+
+    #[test]
+    fn a_full_batch_starts_a_new_one() {
+        let batches = batch(DrawBatcher::with_max_vertices(8), &[key(0); 3]);
+
+        assert_eq!(batches.len(), 2);
+        assert_eq!(batches[0].vertex_count(), 8);
+        assert_eq!(batches[1].indices, QUAD_TRIANGLES);
+    }
+
+    #[test]
+    fn batches_stop_at_the_u16_index_limit() {
+        // 16384 quads fill exactly 65536 vertices; the next one must not wrap its indices.
+        let quads = MAX_BATCH_VERTICES / QUAD.len();
+        let batches = batch(DrawBatcher::with_max_vertices(usize::MAX), &vec![key(0); quads + 1]);
+
+        assert_eq!(batches.len(), 2);
+        assert_eq!(batches[0].vertex_count(), MAX_BATCH_VERTICES);
+        assert_eq!(batches[0].indices.iter().max(), Some(&u16::MAX));
+        assert_eq!(batches[1].indices, QUAD_TRIANGLES);
+    }
+}
@@ -0,0 +1,397 @@
+use super::axis_aligned_matrix;
+use crate::core::{DrawableIndex, DynamicDrawableFlags, ModelDynamic, ModelStatic, Vector2};
+
+/// Number of color channels a mask texture can hold masks in.
+pub const MASK_CHANNEL_COUNT: usize = 4;
+/// Maximum number of layout cells per channel (a 3x3 grid).
+pub const MAX_LAYOUTS_PER_CHANNEL: usize = 9;
+/// Maximum number of mask groups a single mask texture can hold.
+pub const MAX_GROUPS_PER_TEXTURE: usize = MASK_CHANNEL_COUNT * MAX_LAYOUTS_PER_CHANNEL;
+
+/// Relative margin added around the bounds of clipped drawables, as in the official framework.
+const CLIPPED_BOUNDS_MARGIN: f32 = 0.05;
+
+/// Axis-aligned rectangle.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Rect {
+    pub x: f32,
+    pub y: f32,
+    pub width: f32,
+    pub height: f32,
+}
+
+impl Rect {
+    pub fn right(&self) -> f32 {
+        self.x + self.width
+    }
+    pub fn bottom(&self) -> f32 {
+        self.y + self.height
+    }
+
+    /// Grows the rectangle by `ratio` of its size on every side.
+    pub fn expand(&self, ratio: f32) -> Rect {
+        let dx = self.width * ratio;
+        let dy = self.height * ratio;
+        Rect {
+            x: self.x - dx,
+            y: self.y - dy,
+            width: self.width + dx * 2.0,
+            height: self.height + dy * 2.0,
+        }
+    }
+
+    fn from_points(points: impl IntoIterator<Item = Vector2>) -> Option<Rect> {
+        let mut points = points.into_iter();
+        let first = points.next()?;
+        let (mut min_x, mut min_y, mut max_x, mut max_y) = (first.x, first.y, first.x, first.y);
+        for p in points {
+            min_x = min_x.min(p.x);
+            min_y = min_y.min(p.y);
+            max_x = max_x.max(p.x);
+            max_y = max_y.max(p.y);
+        }
+        Some(Rect {
+            x: min_x,
+            y: min_y,
+            width: max_x - min_x,
+            height: max_y - min_y,
+        })
+    }
+}
+
+/// Color channel of a mask texture a mask group is rendered into.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum MaskChannel {
+    Red,
+    Green,
+    Blue,
+    Alpha,
+}
+
+impl MaskChannel {
+    const ALL: [MaskChannel; MASK_CHANNEL_COUNT] = [
+        MaskChannel::Red,
+        MaskChannel::Green,
+        MaskChannel::Blue,
+        MaskChannel::Alpha,
+    ];
+
+    pub fn as_index(&self) -> usize {
+        *self as usize
+    }
+
+    /// RGBA write mask selecting this channel.
+    pub fn color_mask(&self) -> [f32; 4] {
+        let mut mask = [0.0; 4];
+        mask[self.as_index()] = 1.0;
+        mask
+    }
+}
+
+/// Drawables sharing the exact same set of masks.
+#[derive(Debug, Clone)]
+pub struct ClippingContext {
+    mask_drawables: Box<[DrawableIndex]>,
+    clipped_drawables: Vec<DrawableIndex>,
+    texture_slot: usize,
+    channel: MaskChannel,
+    layout: Rect,
+}
+
+impl ClippingContext {
+    /// Mask drawables, sorted by index.
+    pub fn mask_drawables(&self) -> &[DrawableIndex] {
+        &self.mask_drawables
+    }
+    pub fn clipped_drawables(&self) -> &[DrawableIndex] {
+        &self.clipped_drawables
+    }
+    /// Index of the mask texture this context is rendered into.
+    pub fn texture_slot(&self) -> usize {
+        self.texture_slot
+    }
+    pub fn channel(&self) -> MaskChannel {
+        self.channel
+    }
+    /// Cell of the mask texture reserved for this context, in normalized `[0, 1]` coordinates.
+    pub fn layout(&self) -> Rect {
+        self.layout
+    }
+}
+
+/// Per-frame rendering instructions for one [`ClippingContext`].
+#[derive(Debug, Clone)]
+pub struct MaskGroupPlan {
+    /// Index into [`ClippingManager::contexts`].
+    pub context_index: usize,
+    pub texture_slot: usize,
+    pub channel: MaskChannel,
+    pub layout: Rect,
+    /// Bounds of the visible clipped drawables in model units, margin included.
+    pub clipped_bounds: Rect,
+    /// Column-major matrix mapping model units to mask texture NDC, used when drawing the masks.
+    pub mask_matrix: [f32; 16],
+    /// Column-major matrix mapping model units to mask texture UVs, used when sampling the mask.
+    pub draw_matrix: [f32; 16],
+}
+
+/// Mask atlas layout for one frame; only groups with at least one visible clipped drawable are included.
+#[derive(Debug, Clone, Default)]
+pub struct ClippingFramePlan {
+    pub mask_texture_count: usize,
+    pub groups: Vec<MaskGroupPlan>,
+}
+
+/// Groups masked drawables by mask set and plans where each group's mask lives in the mask atlas.
+///
+/// Each group's masks are drawn once per frame into its own channel/cell of the atlas, instead of
+/// once per masked drawable.
+#[derive(Debug, Clone, Default)]
+pub struct ClippingManager {
+    contexts: Vec<ClippingContext>,
+    drawable_contexts: Box<[Option<usize>]>,
+    mask_texture_count: usize,
+}
+
+impl ClippingManager {
+    pub fn new(model_static: &ModelStatic) -> Self {
+        let drawables = model_static.drawables();
+        let (mut contexts, drawable_contexts) = group_contexts(
+            drawables.len(),
+            drawables.iter().map(|drawable| (drawable.index(), drawable.masks())),
+        );
+        let mask_texture_count = layout_contexts(&mut contexts);
+
+        Self {
+            contexts,
+            drawable_contexts,
+            mask_texture_count,
+        }
+    }
+
+    pub fn contexts(&self) -> &[ClippingContext] {
+        &self.contexts
+    }
+
+    /// Number of mask textures needed to hold every context.
+    pub fn mask_texture_count(&self) -> usize {
+        self.mask_texture_count
+    }
+
+    /// Index of the context clipping `drawable`, if it is masked.
+    pub fn context_index_of(&self, drawable: DrawableIndex) -> Option<usize> {
+        self.drawable_contexts.get(drawable.as_usize()).copied().flatten()
+    }
+
+    /// Computes bounds and matrices for every context used by a visible drawable this frame.
+    ///
+    /// Call after [`ModelDynamic::update`].
+    pub fn plan_frame(&self, model_dynamic: &ModelDynamic) -> ClippingFramePlan {
+        let flagsets = model_dynamic.drawable_dynamic_flagsets();
+        let positions = model_dynamic.drawable_vertex_position_containers();
+
+        let groups = self
+            .contexts
+            .iter()
+            .enumerate()
+            .filter_map(|(context_index, context)| {
+                let visible_points = context
+                    .clipped_drawables
+                    .iter()
+                    .map(|index| index.as_usize())
+                    .filter(|&i| flagsets[i].contains(DynamicDrawableFlags::IsVisible))
+                    .flat_map(|i| positions[i].iter().copied());
+
+                let clipped_bounds = Rect::from_points(visible_points)?.expand(CLIPPED_BOUNDS_MARGIN);
+                let (draw_matrix, mask_matrix) = mask_matrices(&context.layout, &clipped_bounds);
+
+                Some(MaskGroupPlan {
+                    context_index,
+                    texture_slot: context.texture_slot,
+                    channel: context.channel,
+                    layout: context.layout,
+                    clipped_bounds,
+                    mask_matrix,
+                    draw_matrix,
+                })
+            })
+            .collect();
+
+        ClippingFramePlan {
+            mask_texture_count: self.mask_texture_count,
+            groups,
+        }
+    }
+}
+
+/// Groups drawables by their (sorted, deduplicated) mask set; unmasked drawables map to `None`.
+fn group_contexts<'a>(
+    drawable_count: usize,
+    drawable_masks: impl IntoIterator<Item = (DrawableIndex, &'a [usize])>,
+) -> (Vec<ClippingContext>, Box<[Option<usize>]>) {
+    let mut contexts: Vec<ClippingContext> = Vec::new();
+    let mut drawable_contexts = vec![None; drawable_count].into_boxed_slice();
+
+    for (drawable, masks) in drawable_masks {
+        if masks.is_empty() {
+            continue;
+        }
+
+        let mut mask_drawables: Vec<DrawableIndex> = masks.iter().map(|&m| DrawableIndex::from(m)).collect();
+        mask_drawables.sort();
+        mask_drawables.dedup();
+
+        let context_index = match contexts
+            .iter()
+            .position(|context| *context.mask_drawables == *mask_drawables)
+        {
+            Some(index) => index,
+            None => {
+                contexts.push(ClippingContext {
+                    mask_drawables: mask_drawables.into_boxed_slice(),
+                    clipped_drawables: Vec::new(),
+                    texture_slot: 0,
+                    channel: MaskChannel::Red,
+                    layout: Rect { x: 0.0, y: 0.0, width: 1.0, height: 1.0 },
+                });
+                contexts.len() - 1
+            }
+        };
+
+        contexts[context_index].clipped_drawables.push(drawable);
+        drawable_contexts[drawable.as_usize()] = Some(context_index);
+    }
+
+    (contexts, drawable_contexts)
+}
+
+/// Assigns texture slot, channel and cell to every context; returns the number of textures used.
+fn layout_contexts(contexts: &mut [ClippingContext]) -> usize {
+    for (texture_slot, chunk) in contexts.chunks_mut(MAX_GROUPS_PER_TEXTURE).enumerate() {
+        let count = chunk.len();
+        let mut next = 0;
+
+        for (channel_index, channel) in MaskChannel::ALL.iter().enumerate() {
+            // Spread contexts evenly; the first `count % 4` channels take one extra.
+            let in_channel =
+                count / MASK_CHANNEL_COUNT + usize::from(channel_index < count % MASK_CHANNEL_COUNT);
+
+            for (cell, context) in chunk[next..next + in_channel].iter_mut().enumerate() {
+                context.texture_slot = texture_slot;
+                context.channel = *channel;
+                context.layout = layout_cell(cell, in_channel);
+            }
+            next += in_channel;
+        }
+    }
+
+    contexts.len().div_ceil(MAX_GROUPS_PER_TEXTURE)
+}
+
+/// Cell `cell` of a channel split into the smallest grid (1, 2x1, 2x2 or 3x3) holding `count` cells.
+fn layout_cell(cell: usize, count: usize) -> Rect {
+    let (columns, rows) = match count {
+        0 | 1 => (1, 1),
+        2 => (2, 1),
+        3 | 4 => (2, 2),
+        _ => (3, 3),
+    };
+    let width = 1.0 / columns as f32;
+    let height = 1.0 / rows as f32;
+    Rect {
+        x: (cell % columns) as f32 * width,
+        y: (cell / columns) as f32 * height,
+        width,
+        height,
+    }
+}
+
+/// Returns `(draw_matrix, mask_matrix)` mapping `bounds` (model units) onto `layout` in UV and NDC space.
+fn mask_matrices(layout: &Rect, bounds: &Rect) -> ([f32; 16], [f32; 16]) {
+    let scale_x = if bounds.width > 0.0 { layout.width / bounds.width } else { 0.0 };
+    let scale_y = if bounds.height > 0.0 { layout.height / bounds.height } else { 0.0 };
+
+    let draw_translate_x = layout.x - bounds.x * scale_x;
+    let draw_translate_y = layout.y - bounds.y * scale_y;
+
+    let draw_matrix = axis_aligned_matrix(scale_x, scale_y, draw_translate_x, draw_translate_y);
+    let mask_matrix = axis_aligned_matrix(
+        scale_x * 2.0,
+        scale_y * 2.0,
+        draw_translate_x * 2.0 - 1.0,
+        draw_translate_y * 2.0 - 1.0,
+    );
+
+    (draw_matrix, mask_matrix)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// `count` contexts, each masked by a single distinct drawable.
+    fn contexts(count: usize) -> Vec<ClippingContext> {
+        let masks: Vec<[usize; 1]> = (0..count).map(|i| [count + i]).collect();
+        let (contexts, _) = group_contexts(
+            count,
+            masks.iter().enumerate().map(|(i, mask)| (DrawableIndex::from(i), &mask[..])),
+        );
+        contexts
+    }
+
+    #[test]
+    fn drawables_with_the_same_mask_set_share_a_context() {
+        let masks: [&[usize]; 5] = [&[3, 1], &[], &[1, 3, 3], &[2], &[1]];
+        let (contexts, drawable_contexts) = group_contexts(
+            masks.len(),
+            masks.iter().enumerate().map(|(i, &mask)| (DrawableIndex::from(i), mask)),
+        );
+
+        assert_eq!(contexts.len(), 3);
+        assert_eq!(contexts[0].mask_drawables(), [DrawableIndex(1), DrawableIndex(3)]);
+        assert_eq!(contexts[0].clipped_drawables(), [DrawableIndex(0), DrawableIndex(2)]);
+        assert_eq!(*drawable_contexts, [Some(0), None, Some(0), Some(1), Some(2)]);
+    }
+
+    #[test]
+    fn contexts_spread_across_channels_before_splitting_cells() {
+        let mut contexts = contexts(6);
+        assert_eq!(layout_contexts(&mut contexts), 1);
+
+        let channels: Vec<MaskChannel> = contexts.iter().map(ClippingContext::channel).collect();
+        use MaskChannel::*;
+        assert_eq!(channels, [Red, Red, Green, Green, Blue, Alpha]);
+
+        // Two contexts in a channel split it into left and right halves.
+        assert_eq!(contexts[0].layout(), Rect { x: 0.0, y: 0.0, width: 0.5, height: 1.0 });
+        assert_eq!(contexts[1].layout(), Rect { x: 0.5, y: 0.0, width: 0.5, height: 1.0 });
+        assert_eq!(contexts[4].layout(), Rect { x: 0.0, y: 0.0, width: 1.0, height: 1.0 });
+    }
+
+    #[test]
+    fn a_texture_holds_36_contexts_in_3x3_grids() {
+        let mut contexts = contexts(MAX_GROUPS_PER_TEXTURE);
+        assert_eq!(layout_contexts(&mut contexts), 1);
+
+        for (i, context) in contexts.iter().enumerate() {
+            let cell = i % MAX_LAYOUTS_PER_CHANNEL;
+            assert_eq!(context.texture_slot(), 0);
+            assert_eq!(context.channel().as_index(), i / MAX_LAYOUTS_PER_CHANNEL);
+            assert_eq!(context.layout().width, 1.0 / 3.0);
+            assert_eq!(context.layout().x, (cell % 3) as f32 / 3.0);
+            assert_eq!(context.layout().y, (cell / 3) as f32 / 3.0);
+        }
+    }
+
+    #[test]
+    fn overflowing_contexts_move_to_another_texture() {
+        let mut contexts = contexts(MAX_GROUPS_PER_TEXTURE + 1);
+        assert_eq!(layout_contexts(&mut contexts), 2);
+
+        let last = contexts.last().unwrap();
+        assert_eq!(last.texture_slot(), 1);
+        assert_eq!(last.channel(), MaskChannel::Red);
+        assert_eq!(last.layout(), Rect { x: 0.0, y: 0.0, width: 1.0, height: 1.0 });
+        assert!(contexts[..MAX_GROUPS_PER_TEXTURE].iter().all(|context| context.texture_slot() == 0));
+    }
+}
@@ -0,0 +1,262 @@
+use crate::core::{ModelDynamic, ModelStatic};
+
+/// Animation layers, in evaluation order.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum LayerKind {
+    Motion,
+    /// Procedural blinking, between motions and expressions as in the official framework.
+    EyeBlink,
+    /// Expressions and emotions.
+    Expression,
+    Idle,
+    LipSync,
+    LookAt,
+    /// Values pinned with [`Compositor::set_override`].
+    UserOverride,
+}
+
+impl LayerKind {
+    pub const ORDER: [LayerKind; 7] = [
+        LayerKind::Motion,
+        LayerKind::EyeBlink,
+        LayerKind::Expression,
+        LayerKind::Idle,
+        LayerKind::LipSync,
+        LayerKind::LookAt,
+        LayerKind::UserOverride,
+    ];
+
+    fn index(&self) -> usize {
+        *self as usize
+    }
+}
+
+/// How the output of a layer combines with the layers below it.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum LayerBlend {
+    /// The layer works on the result of the layers below, which it may blend with or replace.
+    #[default]
+    Normal,
+    /// The layer is evaluated from the parameter defaults, and its offset from them is added.
+    Additive,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct LayerSettings {
+    pub enabled: bool,
+    /// Strength of the layer's change, from 0 (no effect) to 1.
+    pub weight: f32,
+    pub blend: LayerBlend,
+}
+
+impl Default for LayerSettings {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            weight: 1.0,
+            blend: LayerBlend::Normal,
+        }
+    }
+}
+
+/// Evaluates animation layers in a fixed order into [`ModelDynamic`] before
+/// [`ModelDynamic::update`], each with its own weight and blend mode.
+///
+/// Layers are given as closures writing parameter values, so the effects keep their own state:
+///
+/// ```
+/// use live2d_core::core::ModelDynamic;
+/// use live2d_core::framework::{Compositor, EyeBlink, LayerKind};
+///
+/// fn compose(compositor: &mut Compositor, base: &[f32], dynamic: &mut ModelDynamic, eye_blink: &mut EyeBlink) {
+///     compositor.begin(base, dynamic);
+///     for kind in LayerKind::ORDER {
+///         compositor.apply(kind, dynamic, |dynamic| match kind {
+///             LayerKind::EyeBlink => eye_blink.update(1.0 / 60.0, dynamic),
+///             _ => {}
+///         });
+///     }
+/// }
+/// ```
+#[derive(Debug, Clone)]
+pub struct Compositor {
+    settings: [LayerSettings; LayerKind::ORDER.len()],
+    defaults: Vec<f32>,
+    ranges: Vec<(f32, f32)>,
+    overrides: Vec<Option<f32>>,
+    before: Vec<f32>,
+}
+
+impl Compositor {
+    pub fn new(model_static: &ModelStatic) -> Self {
+        let parameters = model_static.parameters();
+        Self::with_parameters(
+            parameters.iter().map(|p| p.default_value()).collect(),
+            parameters.iter().map(|p| p.value_range()).collect(),
+        )
+    }
+
+    /// A compositor for parameters with the given default values and ranges.
+    fn with_parameters(defaults: Vec<f32>, ranges: Vec<(f32, f32)>) -> Self {
+        Self {
+            settings: [LayerSettings::default(); LayerKind::ORDER.len()],
+            overrides: vec![None; defaults.len()],
+            before: Vec::with_capacity(defaults.len()),
+            ranges,
+            defaults,
+        }
+    }
+
+    pub fn settings(&self, kind: LayerKind) -> LayerSettings {
+        self.settings[kind.index()]
+    }
+    pub fn set_settings(&mut self, kind: LayerKind, settings: LayerSettings) {
+        self.settings[kind.index()] = settings;
+    }
+
+    /// Pins the parameter `id` to `value` in the [`LayerKind::UserOverride`] layer, or releases it
+    /// with `None`. Returns `false` if the model does not have the parameter.
+    pub fn set_override(&mut self, model_static: &ModelStatic, id: &str, value: Option<f32>) -> bool {
+        let parameters = model_static.parameters();
+        let Some(index) = parameters.iter().position(|p| p.id() == id) else {
+            return false;
+        };
+        let (min, max) = parameters[index].value_range();
+        self.overrides[index] = value.map(|v| v.clamp(min, max));
+        true
+    }
+
+    pub fn clear_overrides(&mut self) {
+        self.overrides.iter_mut().for_each(|o| *o = None);
+    }
+
+    /// Starts a frame from `base` values.
+    pub fn begin(&mut self, base: &[f32], model_dynamic: &mut ModelDynamic) {
+        model_dynamic.parameter_values_mut().copy_from_slice(base);
+    }
+
+    /// Runs one layer and blends its result according to its settings.
+    ///
+    /// `evaluate` is called even for disabled layers, so time-based effects stay in sync; their
+    /// output is discarded. [`LayerKind::UserOverride`] applies the pinned values after it.
+    pub fn apply(&mut self, kind: LayerKind, model_dynamic: &mut ModelDynamic, evaluate: impl FnOnce(&mut ModelDynamic)) {
+        self.start_layer(kind, model_dynamic.parameter_values_mut());
+        evaluate(model_dynamic);
+        self.finish_layer(kind, model_dynamic.parameter_values_mut());
+    }
+
+    /// Saves the values below the layer and, for an additive layer, resets them to the defaults.
+    fn start_layer(&mut self, kind: LayerKind, values: &mut [f32]) {
+        self.before.clear();
+        self.before.extend_from_slice(values);
+        if self.settings[kind.index()].blend == LayerBlend::Additive {
+            values.copy_from_slice(&self.defaults);
+        }
+    }
+
+    /// Blends the values the layer wrote with those saved by [`Self::start_layer`].
+    fn finish_layer(&mut self, kind: LayerKind, values: &mut [f32]) {
+        let settings = self.settings[kind.index()];
+        if kind == LayerKind::UserOverride {
+            for (value, pinned) in values.iter_mut().zip(&self.overrides) {
+                if let Some(pinned) = pinned {
+                    *value = *pinned;
+                }
+            }
+        }
+
+        let weight = if settings.enabled {
+            settings.weight.clamp(0.0, 1.0)
+        } else {
+            0.0
+        };
+        for (i, value) in values.iter_mut().enumerate() {
+            let from = match settings.blend {
+                LayerBlend::Normal => self.before[i],
+                LayerBlend::Additive => self.defaults[i],
+            };
+            let (min, max) = self.ranges[i];
+            *value = (self.before[i] + (*value - from) * weight).clamp(min, max);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Two parameters with defaults 0 and 1, both in `[-10, 10]`.
+    fn compositor() -> Compositor {
+        Compositor::with_parameters(vec![0.0, 1.0], vec![(-10.0, 10.0); 2])
+    }
+
+    fn run(compositor: &mut Compositor, kind: LayerKind, values: &mut [f32], evaluate: impl FnOnce(&mut [f32])) {
+        compositor.start_layer(kind, values);
+        evaluate(values);
+        compositor.finish_layer(kind, values);
+    }
+
+    #[test]
+    fn order_follows_the_official_framework() {
+        use LayerKind::*;
+        assert_eq!(LayerKind::ORDER, [Motion, EyeBlink, Expression, Idle, LipSync, LookAt, UserOverride]);
+        for (index, kind) in LayerKind::ORDER.iter().enumerate() {
+            assert_eq!(kind.index(), index);
+        }
+    }
+
+    #[test]
+    fn normal_layer_blends_its_change_by_weight() {
+        let mut compositor = compositor();
+        compositor.set_settings(LayerKind::Motion, LayerSettings { weight: 0.25, ..Default::default() });
+
+        let mut values = [2.0, 2.0];
+        run(&mut compositor, LayerKind::Motion, &mut values, |values| values[0] = 6.0);
+        assert_eq!(values, [3.0, 2.0]);
+    }
+
+    #[test]
+    fn additive_layer_adds_its_offset_from_the_defaults() {
+        let mut compositor = compositor();
+        let additive = LayerSettings { blend: LayerBlend::Additive, weight: 0.5, ..Default::default() };
+        compositor.set_settings(LayerKind::Idle, additive);
+
+        let mut values = [2.0, 2.0];
+        run(&mut compositor, LayerKind::Idle, &mut values, |values| {
+            // The layer starts from the defaults, not from the values below it.
+            assert_eq!(values, [0.0, 1.0]);
+            values[0] += 4.0;
+            values[1] -= 2.0;
+        });
+        assert_eq!(values, [4.0, 1.0]);
+    }
+
+    #[test]
+    fn disabled_layer_is_evaluated_but_discarded() {
+        let mut compositor = compositor();
+        compositor.set_settings(LayerKind::LookAt, LayerSettings { enabled: false, ..Default::default() });
+
+        let mut evaluated = false;
+        let mut values = [2.0, 2.0];
+        run(&mut compositor, LayerKind::LookAt, &mut values, |values| {
+            evaluated = true;
+            values[0] = 5.0;
+        });
+        assert!(evaluated);
+        assert_eq!(values, [2.0, 2.0]);
+    }
+
+    #[test]
+    fn user_overrides_are_pinned_and_results_clamped() {
+        let mut compositor = compositor();
+        compositor.overrides[1] = Some(-3.0);
+
+        let mut values = [2.0, 2.0];
+        run(&mut compositor, LayerKind::UserOverride, &mut values, |values| values[0] = 50.0);
+        assert_eq!(values, [10.0, -3.0]);
+
+        compositor.clear_overrides();
+        run(&mut compositor, LayerKind::UserOverride, &mut values, |_| {});
+        assert_eq!(values, [10.0, -3.0]);
+    }
+}
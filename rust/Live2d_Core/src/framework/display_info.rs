@@ -0,0 +1,66 @@
+use serde::Deserialize;
+
+/// Contents of a `cdi3.json`: artist-facing names and grouping of parameters and parts.
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct DisplayInfo {
+    #[serde(default)]
+    pub version: u32,
+    #[serde(default)]
+    pub parameters: Vec<ParameterDisplayInfo>,
+    #[serde(default)]
+    pub parameter_groups: Vec<ParameterGroupDisplayInfo>,
+    #[serde(default)]
+    pub parts: Vec<PartDisplayInfo>,
+    /// Pairs of parameters edited together as a 2D control, e.g. `ParamAngleX`/`ParamAngleY`.
+    #[serde(default)]
+    pub combined_parameters: Vec<Vec<String>>,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct ParameterDisplayInfo {
+    pub id: String,
+    /// Id of the [`ParameterGroupDisplayInfo`] the parameter belongs to; empty at top level.
+    #[serde(default)]
+    pub group_id: String,
+    #[serde(default)]
+    pub name: String,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct ParameterGroupDisplayInfo {
+    pub id: String,
+    /// Id of the parent group; empty at top level.
+    #[serde(default)]
+    pub group_id: String,
+    #[serde(default)]
+    pub name: String,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct PartDisplayInfo {
+    pub id: String,
+    #[serde(default)]
+    pub name: String,
+}
+
+impl DisplayInfo {
+    pub fn from_json(json: &str) -> Result<Self, serde_json::Error> {
+        serde_json::from_str(json)
+    }
+
+    pub fn parameter(&self, id: &str) -> Option<&ParameterDisplayInfo> {
+        self.parameters.iter().find(|p| p.id == id)
+    }
+
+    pub fn parameter_group(&self, id: &str) -> Option<&ParameterGroupDisplayInfo> {
+        self.parameter_groups.iter().find(|g| g.id == id)
+    }
+
+    pub fn part(&self, id: &str) -> Option<&PartDisplayInfo> {
+        self.parts.iter().find(|p| p.id == id)
+    }
+}
@@ -0,0 +1,459 @@
+use std::collections::HashMap;
+
+use serde::Deserialize;
+
+use crate::core::{ModelDynamic, ModelStatic};
+
+/// Mapping shipped with the crate, used when no mapping file is provided.
+const DEFAULT_EMOTIONS_JSON: &str = include_str!("default_emotions.json");
+
+/// How an emotion target combines with the value already set for the frame,
+/// as in `exp3.json` expressions.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize)]
+pub enum EmotionBlend {
+    /// Adds the target.
+    Add,
+    /// Multiplies by the target.
+    Multiply,
+    /// Moves the value towards the target.
+    #[default]
+    Overwrite,
+}
+
+/// Target of a parameter, either a bare number (overwrite) or `{ "Value", "Blend" }`.
+#[derive(Debug, Clone, Copy, PartialEq, Deserialize)]
+#[serde(untagged)]
+pub enum EmotionTarget {
+    Value(f32),
+    #[serde(rename_all = "PascalCase")]
+    Blended {
+        value: f32,
+        #[serde(default)]
+        blend: EmotionBlend,
+    },
+}
+
+impl EmotionTarget {
+    pub fn value(&self) -> f32 {
+        match *self {
+            EmotionTarget::Value(value) | EmotionTarget::Blended { value, .. } => value,
+        }
+    }
+
+    pub fn blend(&self) -> EmotionBlend {
+        match *self {
+            EmotionTarget::Value(_) => EmotionBlend::Overwrite,
+            EmotionTarget::Blended { blend, .. } => blend,
+        }
+    }
+
+    /// `current` blended towards the target by `weight` in `[0, 1]`.
+    fn apply(&self, current: f32, weight: f32) -> f32 {
+        let value = self.value();
+        match self.blend() {
+            EmotionBlend::Add => current + value * weight,
+            EmotionBlend::Multiply => current * (1.0 + (value - 1.0) * weight),
+            EmotionBlend::Overwrite => current + (value - current) * weight,
+        }
+    }
+}
+
+/// What an emotion does to a model.
+#[derive(Debug, Clone, Default, PartialEq, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct EmotionDefinition {
+    #[serde(default)]
+    pub parameters: HashMap<String, EmotionTarget>,
+    /// Expression names (from `model3.json`) applied alongside, once their `exp3.json` has been
+    /// given to [`EmotionEngine::insert_expression`]; unknown names are skipped.
+    #[serde(default)]
+    pub expressions: Vec<String>,
+    /// `model3.json` motion group to play, one of its motions being picked at random.
+    #[serde(default)]
+    pub motion_group: Option<String>,
+}
+
+/// Data-driven emotion mapping: generic definitions, overridden per model.
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct EmotionMap {
+    #[serde(default)]
+    pub default: HashMap<String, EmotionDefinition>,
+    /// Definitions keyed by model name, then emotion name.
+    #[serde(default)]
+    pub models: HashMap<String, HashMap<String, EmotionDefinition>>,
+}
+
+impl EmotionMap {
+    pub fn from_json(json: &str) -> Result<Self, serde_json::Error> {
+        serde_json::from_str(json)
+    }
+
+    /// The built-in mapping: neutral, happy, angry, sad, surprised and shy.
+    pub fn builtin() -> Self {
+        Self::from_json(DEFAULT_EMOTIONS_JSON).expect("built-in emotion mapping is valid")
+    }
+
+    /// Definition of `emotion` (case-insensitive) for `model`, falling back to the generic one.
+    pub fn get(&self, model: Option<&str>, emotion: &str) -> Option<&EmotionDefinition> {
+        model
+            .and_then(|model| self.models.get(model))
+            .and_then(|definitions| find_emotion(definitions, emotion))
+            .or_else(|| find_emotion(&self.default, emotion))
+    }
+}
+
+fn find_emotion<'a>(
+    definitions: &'a HashMap<String, EmotionDefinition>,
+    emotion: &str,
+) -> Option<&'a EmotionDefinition> {
+    definitions
+        .iter()
+        .find(|(name, _)| name.eq_ignore_ascii_case(emotion))
+        .map(|(_, definition)| definition)
+}
+
+/// An `exp3.json` expression, blended in with the emotion that names it.
+///
+/// `FadeInTime` / `FadeOutTime` are ignored: the emotion's [`EmotionTiming`] fades the whole set.
+#[derive(Debug, Clone, Default, PartialEq, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct Expression {
+    #[serde(default)]
+    pub parameters: Vec<ExpressionParameter>,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct ExpressionParameter {
+    pub id: String,
+    pub value: f32,
+    /// `exp3.json` defaults to `Add`, unlike the bare numbers of an emotion mapping.
+    #[serde(default = "ExpressionParameter::default_blend")]
+    pub blend: EmotionBlend,
+}
+
+impl ExpressionParameter {
+    fn default_blend() -> EmotionBlend {
+        EmotionBlend::Add
+    }
+}
+
+impl Expression {
+    pub fn from_json(json: &str) -> Result<Self, serde_json::Error> {
+        serde_json::from_str(json)
+    }
+}
+
+/// Targets of `definition`: those of its loaded expressions first, then its own parameters, each
+/// resolved by `resolve` to a parameter index and range; ids the model lacks are dropped.
+fn resolve_targets(
+    definition: &EmotionDefinition,
+    expressions: &HashMap<String, Expression>,
+    resolve: impl Fn(&str) -> Option<(usize, (f32, f32))>,
+) -> Vec<(usize, (f32, f32), EmotionTarget)> {
+    let expression_targets = definition
+        .expressions
+        .iter()
+        .filter_map(|name| expressions.get(name))
+        .flat_map(|expression| &expression.parameters)
+        .map(|p| (p.id.as_str(), EmotionTarget::Blended { value: p.value, blend: p.blend }));
+    let parameter_targets = definition
+        .parameters
+        .iter()
+        .map(|(id, &target)| (id.as_str(), target));
+
+    expression_targets
+        .chain(parameter_targets)
+        .filter_map(|(id, target)| {
+            let (index, range) = resolve(id)?;
+            Some((index, range, target))
+        })
+        .collect()
+}
+
+/// Timing of emotion changes.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct EmotionTiming {
+    /// Cross-fade time when an emotion starts or is replaced.
+    pub transition_seconds: f32,
+    /// Fade time back to neutral once an emotion's duration has elapsed.
+    pub decay_seconds: f32,
+}
+
+impl Default for EmotionTiming {
+    fn default() -> Self {
+        Self {
+            transition_seconds: 0.5,
+            decay_seconds: 1.5,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+struct EmotionLayer {
+    name: String,
+    /// Resolved parameter index, range and target.
+    targets: Vec<(usize, (f32, f32), EmotionTarget)>,
+    intensity: f32,
+    /// Seconds to hold before decaying; `None` holds until replaced.
+    duration: Option<f32>,
+    elapsed: f32,
+    /// Elapsed time and length of the fade out, once started.
+    fade_out: Option<(f32, f32)>,
+}
+
+impl EmotionLayer {
+    fn weight(&self, timing: &EmotionTiming) -> f32 {
+        let fade_in = if timing.transition_seconds > 0.0 {
+            (self.elapsed / timing.transition_seconds).min(1.0)
+        } else {
+            1.0
+        };
+        let fade_out = match self.fade_out {
+            Some((from, seconds)) if seconds > 0.0 => 1.0 - ((self.elapsed - from) / seconds).min(1.0),
+            Some(_) => 0.0,
+            None => 1.0,
+        };
+        self.intensity * smoothstep(fade_in) * smoothstep(fade_out)
+    }
+
+    fn is_finished(&self) -> bool {
+        self.fade_out
+            .is_some_and(|(from, seconds)| self.elapsed - from >= seconds)
+    }
+}
+
+fn smoothstep(t: f32) -> f32 {
+    let t = t.clamp(0.0, 1.0);
+    t * t * (3.0 - 2.0 * t)
+}
+
+/// Applies named emotions as parameter targets with intensity, transitions and decay.
+#[derive(Debug, Clone)]
+pub struct EmotionEngine {
+    map: EmotionMap,
+    model_name: Option<String>,
+    timing: EmotionTiming,
+    /// Loaded `exp3.json` expressions, keyed by their `model3.json` name.
+    expressions: HashMap<String, Expression>,
+    layers: Vec<EmotionLayer>,
+}
+
+impl EmotionEngine {
+    pub fn new(map: EmotionMap, model_name: Option<String>) -> Self {
+        Self {
+            map,
+            model_name,
+            timing: EmotionTiming::default(),
+            expressions: HashMap::new(),
+            layers: Vec::new(),
+        }
+    }
+
+    pub fn map(&self) -> &EmotionMap {
+        &self.map
+    }
+    /// Replaces the mapping, keeping the timing, loaded expressions and running emotions.
+    pub fn set_map(&mut self, map: EmotionMap, model_name: Option<String>) {
+        self.map = map;
+        self.model_name = model_name;
+    }
+
+    /// Makes `expression` available to definitions that list `name`.
+    pub fn insert_expression(&mut self, name: String, expression: Expression) {
+        self.expressions.insert(name, expression);
+    }
+
+    pub fn timing(&self) -> &EmotionTiming {
+        &self.timing
+    }
+    pub fn set_timing(&mut self, timing: EmotionTiming) {
+        self.timing = timing;
+    }
+
+    /// The emotion fully or partly applied most recently, if any is not fading out.
+    pub fn current(&self) -> Option<&str> {
+        self.layers
+            .iter()
+            .rev()
+            .find(|layer| layer.fade_out.is_none())
+            .map(|layer| layer.name.as_str())
+    }
+
+    /// Transitions to `emotion` at `intensity` (0 to 1), held for `duration` seconds before
+    /// decaying back to neutral (`None` holds until replaced).
+    ///
+    /// Returns the definition so the caller can trigger its motion group, or `None` if the emotion
+    /// is unknown.
+    pub fn set_emotion(
+        &mut self,
+        emotion: &str,
+        intensity: f32,
+        duration: Option<f32>,
+        model_static: &ModelStatic,
+    ) -> Option<&EmotionDefinition> {
+        let definition = self.map.get(self.model_name.as_deref(), emotion)?;
+
+        let parameters = model_static.parameters();
+        let targets = resolve_targets(definition, &self.expressions, |id| {
+            let index = parameters.iter().position(|p| p.id() == id)?;
+            Some((index, parameters[index].value_range()))
+        });
+
+        self.clear();
+        self.layers.push(EmotionLayer {
+            name: emotion.to_string(),
+            targets,
+            intensity: intensity.clamp(0.0, 1.0),
+            duration: duration.filter(|d| *d > 0.0),
+            elapsed: 0.0,
+            fade_out: None,
+        });
+
+        self.map.get(self.model_name.as_deref(), emotion)
+    }
+
+    /// Fades every emotion out, back to neutral.
+    pub fn clear(&mut self) {
+        let seconds = self.timing.transition_seconds;
+        for layer in &mut self.layers {
+            layer.fade_out.get_or_insert((layer.elapsed, seconds));
+        }
+    }
+
+    pub fn update(&mut self, delta_seconds: f32, model_dynamic: &mut ModelDynamic) {
+        let delta_seconds = delta_seconds.max(0.0);
+        let values = model_dynamic.parameter_values_mut();
+
+        for layer in &mut self.layers {
+            layer.elapsed += delta_seconds;
+            if layer.fade_out.is_none() && layer.duration.is_some_and(|d| layer.elapsed >= d) {
+                layer.fade_out = Some((layer.elapsed, self.timing.decay_seconds));
+            }
+
+            let weight = layer.weight(&self.timing);
+            for (index, (min, max), target) in &layer.targets {
+                values[*index] = target.apply(values[*index], weight).clamp(*min, *max);
+            }
+        }
+
+        self.layers.retain(|layer| !layer.is_finished());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn layer(targets: Vec<(usize, (f32, f32), EmotionTarget)>, duration: Option<f32>) -> EmotionLayer {
+        EmotionLayer {
+            name: "test".to_string(),
+            targets,
+            intensity: 1.0,
+            duration,
+            elapsed: 0.0,
+            fade_out: None,
+        }
+    }
+
+    #[test]
+    fn lookup_is_case_insensitive_and_falls_back_to_default() {
+        let map = EmotionMap::from_json(
+            r#"{
+                "Default": { "happy": { "Parameters": { "A": 1.0 } } },
+                "Models": { "hiyori": { "Happy": { "Parameters": { "A": 0.5 } } } }
+            }"#,
+        )
+        .unwrap();
+
+        let value = |model, emotion| map.get(model, emotion).map(|d| d.parameters["A"].value());
+        assert_eq!(value(Some("hiyori"), "HAPPY"), Some(0.5));
+        assert_eq!(value(Some("other"), "Happy"), Some(1.0));
+        assert_eq!(value(None, "happy"), Some(1.0));
+        assert_eq!(value(None, "sad"), None);
+        assert!(EmotionMap::builtin().get(None, "shy").is_some());
+    }
+
+    #[test]
+    fn targets_blend_by_weight() {
+        let target = |blend, value| EmotionTarget::Blended { value, blend };
+        assert_eq!(target(EmotionBlend::Add, 2.0).apply(1.0, 0.5), 2.0);
+        assert_eq!(target(EmotionBlend::Multiply, 0.0).apply(4.0, 0.5), 2.0);
+        assert_eq!(EmotionTarget::Value(1.0).apply(0.0, 0.25), 0.25);
+        assert_eq!(EmotionTarget::Value(1.0).apply(0.0, 1.0), 1.0);
+    }
+
+    #[test]
+    fn expressions_resolve_before_the_emotion_parameters() {
+        let expression = Expression::from_json(
+            r#"{
+                "Type": "Live2D Expression",
+                "FadeInTime": 0.5,
+                "Parameters": [
+                    { "Id": "ParamEyeLSmile", "Value": 1.0 },
+                    { "Id": "ParamEyeLOpen", "Value": 0.5, "Blend": "Multiply" },
+                    { "Id": "Missing", "Value": 1.0, "Blend": "Overwrite" }
+                ]
+            }"#,
+        )
+        .unwrap();
+        let definition: EmotionDefinition = serde_json::from_str(
+            r#"{ "Parameters": { "ParamCheek": 1.0 }, "Expressions": ["smile", "not_loaded"] }"#,
+        )
+        .unwrap();
+        let expressions = HashMap::from([("smile".to_string(), expression)]);
+
+        let ids = ["ParamEyeLSmile", "ParamEyeLOpen", "ParamCheek"];
+        let targets = resolve_targets(&definition, &expressions, |id| {
+            ids.iter().position(|&known| known == id).map(|index| (index, (0.0, 1.0)))
+        });
+
+        let add = EmotionTarget::Blended { value: 1.0, blend: EmotionBlend::Add };
+        let multiply = EmotionTarget::Blended { value: 0.5, blend: EmotionBlend::Multiply };
+        assert_eq!(
+            targets,
+            [
+                (0, (0.0, 1.0), add),
+                (1, (0.0, 1.0), multiply),
+                (2, (0.0, 1.0), EmotionTarget::Value(1.0)),
+            ]
+        );
+    }
+
+    #[test]
+    fn layer_fades_in_holds_and_decays() {
+        let timing = EmotionTiming {
+            transition_seconds: 1.0,
+            decay_seconds: 2.0,
+        };
+        let mut layer = layer(Vec::new(), Some(3.0));
+        assert_eq!(layer.weight(&timing), 0.0);
+
+        layer.elapsed = 0.5;
+        assert_eq!(layer.weight(&timing), 0.5);
+        layer.elapsed = 2.0;
+        assert_eq!(layer.weight(&timing), 1.0);
+
+        layer.elapsed = 3.0;
+        layer.fade_out = Some((3.0, timing.decay_seconds));
+        assert_eq!(layer.weight(&timing), 1.0);
+        layer.elapsed = 4.0;
+        assert_eq!(layer.weight(&timing), 0.5);
+        assert!(!layer.is_finished());
+        layer.elapsed = 5.0;
+        assert_eq!(layer.weight(&timing), 0.0);
+        assert!(layer.is_finished());
+    }
+
+    #[test]
+    fn clear_fades_out_every_layer() {
+        let mut engine = EmotionEngine::new(EmotionMap::builtin(), None);
+        engine.layers.push(layer(Vec::new(), None));
+        assert_eq!(engine.current(), Some("test"));
+
+        engine.clear();
+        assert_eq!(engine.current(), None);
+        assert_eq!(engine.layers[0].fade_out, Some((0.0, engine.timing.transition_seconds)));
+    }
+}
@@ -0,0 +1,285 @@
+use super::model_settings::{ModelSettings, GROUP_EYE_BLINK};
+use super::parameter_indices;
+use super::random::Rng;
+use crate::core::{ModelDynamic, ModelStatic};
+
+/// Distribution of the time between two blinks, in seconds.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum BlinkInterval {
+    Fixed(f32),
+    Uniform { min: f32, max: f32 },
+    /// Exponentially distributed (memoryless) with the given mean, never shorter than `min`.
+    Exponential { mean: f32, min: f32 },
+}
+
+impl BlinkInterval {
+    fn sample(&self, rng: &mut Rng) -> f32 {
+        let seconds = match *self {
+            BlinkInterval::Fixed(seconds) => seconds,
+            BlinkInterval::Uniform { min, max } => rng.range(min, max.max(min)),
+            BlinkInterval::Exponential { mean, min } => {
+                min.max(-mean * (1.0 - rng.next_f32()).ln())
+            }
+        };
+        seconds.max(0.0)
+    }
+}
+
+/// Timing of [`EyeBlink`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct EyeBlinkConfig {
+    pub interval: BlinkInterval,
+    pub closing_seconds: f32,
+    pub closed_seconds: f32,
+    pub opening_seconds: f32,
+    /// Probability that a blink is immediately followed by a second one; a double blink is never followed by a third.
+    pub double_blink_probability: f32,
+    /// Time the eyes stay open between the two blinks of a double blink.
+    pub double_blink_gap_seconds: f32,
+}
+
+impl Default for EyeBlinkConfig {
+    fn default() -> Self {
+        Self {
+            interval: BlinkInterval::Uniform { min: 2.0, max: 6.0 },
+            closing_seconds: 0.1,
+            closed_seconds: 0.05,
+            opening_seconds: 0.15,
+            double_blink_probability: 0.1,
+            double_blink_gap_seconds: 0.3,
+        }
+    }
+}
+
+/// Phase of the blink state machine.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BlinkState {
+    /// Eyes open, waiting for the next blink.
+    Interval,
+    Closing,
+    Closed,
+    Opening,
+}
+
+/// Procedural eye blinking, writing the `EyeBlink` parameter group every tick.
+///
+/// Parameters are set to the eye openness, from 0 (closed) to 1 (open).
+#[derive(Debug, Clone)]
+pub struct EyeBlink {
+    parameters: Vec<usize>,
+    config: EyeBlinkConfig,
+    rng: Rng,
+    state: BlinkState,
+    state_elapsed: f32,
+    next_interval: f32,
+    /// The current interval is the gap inside a double blink.
+    in_double_blink: bool,
+}
+
+impl EyeBlink {
+    /// Creates a blink effect for the given parameter ids; unknown ids are ignored.
+    pub fn new(parameter_ids: &[String], model_static: &ModelStatic, config: EyeBlinkConfig, rng: Rng) -> Self {
+        Self::with_parameters(parameter_indices(model_static, parameter_ids), config, rng)
+    }
+
+    /// Creates a blink effect driving the parameters at the given indices.
+    pub fn with_parameters(parameters: Vec<usize>, config: EyeBlinkConfig, rng: Rng) -> Self {
+        let mut blink = Self {
+            parameters,
+            config,
+            rng,
+            state: BlinkState::Interval,
+            state_elapsed: 0.0,
+            next_interval: 0.0,
+            in_double_blink: false,
+        };
+        blink.next_interval = blink.config.interval.sample(&mut blink.rng);
+        blink
+    }
+
+    /// Creates a blink effect driving the [`GROUP_EYE_BLINK`] group of a `model3.json`.
+    pub fn from_settings(settings: &ModelSettings, model_static: &ModelStatic, config: EyeBlinkConfig, rng: Rng) -> Self {
+        Self::new(settings.parameter_group(GROUP_EYE_BLINK), model_static, config, rng)
+    }
+
+    /// Indices of the driven parameters.
+    pub fn parameters(&self) -> &[usize] {
+        &self.parameters
+    }
+
+    pub fn config(&self) -> &EyeBlinkConfig {
+        &self.config
+    }
+    pub fn set_config(&mut self, config: EyeBlinkConfig) {
+        self.config = config;
+    }
+
+    pub fn state(&self) -> BlinkState {
+        self.state
+    }
+
+    /// Starts a blink now, unless one is already in progress.
+    pub fn force_blink(&mut self) {
+        if self.state == BlinkState::Interval {
+            self.enter(BlinkState::Closing);
+        }
+    }
+
+    /// Current eye openness, from 0 (closed) to 1 (open).
+    pub fn eye_openness(&self) -> f32 {
+        let progress = |duration: f32| {
+            if duration > 0.0 {
+                (self.state_elapsed / duration).clamp(0.0, 1.0)
+            } else {
+                1.0
+            }
+        };
+        match self.state {
+            BlinkState::Interval => 1.0,
+            BlinkState::Closing => 1.0 - progress(self.config.closing_seconds),
+            BlinkState::Closed => 0.0,
+            BlinkState::Opening => progress(self.config.opening_seconds),
+        }
+    }
+
+    /// Advances the state machine by `delta_seconds` and returns the eye openness.
+    pub fn advance(&mut self, delta_seconds: f32) -> f32 {
+        self.state_elapsed += delta_seconds.max(0.0);
+
+        loop {
+            let (duration, next) = match self.state {
+                BlinkState::Interval => (self.next_interval, BlinkState::Closing),
+                BlinkState::Closing => (self.config.closing_seconds, BlinkState::Closed),
+                BlinkState::Closed => (self.config.closed_seconds, BlinkState::Opening),
+                BlinkState::Opening => (self.config.opening_seconds, BlinkState::Interval),
+            };
+            if self.state_elapsed < duration.max(0.0) {
+                break;
+            }
+            let overshoot = self.state_elapsed - duration.max(0.0);
+            self.enter(next);
+            self.state_elapsed = overshoot;
+
+            // A zero-length cycle would loop forever; resume on the next tick.
+            if next == BlinkState::Interval && self.next_interval <= 0.0 {
+                self.state_elapsed = 0.0;
+                break;
+            }
+        }
+
+        self.eye_openness()
+    }
+
+    /// Advances the state machine and writes the eye openness to the driven parameters.
+    pub fn update(&mut self, delta_seconds: f32, model_dynamic: &mut ModelDynamic) {
+        let openness = self.advance(delta_seconds);
+        let values = model_dynamic.parameter_values_mut();
+        for &index in &self.parameters {
+            values[index] = openness;
+        }
+    }
+
+    fn enter(&mut self, state: BlinkState) {
+        if state == BlinkState::Interval {
+            // Only the first blink of a pair may roll for a follow-up.
+            self.in_double_blink = !self.in_double_blink && self.rng.chance(self.config.double_blink_probability);
+            self.next_interval = if self.in_double_blink {
+                self.config.double_blink_gap_seconds.max(0.0)
+            } else {
+                self.config.interval.sample(&mut self.rng)
+            };
+        }
+        self.state = state;
+        self.state_elapsed = 0.0;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn blink(config: EyeBlinkConfig, seed: u64) -> EyeBlink {
+        EyeBlink::with_parameters(Vec::new(), config, Rng::new(seed))
+    }
+
+    /// Advances in small steps and records every state change.
+    fn states(blink: &mut EyeBlink, seconds: f32) -> Vec<BlinkState> {
+        let mut states = vec![blink.state()];
+        for _ in 0..(seconds / 0.01) as usize {
+            blink.advance(0.01);
+            if states.last() != Some(&blink.state()) {
+                states.push(blink.state());
+            }
+        }
+        states
+    }
+
+    #[test]
+    fn blink_runs_through_the_states_in_order() {
+        let config = EyeBlinkConfig {
+            interval: BlinkInterval::Fixed(1.0),
+            double_blink_probability: 0.0,
+            ..EyeBlinkConfig::default()
+        };
+        let mut blink = blink(config, 1);
+        use BlinkState::*;
+        assert_eq!(states(&mut blink, 1.35), [Interval, Closing, Closed, Opening, Interval]);
+        assert_eq!(blink.eye_openness(), 1.0);
+    }
+
+    #[test]
+    fn openness_follows_the_phase() {
+        let config = EyeBlinkConfig {
+            interval: BlinkInterval::Fixed(1.0),
+            double_blink_probability: 0.0,
+            ..EyeBlinkConfig::default()
+        };
+        let mut blink = blink(config, 1);
+        assert_eq!(blink.advance(0.5), 1.0);
+        assert!((blink.advance(0.55) - 0.5).abs() < 1e-3);
+        assert_eq!(blink.advance(0.1), 0.0);
+        assert_eq!(blink.state(), BlinkState::Closed);
+    }
+
+    #[test]
+    fn same_seed_gives_the_same_intervals() {
+        let config = EyeBlinkConfig::default();
+        let mut a = blink(config, 42);
+        let mut b = blink(config, 42);
+        assert_eq!(states(&mut a, 60.0), states(&mut b, 60.0));
+        assert_eq!(a.next_interval, b.next_interval);
+    }
+
+    #[test]
+    fn sampled_intervals_stay_within_bounds() {
+        let mut rng = Rng::new(7);
+        let uniform = BlinkInterval::Uniform { min: 2.0, max: 6.0 };
+        let exponential = BlinkInterval::Exponential { mean: 4.0, min: 1.5 };
+        for _ in 0..10_000 {
+            let seconds = uniform.sample(&mut rng);
+            assert!((2.0..6.0).contains(&seconds), "{seconds}");
+            assert!(exponential.sample(&mut rng) >= 1.5);
+        }
+        assert_eq!(BlinkInterval::Fixed(-1.0).sample(&mut rng), 0.0);
+    }
+
+    #[test]
+    fn double_blinks_do_not_chain() {
+        let config = EyeBlinkConfig {
+            interval: BlinkInterval::Fixed(1.0),
+            double_blink_probability: 1.0,
+            double_blink_gap_seconds: 0.2,
+            ..EyeBlinkConfig::default()
+        };
+        let mut blink = blink(config, 3);
+        let mut gaps = Vec::new();
+        while gaps.len() < 6 {
+            let before = blink.state();
+            blink.advance(0.01);
+            if before == BlinkState::Opening && blink.state() == BlinkState::Interval {
+                gaps.push(blink.next_interval);
+            }
+        }
+        assert_eq!(gaps, [0.2, 1.0, 0.2, 1.0, 0.2, 1.0]);
+    }
+}
@@ -0,0 +1,100 @@
+use std::f32::consts::TAU;
+
+use crate::core::{ModelDynamic, ModelStatic};
+
+/// One periodic idle motion: `offset + peak * sin(2π t / cycle)`, added with `weight`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct IdleEffect {
+    pub parameter_id: String,
+    pub offset: f32,
+    pub peak: f32,
+    pub cycle_seconds: f32,
+    pub weight: f32,
+}
+
+impl IdleEffect {
+    pub fn new(parameter_id: impl Into<String>, offset: f32, peak: f32, cycle_seconds: f32, weight: f32) -> Self {
+        Self {
+            parameter_id: parameter_id.into(),
+            offset,
+            peak,
+            cycle_seconds,
+            weight,
+        }
+    }
+
+    /// The official framework's breath settings: head/body sway and `ParamBreath`.
+    pub fn official_breath() -> Vec<IdleEffect> {
+        vec![
+            IdleEffect::new("ParamAngleX", 0.0, 15.0, 6.5345, 0.5),
+            IdleEffect::new("ParamAngleY", 0.0, 8.0, 3.5345, 0.5),
+            IdleEffect::new("ParamAngleZ", 0.0, 10.0, 5.5345, 0.5),
+            IdleEffect::new("ParamBodyAngleX", 0.0, 4.0, 15.5345, 0.5),
+            IdleEffect::new("ParamBreath", 0.5, 0.5, 3.2345, 1.0),
+        ]
+    }
+
+    /// Value of the effect at `time` seconds, before weighting.
+    pub fn value_at(&self, time: f32) -> f32 {
+        if self.cycle_seconds <= 0.0 {
+            return self.offset;
+        }
+        self.offset + self.peak * (time * TAU / self.cycle_seconds).sin()
+    }
+}
+
+#[derive(Debug, Clone)]
+struct ResolvedEffect {
+    effect: IdleEffect,
+    parameter: usize,
+    range: (f32, f32),
+}
+
+/// Breathing and idle sway, added on top of the parameter values already set for the frame
+/// (so it should run after motions).
+///
+/// Effects targeting parameters the model does not have are ignored.
+#[derive(Debug, Clone)]
+pub struct IdleEffects {
+    effects: Vec<ResolvedEffect>,
+    time: f32,
+}
+
+impl IdleEffects {
+    pub fn new(effects: Vec<IdleEffect>, model_static: &ModelStatic) -> Self {
+        let parameters = model_static.parameters();
+        let effects = effects
+            .into_iter()
+            .filter_map(|effect| {
+                let parameter = parameters.iter().position(|p| p.id() == effect.parameter_id)?;
+                Some(ResolvedEffect {
+                    range: parameters[parameter].value_range(),
+                    parameter,
+                    effect,
+                })
+            })
+            .collect();
+        Self { effects, time: 0.0 }
+    }
+
+    /// Effects applied to the model, i.e. those whose parameter exists.
+    pub fn effects(&self) -> impl Iterator<Item = &IdleEffect> {
+        self.effects.iter().map(|resolved| &resolved.effect)
+    }
+
+    pub fn time(&self) -> f32 {
+        self.time
+    }
+
+    /// Advances the clock and adds every effect to its parameter, clamped to the parameter range.
+    pub fn update(&mut self, delta_seconds: f32, model_dynamic: &mut ModelDynamic) {
+        self.time += delta_seconds.max(0.0);
+
+        let values = model_dynamic.parameter_values_mut();
+        for resolved in &self.effects {
+            let value = &mut values[resolved.parameter];
+            let added = resolved.effect.value_at(self.time) * resolved.effect.weight;
+            *value = (*value + added).clamp(resolved.range.0, resolved.range.1);
+        }
+    }
+}
@@ -0,0 +1,555 @@
+use std::collections::VecDeque;
+
+use thiserror::Error;
+
+use super::model_settings::{ModelSettings, GROUP_LIP_SYNC};
+use super::parameter_indices;
+use crate::core::{ModelDynamic, ModelStatic};
+
+/// Parameter driven when the model has no `LipSync` group.
+pub const FALLBACK_LIP_SYNC_PARAMETER: &str = "ParamMouthOpenY";
+
+/// Errors generated when reading WAV data.
+#[derive(Debug, Error)]
+pub enum LipSyncError {
+    #[error("Invalid WAV data: {0}")]
+    InvalidWav(&'static str),
+    #[error("Unsupported WAV format (format tag {format_tag}, {bits_per_sample} bits per sample)")]
+    UnsupportedWavFormat { format_tag: u16, bits_per_sample: u16 },
+}
+
+/// Sample rate and channel count of interleaved PCM samples.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PcmFormat {
+    pub sample_rate: u32,
+    pub channels: u16,
+}
+
+/// Decoded WAV audio, as interleaved samples in `[-1, 1]`.
+#[derive(Debug, Clone)]
+pub struct WavClip {
+    pub format: PcmFormat,
+    pub samples: Vec<f32>,
+}
+
+impl WavClip {
+    /// Parses a RIFF/WAVE file holding 8/16/24/32-bit integer or 32-bit float PCM.
+    pub fn parse(bytes: &[u8]) -> Result<Self, LipSyncError> {
+        if bytes.len() < 12 || &bytes[0..4] != b"RIFF" || &bytes[8..12] != b"WAVE" {
+            return Err(LipSyncError::InvalidWav("missing RIFF/WAVE header"));
+        }
+
+        let mut format = None;
+        let mut data = None;
+        let mut offset = 12;
+        while bytes.len().saturating_sub(offset) >= 8 {
+            let id = &bytes[offset..offset + 4];
+            let size = u32::from_le_bytes(bytes[offset + 4..offset + 8].try_into().unwrap()) as usize;
+            // `size` comes from the file; on 32-bit targets it can overflow `usize` arithmetic.
+            let overflow = || LipSyncError::InvalidWav("chunk size overflow");
+            let end = (offset + 8).checked_add(size).ok_or_else(overflow)?;
+            let body = &bytes[offset + 8..end.min(bytes.len())];
+            match id {
+                b"fmt " if body.len() >= 16 => format = Some(body),
+                b"data" => data = Some(body),
+                _ => {}
+            }
+            // Chunks are padded to an even size.
+            offset = end.checked_add(size & 1).ok_or_else(overflow)?;
+        }
+
+        let format = format.ok_or(LipSyncError::InvalidWav("missing fmt chunk"))?;
+        let data = data.ok_or(LipSyncError::InvalidWav("missing data chunk"))?;
+
+        let mut format_tag = u16::from_le_bytes([format[0], format[1]]);
+        let channels = u16::from_le_bytes([format[2], format[3]]);
+        let sample_rate = u32::from_le_bytes(format[4..8].try_into().unwrap());
+        let bits_per_sample = u16::from_le_bytes([format[14], format[15]]);
+        // WAVE_FORMAT_EXTENSIBLE stores the actual format tag in the sub-format GUID.
+        if format_tag == 0xFFFE && format.len() >= 26 {
+            format_tag = u16::from_le_bytes([format[24], format[25]]);
+        }
+        if channels == 0 || sample_rate == 0 {
+            return Err(LipSyncError::InvalidWav("zero channels or sample rate"));
+        }
+
+        let samples = match (format_tag, bits_per_sample) {
+            (1, 8) => data.iter().map(|&s| (f32::from(s) - 128.0) / 128.0).collect(),
+            (1, 16) => data
+                .chunks_exact(2)
+                .map(|s| f32::from(i16::from_le_bytes([s[0], s[1]])) / 32768.0)
+                .collect(),
+            (1, 24) => data
+                .chunks_exact(3)
+                .map(|s| (i32::from_le_bytes([0, s[0], s[1], s[2]]) >> 8) as f32 / 8_388_608.0)
+                .collect(),
+            (1, 32) => data
+                .chunks_exact(4)
+                .map(|s| i32::from_le_bytes([s[0], s[1], s[2], s[3]]) as f32 / 2_147_483_648.0)
+                .collect(),
+            (3, 32) => data
+                .chunks_exact(4)
+                .map(|s| f32::from_le_bytes([s[0], s[1], s[2], s[3]]))
+                .collect(),
+            _ => {
+                return Err(LipSyncError::UnsupportedWavFormat {
+                    format_tag,
+                    bits_per_sample,
+                })
+            }
+        };
+
+        Ok(Self {
+            format: PcmFormat {
+                sample_rate,
+                channels,
+            },
+            samples,
+        })
+    }
+
+    pub fn duration_seconds(&self) -> f32 {
+        self.samples.len() as f32 / (self.format.sample_rate as f32 * f32::from(self.format.channels))
+    }
+}
+
+/// How audio loudness turns into mouth openness.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct LipSyncConfig {
+    /// Length of an analysis frame.
+    pub frame_seconds: f32,
+    /// Multiplier from RMS to mouth openness.
+    pub gain: f32,
+    /// RMS below which the mouth stays closed.
+    pub noise_floor: f32,
+    /// Smoothing time constant while the mouth opens.
+    pub attack_seconds: f32,
+    /// Smoothing time constant while the mouth closes.
+    pub release_seconds: f32,
+    /// Weight the openness is added to the parameters with.
+    pub weight: f32,
+}
+
+impl Default for LipSyncConfig {
+    fn default() -> Self {
+        Self {
+            frame_seconds: 1.0 / 60.0,
+            gain: 5.0,
+            noise_floor: 0.01,
+            attack_seconds: 0.03,
+            release_seconds: 0.08,
+            weight: 0.8,
+        }
+    }
+}
+
+impl LipSyncConfig {
+    fn openness(&self, rms: f32) -> f32 {
+        ((rms - self.noise_floor) * self.gain).clamp(0.0, 1.0)
+    }
+
+    fn smooth(&self, current: f32, target: f32, delta_seconds: f32) -> f32 {
+        let tau = if target > current {
+            self.attack_seconds
+        } else {
+            self.release_seconds
+        };
+        if tau <= 0.0 {
+            return target;
+        }
+        current + (target - current) * (1.0 - (-delta_seconds / tau).exp())
+    }
+
+    /// Interleaved samples per analysis frame.
+    fn frame_samples(&self, format: PcmFormat) -> usize {
+        let frame_len = ((format.sample_rate as f32 * self.frame_seconds).round() as usize).max(1);
+        frame_len * usize::from(format.channels.max(1))
+    }
+
+    /// RMS of each analysis frame, after downmixing to mono; the last frame may be partial.
+    fn frame_rms(&self, samples: &[f32], format: PcmFormat) -> Vec<f32> {
+        let channels = usize::from(format.channels.max(1));
+        samples
+            .chunks(self.frame_samples(format))
+            .map(|frame| {
+                let frames = frame.chunks(channels);
+                let count = frames.len().max(1);
+                let sum: f32 = frames
+                    .map(|f| {
+                        let mono = f.iter().sum::<f32>() / f.len() as f32;
+                        mono * mono
+                    })
+                    .sum();
+                (sum / count as f32).sqrt()
+            })
+            .collect()
+    }
+
+    /// Pre-analyzes a whole clip into smoothed mouth openness values.
+    pub fn analyze(&self, samples: &[f32], format: PcmFormat) -> LipSyncTimeline {
+        let mut current = 0.0;
+        let values = self
+            .frame_rms(samples, format)
+            .into_iter()
+            .map(|rms| {
+                current = self.smooth(current, self.openness(rms), self.frame_seconds);
+                current
+            })
+            .collect();
+        LipSyncTimeline {
+            frame_seconds: self.frame_seconds,
+            values,
+        }
+    }
+}
+
+/// Mouth openness sampled at a fixed rate.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct LipSyncTimeline {
+    pub frame_seconds: f32,
+    pub values: Vec<f32>,
+}
+
+impl LipSyncTimeline {
+    pub fn duration_seconds(&self) -> f32 {
+        self.values.len() as f32 * self.frame_seconds
+    }
+
+    /// Linearly interpolated openness at `time`; 0 outside the timeline.
+    pub fn value_at(&self, time: f32) -> f32 {
+        if time < 0.0 || self.frame_seconds <= 0.0 || time >= self.duration_seconds() {
+            return 0.0;
+        }
+        let position = time / self.frame_seconds;
+        let index = position as usize;
+        // The last frame is held; closing the mouth afterwards is left to the release smoothing.
+        let next = self.values.get(index + 1).copied().unwrap_or(self.values[index]);
+        let t = position - index as f32;
+        self.values[index] * (1.0 - t) + next * t
+    }
+}
+
+#[derive(Debug, Clone)]
+enum LipSyncSource {
+    Idle,
+    Timeline { timeline: LipSyncTimeline, time: f32 },
+    /// RMS of pushed frames not played yet, and time spent in the front frame.
+    ///
+    /// Samples that do not fill a whole frame are kept in `pending` until the next push.
+    Live {
+        frames: VecDeque<f32>,
+        time: f32,
+        pending: Vec<f32>,
+        format: PcmFormat,
+    },
+}
+
+/// Lip sync driving the `LipSync` parameter group from audio.
+///
+/// Either plays a pre-analyzed [`LipSyncTimeline`], or consumes live chunks pushed as they are
+/// played back.
+#[derive(Debug, Clone)]
+pub struct LipSync {
+    parameters: Vec<usize>,
+    config: LipSyncConfig,
+    source: LipSyncSource,
+    value: f32,
+}
+
+impl LipSync {
+    /// Creates a lip sync for the given parameter ids; unknown ids are ignored.
+    pub fn new(parameter_ids: &[String], model_static: &ModelStatic, config: LipSyncConfig) -> Self {
+        Self::with_parameters(parameter_indices(model_static, parameter_ids), config)
+    }
+
+    /// Creates a lip sync driving the parameters at the given indices.
+    pub fn with_parameters(parameters: Vec<usize>, config: LipSyncConfig) -> Self {
+        Self {
+            parameters,
+            config,
+            source: LipSyncSource::Idle,
+            value: 0.0,
+        }
+    }
+
+    /// Drives the [`GROUP_LIP_SYNC`] group of a `model3.json`, or
+    /// [`FALLBACK_LIP_SYNC_PARAMETER`] if it is missing or empty.
+    pub fn from_settings(settings: Option<&ModelSettings>, model_static: &ModelStatic, config: LipSyncConfig) -> Self {
+        let ids = settings.map(|s| s.parameter_group(GROUP_LIP_SYNC)).unwrap_or_default();
+        if ids.is_empty() {
+            Self::new(&[FALLBACK_LIP_SYNC_PARAMETER.to_string()], model_static, config)
+        } else {
+            Self::new(ids, model_static, config)
+        }
+    }
+
+    /// Indices of the driven parameters.
+    pub fn parameters(&self) -> &[usize] {
+        &self.parameters
+    }
+
+    pub fn config(&self) -> &LipSyncConfig {
+        &self.config
+    }
+    pub fn set_config(&mut self, config: LipSyncConfig) {
+        self.config = config;
+    }
+
+    /// Current mouth openness.
+    pub fn value(&self) -> f32 {
+        self.value
+    }
+
+    pub fn is_playing(&self) -> bool {
+        match &self.source {
+            LipSyncSource::Idle => false,
+            LipSyncSource::Timeline { timeline, time } => *time < timeline.duration_seconds(),
+            LipSyncSource::Live { frames, .. } => !frames.is_empty(),
+        }
+    }
+
+    /// Plays a pre-analyzed timeline from the start.
+    pub fn play(&mut self, timeline: LipSyncTimeline) {
+        self.source = LipSyncSource::Timeline { timeline, time: 0.0 };
+    }
+
+    /// Queues a live chunk of interleaved samples in `[-1, 1]`, played back from now on.
+    ///
+    /// Only whole analysis frames are queued; the remainder is carried over to the next push
+    /// (and dropped if the format changes).
+    pub fn push(&mut self, samples: &[f32], format: PcmFormat) {
+        let same_format = matches!(&self.source, LipSyncSource::Live { format: f, .. } if *f == format);
+        if !same_format {
+            self.source = LipSyncSource::Live {
+                frames: VecDeque::new(),
+                time: 0.0,
+                pending: Vec::new(),
+                format,
+            };
+        }
+        let LipSyncSource::Live { frames, pending, .. } = &mut self.source else {
+            unreachable!("the source was just made live");
+        };
+
+        pending.extend_from_slice(samples);
+        let frame_samples = self.config.frame_samples(format);
+        let whole = pending.len() - pending.len() % frame_samples;
+        frames.extend(self.config.frame_rms(&pending[..whole], format));
+        pending.drain(..whole);
+    }
+
+    pub fn stop(&mut self) {
+        self.source = LipSyncSource::Idle;
+    }
+
+    /// Advances playback and returns the mouth openness.
+    pub fn advance(&mut self, delta_seconds: f32) -> f32 {
+        let delta_seconds = delta_seconds.max(0.0);
+        let frame_seconds = self.config.frame_seconds.max(f32::EPSILON);
+
+        match &mut self.source {
+            LipSyncSource::Idle => {
+                self.value = self.config.smooth(self.value, 0.0, delta_seconds);
+            }
+            LipSyncSource::Timeline { timeline, time } => {
+                *time += delta_seconds;
+                if *time < timeline.duration_seconds() {
+                    self.value = timeline.value_at(*time);
+                } else {
+                    // Close the mouth with the release smoothing instead of snapping to 0.
+                    self.value = self.config.smooth(self.value, 0.0, delta_seconds);
+                    self.source = LipSyncSource::Idle;
+                }
+            }
+            LipSyncSource::Live { frames, time, .. } => {
+                *time += delta_seconds;
+                while *time >= frame_seconds && !frames.is_empty() {
+                    frames.pop_front();
+                    *time -= frame_seconds;
+                }
+                if frames.is_empty() {
+                    // Underrun: the next chunk starts when it is pushed.
+                    *time = 0.0;
+                }
+                let target = frames.front().map_or(0.0, |&rms| self.config.openness(rms));
+                self.value = self.config.smooth(self.value, target, delta_seconds);
+            }
+        }
+
+        self.value
+    }
+
+    /// Advances playback and adds the weighted mouth openness to the driven parameters.
+    pub fn update(&mut self, delta_seconds: f32, model_static: &ModelStatic, model_dynamic: &mut ModelDynamic) {
+        let value = self.advance(delta_seconds) * self.config.weight;
+        let parameters = model_static.parameters();
+        let values = model_dynamic.parameter_values_mut();
+        for &index in &self.parameters {
+            let (min, max) = parameters[index].value_range();
+            values[index] = (values[index] + value).clamp(min, max);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MONO_400: PcmFormat = PcmFormat {
+        sample_rate: 400,
+        channels: 1,
+    };
+
+    fn chunk(id: &[u8; 4], body: &[u8]) -> Vec<u8> {
+        let mut chunk = [&id[..], &(body.len() as u32).to_le_bytes()].concat();
+        chunk.extend_from_slice(body);
+        if body.len() % 2 == 1 {
+            chunk.push(0);
+        }
+        chunk
+    }
+
+    fn fmt(format_tag: u16, channels: u16, sample_rate: u32, bits_per_sample: u16) -> Vec<u8> {
+        let block_align = channels * bits_per_sample / 8;
+        [
+            &format_tag.to_le_bytes()[..],
+            &channels.to_le_bytes(),
+            &sample_rate.to_le_bytes(),
+            &(sample_rate * u32::from(block_align)).to_le_bytes(),
+            &block_align.to_le_bytes(),
+            &bits_per_sample.to_le_bytes(),
+        ]
+        .concat()
+    }
+
+    fn wav(chunks: &[Vec<u8>]) -> Vec<u8> {
+        let body = chunks.concat();
+        [&b"RIFF"[..], &(body.len() as u32 + 4).to_le_bytes(), b"WAVE", &body].concat()
+    }
+
+    /// A lip sync that opens fully at any sound and does not smooth.
+    fn lip_sync() -> LipSync {
+        LipSync::with_parameters(
+            Vec::new(),
+            LipSyncConfig {
+                frame_seconds: 0.01,
+                gain: 100.0,
+                noise_floor: 0.0,
+                attack_seconds: 0.0,
+                release_seconds: 0.1,
+                weight: 1.0,
+            },
+        )
+    }
+
+    fn queued(lip_sync: &LipSync) -> (usize, usize) {
+        match &lip_sync.source {
+            LipSyncSource::Live { frames, pending, .. } => (frames.len(), pending.len()),
+            _ => (0, 0),
+        }
+    }
+
+    #[test]
+    fn parses_16_bit_stereo_and_skips_padded_chunks() {
+        let samples: Vec<u8> = [0i16, i16::MIN, 16384, -16384].iter().flat_map(|s| s.to_le_bytes()).collect();
+        let bytes = wav(&[chunk(b"LIST", b"odd"), chunk(b"fmt ", &fmt(1, 2, 8000, 16)), chunk(b"data", &samples)]);
+
+        let clip = WavClip::parse(&bytes).unwrap();
+        assert_eq!(clip.format, PcmFormat { sample_rate: 8000, channels: 2 });
+        assert_eq!(clip.samples, [0.0, -1.0, 0.5, -0.5]);
+        assert_eq!(clip.duration_seconds(), 2.0 / 8000.0);
+    }
+
+    #[test]
+    fn parses_8_bit_and_extensible_float() {
+        let bytes = wav(&[chunk(b"fmt ", &fmt(1, 1, 8000, 8)), chunk(b"data", &[128, 0, 192])]);
+        let clip = WavClip::parse(&bytes).unwrap();
+        assert_eq!(clip.samples, [0.0, -1.0, 0.5]);
+
+        let mut extensible = fmt(0xFFFE, 1, 8000, 32);
+        extensible.extend_from_slice(&[22, 0, 32, 0, 0, 0, 0, 0, 3, 0]);
+        let data: Vec<u8> = [0.25f32, -0.75].iter().flat_map(|s| s.to_le_bytes()).collect();
+        let clip = WavClip::parse(&wav(&[chunk(b"fmt ", &extensible), chunk(b"data", &data)])).unwrap();
+        assert_eq!(clip.samples, [0.25, -0.75]);
+    }
+
+    #[test]
+    fn rejects_malformed_wavs() {
+        let invalid = |bytes: &[u8]| matches!(WavClip::parse(bytes), Err(LipSyncError::InvalidWav(_)));
+        assert!(invalid(b"RIFF\0\0\0\0WAV"));
+        assert!(invalid(&wav(&[chunk(b"data", &[0, 0])])));
+        assert!(invalid(&wav(&[chunk(b"fmt ", &fmt(1, 1, 8000, 16))])));
+        assert!(invalid(&wav(&[chunk(b"fmt ", &fmt(1, 0, 8000, 16)), chunk(b"data", &[])])));
+        assert!(matches!(
+            WavClip::parse(&wav(&[chunk(b"fmt ", &fmt(3, 1, 8000, 64)), chunk(b"data", &[])])),
+            Err(LipSyncError::UnsupportedWavFormat { format_tag: 3, bits_per_sample: 64 })
+        ));
+
+        // A data chunk claiming more bytes than the file holds is cut at the end of the file.
+        let mut truncated = wav(&[chunk(b"fmt ", &fmt(1, 1, 8000, 16)), chunk(b"data", &[0, 64])]);
+        let size_offset = truncated.len() - 6;
+        truncated[size_offset..size_offset + 4].copy_from_slice(&u32::MAX.to_le_bytes());
+        assert_eq!(WavClip::parse(&truncated).unwrap().samples, [0.5]);
+    }
+
+    #[test]
+    fn rms_is_computed_per_downmixed_frame() {
+        let config = lip_sync().config;
+        let stereo = PcmFormat { sample_rate: 400, channels: 2 };
+        // Frames of 4 stereo samples; the opposite channels of the second frame cancel out.
+        let samples = [[0.5, 0.5].repeat(4), [1.0, -1.0].repeat(4), vec![0.3, 0.3]].concat();
+
+        let rms = config.frame_rms(&samples, stereo);
+        assert_eq!(rms.len(), 3);
+        assert!((rms[0] - 0.5).abs() < 1e-6);
+        assert_eq!(rms[1], 0.0);
+        assert!((rms[2] - 0.3).abs() < 1e-6);
+    }
+
+    #[test]
+    fn pushes_only_queue_whole_frames() {
+        let mut lip_sync = lip_sync();
+        lip_sync.push(&[0.5; 6], MONO_400);
+        assert_eq!(queued(&lip_sync), (1, 2));
+
+        lip_sync.push(&[0.5; 2], MONO_400);
+        assert_eq!(queued(&lip_sync), (2, 0));
+        let LipSyncSource::Live { frames, .. } = &lip_sync.source else {
+            unreachable!();
+        };
+        assert!(frames.iter().all(|&rms| (rms - 0.5).abs() < 1e-6));
+
+        // Leftovers in another format are dropped along with the queue.
+        lip_sync.push(&[0.5; 3], MONO_400);
+        lip_sync.push(&[0.5; 5], PcmFormat { sample_rate: 800, channels: 1 });
+        assert_eq!(queued(&lip_sync), (0, 5));
+    }
+
+    #[test]
+    fn live_playback_consumes_frames_in_real_time() {
+        let mut lip_sync = lip_sync();
+        lip_sync.push(&[[0.5; 4], [0.0; 4]].concat(), MONO_400);
+
+        assert_eq!(lip_sync.advance(0.005), 1.0);
+        assert!(lip_sync.is_playing());
+        lip_sync.advance(0.01);
+        assert!(lip_sync.value() < 1.0);
+        lip_sync.advance(0.01);
+        assert!(!lip_sync.is_playing());
+    }
+
+    #[test]
+    fn mouth_closes_smoothly_after_a_timeline() {
+        let mut lip_sync = lip_sync();
+        lip_sync.play(LipSyncTimeline {
+            frame_seconds: 0.01,
+            values: vec![1.0, 1.0],
+        });
+
+        assert_eq!(lip_sync.advance(0.015), 1.0);
+        let released = lip_sync.advance(0.01);
+        assert!(released > 0.5 && released < 1.0, "{released}");
+        assert!(!lip_sync.is_playing());
+        assert!(lip_sync.advance(0.01) < released);
+    }
+}
@@ -0,0 +1,174 @@
+use crate::core::{ModelDynamic, ModelStatic};
+
+/// Below this distance the target counts as reached.
+const EPSILON: f32 = 0.01;
+
+/// Damped 2D follower of a target point, after the official framework's `CubismTargetPoint`.
+///
+/// The position accelerates towards the target up to `max_speed`, and brakes early enough to
+/// stop on it without overshooting.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TargetPoint {
+    /// Maximum speed, in normalized units per second.
+    pub max_speed: f32,
+    /// Time needed to reach `max_speed` from rest.
+    pub time_to_max_speed: f32,
+    target: (f32, f32),
+    position: (f32, f32),
+    velocity: (f32, f32),
+}
+
+impl Default for TargetPoint {
+    fn default() -> Self {
+        Self {
+            max_speed: 4.0,
+            time_to_max_speed: 0.15,
+            target: (0.0, 0.0),
+            position: (0.0, 0.0),
+            velocity: (0.0, 0.0),
+        }
+    }
+}
+
+impl TargetPoint {
+    /// Sets the target, clamped to `[-1, 1]` on both axes (Y up).
+    pub fn set_target(&mut self, x: f32, y: f32) {
+        self.target = (x.clamp(-1.0, 1.0), y.clamp(-1.0, 1.0));
+    }
+    pub fn target(&self) -> (f32, f32) {
+        self.target
+    }
+
+    /// Current, damped position.
+    pub fn position(&self) -> (f32, f32) {
+        self.position
+    }
+
+    pub fn update(&mut self, delta_seconds: f32) {
+        if delta_seconds <= 0.0 {
+            return;
+        }
+
+        let dx = self.target.0 - self.position.0;
+        let dy = self.target.1 - self.position.1;
+        let distance = (dx * dx + dy * dy).sqrt();
+        if distance <= EPSILON {
+            self.velocity = (0.0, 0.0);
+            return;
+        }
+
+        let max_acceleration = self.max_speed / self.time_to_max_speed.max(f32::EPSILON);
+
+        // Steer towards the full-speed velocity, limited by the acceleration.
+        let mut ax = self.max_speed * dx / distance - self.velocity.0;
+        let mut ay = self.max_speed * dy / distance - self.velocity.1;
+        let acceleration = (ax * ax + ay * ay).sqrt();
+        let max_delta_v = max_acceleration * delta_seconds;
+        if acceleration > max_delta_v {
+            ax *= max_delta_v / acceleration;
+            ay *= max_delta_v / acceleration;
+        }
+        self.velocity.0 += ax;
+        self.velocity.1 += ay;
+
+        // Brake so that the remaining distance suffices to stop.
+        let braking_speed = (2.0 * max_acceleration * distance).sqrt();
+        let speed = (self.velocity.0 * self.velocity.0 + self.velocity.1 * self.velocity.1).sqrt();
+        if speed > braking_speed {
+            self.velocity.0 *= braking_speed / speed;
+            self.velocity.1 *= braking_speed / speed;
+        }
+
+        self.position.0 += self.velocity.0 * delta_seconds;
+        self.position.1 += self.velocity.1 * delta_seconds;
+    }
+}
+
+/// How a parameter follows the look position `(x, y)`: `x * x_weight + y * y_weight + x * y * xy_weight`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct LookAtMapping {
+    pub parameter_id: String,
+    pub x_weight: f32,
+    pub y_weight: f32,
+    pub xy_weight: f32,
+}
+
+impl LookAtMapping {
+    pub fn new(parameter_id: impl Into<String>, x_weight: f32, y_weight: f32, xy_weight: f32) -> Self {
+        Self {
+            parameter_id: parameter_id.into(),
+            x_weight,
+            y_weight,
+            xy_weight,
+        }
+    }
+
+    /// The mapping used by the official sample application.
+    pub fn official() -> Vec<LookAtMapping> {
+        vec![
+            LookAtMapping::new("ParamAngleX", 30.0, 0.0, 0.0),
+            LookAtMapping::new("ParamAngleY", 0.0, 30.0, 0.0),
+            LookAtMapping::new("ParamAngleZ", 0.0, 0.0, -30.0),
+            LookAtMapping::new("ParamBodyAngleX", 10.0, 0.0, 0.0),
+            LookAtMapping::new("ParamEyeBallX", 1.0, 0.0, 0.0),
+            LookAtMapping::new("ParamEyeBallY", 0.0, 1.0, 0.0),
+        ]
+    }
+
+    pub fn value_at(&self, x: f32, y: f32) -> f32 {
+        x * self.x_weight + y * self.y_weight + x * y * self.xy_weight
+    }
+}
+
+/// Head, body and eye tracking of a target point, added to the parameters of the frame.
+///
+/// Mappings targeting parameters the model does not have are ignored.
+#[derive(Debug, Clone)]
+pub struct LookAt {
+    target_point: TargetPoint,
+    mappings: Vec<(usize, (f32, f32), LookAtMapping)>,
+}
+
+impl LookAt {
+    pub fn new(mappings: Vec<LookAtMapping>, model_static: &ModelStatic) -> Self {
+        let parameters = model_static.parameters();
+        let mappings = mappings
+            .into_iter()
+            .filter_map(|mapping| {
+                let index = parameters.iter().position(|p| p.id() == mapping.parameter_id)?;
+                Some((index, parameters[index].value_range(), mapping))
+            })
+            .collect();
+        Self {
+            target_point: TargetPoint::default(),
+            mappings,
+        }
+    }
+
+    pub fn target_point(&self) -> &TargetPoint {
+        &self.target_point
+    }
+    pub fn target_point_mut(&mut self) -> &mut TargetPoint {
+        &mut self.target_point
+    }
+
+    /// Mappings applied to the model, i.e. those whose parameter exists.
+    pub fn mappings(&self) -> impl Iterator<Item = &LookAtMapping> {
+        self.mappings.iter().map(|(_, _, mapping)| mapping)
+    }
+
+    /// Sets the normalized target, `(-1, -1)` being bottom left and `(1, 1)` top right.
+    pub fn set_target(&mut self, x: f32, y: f32) {
+        self.target_point.set_target(x, y);
+    }
+
+    pub fn update(&mut self, delta_seconds: f32, model_dynamic: &mut ModelDynamic) {
+        self.target_point.update(delta_seconds);
+        let (x, y) = self.target_point.position();
+
+        let values = model_dynamic.parameter_values_mut();
+        for (index, (min, max), mapping) in &self.mappings {
+            values[*index] = (values[*index] + mapping.value_at(x, y)).clamp(*min, *max);
+        }
+    }
+}
@@ -0,0 +1,130 @@
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+
+use serde::Deserialize;
+use thiserror::Error;
+
+/// Name of the parameter group driven by eye blinking.
+pub const GROUP_EYE_BLINK: &str = "EyeBlink";
+/// Name of the parameter group driven by lip sync.
+pub const GROUP_LIP_SYNC: &str = "LipSync";
+
+/// Errors generated when reading a `model3.json`.
+#[derive(Debug, Error)]
+pub enum ModelSettingsError {
+    #[error("Failed to read model settings: {0}")]
+    Io(#[from] std::io::Error),
+    #[error("Invalid model3.json: {0}")]
+    Json(#[from] serde_json::Error),
+}
+
+/// Contents of a `model3.json` file.
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct ModelSettings {
+    #[serde(default)]
+    pub version: u32,
+    pub file_references: FileReferences,
+    #[serde(default)]
+    pub groups: Vec<ParameterGroup>,
+    #[serde(default)]
+    pub hit_areas: Vec<HitArea>,
+    #[serde(default)]
+    pub layout: HashMap<String, f32>,
+}
+
+/// Paths (relative to the `model3.json`) of the files making up a model.
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct FileReferences {
+    pub moc: String,
+    #[serde(default)]
+    pub textures: Vec<String>,
+    #[serde(default)]
+    pub physics: Option<String>,
+    #[serde(default)]
+    pub pose: Option<String>,
+    #[serde(default)]
+    pub display_info: Option<String>,
+    #[serde(default)]
+    pub user_data: Option<String>,
+    #[serde(default)]
+    pub expressions: Vec<ExpressionReference>,
+    #[serde(default)]
+    pub motions: HashMap<String, Vec<MotionReference>>,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct ExpressionReference {
+    pub name: String,
+    pub file: String,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct MotionReference {
+    pub file: String,
+    #[serde(default)]
+    pub sound: Option<String>,
+    #[serde(default)]
+    pub fade_in_time: Option<f32>,
+    #[serde(default)]
+    pub fade_out_time: Option<f32>,
+}
+
+/// A named group of parameter or part ids, such as [`GROUP_EYE_BLINK`].
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct ParameterGroup {
+    /// `"Parameter"` or `"Part"`.
+    pub target: String,
+    pub name: String,
+    #[serde(default)]
+    pub ids: Vec<String>,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct HitArea {
+    pub id: String,
+    pub name: String,
+}
+
+impl ModelSettings {
+    pub fn from_json(json: &str) -> Result<Self, ModelSettingsError> {
+        Ok(serde_json::from_str(json)?)
+    }
+
+    pub fn from_file(path: impl AsRef<Path>) -> Result<Self, ModelSettingsError> {
+        Self::from_json(&std::fs::read_to_string(path)?)
+    }
+
+    /// Ids of the parameter group `name`; empty if the model does not define it.
+    pub fn parameter_group(&self, name: &str) -> &[String] {
+        self.groups
+            .iter()
+            .find(|group| group.target == "Parameter" && group.name == name)
+            .map(|group| group.ids.as_slice())
+            .unwrap_or_default()
+    }
+
+    /// Motions of the group `name`; empty if the model does not define it.
+    pub fn motion_group(&self, name: &str) -> &[MotionReference] {
+        self.file_references
+            .motions
+            .get(name)
+            .map(Vec::as_slice)
+            .unwrap_or_default()
+    }
+
+    /// Texture paths resolved against the directory containing the `model3.json`.
+    pub fn texture_paths(&self, model_dir: impl AsRef<Path>) -> Vec<PathBuf> {
+        let model_dir = model_dir.as_ref();
+        self.file_references
+            .textures
+            .iter()
+            .map(|texture| model_dir.join(texture))
+            .collect()
+    }
+}
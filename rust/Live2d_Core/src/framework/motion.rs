@@ -0,0 +1,613 @@
+use std::sync::Arc;
+
+use serde::Deserialize;
+use thiserror::Error;
+
+use crate::core::{ModelDynamic, ModelStatic};
+
+/// Default fade duration of motions that do not specify one.
+pub const DEFAULT_FADE_SECONDS: f32 = 1.0;
+
+/// Errors generated when reading a `motion3.json`.
+#[derive(Debug, Error)]
+pub enum MotionError {
+    #[error("Invalid motion3.json: {0}")]
+    Json(#[from] serde_json::Error),
+    #[error("Invalid segments in curve \"{0}\"")]
+    InvalidSegments(String),
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct Motion3Json {
+    meta: Motion3Meta,
+    #[serde(default)]
+    curves: Vec<Motion3Curve>,
+    #[serde(default)]
+    user_data: Vec<Motion3UserData>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct Motion3Meta {
+    duration: f32,
+    #[serde(default)]
+    r#loop: bool,
+    #[serde(default)]
+    fade_in_time: Option<f32>,
+    #[serde(default)]
+    fade_out_time: Option<f32>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct Motion3Curve {
+    target: String,
+    id: String,
+    #[serde(default)]
+    fade_in_time: Option<f32>,
+    #[serde(default)]
+    fade_out_time: Option<f32>,
+    segments: Vec<f32>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct Motion3UserData {
+    time: f32,
+    value: String,
+}
+
+/// What a curve animates.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CurveTarget {
+    Parameter,
+    PartOpacity,
+    /// Model-level curves (`Opacity`, `EyeBlink`, `LipSync`), which are not applied.
+    Model,
+}
+
+/// One segment of a curve, ending at its last point.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Segment {
+    Linear { end: (f32, f32) },
+    Bezier {
+        control1: (f32, f32),
+        control2: (f32, f32),
+        end: (f32, f32),
+    },
+    /// Keeps the start value until the end time.
+    Stepped { end: (f32, f32) },
+    /// Jumps to the end value right after the start time.
+    InverseStepped { end: (f32, f32) },
+}
+
+impl Segment {
+    fn end(&self) -> (f32, f32) {
+        match *self {
+            Segment::Linear { end }
+            | Segment::Bezier { end, .. }
+            | Segment::Stepped { end }
+            | Segment::InverseStepped { end } => end,
+        }
+    }
+
+    fn evaluate(&self, start: (f32, f32), time: f32) -> f32 {
+        let end = self.end();
+        match *self {
+            Segment::Linear { .. } => {
+                let t = if end.0 > start.0 {
+                    ((time - start.0) / (end.0 - start.0)).clamp(0.0, 1.0)
+                } else {
+                    1.0
+                };
+                start.1 + (end.1 - start.1) * t
+            }
+            Segment::Bezier { control1, control2, .. } => {
+                let t = bezier_parameter_at(start.0, control1.0, control2.0, end.0, time);
+                cubic(start.1, control1.1, control2.1, end.1, t)
+            }
+            Segment::Stepped { .. } => start.1,
+            Segment::InverseStepped { .. } => end.1,
+        }
+    }
+}
+
+fn cubic(p0: f32, p1: f32, p2: f32, p3: f32, t: f32) -> f32 {
+    let s = 1.0 - t;
+    s * s * s * p0 + 3.0 * s * s * t * p1 + 3.0 * s * t * t * p2 + t * t * t * p3
+}
+
+/// Bezier parameter whose x equals `x`; x is monotonic for valid motion curves.
+fn bezier_parameter_at(x0: f32, x1: f32, x2: f32, x3: f32, x: f32) -> f32 {
+    let (mut low, mut high) = (0.0f32, 1.0f32);
+    for _ in 0..24 {
+        let mid = (low + high) * 0.5;
+        if cubic(x0, x1, x2, x3, mid) < x {
+            low = mid;
+        } else {
+            high = mid;
+        }
+    }
+    (low + high) * 0.5
+}
+
+/// An animated parameter, part opacity or model property.
+#[derive(Debug, Clone, PartialEq)]
+pub struct MotionCurve {
+    pub target: CurveTarget,
+    pub id: String,
+    /// Per-curve fades; `None` uses the motion's.
+    pub fade_in_seconds: Option<f32>,
+    pub fade_out_seconds: Option<f32>,
+    pub start: (f32, f32),
+    pub segments: Vec<Segment>,
+}
+
+impl MotionCurve {
+    /// Piecewise-linear parameter curve through `(time, value)` keyframes.
+    pub fn linear(id: impl Into<String>, keyframes: &[(f32, f32)]) -> Self {
+        Self {
+            target: CurveTarget::Parameter,
+            id: id.into(),
+            fade_in_seconds: None,
+            fade_out_seconds: None,
+            start: keyframes.first().copied().unwrap_or_default(),
+            segments: keyframes
+                .iter()
+                .skip(1)
+                .map(|&end| Segment::Linear { end })
+                .collect(),
+        }
+    }
+
+    fn parse(curve: Motion3Curve) -> Result<Self, MotionError> {
+        let invalid = || MotionError::InvalidSegments(curve.id.clone());
+        let data = &curve.segments;
+        if data.len() < 2 {
+            return Err(invalid());
+        }
+
+        let point = |i: usize| -> Result<(f32, f32), MotionError> {
+            Ok((*data.get(i).ok_or_else(invalid)?, *data.get(i + 1).ok_or_else(invalid)?))
+        };
+        let start = point(0)?;
+        let mut segments = Vec::new();
+        let mut i = 2;
+        while i < data.len() {
+            let segment = match data[i] as i32 {
+                0 => Segment::Linear { end: point(i + 1)? },
+                1 => Segment::Bezier {
+                    control1: point(i + 1)?,
+                    control2: point(i + 3)?,
+                    end: point(i + 5)?,
+                },
+                2 => Segment::Stepped { end: point(i + 1)? },
+                3 => Segment::InverseStepped { end: point(i + 1)? },
+                _ => return Err(invalid()),
+            };
+            i += if matches!(segment, Segment::Bezier { .. }) { 7 } else { 3 };
+            segments.push(segment);
+        }
+
+        Ok(Self {
+            target: match curve.target.as_str() {
+                "Parameter" => CurveTarget::Parameter,
+                "PartOpacity" => CurveTarget::PartOpacity,
+                _ => CurveTarget::Model,
+            },
+            id: curve.id,
+            fade_in_seconds: curve.fade_in_time.filter(|s| *s >= 0.0),
+            fade_out_seconds: curve.fade_out_time.filter(|s| *s >= 0.0),
+            start,
+            segments,
+        })
+    }
+
+    /// Value at `time`, holding the first and last values outside the curve.
+    pub fn value_at(&self, time: f32) -> f32 {
+        let mut start = self.start;
+        for segment in &self.segments {
+            let end = segment.end();
+            if time < end.0 {
+                return if time <= start.0 {
+                    start.1
+                } else {
+                    segment.evaluate(start, time)
+                };
+            }
+            start = end;
+        }
+        start.1
+    }
+}
+
+/// A string attached to a point in time of a motion.
+#[derive(Debug, Clone, PartialEq)]
+pub struct MotionUserData {
+    pub time: f32,
+    pub value: String,
+}
+
+/// Contents of a `motion3.json`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Motion {
+    pub duration: f32,
+    pub looped: bool,
+    pub fade_in_seconds: f32,
+    pub fade_out_seconds: f32,
+    pub curves: Vec<MotionCurve>,
+    /// Events, sorted by time.
+    pub user_data: Vec<MotionUserData>,
+}
+
+impl Motion {
+    pub fn from_json(json: &str) -> Result<Self, MotionError> {
+        let json: Motion3Json = serde_json::from_str(json)?;
+        let mut user_data: Vec<MotionUserData> = json
+            .user_data
+            .into_iter()
+            .map(|u| MotionUserData {
+                time: u.time,
+                value: u.value,
+            })
+            .collect();
+        user_data.sort_by(|a, b| a.time.total_cmp(&b.time));
+
+        Ok(Self {
+            duration: json.meta.duration.max(0.0),
+            looped: json.meta.r#loop,
+            fade_in_seconds: json.meta.fade_in_time.filter(|s| *s >= 0.0).unwrap_or(DEFAULT_FADE_SECONDS),
+            fade_out_seconds: json.meta.fade_out_time.filter(|s| *s >= 0.0).unwrap_or(DEFAULT_FADE_SECONDS),
+            curves: json
+                .curves
+                .into_iter()
+                .map(MotionCurve::parse)
+                .collect::<Result<_, _>>()?,
+            user_data,
+        })
+    }
+
+    /// A motion made of the given curves, lasting until their last keyframe.
+    pub fn from_curves(curves: Vec<MotionCurve>, fade_seconds: f32) -> Self {
+        let duration = curves
+            .iter()
+            .flat_map(|c| c.segments.last().map(|s| s.end().0).or(Some(c.start.0)))
+            .fold(0.0, f32::max);
+        Self {
+            duration,
+            looped: false,
+            fade_in_seconds: fade_seconds,
+            fade_out_seconds: fade_seconds,
+            curves,
+            user_data: Vec::new(),
+        }
+    }
+}
+
+/// A user-data entry crossed during playback.
+#[derive(Debug, Clone, PartialEq)]
+pub struct MotionEvent {
+    pub motion_name: String,
+    pub value: String,
+}
+
+#[derive(Debug, Clone)]
+struct MotionEntry {
+    name: String,
+    motion: Arc<Motion>,
+    looped: bool,
+    elapsed: f32,
+    /// Elapsed time at which the fade out started.
+    fade_out_from: Option<f32>,
+    /// Parameter or part index of each curve, resolved on first use.
+    targets: Option<Vec<Option<usize>>>,
+}
+
+impl MotionEntry {
+    fn motion_time(&self) -> f32 {
+        if self.looped && self.motion.duration > 0.0 {
+            self.elapsed % self.motion.duration
+        } else {
+            self.elapsed.min(self.motion.duration)
+        }
+    }
+
+    fn is_finished(&self) -> bool {
+        match self.fade_out_from {
+            Some(from) => self.elapsed - from >= self.motion.fade_out_seconds,
+            None => !self.looped && self.elapsed >= self.motion.duration,
+        }
+    }
+
+    /// Fade weight from the motion-level or a curve-level fade in / out.
+    fn fade_weight(&self, fade_in: f32, fade_out: f32) -> f32 {
+        let fade_in_weight = if fade_in > 0.0 {
+            ease_sine((self.elapsed / fade_in).min(1.0))
+        } else {
+            1.0
+        };
+        let fade_out_weight = match self.fade_out_from {
+            Some(from) if fade_out > 0.0 => ease_sine(1.0 - ((self.elapsed - from) / fade_out).min(1.0)),
+            Some(_) => 0.0,
+            None if !self.looped && fade_out > 0.0 => {
+                ease_sine(((self.motion.duration - self.elapsed) / fade_out).clamp(0.0, 1.0))
+            }
+            None => 1.0,
+        };
+        fade_in_weight * fade_out_weight
+    }
+
+    /// User data crossed when playback goes from `before` to `after` (elapsed times).
+    fn crossed_events(&self, before: f32, after: f32, events: &mut Vec<MotionEvent>) {
+        let duration = self.motion.duration;
+        let mut push_range = |from: f32, to: f32, inclusive_start: bool| {
+            for user_data in &self.motion.user_data {
+                let after_start = if inclusive_start {
+                    user_data.time >= from
+                } else {
+                    user_data.time > from
+                };
+                if after_start && user_data.time <= to {
+                    events.push(MotionEvent {
+                        motion_name: self.name.clone(),
+                        value: user_data.value.clone(),
+                    });
+                }
+            }
+        };
+
+        if !self.looped || duration <= 0.0 {
+            push_range(before.min(duration), after.min(duration), before == 0.0);
+            return;
+        }
+
+        // Walk every loop iteration crossed in this tick.
+        let mut loop_start = (before / duration).floor() * duration;
+        while loop_start < after {
+            let from = (before - loop_start).max(0.0);
+            let to = (after - loop_start).min(duration);
+            push_range(from, to, before <= loop_start);
+            loop_start += duration;
+        }
+    }
+}
+
+fn ease_sine(t: f32) -> f32 {
+    0.5 - 0.5 * (t.clamp(0.0, 1.0) * std::f32::consts::PI).cos()
+}
+
+/// Plays motions with cross-fading, after the official framework's `CubismMotionQueueManager`.
+///
+/// Starting a motion fades out the ones playing; each curve blends from the current value
+/// towards its own by the fade weight.
+#[derive(Debug, Clone, Default)]
+pub struct MotionPlayer {
+    entries: Vec<MotionEntry>,
+}
+
+impl MotionPlayer {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Starts `motion`, fading out every motion currently playing.
+    pub fn start(&mut self, name: impl Into<String>, motion: Arc<Motion>, looped: Option<bool>) {
+        self.fade_out_all();
+        self.entries.push(MotionEntry {
+            name: name.into(),
+            looped: looped.unwrap_or(motion.looped),
+            motion,
+            elapsed: 0.0,
+            fade_out_from: None,
+            targets: None,
+        });
+    }
+
+    /// Fades out every playing motion.
+    pub fn fade_out_all(&mut self) {
+        for entry in &mut self.entries {
+            entry.fade_out_from.get_or_insert(entry.elapsed);
+        }
+    }
+
+    /// Stops every motion immediately.
+    pub fn stop_all(&mut self) {
+        self.entries.clear();
+    }
+
+    pub fn is_playing(&self) -> bool {
+        !self.entries.is_empty()
+    }
+
+    /// Names of the motions playing or fading out, oldest first.
+    pub fn playing(&self) -> impl Iterator<Item = &str> {
+        self.entries.iter().map(|entry| entry.name.as_str())
+    }
+
+    /// Advances playback, applies the curves and returns the user data crossed during the tick.
+    pub fn update(&mut self, delta_seconds: f32, model_static: &ModelStatic, model_dynamic: &mut ModelDynamic) -> Vec<MotionEvent> {
+        let mut events = Vec::new();
+        let delta_seconds = delta_seconds.max(0.0);
+
+        for entry in &mut self.entries {
+            let before = entry.elapsed;
+            entry.elapsed += delta_seconds;
+            entry.crossed_events(before, entry.elapsed, &mut events);
+            apply(entry, model_static, model_dynamic);
+        }
+
+        self.entries.retain(|entry| !entry.is_finished());
+        events
+    }
+}
+
+fn apply(entry: &mut MotionEntry, model_static: &ModelStatic, model_dynamic: &mut ModelDynamic) {
+    let parameters = model_static.parameters();
+    if entry.targets.is_none() {
+        entry.targets = Some(
+            entry
+                .motion
+                .curves
+                .iter()
+                .map(|curve| match curve.target {
+                    CurveTarget::Parameter => parameters.iter().position(|p| p.id() == curve.id),
+                    CurveTarget::PartOpacity => model_static.parts().iter().position(|p| p.id() == curve.id),
+                    CurveTarget::Model => None,
+                })
+                .collect(),
+        );
+    }
+
+    let entry = &*entry;
+    let time = entry.motion_time();
+    let motion = &entry.motion;
+    let targets = entry.targets.as_deref().unwrap_or_default();
+
+    for (curve, &index) in motion.curves.iter().zip(targets) {
+        let Some(index) = index else {
+            continue;
+        };
+        let fade_in = curve.fade_in_seconds.unwrap_or(motion.fade_in_seconds);
+        let fade_out = curve.fade_out_seconds.unwrap_or(motion.fade_out_seconds);
+        let weight = entry.fade_weight(fade_in, fade_out);
+        let value = curve.value_at(time);
+
+        let (current, (min, max)) = match curve.target {
+            CurveTarget::Parameter => (
+                &mut model_dynamic.parameter_values_mut()[index],
+                parameters[index].value_range(),
+            ),
+            CurveTarget::PartOpacity => (&mut model_dynamic.part_opacities_mut()[index], (0.0, 1.0)),
+            CurveTarget::Model => continue,
+        };
+        *current = (*current + (value - *current) * weight).clamp(min, max);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MOTION: &str = r#"{
+        "Version": 3,
+        "Meta": { "Duration": 4.0, "Loop": true, "FadeInTime": 0.5 },
+        "Curves": [
+            {
+                "Target": "Parameter",
+                "Id": "ParamAngleX",
+                "FadeOutTime": 0.25,
+                "Segments": [0, 0, 0, 1, 10, 1, 1, 1, 2, 0, 2, 0, 2, 3, 10, 3, 4, -10]
+            },
+            { "Target": "Model", "Id": "EyeBlink", "Segments": [0, 1] }
+        ],
+        "UserData": [{ "Time": 3.5, "Value": "late" }, { "Time": 0.0, "Value": "start" }]
+    }"#;
+
+    fn entry(motion: Motion, looped: bool) -> MotionEntry {
+        MotionEntry {
+            name: "test".to_string(),
+            motion: Arc::new(motion),
+            looped,
+            elapsed: 0.0,
+            fade_out_from: None,
+            targets: None,
+        }
+    }
+
+    fn events(entry: &MotionEntry, before: f32, after: f32) -> Vec<String> {
+        let mut events = Vec::new();
+        entry.crossed_events(before, after, &mut events);
+        events.into_iter().map(|event| event.value).collect()
+    }
+
+    #[test]
+    fn parses_every_segment_type() {
+        let motion = Motion::from_json(MOTION).unwrap();
+        assert_eq!((motion.duration, motion.looped), (4.0, true));
+        assert_eq!((motion.fade_in_seconds, motion.fade_out_seconds), (0.5, DEFAULT_FADE_SECONDS));
+        assert_eq!(motion.user_data[0].value, "start");
+
+        let curve = &motion.curves[0];
+        assert_eq!(curve.fade_out_seconds, Some(0.25));
+        assert_eq!(
+            curve.segments,
+            [
+                Segment::Linear { end: (1.0, 10.0) },
+                Segment::Bezier {
+                    control1: (1.0, 1.0),
+                    control2: (2.0, 0.0),
+                    end: (2.0, 0.0),
+                },
+                Segment::Stepped { end: (3.0, 10.0) },
+                Segment::InverseStepped { end: (4.0, -10.0) },
+            ]
+        );
+        assert_eq!(motion.curves[1].target, CurveTarget::Model);
+        assert!(motion.curves[1].segments.is_empty());
+    }
+
+    #[test]
+    fn evaluates_every_segment_type() {
+        let curve = &Motion::from_json(MOTION).unwrap().curves[0];
+        assert_eq!(curve.value_at(-1.0), 0.0);
+        assert_eq!(curve.value_at(0.5), 5.0);
+        // The bezier starts at 10 and falls towards 0.
+        let bezier = curve.value_at(1.5);
+        assert!(bezier > 0.0 && bezier < 10.0, "{bezier}");
+        assert!(curve.value_at(1.99) < 0.1);
+        assert_eq!(curve.value_at(2.9), 0.0);
+        assert_eq!(curve.value_at(3.01), -10.0);
+        assert_eq!(curve.value_at(9.0), -10.0);
+    }
+
+    #[test]
+    fn rejects_truncated_segments() {
+        let json = r#"{ "Meta": { "Duration": 1 }, "Curves": [
+            { "Target": "Parameter", "Id": "ParamA", "Segments": [0, 0, 1, 0.5, 1, 0.5] }
+        ] }"#;
+        assert!(matches!(Motion::from_json(json), Err(MotionError::InvalidSegments(id)) if id == "ParamA"));
+        let unknown_type = json.replace("[0, 0, 1, 0.5, 1, 0.5]", "[0, 0, 7, 1, 1]");
+        assert!(Motion::from_json(&unknown_type).is_err());
+    }
+
+    #[test]
+    fn fades_in_and_out_with_a_sine_ease() {
+        let mut motion = Motion::from_curves(vec![MotionCurve::linear("ParamA", &[(0.0, 0.0), (4.0, 1.0)])], 1.0);
+        motion.duration = 4.0;
+        let mut entry = entry(motion, false);
+
+        let weight_at = |entry: &mut MotionEntry, elapsed: f32| {
+            entry.elapsed = elapsed;
+            entry.fade_weight(1.0, 1.0)
+        };
+        assert_eq!(weight_at(&mut entry, 0.0), 0.0);
+        assert!((weight_at(&mut entry, 0.5) - 0.5).abs() < 1e-6);
+        assert_eq!(weight_at(&mut entry, 2.0), 1.0);
+        assert!((weight_at(&mut entry, 3.5) - 0.5).abs() < 1e-6);
+
+        entry.fade_out_from = Some(2.0);
+        assert!((weight_at(&mut entry, 2.5) - 0.5).abs() < 1e-6);
+        assert_eq!(weight_at(&mut entry, 3.0), 0.0);
+        assert!(entry.is_finished());
+    }
+
+    #[test]
+    fn events_fire_once_per_loop_including_across_the_wrap() {
+        let entry = entry(Motion::from_json(MOTION).unwrap(), true);
+
+        assert_eq!(events(&entry, 0.0, 0.1), ["start"]);
+        assert_eq!(events(&entry, 0.1, 3.0), Vec::<String>::new());
+        // Crossing the loop end fires the late event, then the start of the next iteration.
+        assert_eq!(events(&entry, 3.0, 4.2), ["late", "start"]);
+        // A tick spanning more than one loop fires every iteration's events.
+        assert_eq!(events(&entry, 3.0, 8.5), ["late", "start", "late", "start"]);
+    }
+
+    #[test]
+    fn events_of_a_finished_motion_do_not_repeat() {
+        let entry = entry(Motion::from_json(MOTION).unwrap(), false);
+        assert_eq!(events(&entry, 3.0, 5.0), ["late"]);
+        assert_eq!(events(&entry, 5.0, 6.0), Vec::<String>::new());
+    }
+}
@@ -0,0 +1,158 @@
+use crate::core::{DrawableIndex, DynamicDrawableFlagSet, DynamicDrawableFlags, ModelDynamic};
+
+/// Source of the order drawables are rendered in.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum DrawOrderMode {
+    /// Core-computed render orders, which also account for part draw orders.
+    #[default]
+    RenderOrder,
+    /// Drawable draw orders, sorted every time they change.
+    DrawOrder,
+}
+
+/// Drawable indices in render order, recomputed only when Core flags an order change.
+///
+/// [`RenderOrderCache::update`] must be called after [`ModelDynamic::update`] and before
+/// [`ModelDynamic::reset_drawable_dynamic_flags`], as it relies on the "did change" flags.
+#[derive(Debug, Clone, Default)]
+pub struct RenderOrderCache {
+    mode: DrawOrderMode,
+    sorted: Vec<DrawableIndex>,
+    valid: bool,
+}
+
+impl RenderOrderCache {
+    pub fn new(mode: DrawOrderMode) -> Self {
+        Self {
+            mode,
+            sorted: Vec::new(),
+            valid: false,
+        }
+    }
+
+    pub fn mode(&self) -> DrawOrderMode {
+        self.mode
+    }
+    pub fn set_mode(&mut self, mode: DrawOrderMode) {
+        if self.mode != mode {
+            self.mode = mode;
+            self.invalidate();
+        }
+    }
+
+    /// Forces a recomputation on the next [`RenderOrderCache::update`].
+    pub fn invalidate(&mut self) {
+        self.valid = false;
+    }
+
+    /// Drawable indices from first to last rendered, as of the last update.
+    pub fn sorted(&self) -> &[DrawableIndex] {
+        &self.sorted
+    }
+
+    /// Refreshes the order if it changed and returns it.
+    pub fn update(&mut self, model_dynamic: &ModelDynamic) -> &[DrawableIndex] {
+        self.refresh(
+            model_dynamic.drawable_dynamic_flagsets(),
+            model_dynamic.drawable_render_orders(),
+            model_dynamic.drawable_draw_orders(),
+        )
+    }
+
+    fn refresh(
+        &mut self,
+        flagsets: &[DynamicDrawableFlagSet],
+        render_orders: &[i32],
+        draw_orders: &[i32],
+    ) -> &[DrawableIndex] {
+        let changed_flag = match self.mode {
+            DrawOrderMode::RenderOrder => DynamicDrawableFlags::RenderOrderDidChange,
+            DrawOrderMode::DrawOrder => DynamicDrawableFlags::DrawOrderDidChange,
+        };
+        let changed = flagsets.iter().any(|flagset| flagset.contains(changed_flag));
+
+        if !self.valid || changed || self.sorted.len() != flagsets.len() {
+            self.sorted = match self.mode {
+                DrawOrderMode::RenderOrder => {
+                    invert_render_orders(render_orders).unwrap_or_else(|| sort_by_orders(render_orders))
+                }
+                DrawOrderMode::DrawOrder => sort_by_orders(draw_orders),
+            };
+            self.valid = true;
+        }
+
+        &self.sorted
+    }
+}
+
+/// Render orders are a permutation (`render_orders[drawable] = position`), so they can be
+/// inverted in linear time. Returns `None` if they are not a valid permutation.
+fn invert_render_orders(render_orders: &[i32]) -> Option<Vec<DrawableIndex>> {
+    let mut sorted = vec![None; render_orders.len()];
+    for (drawable, &position) in render_orders.iter().enumerate() {
+        let slot = sorted.get_mut(usize::try_from(position).ok()?)?;
+        if slot.is_some() {
+            return None;
+        }
+        *slot = Some(DrawableIndex::from(drawable));
+    }
+    sorted.into_iter().collect()
+}
+
+fn sort_by_orders(orders: &[i32]) -> Vec<DrawableIndex> {
+    let mut sorted: Vec<usize> = (0..orders.len()).collect();
+    sorted.sort_by_key(|&i| orders[i]);
+    sorted.into_iter().map(DrawableIndex::from).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn indices(indices: &[u64]) -> Vec<DrawableIndex> {
+        indices.iter().map(|&i| DrawableIndex(i)).collect()
+    }
+
+    #[test]
+    fn render_orders_are_inverted() {
+        assert_eq!(invert_render_orders(&[2, 0, 1]), Some(indices(&[1, 2, 0])));
+        assert_eq!(invert_render_orders(&[]), Some(Vec::new()));
+    }
+
+    #[test]
+    fn invalid_permutations_fall_back_to_sorting() {
+        assert_eq!(invert_render_orders(&[0, 0, 1]), None);
+        assert_eq!(invert_render_orders(&[0, 3, 1]), None);
+        assert_eq!(invert_render_orders(&[-1, 0, 1]), None);
+        assert_eq!(sort_by_orders(&[5, -1, 5, 2]), indices(&[1, 3, 0, 2]));
+    }
+
+    #[test]
+    fn order_is_recomputed_only_when_core_flags_a_change() {
+        let mut cache = RenderOrderCache::new(DrawOrderMode::RenderOrder);
+        let unchanged = [DynamicDrawableFlagSet::default(); 3];
+        let mut changed = unchanged;
+        changed[1] = DynamicDrawableFlags::RenderOrderDidChange.into();
+
+        assert_eq!(cache.refresh(&unchanged, &[0, 1, 2], &[0, 0, 0]), indices(&[0, 1, 2]));
+        // Without the flag the cached order is kept, even though the orders moved.
+        assert_eq!(cache.refresh(&unchanged, &[2, 1, 0], &[0, 0, 0]), indices(&[0, 1, 2]));
+        // A draw order change does not affect render orders.
+        let draw_order_changed = [DynamicDrawableFlags::DrawOrderDidChange.into(); 3];
+        assert_eq!(cache.refresh(&draw_order_changed, &[2, 1, 0], &[0, 0, 0]), indices(&[0, 1, 2]));
+        assert_eq!(cache.refresh(&changed, &[2, 1, 0], &[0, 0, 0]), indices(&[2, 1, 0]));
+    }
+
+    #[test]
+    fn mode_change_and_invalidate_force_a_recomputation() {
+        let mut cache = RenderOrderCache::new(DrawOrderMode::RenderOrder);
+        let unchanged = [DynamicDrawableFlagSet::default(); 3];
+        cache.refresh(&unchanged, &[0, 1, 2], &[30, 10, 20]);
+
+        cache.set_mode(DrawOrderMode::DrawOrder);
+        assert_eq!(cache.refresh(&unchanged, &[0, 1, 2], &[30, 10, 20]), indices(&[1, 2, 0]));
+
+        cache.invalidate();
+        assert_eq!(cache.refresh(&unchanged, &[0, 1, 2], &[10, 20, 30]), indices(&[0, 1, 2]));
+    }
+}
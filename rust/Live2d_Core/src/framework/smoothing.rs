@@ -0,0 +1,195 @@
+use crate::core::{ModelDynamic, ModelStatic};
+
+/// Filter of a smoothed parameter.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub enum SmoothingFilter {
+    /// Follows the input directly (still subject to the velocity limit).
+    #[default]
+    None,
+    /// First-order low-pass with the given time constant.
+    Exponential { time_constant: f32 },
+    /// Critically damped spring reaching the input in roughly `smooth_time` without overshoot.
+    Spring { smooth_time: f32 },
+}
+
+/// Smoothing of one parameter.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct SmoothingConfig {
+    pub filter: SmoothingFilter,
+    /// Maximum change per second, applied after the filter.
+    pub max_velocity: Option<f32>,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct SmoothedParameter {
+    config: SmoothingConfig,
+    value: f32,
+    velocity: f32,
+    initialized: bool,
+}
+
+/// Per-parameter smoothing, run last so every source writing a parameter goes through it.
+///
+/// Each frame, the value written by the earlier stages is taken as the target and replaced by
+/// the smoothed value.
+#[derive(Debug, Clone)]
+pub struct ParameterSmoother {
+    parameters: Vec<Option<SmoothedParameter>>,
+}
+
+impl ParameterSmoother {
+    pub fn new(model_static: &ModelStatic) -> Self {
+        Self {
+            parameters: vec![None; model_static.parameters().len()],
+        }
+    }
+
+    /// Smooths the parameter `id`; returns `false` if the model does not have it.
+    pub fn set(&mut self, model_static: &ModelStatic, id: &str, config: SmoothingConfig) -> bool {
+        let Some(index) = model_static.parameters().iter().position(|p| p.id() == id) else {
+            return false;
+        };
+        let slot = &mut self.parameters[index];
+        match slot {
+            Some(parameter) => parameter.config = config,
+            None => {
+                *slot = Some(SmoothedParameter {
+                    config,
+                    value: 0.0,
+                    velocity: 0.0,
+                    initialized: false,
+                })
+            }
+        }
+        true
+    }
+
+    /// Stops smoothing the parameter `id`.
+    pub fn remove(&mut self, model_static: &ModelStatic, id: &str) {
+        if let Some(index) = model_static.parameters().iter().position(|p| p.id() == id) {
+            self.parameters[index] = None;
+        }
+    }
+
+    pub fn clear(&mut self) {
+        self.parameters.iter_mut().for_each(|p| *p = None);
+    }
+
+    pub fn update(&mut self, delta_seconds: f32, model_dynamic: &mut ModelDynamic) {
+        let delta_seconds = delta_seconds.max(0.0);
+        let values = model_dynamic.parameter_values_mut();
+
+        for (value, parameter) in values.iter_mut().zip(&mut self.parameters) {
+            let Some(parameter) = parameter else {
+                continue;
+            };
+            if !parameter.initialized {
+                parameter.value = *value;
+                parameter.velocity = 0.0;
+                parameter.initialized = true;
+                continue;
+            }
+
+            *value = parameter.step(*value, delta_seconds);
+        }
+    }
+}
+
+impl SmoothedParameter {
+    fn step(&mut self, target: f32, dt: f32) -> f32 {
+        let previous = self.value;
+        let mut next = match self.config.filter {
+            SmoothingFilter::None => target,
+            SmoothingFilter::Exponential { time_constant } if time_constant > 0.0 => {
+                previous + (target - previous) * (1.0 - (-dt / time_constant).exp())
+            }
+            SmoothingFilter::Spring { smooth_time } if smooth_time > 0.0 => {
+                // Exact integration of a critically damped spring.
+                let omega = 2.0 / smooth_time;
+                let decay = (-omega * dt).exp();
+                let offset = previous - target;
+                let temp = (self.velocity + omega * offset) * dt;
+                self.velocity = (self.velocity - omega * temp) * decay;
+                target + (offset + temp) * decay
+            }
+            SmoothingFilter::Exponential { .. } | SmoothingFilter::Spring { .. } => target,
+        };
+
+        if let Some(max_velocity) = self.config.max_velocity {
+            let max_velocity = max_velocity.max(0.0);
+            let max_step = max_velocity * dt;
+            next = previous + (next - previous).clamp(-max_step, max_step);
+            // The spring keeps its own velocity; left unclamped it would build up while limited and
+            // overshoot once the limit stops binding.
+            self.velocity = self.velocity.clamp(-max_velocity, max_velocity);
+        }
+
+        if !matches!(self.config.filter, SmoothingFilter::Spring { .. }) && dt > 0.0 {
+            self.velocity = (next - previous) / dt;
+        }
+        self.value = next;
+        next
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parameter(filter: SmoothingFilter, max_velocity: Option<f32>) -> SmoothedParameter {
+        SmoothedParameter {
+            config: SmoothingConfig { filter, max_velocity },
+            value: 0.0,
+            velocity: 0.0,
+            initialized: true,
+        }
+    }
+
+    fn parameter_with(filter: SmoothingFilter) -> SmoothedParameter {
+        parameter(filter, None)
+    }
+
+    #[test]
+    fn velocity_limit_caps_each_step() {
+        let mut parameter = parameter(SmoothingFilter::None, Some(2.0));
+        assert_eq!(parameter.step(10.0, 0.5), 1.0);
+        assert_eq!(parameter.step(10.0, 0.5), 2.0);
+        assert_eq!(parameter.step(-10.0, 0.25), 1.5);
+        assert_eq!(parameter.step(1.6, 0.25), 1.6);
+    }
+
+    #[test]
+    fn exponential_moves_by_the_time_constant() {
+        let mut parameter = parameter_with(SmoothingFilter::Exponential { time_constant: 1.0 });
+        let value = parameter.step(1.0, 1.0);
+        assert!((value - (1.0 - (-1.0f32).exp())).abs() < 1e-6);
+
+        // A non-positive time constant follows the input.
+        let mut parameter = parameter_with(SmoothingFilter::Exponential { time_constant: 0.0 });
+        assert_eq!(parameter.step(1.0, 1.0 / 60.0), 1.0);
+    }
+
+    #[test]
+    fn spring_settles_without_overshoot() {
+        let mut parameter = parameter_with(SmoothingFilter::Spring { smooth_time: 0.3 });
+        let mut value = 0.0;
+        for _ in 0..120 {
+            let next = parameter.step(1.0, 1.0 / 60.0);
+            assert!(next >= value && next <= 1.0);
+            value = next;
+        }
+        assert!((value - 1.0).abs() < 1e-3);
+    }
+
+    #[test]
+    fn limited_spring_keeps_its_velocity_within_the_limit() {
+        let mut parameter = parameter(SmoothingFilter::Spring { smooth_time: 0.1 }, Some(1.0));
+        let dt = 1.0 / 60.0;
+        for _ in 0..600 {
+            let value = parameter.step(2.0, dt);
+            assert!(parameter.velocity.abs() <= 1.0);
+            assert!(value <= 2.0, "overshot to {value}");
+        }
+        assert!((parameter.value - 2.0).abs() < 1e-3);
+    }
+}
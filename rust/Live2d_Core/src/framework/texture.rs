@@ -0,0 +1,329 @@
+use std::io::Cursor;
+use std::path::Path;
+
+use thiserror::Error;
+
+use super::model_settings::ModelSettings;
+use crate::core::TextureIndex;
+
+/// Errors generated when loading textures.
+#[derive(Debug, Error)]
+pub enum TextureError {
+    #[error("Failed to read texture \"{path}\": {source}")]
+    Io {
+        path: String,
+        source: std::io::Error,
+    },
+    #[error("Failed to decode PNG: {0}")]
+    Decode(#[from] png::DecodingError),
+    #[error("Unsupported PNG color type: {0:?}")]
+    UnsupportedColorType(png::ColorType),
+    #[error("Texture too large: {width}x{height}")]
+    TooLarge { width: u32, height: u32 },
+}
+
+/// Byte size of a `width` x `height` RGBA8 image, or `None` if it does not fit in memory.
+fn rgba_size(width: u32, height: u32) -> Option<usize> {
+    let size = u64::from(width).checked_mul(u64::from(height))?.checked_mul(4)?;
+    usize::try_from(size).ok()
+}
+
+/// An RGBA8 image.
+#[derive(Debug, Clone)]
+pub struct TextureImage {
+    pub width: u32,
+    pub height: u32,
+    /// Tightly packed `[r, g, b, a, ...]` rows, top to bottom.
+    pub pixels: Vec<u8>,
+}
+
+impl TextureImage {
+    pub fn byte_size(&self) -> usize {
+        self.pixels.len()
+    }
+
+    /// Decodes a PNG into RGBA8, expanding palette, grayscale and 16-bit images.
+    pub fn from_png(bytes: &[u8]) -> Result<Self, TextureError> {
+        let mut decoder = png::Decoder::new(Cursor::new(bytes));
+        decoder.set_transformations(png::Transformations::normalize_to_color8());
+
+        let mut reader = decoder.read_info()?;
+        let (width, height) = reader.info().size();
+        if rgba_size(width, height).is_none() {
+            return Err(TextureError::TooLarge { width, height });
+        }
+        let mut buffer = vec![0; reader.output_buffer_size()];
+        let info = reader.next_frame(&mut buffer)?;
+        buffer.truncate(info.buffer_size());
+
+        let pixels = match info.color_type {
+            png::ColorType::Rgba => buffer,
+            png::ColorType::Rgb => buffer
+                .chunks_exact(3)
+                .flat_map(|p| [p[0], p[1], p[2], u8::MAX])
+                .collect(),
+            png::ColorType::GrayscaleAlpha => buffer
+                .chunks_exact(2)
+                .flat_map(|p| [p[0], p[0], p[0], p[1]])
+                .collect(),
+            png::ColorType::Grayscale => buffer.iter().flat_map(|&g| [g, g, g, u8::MAX]).collect(),
+            other => return Err(TextureError::UnsupportedColorType(other)),
+        };
+
+        Ok(Self {
+            width: info.width,
+            height: info.height,
+            pixels,
+        })
+    }
+
+    /// Multiplies color channels by alpha, as expected by Cubism's premultiplied blending.
+    pub fn premultiply_alpha(&mut self) {
+        for pixel in self.pixels.chunks_exact_mut(4) {
+            let alpha = u32::from(pixel[3]);
+            for channel in &mut pixel[..3] {
+                *channel = ((u32::from(*channel) * alpha + 127) / 255) as u8;
+            }
+        }
+    }
+
+    /// Halves both dimensions (rounding up) with a 2x2 box filter.
+    ///
+    /// For straight alpha, colors are weighted by alpha so transparent texels do not bleed in.
+    pub fn downsample(&self, premultiplied: bool) -> TextureImage {
+        // The source already fits in memory, so index arithmetic is done in `usize`.
+        let (src_width, src_height) = (self.width as usize, self.height as usize);
+        let width = src_width.div_ceil(2).max(1);
+        let height = src_height.div_ceil(2).max(1);
+        let mut pixels = Vec::with_capacity(width * height * 4);
+
+        for y in 0..height {
+            for x in 0..width {
+                let mut sum = [0u32; 4];
+                for (sx, sy) in [(0, 0), (1, 0), (0, 1), (1, 1)] {
+                    let src_x = (x * 2 + sx).min(src_width - 1);
+                    let src_y = (y * 2 + sy).min(src_height - 1);
+                    let offset = (src_y * src_width + src_x) * 4;
+                    let texel = &self.pixels[offset..offset + 4];
+                    let weight = if premultiplied { 1 } else { u32::from(texel[3]) };
+                    for channel in 0..3 {
+                        sum[channel] += u32::from(texel[channel]) * weight;
+                    }
+                    sum[3] += u32::from(texel[3]);
+                }
+
+                let color_divisor = if premultiplied { 4 } else { sum[3].max(1) };
+                for &channel_sum in &sum[..3] {
+                    pixels.push(((channel_sum + color_divisor / 2) / color_divisor) as u8);
+                }
+                pixels.push(((sum[3] + 2) / 4) as u8);
+            }
+        }
+
+        TextureImage {
+            width: width as u32,
+            height: height as u32,
+            pixels,
+        }
+    }
+}
+
+/// Options for [`TextureLoader`].
+#[derive(Debug, Clone, Copy, Default)]
+pub struct TextureLoadOptions {
+    pub premultiply_alpha: bool,
+    pub generate_mipmaps: bool,
+    /// Upper bound of the total size in bytes of all textures (mipmaps included).
+    /// Textures are halved uniformly until they fit.
+    pub memory_budget: Option<usize>,
+}
+
+/// A decoded texture with its mip chain, ready to upload.
+#[derive(Debug, Clone)]
+pub struct LoadedTexture {
+    pub index: TextureIndex,
+    pub premultiplied_alpha: bool,
+    /// `levels[0]` is the base image; further entries are successive mipmaps down to 1x1.
+    pub levels: Vec<TextureImage>,
+}
+
+impl LoadedTexture {
+    pub fn byte_size(&self) -> usize {
+        self.levels.iter().map(TextureImage::byte_size).sum()
+    }
+}
+
+/// Loads and prepares the textures of a model.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct TextureLoader {
+    options: TextureLoadOptions,
+}
+
+impl TextureLoader {
+    pub fn new(options: TextureLoadOptions) -> Self {
+        Self { options }
+    }
+
+    pub fn options(&self) -> TextureLoadOptions {
+        self.options
+    }
+
+    /// Loads the textures listed in a `model3.json` located in `model_dir`.
+    pub fn load_model_textures(
+        &self,
+        settings: &ModelSettings,
+        model_dir: impl AsRef<Path>,
+    ) -> Result<Vec<LoadedTexture>, TextureError> {
+        let pngs = settings
+            .texture_paths(model_dir)
+            .into_iter()
+            .map(|path| {
+                std::fs::read(&path).map_err(|source| TextureError::Io {
+                    path: path.display().to_string(),
+                    source,
+                })
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        self.load_pngs(pngs.iter().map(Vec::as_slice))
+    }
+
+    /// Loads PNG-encoded textures; the n-th image becomes texture index n.
+    pub fn load_pngs<'a>(
+        &self,
+        pngs: impl IntoIterator<Item = &'a [u8]>,
+    ) -> Result<Vec<LoadedTexture>, TextureError> {
+        let images = pngs
+            .into_iter()
+            .map(TextureImage::from_png)
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(self.prepare(images))
+    }
+
+    /// Applies premultiplication, the memory budget and mipmaps to decoded images.
+    fn prepare(&self, mut images: Vec<TextureImage>) -> Vec<LoadedTexture> {
+        if self.options.premultiply_alpha {
+            images.iter_mut().for_each(TextureImage::premultiply_alpha);
+        }
+
+        if let Some(budget) = self.options.memory_budget {
+            while self.estimated_size(&images) > budget
+                && images.iter().any(|image| image.width > 1 || image.height > 1)
+            {
+                images = images
+                    .iter()
+                    .map(|image| image.downsample(self.options.premultiply_alpha))
+                    .collect();
+            }
+        }
+
+        images
+            .into_iter()
+            .enumerate()
+            .map(|(index, image)| LoadedTexture {
+                index: TextureIndex::from(index),
+                premultiplied_alpha: self.options.premultiply_alpha,
+                levels: self.mip_chain(image),
+            })
+            .collect()
+    }
+
+    fn mip_chain(&self, base: TextureImage) -> Vec<TextureImage> {
+        let mut levels = vec![base];
+        if self.options.generate_mipmaps {
+            while let Some(last) = levels.last().filter(|l| l.width > 1 || l.height > 1) {
+                let next = last.downsample(self.options.premultiply_alpha);
+                levels.push(next);
+            }
+        }
+        levels
+    }
+
+    fn estimated_size(&self, images: &[TextureImage]) -> usize {
+        let base: usize = images.iter().map(TextureImage::byte_size).sum();
+        if self.options.generate_mipmaps {
+            // A full mip chain adds about a third of the base size.
+            base + base / 3
+        } else {
+            base
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn image(width: u32, height: u32, pixel: [u8; 4]) -> TextureImage {
+        TextureImage {
+            width,
+            height,
+            pixels: pixel.repeat((width * height) as usize),
+        }
+    }
+
+    #[test]
+    fn premultiply_scales_colors_by_alpha() {
+        let mut image = TextureImage {
+            width: 3,
+            height: 1,
+            pixels: vec![255, 128, 0, 128, 200, 100, 50, 0, 10, 20, 30, 255],
+        };
+        image.premultiply_alpha();
+        assert_eq!(image.pixels, [128, 64, 0, 128, 0, 0, 0, 0, 10, 20, 30, 255]);
+    }
+
+    #[test]
+    fn downsample_rounds_odd_sizes_up() {
+        let half = image(5, 3, [10, 20, 30, 255]).downsample(true);
+        assert_eq!((half.width, half.height), (3, 2));
+        assert_eq!(half.pixels, [10, 20, 30, 255].repeat(6));
+
+        let line = image(1, 1, [1, 2, 3, 4]).downsample(true);
+        assert_eq!((line.width, line.height, line.byte_size()), (1, 1, 4));
+    }
+
+    #[test]
+    fn straight_alpha_downsample_ignores_transparent_colors() {
+        // Left column opaque red, right column fully transparent green.
+        let source = TextureImage {
+            width: 2,
+            height: 2,
+            pixels: [[255, 0, 0, 255], [0, 255, 0, 0], [255, 0, 0, 255], [0, 255, 0, 0]].concat(),
+        };
+        assert_eq!(source.downsample(false).pixels, [255, 0, 0, 128]);
+        assert_eq!(source.downsample(true).pixels, [128, 128, 0, 128]);
+    }
+
+    #[test]
+    fn memory_budget_halves_every_texture_until_it_fits() {
+        let loader = TextureLoader::new(TextureLoadOptions {
+            memory_budget: Some(5_000),
+            ..TextureLoadOptions::default()
+        });
+        let textures = loader.prepare(vec![image(64, 64, [0; 4]), image(16, 8, [0; 4])]);
+
+        // 64x64 + 16x8 is 16896 bytes, 4224 once both are halved.
+        let sizes: Vec<(u32, u32)> = textures.iter().map(|t| (t.levels[0].width, t.levels[0].height)).collect();
+        assert_eq!(sizes, [(32, 32), (8, 4)]);
+        assert_eq!(textures[1].index, TextureIndex(1));
+    }
+
+    #[test]
+    fn mip_chain_goes_down_to_one_pixel() {
+        let loader = TextureLoader::new(TextureLoadOptions {
+            generate_mipmaps: true,
+            ..TextureLoadOptions::default()
+        });
+        let texture = &loader.prepare(vec![image(8, 3, [0; 4])])[0];
+        let sizes: Vec<(u32, u32)> = texture.levels.iter().map(|l| (l.width, l.height)).collect();
+        assert_eq!(sizes, [(8, 3), (4, 2), (2, 1), (1, 1)]);
+    }
+
+    #[test]
+    fn oversized_dimensions_are_rejected() {
+        assert_eq!(rgba_size(4, 2), Some(32));
+        assert_eq!(rgba_size(u32::MAX, u32::MAX), None);
+    }
+}
@@ -0,0 +1,42 @@
+use serde::Deserialize;
+
+use crate::core::{DrawableIndex, ModelStatic};
+
+/// Contents of a `userdata3.json`: strings attached to art meshes by the artist.
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct ModelUserData {
+    #[serde(default)]
+    pub version: u32,
+    #[serde(default)]
+    pub user_data: Vec<UserDataEntry>,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct UserDataEntry {
+    /// Only `"ArtMesh"` is defined by the format.
+    pub target: String,
+    pub id: String,
+    pub value: String,
+}
+
+impl ModelUserData {
+    pub fn from_json(json: &str) -> Result<Self, serde_json::Error> {
+        serde_json::from_str(json)
+    }
+
+    /// Art mesh entries resolved to drawables; entries for unknown meshes are skipped.
+    pub fn art_mesh_entries<'a>(
+        &'a self,
+        model_static: &'a ModelStatic,
+    ) -> impl Iterator<Item = (DrawableIndex, &'a UserDataEntry)> + 'a {
+        self.user_data
+            .iter()
+            .filter(|entry| entry.target == "ArtMesh")
+            .filter_map(move |entry| {
+                let drawable = model_static.drawables().iter().find(|d| d.id() == entry.id)?;
+                Some((drawable.index(), entry))
+            })
+    }
+}
@@ -0,0 +1,204 @@
+use super::axis_aligned_matrix;
+use crate::core::{CanvasInfo, Vector2};
+
+/// How the model canvas is fitted into the viewport before pan and zoom are applied.
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub enum FitMode {
+    /// The whole canvas is visible; the shorter viewport side may show empty margins.
+    #[default]
+    Contain,
+    /// The canvas fills the viewport; the longer canvas side may be cropped.
+    Cover,
+    /// A fixed number of viewport pixels per canvas pixel.
+    FixedScale(f32),
+}
+
+/// Maps between the coordinate spaces a renderer deals with.
+///
+/// - **Model units:** drawable vertex positions, origin at the canvas origin, Y up.
+/// - **Canvas pixels:** pixels of the canvas authored in the Cubism Editor, origin top-left, Y down.
+/// - **Viewport pixels:** pixels of the render target, origin top-left, Y down.
+/// - **NDC:** normalized device coordinates, `[-1, 1]` on both axes, Y up.
+///
+/// The canvas is fitted into the viewport according to [`FitMode`], centered, scaled by the zoom
+/// factor around the viewport center and finally translated by the pan offset (in viewport pixels).
+#[derive(Debug, Clone, Copy)]
+pub struct ViewTransform {
+    canvas: CanvasInfo,
+    viewport_size: (f32, f32),
+    fit_mode: FitMode,
+    pan: (f32, f32),
+    zoom: f32,
+}
+
+impl ViewTransform {
+    pub fn new(canvas: CanvasInfo, viewport_size: (f32, f32)) -> Self {
+        Self {
+            canvas,
+            viewport_size,
+            fit_mode: FitMode::default(),
+            pan: (0.0, 0.0),
+            zoom: 1.0,
+        }
+    }
+
+    pub fn canvas(&self) -> CanvasInfo {
+        self.canvas
+    }
+    pub fn viewport_size(&self) -> (f32, f32) {
+        self.viewport_size
+    }
+    pub fn set_viewport_size(&mut self, viewport_size: (f32, f32)) {
+        self.viewport_size = viewport_size;
+    }
+    pub fn fit_mode(&self) -> FitMode {
+        self.fit_mode
+    }
+    pub fn set_fit_mode(&mut self, fit_mode: FitMode) {
+        self.fit_mode = fit_mode;
+    }
+    /// Pan offset in viewport pixels.
+    pub fn pan(&self) -> (f32, f32) {
+        self.pan
+    }
+    pub fn set_pan(&mut self, pan: (f32, f32)) {
+        self.pan = pan;
+    }
+    pub fn zoom(&self) -> f32 {
+        self.zoom
+    }
+    pub fn set_zoom(&mut self, zoom: f32) {
+        self.zoom = zoom.max(0.0);
+    }
+
+    /// Changes the zoom while keeping the point under `anchor` (viewport pixels) in place.
+    pub fn zoom_around(&mut self, anchor: Vector2, zoom: f32) {
+        let model_point = self.viewport_to_model(anchor);
+        self.set_zoom(zoom);
+        let moved = self.model_to_viewport(model_point);
+        self.pan.0 += anchor.x - moved.x;
+        self.pan.1 += anchor.y - moved.y;
+    }
+
+    /// Viewport pixels per canvas pixel resulting from the fit mode alone.
+    pub fn fit_scale(&self) -> f32 {
+        let (canvas_width, canvas_height) = self.canvas.size_in_pixels;
+        let (viewport_width, viewport_height) = self.viewport_size;
+
+        if canvas_width <= 0.0 || canvas_height <= 0.0 {
+            return 1.0;
+        }
+
+        let sx = viewport_width / canvas_width;
+        let sy = viewport_height / canvas_height;
+
+        match self.fit_mode {
+            FitMode::Contain => sx.min(sy),
+            FitMode::Cover => sx.max(sy),
+            FitMode::FixedScale(scale) => scale,
+        }
+    }
+
+    /// Viewport pixels per canvas pixel, including zoom.
+    pub fn scale(&self) -> f32 {
+        self.fit_scale() * self.zoom
+    }
+
+    /// Viewport pixels per model unit.
+    pub fn pixels_per_unit(&self) -> f32 {
+        self.scale() * self.canvas.pixels_per_unit
+    }
+
+    pub fn model_to_canvas(&self, point: Vector2) -> Vector2 {
+        let (origin_x, origin_y) = self.canvas.origin_in_pixels;
+        let ppu = self.canvas.pixels_per_unit;
+        Vector2 {
+            x: origin_x + point.x * ppu,
+            y: origin_y - point.y * ppu,
+        }
+    }
+    pub fn canvas_to_model(&self, point: Vector2) -> Vector2 {
+        let (origin_x, origin_y) = self.canvas.origin_in_pixels;
+        let inv_ppu = safe_recip(self.canvas.pixels_per_unit);
+        Vector2 {
+            x: (point.x - origin_x) * inv_ppu,
+            y: (origin_y - point.y) * inv_ppu,
+        }
+    }
+
+    pub fn canvas_to_viewport(&self, point: Vector2) -> Vector2 {
+        let (canvas_width, canvas_height) = self.canvas.size_in_pixels;
+        let (viewport_width, viewport_height) = self.viewport_size;
+        let scale = self.scale();
+        Vector2 {
+            x: viewport_width * 0.5 + (point.x - canvas_width * 0.5) * scale + self.pan.0,
+            y: viewport_height * 0.5 + (point.y - canvas_height * 0.5) * scale + self.pan.1,
+        }
+    }
+    pub fn viewport_to_canvas(&self, point: Vector2) -> Vector2 {
+        let (canvas_width, canvas_height) = self.canvas.size_in_pixels;
+        let (viewport_width, viewport_height) = self.viewport_size;
+        let inv_scale = safe_recip(self.scale());
+        Vector2 {
+            x: canvas_width * 0.5 + (point.x - viewport_width * 0.5 - self.pan.0) * inv_scale,
+            y: canvas_height * 0.5 + (point.y - viewport_height * 0.5 - self.pan.1) * inv_scale,
+        }
+    }
+
+    pub fn viewport_to_ndc(&self, point: Vector2) -> Vector2 {
+        let (viewport_width, viewport_height) = self.viewport_size;
+        Vector2 {
+            x: point.x * 2.0 * safe_recip(viewport_width) - 1.0,
+            y: 1.0 - point.y * 2.0 * safe_recip(viewport_height),
+        }
+    }
+    pub fn ndc_to_viewport(&self, point: Vector2) -> Vector2 {
+        let (viewport_width, viewport_height) = self.viewport_size;
+        Vector2 {
+            x: (point.x + 1.0) * 0.5 * viewport_width,
+            y: (1.0 - point.y) * 0.5 * viewport_height,
+        }
+    }
+
+    pub fn model_to_viewport(&self, point: Vector2) -> Vector2 {
+        self.canvas_to_viewport(self.model_to_canvas(point))
+    }
+    pub fn viewport_to_model(&self, point: Vector2) -> Vector2 {
+        self.canvas_to_model(self.viewport_to_canvas(point))
+    }
+    pub fn model_to_ndc(&self, point: Vector2) -> Vector2 {
+        self.viewport_to_ndc(self.model_to_viewport(point))
+    }
+    pub fn ndc_to_model(&self, point: Vector2) -> Vector2 {
+        self.viewport_to_model(self.ndc_to_viewport(point))
+    }
+
+    /// Column-major 4x4 matrix mapping model units to viewport pixels.
+    pub fn model_to_viewport_matrix(&self) -> [f32; 16] {
+        let ppu = self.pixels_per_unit();
+        let translation = self.model_to_viewport(Vector2 { x: 0.0, y: 0.0 });
+        axis_aligned_matrix(ppu, -ppu, translation.x, translation.y)
+    }
+
+    /// Column-major 4x4 matrix mapping model units to NDC, suitable as a vertex shader MVP.
+    pub fn model_to_ndc_matrix(&self) -> [f32; 16] {
+        let (viewport_width, viewport_height) = self.viewport_size;
+        let ppu = self.pixels_per_unit();
+        let translation = self.model_to_ndc(Vector2 { x: 0.0, y: 0.0 });
+        axis_aligned_matrix(
+            ppu * 2.0 * safe_recip(viewport_width),
+            ppu * 2.0 * safe_recip(viewport_height),
+            translation.x,
+            translation.y,
+        )
+    }
+}
+
+/// Reciprocal that maps degenerate (zero-sized) inputs to zero instead of infinity.
+fn safe_recip(value: f32) -> f32 {
+    if value == 0.0 {
+        0.0
+    } else {
+        value.recip()
+    }
+}
@@ -0,0 +1,294 @@
+use pinyin::ToPinyin;
+
+use super::motion::{Motion, MotionCurve};
+
+/// Parameter id of the mouth opening.
+pub const PARAM_MOUTH_OPEN_Y: &str = "ParamMouthOpenY";
+/// Parameter id of the mouth shape (-1 rounded, 1 wide / smiling).
+pub const PARAM_MOUTH_FORM: &str = "ParamMouthForm";
+
+/// Openness kept between two syllables, so consecutive equal vowels stay visible.
+const SYLLABLE_GAP_OPENNESS: f32 = 0.3;
+/// Pause after clause punctuation, in characters.
+const PUNCTUATION_PAUSE_CHARS: f32 = 2.0;
+
+/// Mouth shape of a spoken vowel.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Viseme {
+    A,
+    I,
+    U,
+    E,
+    O,
+    Closed,
+}
+
+impl Viseme {
+    /// `(ParamMouthOpenY, ParamMouthForm)` of the shape.
+    pub fn mouth(&self) -> (f32, f32) {
+        match self {
+            Viseme::A => (1.0, 0.0),
+            Viseme::I => (0.3, 1.0),
+            Viseme::U => (0.3, -1.0),
+            Viseme::E => (0.6, 0.5),
+            Viseme::O => (0.8, -0.6),
+            Viseme::Closed => (0.0, 0.0),
+        }
+    }
+
+    fn of_vowel(vowel: char) -> Option<Viseme> {
+        match vowel {
+            'a' => Some(Viseme::A),
+            'i' | 'y' => Some(Viseme::I),
+            'u' | 'v' | 'ü' => Some(Viseme::U),
+            'e' => Some(Viseme::E),
+            'o' => Some(Viseme::O),
+            _ => None,
+        }
+    }
+
+    /// Vowel of a pinyin syllable: the main vowel of its final (`a` > `o` > `e`, else the last
+    /// of `i`/`u`/`ü`).
+    fn of_pinyin(syllable: &str) -> Viseme {
+        ['a', 'o', 'e']
+            .into_iter()
+            .find(|&vowel| syllable.contains(vowel))
+            .and_then(Viseme::of_vowel)
+            .or_else(|| syllable.chars().rev().find_map(Viseme::of_vowel))
+            .unwrap_or(Viseme::Closed)
+    }
+
+    /// Vowel of a kana; `None` for characters that are not kana.
+    fn of_kana(kana: char) -> Option<Viseme> {
+        // Katakana mirror hiragana 0x60 code points higher.
+        let hiragana = match kana {
+            '\u{30A1}'..='\u{30F6}' => char::from_u32(kana as u32 - 0x60)?,
+            _ => kana,
+        };
+        const ROWS: [(&str, Viseme); 6] = [
+            ("あかさたなはまやらわがざだばぱぁゃゎ", Viseme::A),
+            ("いきしちにひみりぎじぢびぴぃゐ", Viseme::I),
+            ("うくすつぬふむゆるぐずづぶぷぅゅゔ", Viseme::U),
+            ("えけせてねへめれげぜでべぺぇゑ", Viseme::E),
+            ("おこそとのほもよろをごぞどぼぽぉょ", Viseme::O),
+            ("んっ", Viseme::Closed),
+        ];
+        ROWS.iter()
+            .find(|(row, _)| row.contains(hiragana))
+            .map(|&(_, viseme)| viseme)
+    }
+}
+
+/// A viseme starting at `time`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct VisemeKeyframe {
+    pub time: f32,
+    pub viseme: Viseme,
+    /// Mouth opening reached at `time`; lower than the viseme's own at syllable boundaries.
+    pub mouth_open: f32,
+    pub mouth_form: f32,
+}
+
+/// Mouth keyframes for a piece of text, as linear segments.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct VisemeTimeline {
+    pub keyframes: Vec<VisemeKeyframe>,
+    pub duration: f32,
+}
+
+impl VisemeTimeline {
+    /// Builds the timeline of `text` revealed at `chars_per_second`.
+    ///
+    /// Chinese characters use the final of their pinyin, kana their vowel row, and Latin text its
+    /// vowel letters; `m`, `b`, `p`, `ん`, `っ` and pauses close the mouth.
+    pub fn from_text(text: &str, chars_per_second: f32) -> Self {
+        let char_seconds = 1.0 / chars_per_second.max(f32::EPSILON);
+        let mut builder = TimelineBuilder::default();
+
+        for c in text.chars() {
+            if let Some(pinyin) = c.to_pinyin() {
+                builder.syllable(Viseme::of_pinyin(pinyin.plain()), char_seconds);
+            } else if c == 'ー' {
+                builder.hold(char_seconds);
+            } else if let Some(viseme) = Viseme::of_kana(c) {
+                builder.syllable(viseme, char_seconds);
+            } else if c.is_ascii_alphabetic() {
+                let lower = c.to_ascii_lowercase();
+                match Viseme::of_vowel(lower) {
+                    Some(viseme) => builder.syllable(viseme, char_seconds),
+                    None if matches!(lower, 'm' | 'b' | 'p') => builder.syllable(Viseme::Closed, char_seconds),
+                    // Other consonants blend into the surrounding vowels.
+                    None => builder.hold(char_seconds),
+                }
+            } else if is_pause(c) {
+                builder.syllable(Viseme::Closed, char_seconds * PUNCTUATION_PAUSE_CHARS);
+            } else if c.is_whitespace() {
+                builder.syllable(Viseme::Closed, char_seconds);
+            } else {
+                // Digits, symbols, emoji and unknown scripts: keep the rhythm with a neutral mouth.
+                builder.syllable(Viseme::E, char_seconds);
+            }
+        }
+
+        builder.finish()
+    }
+
+    /// Keyframes `(time, value)` of `ParamMouthOpenY` and `ParamMouthForm`, in that order.
+    pub fn parameter_tracks(&self) -> [(&'static str, Vec<(f32, f32)>); 2] {
+        [
+            (
+                PARAM_MOUTH_OPEN_Y,
+                self.keyframes.iter().map(|k| (k.time, k.mouth_open)).collect(),
+            ),
+            (
+                PARAM_MOUTH_FORM,
+                self.keyframes.iter().map(|k| (k.time, k.mouth_form)).collect(),
+            ),
+        ]
+    }
+
+    /// A motion playing [`Self::parameter_tracks`], for a [`MotionPlayer`](super::MotionPlayer).
+    pub fn to_motion(&self, fade_seconds: f32) -> Motion {
+        let curves = self
+            .parameter_tracks()
+            .into_iter()
+            .map(|(id, keyframes)| MotionCurve::linear(id, &keyframes))
+            .collect();
+        let mut motion = Motion::from_curves(curves, fade_seconds);
+        motion.duration = motion.duration.max(self.duration);
+        motion
+    }
+}
+
+fn is_pause(c: char) -> bool {
+    matches!(
+        c,
+        ',' | '.' | '!' | '?' | ';' | ':' | '，' | '。' | '！' | '？' | '；' | '：' | '、' | '…' | '\n'
+    )
+}
+
+#[derive(Default)]
+struct TimelineBuilder {
+    keyframes: Vec<VisemeKeyframe>,
+    time: f32,
+}
+
+impl TimelineBuilder {
+    fn push(&mut self, time: f32, viseme: Viseme, openness_scale: f32) {
+        let (open, form) = viseme.mouth();
+        self.keyframes.push(VisemeKeyframe {
+            time,
+            viseme,
+            mouth_open: open * openness_scale,
+            mouth_form: form,
+        });
+    }
+
+    /// A syllable: the mouth eases from a partly closed transition into the shape and holds it.
+    fn syllable(&mut self, viseme: Viseme, seconds: f32) {
+        let start = self.time;
+        if viseme == Viseme::Closed {
+            if self.keyframes.last().map(|k| k.viseme) != Some(Viseme::Closed) {
+                self.push(start, Viseme::Closed, 1.0);
+            }
+        } else {
+            match self.keyframes.last() {
+                Some(last) if last.viseme != Viseme::Closed => self.push(start, viseme, SYLLABLE_GAP_OPENNESS),
+                // Stay closed through a pause: open only from the start of this syllable.
+                Some(last) if last.time < start => self.push(start, Viseme::Closed, 1.0),
+                Some(_) => {}
+                None => self.push(start, Viseme::Closed, 1.0),
+            }
+            self.push(start + seconds * 0.3, viseme, 1.0);
+            self.push(start + seconds * 0.8, viseme, 1.0);
+        }
+        self.time += seconds;
+    }
+
+    /// Extends the current shape.
+    fn hold(&mut self, seconds: f32) {
+        self.time += seconds;
+    }
+
+    fn finish(mut self) -> VisemeTimeline {
+        if self.keyframes.last().is_some_and(|k| k.viseme != Viseme::Closed) {
+            let end = self.time;
+            self.push(end, Viseme::Closed, 1.0);
+        }
+        VisemeTimeline {
+            duration: self.time,
+            keyframes: self.keyframes,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn keyframes(text: &str) -> Vec<(f32, Viseme, f32)> {
+        VisemeTimeline::from_text(text, 1.0)
+            .keyframes
+            .iter()
+            .map(|k| (k.time, k.viseme, k.mouth_open))
+            .collect()
+    }
+
+    #[test]
+    fn consecutive_vowels_pass_through_a_partly_closed_mouth() {
+        use Viseme::*;
+        assert_eq!(
+            keyframes("ai"),
+            [
+                (0.0, Closed, 0.0),
+                (0.3, A, 1.0),
+                (0.8, A, 1.0),
+                (1.0, I, 0.3 * SYLLABLE_GAP_OPENNESS),
+                (1.3, I, 0.3),
+                (1.8, I, 0.3),
+                (2.0, Closed, 0.0),
+            ]
+        );
+    }
+
+    #[test]
+    fn mouth_stays_closed_during_a_pause_between_vowels() {
+        use Viseme::*;
+        // The comma pauses for two characters and the space for one, so the second `a` starts at 4.
+        assert_eq!(
+            keyframes("a, a"),
+            [
+                (0.0, Closed, 0.0),
+                (0.3, A, 1.0),
+                (0.8, A, 1.0),
+                (1.0, Closed, 0.0),
+                (4.0, Closed, 0.0),
+                (4.3, A, 1.0),
+                (4.8, A, 1.0),
+                (5.0, Closed, 0.0),
+            ]
+        );
+    }
+
+    #[test]
+    fn vowels_are_read_from_pinyin_kana_and_latin() {
+        let visemes = |text: &str| -> Vec<Viseme> {
+            let timeline = VisemeTimeline::from_text(text, 1.0);
+            let mut visemes: Vec<Viseme> = timeline.keyframes.iter().map(|k| k.viseme).collect();
+            visemes.dedup();
+            visemes
+        };
+        use Viseme::*;
+        assert_eq!(visemes("你好"), [Closed, I, A, Closed]);
+        assert_eq!(visemes("カー"), [Closed, A, Closed]);
+        assert_eq!(visemes("ama"), [Closed, A, Closed, A, Closed]);
+        assert_eq!(Viseme::of_pinyin("xiong"), O);
+        assert_eq!(Viseme::of_pinyin("lü"), U);
+    }
+
+    #[test]
+    fn empty_text_gives_an_empty_timeline() {
+        assert_eq!(VisemeTimeline::from_text("", 10.0), VisemeTimeline::default());
+        assert_eq!(VisemeTimeline::from_text("ー", 1.0).duration, 1.0);
+    }
+}
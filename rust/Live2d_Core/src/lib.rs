@@ -9,4 +9,7 @@ pub use sys::*;
 #[cfg(feature = "core")]
 pub mod core;
 
+#[cfg(feature = "framework")]
+pub mod framework;
+
 
@@ -0,0 +1,594 @@
+use crate::frb_generated::StreamSink;
+use live2d_chat_core::{
+    AppConfig, CancelToken, ChatClient, ChatConfig, ChatError, ChatMessage, ChatReply, ChatRole, ContextBudget, ConversationStore, InvokeMode,
+    KeySelection, KeyUsage, ModelProfile, PollConfig, ProviderConfig, ProviderKind,
+};
+use std::collections::BTreeMap;
+use std::sync::{Mutex as StdMutex, OnceLock, PoisonError};
+use std::time::Duration;
+use tokio::sync::Mutex;
+
+fn chat() -> &'static Mutex<Option<ChatClient>> {
+    static CHAT: OnceLock<Mutex<Option<ChatClient>>> = OnceLock::new();
+    CHAT.get_or_init(|| Mutex::new(None))
+}
+
+/// 当前轮询使用的取消令牌；`chat_cancel` 取消后换成新的
+fn cancel_token() -> &'static StdMutex<CancelToken> {
+    static TOKEN: OnceLock<StdMutex<CancelToken>> = OnceLock::new();
+    TOKEN.get_or_init(|| StdMutex::new(CancelToken::new()))
+}
+
+/// 对话参数
+#[derive(Debug, Clone)]
+pub struct ChatConfigDto {
+    /// 模型名称，如 `glm-4-flash`
+    pub language_model: String,
+    /// 角色设定
+    pub system_content: Option<String>,
+    pub temperature: f64,
+    pub top_p: f64,
+    /// 每次请求最多带上的历史消息条数，更早的对话按 `summarize` 概括或丢弃
+    pub max_history: u32,
+    /// 模型的上下文窗口（token），为空时按模型名称选择
+    pub context_tokens: Option<u32>,
+    /// 为回复预留的 token 数，只在 `context_tokens` 不为空时使用
+    pub reply_tokens: Option<u32>,
+    /// 历史超出上下文窗口时概括为摘要，否则直接丢弃
+    pub summarize: bool,
+}
+
+/// 大语言模型后端
+#[derive(Debug, Clone, Copy)]
+pub enum ChatProviderKindDto {
+    ChatGlm,
+    /// OpenAI 兼容接口，包括 llama.cpp server
+    OpenAi,
+    /// 本地 Ollama
+    Ollama,
+}
+
+#[derive(Debug, Clone)]
+pub struct ChatProviderDto {
+    pub kind: ChatProviderKindDto,
+    /// 为空时使用后端的默认地址；OpenAI 兼容接口必须提供
+    pub base_url: Option<String>,
+    /// ChatGLM 为 `id.secret` 格式；本地后端可为空
+    pub api_key: Option<String>,
+    /// 额外的 Key，与 `api_key` 一起按 `key_selection` 选用，被限流或鉴权失败的 Key 会暂时停用
+    pub api_keys: Vec<String>,
+    pub key_selection: ChatKeySelectionDto,
+    /// 异步任务方式的首次轮询间隔（毫秒），之后按指数退避增长
+    pub poll_interval_ms: u32,
+    /// 异步任务方式的最大轮询间隔（毫秒）
+    pub max_poll_interval_ms: u32,
+    /// 异步任务方式的最长等待时间（秒）
+    pub task_timeout_seconds: u32,
+    /// 每次轮询后间隔的增长倍数
+    pub poll_multiplier: f64,
+    /// 轮询间隔的随机抖动比例，0.2 表示在 ±20% 范围内浮动
+    pub poll_jitter: f64,
+}
+
+/// 有多个 API Key 时的选用方式
+#[derive(Debug, Clone, Copy)]
+pub enum ChatKeySelectionDto {
+    /// 轮流使用
+    RoundRobin,
+    /// 优先使用排在前面的，其余作为备用
+    Priority,
+}
+
+/// 一个 API Key 的用量
+#[derive(Debug, Clone)]
+pub struct ChatKeyUsageDto {
+    /// 只显示首尾几位的 Key
+    pub label: String,
+    pub requests: u64,
+    pub successes: u64,
+    pub failures: u64,
+    pub rate_limited: u64,
+    pub unauthorized: u64,
+    /// 被停用时距恢复的秒数
+    pub disabled_for_seconds: Option<u32>,
+}
+
+/// 配置文件中的模型档案，为空的项取 `defaults` 或内置默认值
+#[derive(Debug, Clone, Default)]
+pub struct ChatModelProfileDto {
+    /// 为空时为档案名
+    pub language_model: Option<String>,
+    pub system_content: Option<String>,
+    pub temperature: Option<f64>,
+    pub top_p: Option<f64>,
+    pub max_history: Option<u32>,
+    pub context_tokens: Option<u32>,
+    pub reply_tokens: Option<u32>,
+    pub summarize: Option<bool>,
+}
+
+#[derive(Debug, Clone)]
+pub struct ChatProfileDto {
+    pub name: String,
+    pub profile: ChatModelProfileDto,
+}
+
+/// 整个配置文件
+#[derive(Debug, Clone)]
+pub struct ChatAppConfigDto {
+    /// 未指定档案时使用，必须是 `profiles` 中的一个
+    pub default_profile: Option<String>,
+    pub provider: ChatProviderDto,
+    /// 各档案共用的默认值
+    pub defaults: ChatModelProfileDto,
+    pub profiles: Vec<ChatProfileDto>,
+}
+
+/// 保存的会话
+#[derive(Debug, Clone)]
+pub struct ChatSessionDto {
+    pub id: String,
+    pub message_count: u32,
+    /// 估算的 token 总数
+    pub tokens: u64,
+    /// 第一条与最后一条消息的毫秒时间戳，空会话为 None
+    pub created_at_ms: Option<i64>,
+    pub updated_at_ms: Option<i64>,
+}
+
+/// 后端支持的功能
+#[derive(Debug, Clone, Copy)]
+pub struct ChatCapabilitiesDto {
+    pub streaming: bool,
+    pub async_task: bool,
+    pub list_models: bool,
+}
+
+/// 调用方式
+#[derive(Debug, Clone, Copy)]
+pub enum ChatInvokeModeDto {
+    /// 直接请求并等待完整回复
+    Sync,
+    /// 提交异步任务后轮询结果
+    AsyncTask,
+    /// 流式请求，收集完整回复后返回
+    Stream,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub enum ChatRoleDto {
+    System,
+    User,
+    Assistant,
+}
+
+#[derive(Debug, Clone)]
+pub struct ChatMessageDto {
+    pub role: ChatRoleDto,
+    pub content: String,
+}
+
+impl From<&ChatMessage> for ChatMessageDto {
+    fn from(message: &ChatMessage) -> Self {
+        Self {
+            role: match message.role {
+                ChatRole::System => ChatRoleDto::System,
+                ChatRole::User => ChatRoleDto::User,
+                ChatRole::Assistant => ChatRoleDto::Assistant,
+            },
+            content: message.content.clone(),
+        }
+    }
+}
+
+/// 一次发送的回复
+#[derive(Debug, Clone)]
+pub struct ChatReplyDto {
+    pub content: String,
+    /// 不影响本次回复的失败，如概括早期对话或写入会话文件失败
+    pub warnings: Vec<String>,
+}
+
+impl From<ChatReply> for ChatReplyDto {
+    fn from(reply: ChatReply) -> Self {
+        Self {
+            content: reply.content,
+            warnings: reply.warnings.iter().map(ToString::to_string).collect(),
+        }
+    }
+}
+
+impl From<ChatConfig> for ChatConfigDto {
+    fn from(config: ChatConfig) -> Self {
+        Self {
+            language_model: config.language_model,
+            system_content: config.system_content,
+            temperature: config.temperature,
+            top_p: config.top_p,
+            max_history: config.max_history as u32,
+            context_tokens: config.context_budget.map(|budget| budget.context_tokens),
+            reply_tokens: config.context_budget.map(|budget| budget.reply_tokens),
+            summarize: config.summarize,
+        }
+    }
+}
+
+impl From<ChatConfigDto> for ChatConfig {
+    fn from(config: ChatConfigDto) -> Self {
+        Self {
+            language_model: config.language_model,
+            system_content: config.system_content,
+            temperature: config.temperature,
+            top_p: config.top_p,
+            max_history: config.max_history as usize,
+            context_budget: config.context_tokens.map(|context_tokens| {
+                let mut budget = ContextBudget::with_context_tokens(context_tokens);
+                if let Some(reply_tokens) = config.reply_tokens {
+                    budget.reply_tokens = reply_tokens.min(context_tokens);
+                }
+                budget
+            }),
+            summarize: config.summarize,
+        }
+    }
+}
+
+impl From<&ProviderConfig> for ChatProviderDto {
+    fn from(provider: &ProviderConfig) -> Self {
+        Self {
+            kind: match provider.kind {
+                ProviderKind::ChatGlm => ChatProviderKindDto::ChatGlm,
+                ProviderKind::OpenAi => ChatProviderKindDto::OpenAi,
+                ProviderKind::Ollama => ChatProviderKindDto::Ollama,
+            },
+            base_url: provider.base_url.clone(),
+            api_key: provider.api_key.clone(),
+            api_keys: provider.api_keys.clone(),
+            key_selection: match provider.key_selection {
+                KeySelection::RoundRobin => ChatKeySelectionDto::RoundRobin,
+                KeySelection::Priority => ChatKeySelectionDto::Priority,
+            },
+            poll_interval_ms: provider.poll.initial_interval.as_millis() as u32,
+            max_poll_interval_ms: provider.poll.max_interval.as_millis() as u32,
+            task_timeout_seconds: provider.poll.timeout.as_secs() as u32,
+            poll_multiplier: provider.poll.multiplier,
+            poll_jitter: provider.poll.jitter,
+        }
+    }
+}
+
+impl From<ChatProviderDto> for ProviderConfig {
+    fn from(provider: ChatProviderDto) -> Self {
+        Self {
+            kind: match provider.kind {
+                ChatProviderKindDto::ChatGlm => ProviderKind::ChatGlm,
+                ChatProviderKindDto::OpenAi => ProviderKind::OpenAi,
+                ChatProviderKindDto::Ollama => ProviderKind::Ollama,
+            },
+            base_url: provider.base_url.filter(|url| !url.trim().is_empty()),
+            api_key: provider.api_key.filter(|key| !key.trim().is_empty()),
+            api_keys: provider
+                .api_keys
+                .into_iter()
+                .filter(|key| !key.trim().is_empty())
+                .collect(),
+            key_selection: match provider.key_selection {
+                ChatKeySelectionDto::RoundRobin => KeySelection::RoundRobin,
+                ChatKeySelectionDto::Priority => KeySelection::Priority,
+            },
+            poll: PollConfig {
+                initial_interval: Duration::from_millis(provider.poll_interval_ms.into()),
+                max_interval: Duration::from_millis(provider.max_poll_interval_ms.into()),
+                timeout: Duration::from_secs(provider.task_timeout_seconds.into()),
+                multiplier: provider.poll_multiplier,
+                jitter: provider.poll_jitter,
+            },
+        }
+    }
+}
+
+impl From<&KeyUsage> for ChatKeyUsageDto {
+    fn from(usage: &KeyUsage) -> Self {
+        Self {
+            label: usage.label.clone(),
+            requests: usage.requests,
+            successes: usage.successes,
+            failures: usage.failures,
+            rate_limited: usage.rate_limited,
+            unauthorized: usage.unauthorized,
+            disabled_for_seconds: usage.disabled_for.map(|duration| duration.as_secs_f64().ceil() as u32),
+        }
+    }
+}
+
+impl From<&ModelProfile> for ChatModelProfileDto {
+    fn from(profile: &ModelProfile) -> Self {
+        Self {
+            language_model: profile.language_model.clone(),
+            system_content: profile.system_content.clone(),
+            temperature: profile.temperature,
+            top_p: profile.top_p,
+            max_history: profile.max_history.map(|max| max as u32),
+            context_tokens: profile.context_tokens,
+            reply_tokens: profile.reply_tokens,
+            summarize: profile.summarize,
+        }
+    }
+}
+
+impl From<ChatModelProfileDto> for ModelProfile {
+    fn from(profile: ChatModelProfileDto) -> Self {
+        Self {
+            language_model: profile.language_model.filter(|model| !model.trim().is_empty()),
+            system_content: profile.system_content,
+            temperature: profile.temperature,
+            top_p: profile.top_p,
+            max_history: profile.max_history.map(|max| max as usize),
+            context_tokens: profile.context_tokens,
+            reply_tokens: profile.reply_tokens,
+            summarize: profile.summarize,
+        }
+    }
+}
+
+impl From<&AppConfig> for ChatAppConfigDto {
+    fn from(config: &AppConfig) -> Self {
+        Self {
+            default_profile: config.default_profile.clone(),
+            provider: ChatProviderDto::from(&config.provider),
+            defaults: ChatModelProfileDto::from(&config.defaults),
+            profiles: config
+                .profiles
+                .iter()
+                .map(|(name, profile)| ChatProfileDto {
+                    name: name.clone(),
+                    profile: ChatModelProfileDto::from(profile),
+                })
+                .collect(),
+        }
+    }
+}
+
+impl TryFrom<ChatAppConfigDto> for AppConfig {
+    type Error = ChatError;
+
+    /// 重名的档案视为错误
+    fn try_from(config: ChatAppConfigDto) -> Result<Self, ChatError> {
+        let mut app_config = AppConfig {
+            default_profile: config.default_profile.filter(|name| !name.trim().is_empty()),
+            provider: ProviderConfig::from(config.provider),
+            defaults: ModelProfile::from(config.defaults),
+            profiles: BTreeMap::new(),
+        };
+        for profile in config.profiles {
+            app_config.insert_profile(profile.name, ModelProfile::from(profile.profile))?;
+        }
+        Ok(app_config)
+    }
+}
+
+/// 默认对话参数
+#[flutter_rust_bridge::frb(sync)]
+pub fn chat_default_config() -> ChatConfigDto {
+    ChatConfigDto::from(ChatConfig::default())
+}
+
+/// `kind` 后端的默认连接参数
+#[flutter_rust_bridge::frb(sync)]
+pub fn chat_default_provider(kind: ChatProviderKindDto) -> ChatProviderDto {
+    let mut provider = ChatProviderDto::from(&ProviderConfig::default());
+    provider.kind = kind;
+    provider
+}
+
+/// 连接 `provider` 并创建对话，清空之前的历史
+pub async fn chat_configure(provider: ChatProviderDto, config: ChatConfigDto) -> Result<(), String> {
+    let provider = ProviderConfig::from(provider).build().map_err(|e| e.to_string())?;
+    *chat().lock().await = Some(ChatClient::new(provider, ChatConfig::from(config)));
+    Ok(())
+}
+
+/// 读取配置文件，文件不存在时返回默认配置；校验失败时错误信息指出对应的配置项
+///
+/// 不应用环境变量，返回的 API Key 只是文件中的值。
+pub fn chat_load_config(path: String) -> Result<ChatAppConfigDto, String> {
+    let config = AppConfig::load(&path).map_err(|e| e.to_string())?;
+    Ok(ChatAppConfigDto::from(&config))
+}
+
+/// 校验后写入配置文件；旧格式的文件会被写为新格式，档案重名时返回错误
+pub fn chat_save_config(path: String, config: ChatAppConfigDto) -> Result<(), String> {
+    AppConfig::try_from(config)
+        .and_then(|config| config.save(&path))
+        .map_err(|e| e.to_string())
+}
+
+/// 按配置文件中的 `[provider]` 与 `profile` 档案创建对话，`profile` 为空时使用 `default_profile`
+///
+/// API Key 可被环境变量覆盖，见 `WISE_LOVER_API_KEY`。
+pub async fn chat_configure_from_file(path: String, profile: Option<String>) -> Result<(), String> {
+    let config = AppConfig::load(&path).map_err(|e| e.to_string())?;
+    configure_from(config, profile.as_deref(), None).await
+}
+
+/// 与 `chat_configure_from_file` 相同，但直接传入配置文本
+///
+/// `api_key` 不为空时代替配置文件与环境变量中的所有 API Key。
+pub async fn chat_configure_from_toml(
+    config_toml: String,
+    profile: Option<String>,
+    api_key: Option<String>,
+) -> Result<(), String> {
+    let config = AppConfig::from_toml(&config_toml).map_err(|e| e.to_string())?;
+    configure_from(config, profile.as_deref(), api_key).await
+}
+
+async fn configure_from(mut config: AppConfig, profile: Option<&str>, api_key: Option<String>) -> Result<(), String> {
+    config.apply_env_overrides();
+    if let Some(api_key) = api_key.filter(|key| !key.trim().is_empty()) {
+        config.provider.api_key = Some(api_key);
+        config.provider.api_keys.clear();
+    }
+    let chat_config = config.chat_config(profile).map_err(|e| e.to_string())?;
+    let provider = config.provider.build().map_err(|e| e.to_string())?;
+    *chat().lock().await = Some(ChatClient::new(provider, chat_config));
+    Ok(())
+}
+
+/// 当前后端支持的功能
+pub async fn chat_capabilities() -> Result<ChatCapabilitiesDto, String> {
+    let chat = chat().lock().await;
+    let client = chat
+        .as_ref()
+        .ok_or_else(|| "chat_capabilities: chat is not configured".to_string())?;
+    let capabilities = client.capabilities();
+    Ok(ChatCapabilitiesDto {
+        streaming: capabilities.streaming,
+        async_task: capabilities.async_task,
+        list_models: capabilities.list_models,
+    })
+}
+
+/// 当前后端可用的模型
+pub async fn chat_list_models() -> Result<Vec<String>, String> {
+    let chat = chat().lock().await;
+    let client = chat
+        .as_ref()
+        .ok_or_else(|| "chat_list_models: chat is not configured".to_string())?;
+    client.list_models().await.map_err(|e| e.to_string())
+}
+
+/// 当前后端每个 API Key 的用量，不使用 Key 的后端为空
+pub async fn chat_key_usage() -> Vec<ChatKeyUsageDto> {
+    chat()
+        .lock()
+        .await
+        .as_ref()
+        .map(|client| client.key_usage().iter().map(ChatKeyUsageDto::from).collect())
+        .unwrap_or_default()
+}
+
+/// 发送一条消息并返回回复，`mode` 默认为直接请求；同一时间只处理一条消息，后发送的会排队等待
+pub async fn chat_send(message: String, mode: Option<ChatInvokeModeDto>) -> Result<ChatReplyDto, String> {
+    let mode = match mode.unwrap_or(ChatInvokeModeDto::Sync) {
+        ChatInvokeModeDto::Sync => InvokeMode::Sync,
+        ChatInvokeModeDto::AsyncTask => InvokeMode::AsyncTask,
+        ChatInvokeModeDto::Stream => InvokeMode::Stream,
+    };
+    let cancel = cancel_token().lock().unwrap_or_else(PoisonError::into_inner).clone();
+    let mut chat = chat().lock().await;
+    let client = chat
+        .as_mut()
+        .ok_or_else(|| "chat_send: chat is not configured".to_string())?;
+    client
+        .send_with(&message, mode, Some(&cancel))
+        .await
+        .map(ChatReplyDto::from)
+        .map_err(|e| e.to_string())
+}
+
+/// 停止正在进行以及排队中的异步任务轮询，例如用户离开聊天界面时
+#[flutter_rust_bridge::frb(sync)]
+pub fn chat_cancel() {
+    let mut token = cancel_token().lock().unwrap_or_else(PoisonError::into_inner);
+    token.cancel();
+    *token = CancelToken::new();
+}
+
+/// 以流式响应发送消息：每段增量文本立即写入 `sink`，便于气泡文字与口型尽早开始
+///
+/// 完整回复在结束后记入 `chat_history` 并返回；出错时错误发送到 Dart 端的 Stream。
+pub async fn chat_send_stream(message: String, sink: StreamSink<String>) -> Result<ChatReplyDto, String> {
+    let mut chat = chat().lock().await;
+    let client = chat
+        .as_mut()
+        .ok_or_else(|| "chat_send_stream: chat is not configured".to_string())?;
+    client
+        .send_stream(&message, |delta| {
+            // Dart 端取消订阅后仍读完响应，以便完整回复记入历史
+            let _ = sink.add(delta.to_string());
+        })
+        .await
+        .map(ChatReplyDto::from)
+        .map_err(|e| e.to_string())
+}
+
+/// 对话历史（不含角色设定）
+pub async fn chat_history() -> Vec<ChatMessageDto> {
+    chat()
+        .lock()
+        .await
+        .as_ref()
+        .map(|client| client.history().iter().map(ChatMessageDto::from).collect())
+        .unwrap_or_default()
+}
+
+/// 已移出上下文的早期对话的摘要
+pub async fn chat_summary() -> Option<String> {
+    chat()
+        .lock()
+        .await
+        .as_ref()
+        .and_then(|client| client.summary().map(str::to_string))
+}
+
+/// 清空对话历史，保留配置
+pub async fn chat_reset() {
+    if let Some(client) = chat().lock().await.as_mut() {
+        client.reset();
+    }
+}
+
+/// `store_dir` 中 `character` 的所有会话，最近更新的在前
+pub fn chat_list_sessions(store_dir: String, character: String) -> Result<Vec<ChatSessionDto>, String> {
+    let sessions = ConversationStore::new(store_dir)
+        .sessions(&character)
+        .map_err(|e| e.to_string())?;
+    Ok(sessions
+        .into_iter()
+        .map(|session| ChatSessionDto {
+            id: session.id,
+            message_count: session.message_count as u32,
+            tokens: session.tokens,
+            created_at_ms: session.created_at,
+            updated_at_ms: session.updated_at,
+        })
+        .collect())
+}
+
+/// 打开 `session_id` 会话并恢复最近的历史（会话不存在时返回错误），为 None 时新建会话；
+/// 之后的对话追加写入该会话，返回会话 id
+pub async fn chat_open_session(
+    store_dir: String,
+    character: String,
+    session_id: Option<String>,
+) -> Result<String, String> {
+    let store = ConversationStore::new(store_dir);
+    let session = match session_id {
+        Some(session_id) => store.open_session(&character, &session_id),
+        None => store.create_session(&character),
+    }
+    .map_err(|e| e.to_string())?;
+    let id = session.id().to_string();
+
+    let mut chat = chat().lock().await;
+    let client = chat
+        .as_mut()
+        .ok_or_else(|| "chat_open_session: chat is not configured".to_string())?;
+    client.attach_session(session).map_err(|e| e.to_string())?;
+    Ok(id)
+}
+
+/// 停止写入当前会话，内存中的历史保留
+pub async fn chat_close_session() {
+    if let Some(client) = chat().lock().await.as_mut() {
+        client.detach_session();
+    }
+}
+
+/// 删除会话文件
+pub fn chat_delete_session(store_dir: String, character: String, session_id: String) -> Result<(), String> {
+    ConversationStore::new(store_dir)
+        .delete_session(&character, &session_id)
+        .map_err(|e| e.to_string())
+}
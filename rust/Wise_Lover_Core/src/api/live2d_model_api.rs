@@ -1,22 +1,65 @@
 use std::collections::HashMap;
-use std::sync::{Mutex, OnceLock};
+use std::sync::{Arc, Mutex, OnceLock};
 
 use live2d_core::core::{
     CanvasInfo, Model, Moc, MocError, Vector2, Vector4,
 };
+use live2d_core::framework::{
+    BlendMode, BlinkInterval, ClippingManager, Compositor, DisplayInfo, EmotionEngine,
+    EmotionMap, EmotionTiming, Expression, DrawBatch, DrawBatcher, DrawOrderMode, EyeBlink,
+    EyeBlinkConfig, FitMode, IdleEffect, IdleEffects, LayerBlend, LayerKind, LayerSettings, LipSync, LipSyncConfig, LipSyncTimeline, LookAt,
+    LookAtMapping, ModelUserData, Motion, MotionPlayer, ParameterSmoother, PcmFormat,
+    SmoothingConfig, SmoothingFilter, Viseme, VisemeTimeline, WavClip, MaskGroupPlan, ModelSettings, RenderOrderCache, Rng, ViewTransform,
+};
+use live2d_core::framework::model_settings::GROUP_EYE_BLINK;
+
+use crate::frb_generated::StreamSink;
 
 /// 内部：一个已加载的 Live2D 模型实例（持有 moc 与 model）
 struct Live2dModelInstance {
     #[allow(dead_code)]
     moc: Moc,
     model: Model,
+    /// 遮罩分组与遮罩图集布局（按模型缓存）
+    clipping: ClippingManager,
+    /// 排序后的 drawable 索引，只在 Core 标记顺序变化时重新计算
+    render_order: RenderOrderCache,
+    /// 通过 `live2d_model_apply_settings` 读入的 model3.json
+    settings: Option<ModelSettings>,
+    /// 自动眨眼，在 `live2d_model_update` 中驱动
+    eye_blink: Option<EyeBlink>,
+    /// 呼吸与待机摆动，叠加在动作之后
+    idle_effects: Option<IdleEffects>,
+    /// 视线 / 拖拽跟随
+    look_at: LookAt,
+    /// 口型同步（LipSync 分组，缺失时驱动 ParamMouthOpenY）
+    lip_sync: LipSync,
+    /// 已加载的 motion3.json，按名称索引
+    motions: HashMap<String, Arc<Motion>>,
+    /// cdi3.json 中的显示名称与参数分组
+    display_info: Option<DisplayInfo>,
+    /// userdata3.json 中附加在 ArtMesh 上的字符串
+    user_data: Option<ModelUserData>,
+    motion_player: MotionPlayer,
+    /// 按文本生成的口型动作，与身体动作分开播放，在口型同步之后执行
+    viseme_player: MotionPlayer,
+    /// 情绪：按映射文件把情绪名转换为参数目标，带过渡与衰减
+    emotion: EmotionEngine,
+    /// 情绪动作组的随机选择，加载时播种一次
+    rng: Rng,
+    /// 按固定顺序合成各动画层
+    compositor: Compositor,
+    /// 逐参数平滑，最后执行
+    smoother: ParameterSmoother,
+    /// 动作播放后的参数值；程序化效果每帧在此基础上叠加，避免逐帧累积
+    base_parameters: Vec<f32>,
 }
 
 fn core() -> &'static live2d_core::core::CubismCore {
     // 复用 wise_lover_boot_api 里已经使用的 CubismCore 初始化逻辑会更好，
     // 这里为了避免循环依赖简单重新实现一份懒加载。
     static CORE: OnceLock<live2d_core::core::CubismCore> = OnceLock::new();
-    CORE.get_or_init(|| live2d_core::core::CubismCore::default())
+    CORE.get_or_init(live2d_core::core::CubismCore::default)
 }
 
 fn models() -> &'static Mutex<HashMap<u64, Live2dModelInstance>> {
@@ -24,6 +67,11 @@ fn models() -> &'static Mutex<HashMap<u64, Live2dModelInstance>> {
     MODELS.get_or_init(|| Mutex::new(HashMap::new()))
 }
 
+fn motion_event_sinks() -> &'static Mutex<Vec<StreamSink<MotionEventDto>>> {
+    static SINKS: OnceLock<Mutex<Vec<StreamSink<MotionEventDto>>>> = OnceLock::new();
+    SINKS.get_or_init(|| Mutex::new(Vec::new()))
+}
+
 fn next_handle() -> u64 {
     use std::sync::atomic::{AtomicU64, Ordering};
     static NEXT: AtomicU64 = AtomicU64::new(1);
@@ -44,6 +92,8 @@ pub struct DrawableFrameDto {
     pub indices: Vec<u16>,
     /// 遮罩列表，元素为 drawable 索引
     pub masks: Vec<u16>,
+    /// 所属遮罩分组在 `FrameDto::mask_groups` 中的下标；无遮罩时为 None
+    pub mask_group: Option<u32>,
     pub opacity: f32,
     /// 乘色
     pub multiply_color: [f32; 4],
@@ -58,7 +108,125 @@ pub struct DrawableFrameDto {
 pub struct FrameDto {
     pub canvas_width: f32,
     pub canvas_height: f32,
+    /// 模型原点在画布中的像素位置（左上角为原点，Y 向下）
+    pub canvas_origin_x: f32,
+    pub canvas_origin_y: f32,
+    /// 每个模型单位对应的画布像素数
+    pub pixels_per_unit: f32,
     pub drawables: Vec<DrawableFrameDto>,
+    /// 本帧需要绘制的遮罩图集张数
+    pub mask_texture_count: u32,
+    /// 本帧用到的遮罩分组，每组遮罩只需绘制一次
+    pub mask_groups: Vec<MaskGroupDto>,
+}
+
+/// 共享同一组遮罩的 drawable 分组，以及它在遮罩图集中的位置
+#[derive(Debug, Clone)]
+pub struct MaskGroupDto {
+    /// 遮罩图集下标
+    pub texture_slot: u32,
+    /// 写入的颜色通道：0=R, 1=G, 2=B, 3=A
+    pub channel: u32,
+    /// 图集中的矩形区域 [x, y, width, height]，归一化到 [0, 1]
+    pub layout: [f32; 4],
+    /// 作为遮罩绘制的 drawable 索引
+    pub mask_drawables: Vec<u32>,
+    /// 被该组遮罩裁剪的 drawable 索引
+    pub clipped_drawables: Vec<u32>,
+    /// 绘制遮罩时使用：模型坐标 -> 图集 NDC（列主序 4x4）
+    pub mask_matrix: [f32; 16],
+    /// 绘制被裁剪 drawable 时使用：模型坐标 -> 图集 UV（列主序 4x4）
+    pub draw_matrix: [f32; 16],
+}
+
+/// drawable 排序方式
+#[derive(Debug, Clone, Copy)]
+pub enum DrawOrderModeDto {
+    /// 使用 Core 计算的渲染顺序（已考虑 Part 的绘制顺序）
+    RenderOrder,
+    /// 按 drawable 的 draw order 排序
+    DrawOrder,
+}
+
+/// drawable 的混合模式
+#[derive(Debug, Clone, Copy)]
+pub enum BlendModeDto {
+    Normal,
+    Additive,
+    Multiplicative,
+}
+
+/// 合批后的一次绘制：渲染顺序上相邻、纹理 / 混合模式 / 遮罩分组都相同的 drawable 合并而成
+#[derive(Debug, Clone)]
+pub struct DrawBatchDto {
+    pub texture_index: u32,
+    pub blend_mode: BlendModeDto,
+    /// 所属遮罩分组在 `BatchedFrameDto::mask_groups` 中的下标；无遮罩时为 None
+    pub mask_group: Option<u32>,
+    /// 是否为反向遮罩
+    pub inverted_mask: bool,
+    /// 合并进来的 drawable 索引（按渲染顺序）
+    pub drawables: Vec<u32>,
+    /// 展平后的顶点坐标数组：[x0, y0, x1, y1, ...]
+    pub vertices: Vec<f32>,
+    /// 展平后的纹理坐标数组：[u0, v0, u1, v1, ...]
+    pub uvs: Vec<f32>,
+    /// 已按合并后的顶点重新编号的索引缓冲
+    pub indices: Vec<u16>,
+    /// 每个顶点的乘色 [r, g, b, a, ...]，a 已乘上 drawable 不透明度
+    pub multiply_colors: Vec<f32>,
+    /// 每个顶点的屏幕色 [r, g, b, a, ...]
+    pub screen_colors: Vec<f32>,
+}
+
+/// 合批格式的一帧数据
+#[derive(Debug, Clone)]
+pub struct BatchedFrameDto {
+    pub canvas_width: f32,
+    pub canvas_height: f32,
+    pub canvas_origin_x: f32,
+    pub canvas_origin_y: f32,
+    pub pixels_per_unit: f32,
+    /// 按渲染顺序排列的绘制批次
+    pub batches: Vec<DrawBatchDto>,
+    pub mask_texture_count: u32,
+    pub mask_groups: Vec<MaskGroupDto>,
+    /// 遮罩网格批次，`mask_group` 指明要绘制进哪个遮罩分组
+    pub mask_batches: Vec<DrawBatchDto>,
+}
+
+/// 画布适配到视口的方式
+#[derive(Debug, Clone, Copy)]
+pub enum ViewFitModeDto {
+    /// 完整显示画布
+    Contain,
+    /// 画布铺满视口（可能裁切）
+    Cover,
+    /// 使用 `ViewportDto::fixed_scale` 作为 视口像素 / 画布像素 的比例
+    FixedScale,
+}
+
+/// 视口描述：尺寸、适配方式、平移（视口像素）与缩放
+#[derive(Debug, Clone)]
+pub struct ViewportDto {
+    pub width: f32,
+    pub height: f32,
+    pub fit_mode: ViewFitModeDto,
+    pub fixed_scale: f32,
+    pub pan_x: f32,
+    pub pan_y: f32,
+    pub zoom: f32,
+}
+
+/// 视图变换结果，矩阵均为列主序 4x4
+#[derive(Debug, Clone)]
+pub struct ViewTransformDto {
+    /// 每个模型单位对应的视口像素数
+    pub pixels_per_unit: f32,
+    /// 模型单位 -> 视口像素（左上角为原点，Y 向下）
+    pub model_to_viewport: [f32; 16],
+    /// 模型单位 -> 标准化设备坐标（[-1, 1]，Y 向上）
+    pub model_to_ndc: [f32; 16],
 }
 
 fn canvas_size(info: CanvasInfo) -> (f32, f32) {
@@ -66,6 +234,331 @@ fn canvas_size(info: CanvasInfo) -> (f32, f32) {
     (w, h)
 }
 
+fn mask_group_dto(clipping: &ClippingManager, plan: &MaskGroupPlan) -> MaskGroupDto {
+    let context = &clipping.contexts()[plan.context_index];
+    MaskGroupDto {
+        texture_slot: plan.texture_slot as u32,
+        channel: plan.channel.as_index() as u32,
+        layout: [plan.layout.x, plan.layout.y, plan.layout.width, plan.layout.height],
+        mask_drawables: context.mask_drawables().iter().map(|i| i.as_usize() as u32).collect(),
+        clipped_drawables: context.clipped_drawables().iter().map(|i| i.as_usize() as u32).collect(),
+        mask_matrix: plan.mask_matrix,
+        draw_matrix: plan.draw_matrix,
+    }
+}
+
+fn draw_batch_dto(batch: DrawBatch, mask_group: Option<u32>) -> DrawBatchDto {
+    DrawBatchDto {
+        texture_index: batch.key.texture_index.as_usize() as u32,
+        blend_mode: match batch.key.blend_mode {
+            BlendMode::Normal => BlendModeDto::Normal,
+            BlendMode::Additive => BlendModeDto::Additive,
+            BlendMode::Multiplicative => BlendModeDto::Multiplicative,
+        },
+        mask_group,
+        inverted_mask: batch.key.inverted_mask,
+        drawables: batch.drawables.iter().map(|i| i.as_usize() as u32).collect(),
+        vertices: batch.positions,
+        uvs: batch.uvs,
+        indices: batch.indices,
+        multiply_colors: batch.multiply_colors,
+        screen_colors: batch.screen_colors,
+    }
+}
+
+fn view_transform(canvas: CanvasInfo, viewport: &ViewportDto) -> ViewTransform {
+    let mut transform = ViewTransform::new(canvas, (viewport.width, viewport.height));
+    transform.set_fit_mode(match viewport.fit_mode {
+        ViewFitModeDto::Contain => FitMode::Contain,
+        ViewFitModeDto::Cover => FitMode::Cover,
+        ViewFitModeDto::FixedScale => FitMode::FixedScale(viewport.fixed_scale),
+    });
+    transform.set_zoom(viewport.zoom);
+    transform.set_pan((viewport.pan_x, viewport.pan_y));
+    transform
+}
+
+/// 两次眨眼之间的间隔分布
+#[derive(Debug, Clone, Copy)]
+pub enum BlinkIntervalDto {
+    /// 固定间隔 `min_seconds`
+    Fixed,
+    /// 在 [min_seconds, max_seconds) 内均匀分布
+    Uniform,
+    /// 指数分布，均值为 `mean_seconds`，且不短于 `min_seconds`
+    Exponential,
+}
+
+/// 自动眨眼配置
+#[derive(Debug, Clone)]
+pub struct EyeBlinkConfigDto {
+    pub enabled: bool,
+    pub interval: BlinkIntervalDto,
+    pub min_seconds: f32,
+    pub max_seconds: f32,
+    pub mean_seconds: f32,
+    /// 闭眼 / 保持闭眼 / 睁眼各阶段的时长（秒）
+    pub closing_seconds: f32,
+    pub closed_seconds: f32,
+    pub opening_seconds: f32,
+    /// 出现双眨眼的概率
+    pub double_blink_probability: f32,
+    /// 双眨眼两次之间睁眼的时长（秒）
+    pub double_blink_gap_seconds: f32,
+    /// 驱动的参数 ID；为空时使用 model3.json 的 EyeBlink 分组
+    pub parameter_ids: Vec<String>,
+    /// 随机种子；None 时使用系统时间（测试时可固定种子以得到确定结果）
+    pub seed: Option<u64>,
+}
+
+fn eye_blink_config(config: &EyeBlinkConfigDto) -> EyeBlinkConfig {
+    EyeBlinkConfig {
+        interval: match config.interval {
+            BlinkIntervalDto::Fixed => BlinkInterval::Fixed(config.min_seconds),
+            BlinkIntervalDto::Uniform => BlinkInterval::Uniform {
+                min: config.min_seconds,
+                max: config.max_seconds,
+            },
+            BlinkIntervalDto::Exponential => BlinkInterval::Exponential {
+                mean: config.mean_seconds,
+                min: config.min_seconds,
+            },
+        },
+        closing_seconds: config.closing_seconds,
+        closed_seconds: config.closed_seconds,
+        opening_seconds: config.opening_seconds,
+        double_blink_probability: config.double_blink_probability,
+        double_blink_gap_seconds: config.double_blink_gap_seconds,
+    }
+}
+
+/// 一条待机效果：数值为 `offset + peak * sin(2π t / cycle_seconds)`，按 `weight` 叠加到参数上
+#[derive(Debug, Clone)]
+pub struct IdleEffectDto {
+    pub parameter_id: String,
+    pub offset: f32,
+    pub peak: f32,
+    pub cycle_seconds: f32,
+    pub weight: f32,
+}
+
+/// 视线跟随时单个参数的映射：`x * x_weight + y * y_weight + x * y * xy_weight`
+#[derive(Debug, Clone)]
+pub struct LookAtMappingDto {
+    pub parameter_id: String,
+    pub x_weight: f32,
+    pub y_weight: f32,
+    pub xy_weight: f32,
+}
+
+/// 视线跟随配置
+#[derive(Debug, Clone)]
+pub struct LookAtConfigDto {
+    /// 最大速度（归一化单位 / 秒），默认 4.0
+    pub max_speed: f32,
+    /// 从静止加速到最大速度所需时间（秒），默认 0.15
+    pub time_to_max_speed: f32,
+    /// 参数映射；为空时使用官方示例的映射
+    pub mappings: Vec<LookAtMappingDto>,
+}
+
+/// 口型同步参数
+#[derive(Debug, Clone)]
+pub struct LipSyncConfigDto {
+    /// 分析帧长（秒），默认 1/60
+    pub frame_seconds: f32,
+    /// RMS 到张嘴程度的增益，默认 5.0
+    pub gain: f32,
+    /// 低于此 RMS 时保持闭嘴，默认 0.01
+    pub noise_floor: f32,
+    /// 张嘴 / 闭嘴时的平滑时间常数（秒）
+    pub attack_seconds: f32,
+    pub release_seconds: f32,
+    /// 叠加到参数上的权重，默认 0.8
+    pub weight: f32,
+}
+
+/// 预先分析好的口型时间轴：按固定帧长采样的张嘴程度 [0, 1]
+#[derive(Debug, Clone)]
+pub struct LipSyncTimelineDto {
+    pub frame_seconds: f32,
+    pub values: Vec<f32>,
+}
+
+/// 口型（五个元音 + 闭嘴）
+#[derive(Debug, Clone, Copy)]
+pub enum VisemeDto {
+    A,
+    I,
+    U,
+    E,
+    O,
+    Closed,
+}
+
+/// 口型关键帧，相邻关键帧之间线性插值
+#[derive(Debug, Clone)]
+pub struct VisemeKeyframeDto {
+    pub time: f32,
+    pub viseme: VisemeDto,
+    /// ParamMouthOpenY 的值
+    pub mouth_open: f32,
+    /// ParamMouthForm 的值
+    pub mouth_form: f32,
+}
+
+/// 由文本生成的口型时间轴
+#[derive(Debug, Clone)]
+pub struct VisemeTimelineDto {
+    pub duration: f32,
+    pub keyframes: Vec<VisemeKeyframeDto>,
+}
+
+fn viseme_timeline_dto(timeline: VisemeTimeline) -> VisemeTimelineDto {
+    VisemeTimelineDto {
+        duration: timeline.duration,
+        keyframes: timeline
+            .keyframes
+            .into_iter()
+            .map(|k| VisemeKeyframeDto {
+                time: k.time,
+                viseme: match k.viseme {
+                    Viseme::A => VisemeDto::A,
+                    Viseme::I => VisemeDto::I,
+                    Viseme::U => VisemeDto::U,
+                    Viseme::E => VisemeDto::E,
+                    Viseme::O => VisemeDto::O,
+                    Viseme::Closed => VisemeDto::Closed,
+                },
+                mouth_open: k.mouth_open,
+                mouth_form: k.mouth_form,
+            })
+            .collect(),
+    }
+}
+
+/// 动作播放到 UserData 时间点时发出的事件
+#[derive(Debug, Clone)]
+pub struct MotionEventDto {
+    /// 模型句柄
+    pub handle: u64,
+    /// `live2d_model_load_motion` 时指定的动作名
+    pub motion_name: String,
+    /// UserData 中的字符串
+    pub value: String,
+}
+
+/// 参数信息（含 cdi3.json 中的显示名称）
+#[derive(Debug, Clone)]
+pub struct ParameterInfoDto {
+    pub id: String,
+    /// 显示名称；没有 cdi3.json 或未定义时为 id
+    pub name: String,
+    /// 所属参数分组 id；顶层或未知时为空
+    pub group_id: String,
+    pub min_value: f32,
+    pub max_value: f32,
+    pub default_value: f32,
+}
+
+/// 参数分组（cdi3.json 的 ParameterGroups）
+#[derive(Debug, Clone)]
+pub struct ParameterGroupInfoDto {
+    pub id: String,
+    pub name: String,
+    /// 父分组 id；顶层时为空
+    pub parent_id: String,
+}
+
+/// Part 信息（含 cdi3.json 中的显示名称）
+#[derive(Debug, Clone)]
+pub struct PartInfoDto {
+    pub id: String,
+    pub name: String,
+}
+
+/// userdata3.json 中附加在某个 ArtMesh 上的字符串
+#[derive(Debug, Clone)]
+pub struct DrawableUserDataDto {
+    pub drawable_index: u32,
+    pub drawable_id: String,
+    pub value: String,
+}
+
+/// 设置情绪的结果
+#[derive(Debug, Clone)]
+pub struct EmotionResultDto {
+    /// 映射中为该情绪指定的表情名；已用 `live2d_model_load_expression` 读入的会随情绪一起应用
+    pub expressions: Vec<String>,
+    /// 从映射指定的动作组中随机选出并开始播放的动作名；未指定动作组、
+    /// 未读入 model3.json 或组内动作均未读入时为 None
+    pub started_motion: Option<String>,
+}
+
+/// 参数平滑方式
+#[derive(Debug, Clone, Copy)]
+pub enum SmoothingFilterDto {
+    /// 不滤波（仍受最大速度限制）
+    None,
+    /// 指数平滑，`time_seconds` 为时间常数
+    Exponential,
+    /// 临界阻尼弹簧，约 `time_seconds` 到达目标且不过冲
+    Spring,
+}
+
+/// 单个参数的平滑配置
+#[derive(Debug, Clone)]
+pub struct ParameterSmoothingDto {
+    pub filter: SmoothingFilterDto,
+    pub time_seconds: f32,
+    /// 每秒最大变化量；None 表示不限制
+    pub max_velocity: Option<f32>,
+}
+
+/// 动画层
+#[derive(Debug, Clone, Copy)]
+pub enum AnimationLayerDto {
+    Motion,
+    EyeBlink,
+    Expression,
+    Idle,
+    LipSync,
+    LookAt,
+    UserOverride,
+}
+
+/// 动画层的混合方式
+#[derive(Debug, Clone, Copy)]
+pub enum LayerBlendDto {
+    /// 在下层结果之上计算，按权重混合其改动
+    Normal,
+    /// 从参数默认值开始计算，把相对默认值的偏移按权重叠加
+    Additive,
+}
+
+fn layer_kind(layer: AnimationLayerDto) -> LayerKind {
+    match layer {
+        AnimationLayerDto::Motion => LayerKind::Motion,
+        AnimationLayerDto::EyeBlink => LayerKind::EyeBlink,
+        AnimationLayerDto::Expression => LayerKind::Expression,
+        AnimationLayerDto::Idle => LayerKind::Idle,
+        AnimationLayerDto::LipSync => LayerKind::LipSync,
+        AnimationLayerDto::LookAt => LayerKind::LookAt,
+        AnimationLayerDto::UserOverride => LayerKind::UserOverride,
+    }
+}
+
+fn lip_sync_config(config: &LipSyncConfigDto) -> LipSyncConfig {
+    LipSyncConfig {
+        frame_seconds: config.frame_seconds,
+        gain: config.gain,
+        noise_floor: config.noise_floor,
+        attack_seconds: config.attack_seconds,
+        release_seconds: config.release_seconds,
+        weight: config.weight,
+    }
+}
+
 fn vec2_to_f32(v: &Vector2) -> [f32; 2] {
     [v.x, v.y]
 }
@@ -89,9 +582,36 @@ pub fn live2d_model_load(moc_bytes: Vec<u8>) -> Result<u64, String> {
         })?;
 
     let model = Model::from_moc(&moc);
+    let clipping = ClippingManager::new(model.get_static());
+    let idle_effects = IdleEffects::new(IdleEffect::official_breath(), model.get_static());
+    let look_at = LookAt::new(LookAtMapping::official(), model.get_static());
+    let lip_sync = LipSync::from_settings(None, model.get_static(), LipSyncConfig::default());
+    let base_parameters = model.read_dynamic().parameter_values().to_vec();
+    let smoother = ParameterSmoother::new(model.get_static());
+    let compositor = Compositor::new(model.get_static());
     let handle = next_handle();
 
-    let instance = Live2dModelInstance { moc, model };
+    let instance = Live2dModelInstance {
+        moc,
+        model,
+        clipping,
+        render_order: RenderOrderCache::new(DrawOrderMode::default()),
+        settings: None,
+        eye_blink: None,
+        idle_effects: Some(idle_effects),
+        look_at,
+        lip_sync,
+        motions: HashMap::new(),
+        display_info: None,
+        user_data: None,
+        motion_player: MotionPlayer::new(),
+        viseme_player: MotionPlayer::new(),
+        emotion: EmotionEngine::new(EmotionMap::builtin(), None),
+        rng: Rng::from_time(),
+        compositor,
+        smoother,
+        base_parameters,
+    };
     let mut map = models().lock().expect("models mutex poisoned");
     map.insert(handle, instance);
 
@@ -112,9 +632,9 @@ pub fn live2d_model_set_parameter(
     parameter_id: String,
     value: f32,
 ) -> Result<(), String> {
-    let map = models().lock().expect("models mutex poisoned");
+    let mut map = models().lock().expect("models mutex poisoned");
     let instance = map
-        .get(&handle)
+        .get_mut(&handle)
         .ok_or_else(|| "live2d_model_set_parameter: invalid handle".to_string())?;
 
     let model_static = instance.model.get_static();
@@ -129,6 +649,9 @@ pub fn live2d_model_set_parameter(
             let range = param.value_range();
             let clamped_value = value.clamp(range.0, range.1);
             parameter_values[i] = clamped_value;
+            if let Some(base) = instance.base_parameters.get_mut(i) {
+                *base = clamped_value;
+            }
             return Ok(());
         }
     }
@@ -138,12 +661,650 @@ pub fn live2d_model_set_parameter(
     Err(format!("Parameter '{}' not found. First 10 available: {:?}", parameter_id, available))
 }
 
+/// 读入 model3.json（参数分组等），并按其中的 EyeBlink / LipSync 分组启用自动眨眼与口型同步
+#[flutter_rust_bridge::frb(sync)]
+pub fn live2d_model_apply_settings(handle: u64, model3_json: String) -> Result<(), String> {
+    let settings = ModelSettings::from_json(&model3_json)
+        .map_err(|e| format!("live2d_model_apply_settings: {e}"))?;
+
+    let mut map = models().lock().expect("models mutex poisoned");
+    let instance = map
+        .get_mut(&handle)
+        .ok_or_else(|| "live2d_model_apply_settings: invalid handle".to_string())?;
+
+    let eye_blink = EyeBlink::from_settings(
+        &settings,
+        instance.model.get_static(),
+        EyeBlinkConfig::default(),
+        Rng::from_time(),
+    );
+    instance.eye_blink = (!eye_blink.parameters().is_empty()).then_some(eye_blink);
+    instance.lip_sync = LipSync::from_settings(
+        Some(&settings),
+        instance.model.get_static(),
+        *instance.lip_sync.config(),
+    );
+    instance.settings = Some(settings);
+    Ok(())
+}
+
+/// 推进动作播放与程序化效果，并按层合成写入参数；应在 `live2d_model_step` 之前每帧调用
+///
+/// 合成顺序：动作 → 眨眼 → 表情 / 情绪 → 物理 → 呼吸 / 待机 → 口型 → 视线 → 用户覆盖 → 平滑
+#[flutter_rust_bridge::frb(sync)]
+pub fn live2d_model_update(handle: u64, delta_seconds: f32) -> Result<(), String> {
+    let mut map = models().lock().expect("models mutex poisoned");
+    let instance = map
+        .get_mut(&handle)
+        .ok_or_else(|| "live2d_model_update: invalid handle".to_string())?;
+
+    let Live2dModelInstance {
+        model,
+        base_parameters,
+        compositor,
+        motion_player,
+        viseme_player,
+        eye_blink,
+        emotion,
+        idle_effects,
+        lip_sync,
+        look_at,
+        smoother,
+        ..
+    } = instance;
+    let model_static = model.get_static();
+    let mut dynamic = model.write_dynamic();
+
+    // 从上一帧动作播放后的参数开始，按层的顺序合成；动作层的结果保存为下一帧的起点
+    compositor.begin(base_parameters, &mut dynamic);
+
+    let mut events = Vec::new();
+    for kind in LayerKind::ORDER {
+        compositor.apply(kind, &mut dynamic, |dynamic| match kind {
+            LayerKind::Motion => events = motion_player.update(delta_seconds, model_static, dynamic),
+            LayerKind::EyeBlink => {
+                if let Some(eye_blink) = eye_blink.as_mut() {
+                    eye_blink.update(delta_seconds, dynamic);
+                }
+            }
+            LayerKind::Expression => emotion.update(delta_seconds, dynamic),
+            LayerKind::Idle => {
+                if let Some(idle_effects) = idle_effects.as_mut() {
+                    idle_effects.update(delta_seconds, dynamic);
+                }
+            }
+            LayerKind::LipSync => {
+                lip_sync.update(delta_seconds, model_static, dynamic);
+                viseme_player.update(delta_seconds, model_static, dynamic);
+            }
+            LayerKind::LookAt => look_at.update(delta_seconds, dynamic),
+            // 覆盖值由 compositor 自身写入
+            LayerKind::UserOverride => {}
+        });
+
+        if kind == LayerKind::Motion {
+            base_parameters.copy_from_slice(dynamic.parameter_values());
+        }
+    }
+
+    smoother.update(delta_seconds, &mut dynamic);
+
+    if !events.is_empty() {
+        let mut sinks = motion_event_sinks().lock().expect("motion event sinks mutex poisoned");
+        for event in events {
+            let dto = MotionEventDto {
+                handle,
+                motion_name: event.motion_name,
+                value: event.value,
+            };
+            // 已关闭的 Dart 端 Stream 在发送失败时移除
+            sinks.retain(|sink| sink.add(dto.clone()).is_ok());
+        }
+    }
+    Ok(())
+}
+
+/// 配置自动眨眼；`enabled` 为 false 时关闭
+#[flutter_rust_bridge::frb(sync)]
+pub fn live2d_model_configure_eye_blink(
+    handle: u64,
+    config: EyeBlinkConfigDto,
+) -> Result<(), String> {
+    let mut map = models().lock().expect("models mutex poisoned");
+    let instance = map
+        .get_mut(&handle)
+        .ok_or_else(|| "live2d_model_configure_eye_blink: invalid handle".to_string())?;
+
+    if !config.enabled {
+        instance.eye_blink = None;
+        return Ok(());
+    }
+
+    let parameter_ids = if config.parameter_ids.is_empty() {
+        instance
+            .settings
+            .as_ref()
+            .map(|settings| settings.parameter_group(GROUP_EYE_BLINK).to_vec())
+            .unwrap_or_default()
+    } else {
+        config.parameter_ids.clone()
+    };
+    if parameter_ids.is_empty() {
+        return Err("live2d_model_configure_eye_blink: no EyeBlink parameters".to_string());
+    }
+
+    let rng = config.seed.map(Rng::new).unwrap_or_else(Rng::from_time);
+    instance.eye_blink = Some(EyeBlink::new(
+        &parameter_ids,
+        instance.model.get_static(),
+        eye_blink_config(&config),
+        rng,
+    ));
+    Ok(())
+}
+
+/// 设置呼吸 / 待机摆动效果；传入空列表则关闭。模型中不存在的参数会被忽略
+#[flutter_rust_bridge::frb(sync)]
+pub fn live2d_model_set_idle_effects(
+    handle: u64,
+    effects: Vec<IdleEffectDto>,
+) -> Result<(), String> {
+    let mut map = models().lock().expect("models mutex poisoned");
+    let instance = map
+        .get_mut(&handle)
+        .ok_or_else(|| "live2d_model_set_idle_effects: invalid handle".to_string())?;
+
+    if effects.is_empty() {
+        instance.idle_effects = None;
+        return Ok(());
+    }
+
+    let effects = effects
+        .into_iter()
+        .map(|e| IdleEffect::new(e.parameter_id, e.offset, e.peak, e.cycle_seconds, e.weight))
+        .collect();
+    instance.idle_effects = Some(IdleEffects::new(effects, instance.model.get_static()));
+    Ok(())
+}
+
+/// 官方默认的呼吸 / 待机摆动设置
+#[flutter_rust_bridge::frb(sync)]
+pub fn live2d_default_idle_effects() -> Vec<IdleEffectDto> {
+    IdleEffect::official_breath()
+        .into_iter()
+        .map(|e| IdleEffectDto {
+            parameter_id: e.parameter_id,
+            offset: e.offset,
+            peak: e.peak,
+            cycle_seconds: e.cycle_seconds,
+            weight: e.weight,
+        })
+        .collect()
+}
+
+/// 设置视线跟随目标（归一化坐标，范围 [-1, 1]，Y 轴向上）；松开拖拽时传 (0, 0) 回正
+#[flutter_rust_bridge::frb(sync)]
+pub fn live2d_model_set_look_target(handle: u64, x: f32, y: f32) -> Result<(), String> {
+    let mut map = models().lock().expect("models mutex poisoned");
+    let instance = map
+        .get_mut(&handle)
+        .ok_or_else(|| "live2d_model_set_look_target: invalid handle".to_string())?;
+
+    instance.look_at.set_target(x, y);
+    Ok(())
+}
+
+/// 配置视线跟随的速度与参数映射
+#[flutter_rust_bridge::frb(sync)]
+pub fn live2d_model_configure_look_at(handle: u64, config: LookAtConfigDto) -> Result<(), String> {
+    let mut map = models().lock().expect("models mutex poisoned");
+    let instance = map
+        .get_mut(&handle)
+        .ok_or_else(|| "live2d_model_configure_look_at: invalid handle".to_string())?;
+
+    let mappings = if config.mappings.is_empty() {
+        LookAtMapping::official()
+    } else {
+        config
+            .mappings
+            .into_iter()
+            .map(|m| LookAtMapping::new(m.parameter_id, m.x_weight, m.y_weight, m.xy_weight))
+            .collect()
+    };
+
+    let previous = *instance.look_at.target_point();
+    instance.look_at = LookAt::new(mappings, instance.model.get_static());
+    let target_point = instance.look_at.target_point_mut();
+    *target_point = previous;
+    target_point.max_speed = config.max_speed;
+    target_point.time_to_max_speed = config.time_to_max_speed;
+    Ok(())
+}
+
+/// 把 WAV 音频预分析为口型时间轴（不需要模型句柄）
+pub fn live2d_lip_sync_analyze_wav(
+    wav: Vec<u8>,
+    config: LipSyncConfigDto,
+) -> Result<LipSyncTimelineDto, String> {
+    let clip = WavClip::parse(&wav).map_err(|e| format!("live2d_lip_sync_analyze_wav: {e}"))?;
+    let timeline = lip_sync_config(&config).analyze(&clip.samples, clip.format);
+    Ok(LipSyncTimelineDto {
+        frame_seconds: timeline.frame_seconds,
+        values: timeline.values,
+    })
+}
+
+/// 根据文本生成口型时间轴（中文按拼音韵母、日文按假名、拉丁字母按元音），用于没有音频的回复
+#[flutter_rust_bridge::frb(sync)]
+pub fn live2d_text_visemes(text: String, chars_per_second: f32) -> VisemeTimelineDto {
+    viseme_timeline_dto(VisemeTimeline::from_text(&text, chars_per_second))
+}
+
+/// 文本口型动作的淡入淡出时长
+const VISEME_FADE_SECONDS: f32 = 0.1;
+
+/// 按文本生成口型动作并播放，返回所用的时间轴；不会打断正在播放的身体动作，新的文本会淡出上一段
+#[flutter_rust_bridge::frb(sync)]
+pub fn live2d_model_play_text_visemes(
+    handle: u64,
+    text: String,
+    chars_per_second: f32,
+) -> Result<VisemeTimelineDto, String> {
+    let mut map = models().lock().expect("models mutex poisoned");
+    let instance = map
+        .get_mut(&handle)
+        .ok_or_else(|| "live2d_model_play_text_visemes: invalid handle".to_string())?;
+
+    let timeline = VisemeTimeline::from_text(&text, chars_per_second);
+    let motion = Arc::new(timeline.to_motion(VISEME_FADE_SECONDS));
+    instance.viseme_player.start("visemes", motion, Some(false));
+    Ok(viseme_timeline_dto(timeline))
+}
+
+/// 设置口型同步参数
+#[flutter_rust_bridge::frb(sync)]
+pub fn live2d_model_configure_lip_sync(handle: u64, config: LipSyncConfigDto) -> Result<(), String> {
+    let mut map = models().lock().expect("models mutex poisoned");
+    let instance = map
+        .get_mut(&handle)
+        .ok_or_else(|| "live2d_model_configure_lip_sync: invalid handle".to_string())?;
+
+    instance.lip_sync.set_config(lip_sync_config(&config));
+    Ok(())
+}
+
+/// 从头播放预分析好的口型时间轴（与音频同时开始播放）
+#[flutter_rust_bridge::frb(sync)]
+pub fn live2d_model_lip_sync_play(handle: u64, timeline: LipSyncTimelineDto) -> Result<(), String> {
+    let mut map = models().lock().expect("models mutex poisoned");
+    let instance = map
+        .get_mut(&handle)
+        .ok_or_else(|| "live2d_model_lip_sync_play: invalid handle".to_string())?;
+
+    instance.lip_sync.play(LipSyncTimeline {
+        frame_seconds: timeline.frame_seconds,
+        values: timeline.values,
+    });
+    Ok(())
+}
+
+/// 按模型当前的口型参数分析 WAV 并从头播放
+#[flutter_rust_bridge::frb(sync)]
+pub fn live2d_model_lip_sync_play_wav(handle: u64, wav: Vec<u8>) -> Result<(), String> {
+    let clip = WavClip::parse(&wav).map_err(|e| format!("live2d_model_lip_sync_play_wav: {e}"))?;
+
+    let mut map = models().lock().expect("models mutex poisoned");
+    let instance = map
+        .get_mut(&handle)
+        .ok_or_else(|| "live2d_model_lip_sync_play_wav: invalid handle".to_string())?;
+
+    let timeline = instance.lip_sync.config().analyze(&clip.samples, clip.format);
+    instance.lip_sync.play(timeline);
+    Ok(())
+}
+
+/// 推送一段正在播放的 PCM（交错排列、范围 [-1, 1]），用于 TTS 流式播放或麦克风回放
+///
+/// 不足一个分析帧的采样会保留到下一次推送；采样率或声道数改变时丢弃。
+#[flutter_rust_bridge::frb(sync)]
+pub fn live2d_model_lip_sync_push_pcm(
+    handle: u64,
+    samples: Vec<f32>,
+    sample_rate: u32,
+    channels: u16,
+) -> Result<(), String> {
+    let mut map = models().lock().expect("models mutex poisoned");
+    let instance = map
+        .get_mut(&handle)
+        .ok_or_else(|| "live2d_model_lip_sync_push_pcm: invalid handle".to_string())?;
+
+    instance.lip_sync.push(
+        &samples,
+        PcmFormat {
+            sample_rate,
+            channels,
+        },
+    );
+    Ok(())
+}
+
+/// 推送一段 16 位整型 PCM（交错排列）
+#[flutter_rust_bridge::frb(sync)]
+pub fn live2d_model_lip_sync_push_pcm16(
+    handle: u64,
+    samples: Vec<i16>,
+    sample_rate: u32,
+    channels: u16,
+) -> Result<(), String> {
+    let samples = samples.iter().map(|&s| f32::from(s) / 32768.0).collect();
+    live2d_model_lip_sync_push_pcm(handle, samples, sample_rate, channels)
+}
+
+/// 停止口型同步，嘴巴平滑闭合
+#[flutter_rust_bridge::frb(sync)]
+pub fn live2d_model_lip_sync_stop(handle: u64) -> Result<(), String> {
+    let mut map = models().lock().expect("models mutex poisoned");
+    let instance = map
+        .get_mut(&handle)
+        .ok_or_else(|| "live2d_model_lip_sync_stop: invalid handle".to_string())?;
+
+    instance.lip_sync.stop();
+    Ok(())
+}
+
+/// 读入一个 motion3.json，之后可用 `name` 播放；以 model3.json 中的 File 路径为名称时，情绪的动作组可以选到它
+#[flutter_rust_bridge::frb(sync)]
+pub fn live2d_model_load_motion(handle: u64, name: String, motion3_json: String) -> Result<(), String> {
+    let motion = Motion::from_json(&motion3_json)
+        .map_err(|e| format!("live2d_model_load_motion: {e}"))?;
+
+    let mut map = models().lock().expect("models mutex poisoned");
+    let instance = map
+        .get_mut(&handle)
+        .ok_or_else(|| "live2d_model_load_motion: invalid handle".to_string())?;
+
+    instance.motions.insert(name, Arc::new(motion));
+    Ok(())
+}
+
+/// 播放已读入的动作，正在播放的动作会淡出；`looped` 为 None 时使用 motion3.json 的设置
+#[flutter_rust_bridge::frb(sync)]
+pub fn live2d_model_start_motion(handle: u64, name: String, looped: Option<bool>) -> Result<(), String> {
+    let mut map = models().lock().expect("models mutex poisoned");
+    let instance = map
+        .get_mut(&handle)
+        .ok_or_else(|| "live2d_model_start_motion: invalid handle".to_string())?;
+
+    let motion = instance
+        .motions
+        .get(&name)
+        .cloned()
+        .ok_or_else(|| format!("live2d_model_start_motion: motion '{name}' not loaded"))?;
+    instance.motion_player.start(name, motion, looped);
+    Ok(())
+}
+
+/// 停止所有动作；`fade` 为 true 时淡出，否则立即停止
+#[flutter_rust_bridge::frb(sync)]
+pub fn live2d_model_stop_motions(handle: u64, fade: bool) -> Result<(), String> {
+    let mut map = models().lock().expect("models mutex poisoned");
+    let instance = map
+        .get_mut(&handle)
+        .ok_or_else(|| "live2d_model_stop_motions: invalid handle".to_string())?;
+
+    if fade {
+        instance.motion_player.fade_out_all();
+    } else {
+        instance.motion_player.stop_all();
+    }
+    Ok(())
+}
+
+/// 订阅所有模型的动作 UserData 事件（用于同步音效、对话气泡等）
+///
+/// Dart 端取消订阅后，对应的 sink 在下一次发送事件失败时移除。
+pub fn live2d_motion_events(sink: StreamSink<MotionEventDto>) {
+    motion_event_sinks()
+        .lock()
+        .expect("motion event sinks mutex poisoned")
+        .push(sink);
+}
+
+/// 读入情绪映射文件（JSON：`Default` 为通用定义，`Models` 按模型名覆盖）；
+/// `model_name` 用于选择 `Models` 中的条目
+#[flutter_rust_bridge::frb(sync)]
+pub fn live2d_model_load_emotion_map(
+    handle: u64,
+    mapping_json: String,
+    model_name: Option<String>,
+) -> Result<(), String> {
+    let map_data = EmotionMap::from_json(&mapping_json)
+        .map_err(|e| format!("live2d_model_load_emotion_map: {e}"))?;
+
+    let mut map = models().lock().expect("models mutex poisoned");
+    let instance = map
+        .get_mut(&handle)
+        .ok_or_else(|| "live2d_model_load_emotion_map: invalid handle".to_string())?;
+
+    instance.emotion.set_map(map_data, model_name);
+    Ok(())
+}
+
+/// 读入一个 exp3.json，`name` 为 model3.json 中 Expressions 的 Name；
+/// 情绪映射的 `Expressions` 列出该名称时随情绪一起应用
+#[flutter_rust_bridge::frb(sync)]
+pub fn live2d_model_load_expression(handle: u64, name: String, exp3_json: String) -> Result<(), String> {
+    let expression = Expression::from_json(&exp3_json)
+        .map_err(|e| format!("live2d_model_load_expression: {e}"))?;
+
+    let mut map = models().lock().expect("models mutex poisoned");
+    let instance = map
+        .get_mut(&handle)
+        .ok_or_else(|| "live2d_model_load_expression: invalid handle".to_string())?;
+
+    instance.emotion.insert_expression(name, expression);
+    Ok(())
+}
+
+/// 设置情绪过渡时间与衰减时间（秒）
+#[flutter_rust_bridge::frb(sync)]
+pub fn live2d_model_set_emotion_timing(
+    handle: u64,
+    transition_seconds: f32,
+    decay_seconds: f32,
+) -> Result<(), String> {
+    let mut map = models().lock().expect("models mutex poisoned");
+    let instance = map
+        .get_mut(&handle)
+        .ok_or_else(|| "live2d_model_set_emotion_timing: invalid handle".to_string())?;
+
+    instance.emotion.set_timing(EmotionTiming {
+        transition_seconds,
+        decay_seconds,
+    });
+    Ok(())
+}
+
+/// 切换情绪：`intensity` 为强度 [0, 1]，`duration_seconds` 后衰减回中性（None 表示一直保持）
+#[flutter_rust_bridge::frb(sync)]
+pub fn live2d_model_set_emotion(
+    handle: u64,
+    name: String,
+    intensity: f32,
+    duration_seconds: Option<f32>,
+) -> Result<EmotionResultDto, String> {
+    let mut map = models().lock().expect("models mutex poisoned");
+    let instance = map
+        .get_mut(&handle)
+        .ok_or_else(|| "live2d_model_set_emotion: invalid handle".to_string())?;
+
+    let definition = instance
+        .emotion
+        .set_emotion(&name, intensity, duration_seconds, instance.model.get_static())
+        .ok_or_else(|| format!("live2d_model_set_emotion: unknown emotion '{name}'"))?;
+
+    let expressions = definition.expressions.clone();
+    let motion_group = definition.motion_group.clone();
+
+    // 动作组来自 model3.json，组内的动作需已用其 File 路径作为名称读入
+    let candidates: Vec<(&String, &Arc<Motion>)> = motion_group
+        .as_deref()
+        .zip(instance.settings.as_ref())
+        .map(|(group, settings)| {
+            settings
+                .motion_group(group)
+                .iter()
+                .filter_map(|reference| instance.motions.get_key_value(&reference.file))
+                .collect()
+        })
+        .unwrap_or_default();
+    let started_motion = (!candidates.is_empty()).then(|| {
+        let (motion_name, motion) = candidates[instance.rng.index(candidates.len())];
+        let (motion_name, motion) = (motion_name.clone(), Arc::clone(motion));
+        instance.motion_player.start(motion_name.clone(), motion, None);
+        motion_name
+    });
+
+    Ok(EmotionResultDto {
+        expressions,
+        started_motion,
+    })
+}
+
+/// 淡出当前情绪，回到中性
+#[flutter_rust_bridge::frb(sync)]
+pub fn live2d_model_clear_emotion(handle: u64) -> Result<(), String> {
+    let mut map = models().lock().expect("models mutex poisoned");
+    let instance = map
+        .get_mut(&handle)
+        .ok_or_else(|| "live2d_model_clear_emotion: invalid handle".to_string())?;
+
+    instance.emotion.clear();
+    Ok(())
+}
+
+/// 为参数设置平滑（在所有输入之后、模型更新之前执行），用于消除多个来源同时写入时的抖动
+#[flutter_rust_bridge::frb(sync)]
+pub fn live2d_model_set_parameter_smoothing(
+    handle: u64,
+    parameter_id: String,
+    smoothing: ParameterSmoothingDto,
+) -> Result<(), String> {
+    let mut map = models().lock().expect("models mutex poisoned");
+    let instance = map
+        .get_mut(&handle)
+        .ok_or_else(|| "live2d_model_set_parameter_smoothing: invalid handle".to_string())?;
+
+    let config = SmoothingConfig {
+        filter: match smoothing.filter {
+            SmoothingFilterDto::None => SmoothingFilter::None,
+            SmoothingFilterDto::Exponential => SmoothingFilter::Exponential {
+                time_constant: smoothing.time_seconds,
+            },
+            SmoothingFilterDto::Spring => SmoothingFilter::Spring {
+                smooth_time: smoothing.time_seconds,
+            },
+        },
+        max_velocity: smoothing.max_velocity,
+    };
+    if instance
+        .smoother
+        .set(instance.model.get_static(), &parameter_id, config)
+    {
+        Ok(())
+    } else {
+        Err(format!("Parameter '{}' not found", parameter_id))
+    }
+}
+
+/// 取消参数平滑；`parameter_id` 为 None 时取消全部
+#[flutter_rust_bridge::frb(sync)]
+pub fn live2d_model_clear_parameter_smoothing(
+    handle: u64,
+    parameter_id: Option<String>,
+) -> Result<(), String> {
+    let mut map = models().lock().expect("models mutex poisoned");
+    let instance = map
+        .get_mut(&handle)
+        .ok_or_else(|| "live2d_model_clear_parameter_smoothing: invalid handle".to_string())?;
+
+    match parameter_id {
+        Some(id) => instance.smoother.remove(instance.model.get_static(), &id),
+        None => instance.smoother.clear(),
+    }
+    Ok(())
+}
+
+/// 设置动画层的开关、权重 [0, 1] 与混合方式
+#[flutter_rust_bridge::frb(sync)]
+pub fn live2d_model_set_layer(
+    handle: u64,
+    layer: AnimationLayerDto,
+    enabled: bool,
+    weight: f32,
+    blend: LayerBlendDto,
+) -> Result<(), String> {
+    let mut map = models().lock().expect("models mutex poisoned");
+    let instance = map
+        .get_mut(&handle)
+        .ok_or_else(|| "live2d_model_set_layer: invalid handle".to_string())?;
+
+    instance.compositor.set_settings(
+        layer_kind(layer),
+        LayerSettings {
+            enabled,
+            weight,
+            blend: match blend {
+                LayerBlendDto::Normal => LayerBlend::Normal,
+                LayerBlendDto::Additive => LayerBlend::Additive,
+            },
+        },
+    );
+    Ok(())
+}
+
+/// 在最上层固定参数值（优先于所有效果）；`value` 为 None 时取消固定
+#[flutter_rust_bridge::frb(sync)]
+pub fn live2d_model_set_parameter_override(
+    handle: u64,
+    parameter_id: String,
+    value: Option<f32>,
+) -> Result<(), String> {
+    let mut map = models().lock().expect("models mutex poisoned");
+    let instance = map
+        .get_mut(&handle)
+        .ok_or_else(|| "live2d_model_set_parameter_override: invalid handle".to_string())?;
+
+    if instance
+        .compositor
+        .set_override(instance.model.get_static(), &parameter_id, value)
+    {
+        Ok(())
+    } else {
+        Err(format!("Parameter '{}' not found", parameter_id))
+    }
+}
+
+/// 立即眨一次眼（正在眨眼时忽略）
+#[flutter_rust_bridge::frb(sync)]
+pub fn live2d_model_force_blink(handle: u64) -> Result<(), String> {
+    let mut map = models().lock().expect("models mutex poisoned");
+    let instance = map
+        .get_mut(&handle)
+        .ok_or_else(|| "live2d_model_force_blink: invalid handle".to_string())?;
+
+    if let Some(eye_blink) = instance.eye_blink.as_mut() {
+        eye_blink.force_blink();
+    }
+    Ok(())
+}
+
 /// 生成一帧渲染数据（目前不做参数动画，只是把底层顶点 / 颜色等导出来）
 #[flutter_rust_bridge::frb(sync)]
 pub fn live2d_model_step(handle: u64) -> Result<FrameDto, String> {
-    let map = models().lock().expect("models mutex poisoned");
+    let mut map = models().lock().expect("models mutex poisoned");
     let instance = map
-        .get(&handle)
+        .get_mut(&handle)
         .ok_or_else(|| "live2d_model_step: invalid handle".to_string())?;
 
     let model_static = instance.model.get_static();
@@ -151,29 +1312,33 @@ pub fn live2d_model_step(handle: u64) -> Result<FrameDto, String> {
 
     // 一般这里应该做：根据时间、输入参数驱动 parameter_values，然后再 update
     dynamic.update();
-    dynamic.reset_drawable_dynamic_flags();
 
     let canvas = model_static.canvas_info();
     let (canvas_width, canvas_height) = canvas_size(canvas);
 
     let drawables = model_static.drawables();
     let vertex_positions_containers = dynamic.drawable_vertex_position_containers();
-    let draw_orders = dynamic.drawable_draw_orders();
+    let draw_orders = match instance.render_order.mode() {
+        DrawOrderMode::RenderOrder => dynamic.drawable_render_orders(),
+        DrawOrderMode::DrawOrder => dynamic.drawable_draw_orders(),
+    };
     let opacities = dynamic.drawable_opacities();
     let multiply_colors = dynamic.drawable_multiply_colors();
     let screen_colors = dynamic.drawable_screen_colors();
 
-    // draw_order 升序排序索引
-    let mut indices_sorted: Vec<usize> = (0..drawables.len()).collect();
-    indices_sorted.sort_by(|&a, &b| {
-        let oa = draw_orders[a];
-        let ob = draw_orders[b];
-        oa.cmp(&ob)
-    });
+    // 遮罩分组：只保留本帧有可见被裁剪 drawable 的分组
+    let clipping_plan = instance.clipping.plan_frame(&dynamic);
+    let mut frame_group_of_context = vec![None; instance.clipping.contexts().len()];
+    for (frame_index, group) in clipping_plan.groups.iter().enumerate() {
+        frame_group_of_context[group.context_index] = Some(frame_index as u32);
+    }
+
+    // 渲染顺序（有缓存，只有 Core 标记顺序变化时才重新计算）
+    let indices_sorted = instance.render_order.update(&dynamic);
 
     let mut drawable_frames = Vec::with_capacity(drawables.len());
 
-    for drawable_i in indices_sorted {
+    for drawable_i in indices_sorted.iter().map(|i| i.as_usize()) {
         let drawable = &drawables[drawable_i];
         let positions: &[Vector2] = vertex_positions_containers[drawable_i];
         let uvs_src: &[Vector2] = drawable.vertex_uvs();
@@ -217,6 +1382,10 @@ pub fn live2d_model_step(handle: u64) -> Result<FrameDto, String> {
                 .iter()
                 .map(|&m| m as u16)
                 .collect(),
+            mask_group: instance
+                .clipping
+                .context_index_of(drawable.index())
+                .and_then(|context_index| frame_group_of_context[context_index]),
             opacity: opacities[drawable_i],
             multiply_color: vec4_to_f32(&multiply),
             screen_color: vec4_to_f32(&screen),
@@ -226,13 +1395,151 @@ pub fn live2d_model_step(handle: u64) -> Result<FrameDto, String> {
         drawable_frames.push(frame);
     }
 
-    Ok(FrameDto {
+    let frame = FrameDto {
         canvas_width,
         canvas_height,
+        canvas_origin_x: canvas.origin_in_pixels.0,
+        canvas_origin_y: canvas.origin_in_pixels.1,
+        pixels_per_unit: canvas.pixels_per_unit,
         drawables: drawable_frames,
+        mask_texture_count: clipping_plan.mask_texture_count as u32,
+        mask_groups: clipping_plan
+            .groups
+            .iter()
+            .map(|group| mask_group_dto(&instance.clipping, group))
+            .collect(),
+    };
+
+    // 读取完本帧的变化标记后再重置
+    dynamic.reset_drawable_dynamic_flags();
+
+    Ok(frame)
+}
+
+/// 生成一帧合批后的渲染数据：相邻且渲染状态相同的 drawable 合并为一次绘制
+#[flutter_rust_bridge::frb(sync)]
+pub fn live2d_model_step_batched(handle: u64) -> Result<BatchedFrameDto, String> {
+    let mut map = models().lock().expect("models mutex poisoned");
+    let instance = map
+        .get_mut(&handle)
+        .ok_or_else(|| "live2d_model_step_batched: invalid handle".to_string())?;
+
+    let model_static = instance.model.get_static();
+    let mut dynamic = instance.model.write_dynamic();
+
+    dynamic.update();
+
+    let canvas = model_static.canvas_info();
+    let (canvas_width, canvas_height) = canvas_size(canvas);
+
+    let clipping_plan = instance.clipping.plan_frame(&dynamic);
+    let mut frame_group_of_context = vec![None; instance.clipping.contexts().len()];
+    for (frame_index, group) in clipping_plan.groups.iter().enumerate() {
+        frame_group_of_context[group.context_index] = Some(frame_index as u32);
+    }
+
+    let batcher = DrawBatcher::default();
+    let order = instance.render_order.update(&dynamic).iter().copied();
+
+    let batches = batcher
+        .batch(order, model_static, &dynamic, Some(&instance.clipping))
+        .into_iter()
+        .map(|batch| {
+            let mask_group = batch
+                .key
+                .mask_context
+                .and_then(|context_index| frame_group_of_context[context_index]);
+            draw_batch_dto(batch, mask_group)
+        })
+        .collect();
+
+    let mask_batches = clipping_plan
+        .groups
+        .iter()
+        .enumerate()
+        .flat_map(|(frame_index, group)| {
+            let context = &instance.clipping.contexts()[group.context_index];
+            batcher
+                .batch_masks(context, model_static, &dynamic)
+                .into_iter()
+                .map(move |batch| draw_batch_dto(batch, Some(frame_index as u32)))
+        })
+        .collect();
+
+    let frame = BatchedFrameDto {
+        canvas_width,
+        canvas_height,
+        canvas_origin_x: canvas.origin_in_pixels.0,
+        canvas_origin_y: canvas.origin_in_pixels.1,
+        pixels_per_unit: canvas.pixels_per_unit,
+        batches,
+        mask_texture_count: clipping_plan.mask_texture_count as u32,
+        mask_groups: clipping_plan
+            .groups
+            .iter()
+            .map(|group| mask_group_dto(&instance.clipping, group))
+            .collect(),
+        mask_batches,
+    };
+
+    dynamic.reset_drawable_dynamic_flags();
+
+    Ok(frame)
+}
+
+/// 设置 drawable 的排序方式（默认使用 Core 的渲染顺序）
+#[flutter_rust_bridge::frb(sync)]
+pub fn live2d_model_set_draw_order_mode(handle: u64, mode: DrawOrderModeDto) -> Result<(), String> {
+    let mut map = models().lock().expect("models mutex poisoned");
+    let instance = map
+        .get_mut(&handle)
+        .ok_or_else(|| "live2d_model_set_draw_order_mode: invalid handle".to_string())?;
+
+    instance.render_order.set_mode(match mode {
+        DrawOrderModeDto::RenderOrder => DrawOrderMode::RenderOrder,
+        DrawOrderModeDto::DrawOrder => DrawOrderMode::DrawOrder,
+    });
+    Ok(())
+}
+
+/// 计算模型到视口的变换矩阵（考虑画布原点、适配方式、平移与缩放）
+#[flutter_rust_bridge::frb(sync)]
+pub fn live2d_model_view_transform(
+    handle: u64,
+    viewport: ViewportDto,
+) -> Result<ViewTransformDto, String> {
+    let map = models().lock().expect("models mutex poisoned");
+    let instance = map
+        .get(&handle)
+        .ok_or_else(|| "live2d_model_view_transform: invalid handle".to_string())?;
+
+    let transform = view_transform(instance.model.get_static().canvas_info(), &viewport);
+
+    Ok(ViewTransformDto {
+        pixels_per_unit: transform.pixels_per_unit(),
+        model_to_viewport: transform.model_to_viewport_matrix(),
+        model_to_ndc: transform.model_to_ndc_matrix(),
     })
 }
 
+/// 把视口像素坐标（例如指针位置）换算为模型坐标
+#[flutter_rust_bridge::frb(sync)]
+pub fn live2d_model_viewport_to_model(
+    handle: u64,
+    viewport: ViewportDto,
+    x: f32,
+    y: f32,
+) -> Result<[f32; 2], String> {
+    let map = models().lock().expect("models mutex poisoned");
+    let instance = map
+        .get(&handle)
+        .ok_or_else(|| "live2d_model_viewport_to_model: invalid handle".to_string())?;
+
+    let transform = view_transform(instance.model.get_static().canvas_info(), &viewport);
+
+    Ok(vec2_to_f32(&transform.viewport_to_model(Vector2 { x, y })))
+}
+
 /// 设置 Part 的不透明度
 #[flutter_rust_bridge::frb(sync)]
 pub fn live2d_model_set_part_opacity(
@@ -262,6 +1569,134 @@ pub fn live2d_model_set_part_opacity(
     Err(format!("Part '{}' not found. First 10 available: {:?}", part_id, available))
 }
 
+/// 读入 cdi3.json（参数 / Part 的显示名称与参数分组）
+#[flutter_rust_bridge::frb(sync)]
+pub fn live2d_model_apply_display_info(handle: u64, cdi3_json: String) -> Result<(), String> {
+    let display_info = DisplayInfo::from_json(&cdi3_json)
+        .map_err(|e| format!("live2d_model_apply_display_info: {e}"))?;
+
+    let mut map = models().lock().expect("models mutex poisoned");
+    let instance = map
+        .get_mut(&handle)
+        .ok_or_else(|| "live2d_model_apply_display_info: invalid handle".to_string())?;
+
+    instance.display_info = Some(display_info);
+    Ok(())
+}
+
+/// 读入 userdata3.json（ArtMesh 上的自定义字符串，例如可点击区域标记）
+#[flutter_rust_bridge::frb(sync)]
+pub fn live2d_model_apply_user_data(handle: u64, userdata3_json: String) -> Result<(), String> {
+    let user_data = ModelUserData::from_json(&userdata3_json)
+        .map_err(|e| format!("live2d_model_apply_user_data: {e}"))?;
+
+    let mut map = models().lock().expect("models mutex poisoned");
+    let instance = map
+        .get_mut(&handle)
+        .ok_or_else(|| "live2d_model_apply_user_data: invalid handle".to_string())?;
+
+    instance.user_data = Some(user_data);
+    Ok(())
+}
+
+/// 获取所有参数的信息（显示名称、分组、取值范围）
+#[flutter_rust_bridge::frb(sync)]
+pub fn live2d_model_get_parameter_infos(handle: u64) -> Result<Vec<ParameterInfoDto>, String> {
+    let map = models().lock().expect("models mutex poisoned");
+    let instance = map
+        .get(&handle)
+        .ok_or_else(|| "live2d_model_get_parameter_infos: invalid handle".to_string())?;
+
+    let display_info = instance.display_info.as_ref();
+    Ok(instance
+        .model
+        .get_static()
+        .parameters()
+        .iter()
+        .map(|p| {
+            let info = display_info.and_then(|d| d.parameter(p.id()));
+            let (min_value, max_value) = p.value_range();
+            ParameterInfoDto {
+                id: p.id().to_string(),
+                name: info
+                    .map(|i| i.name.clone())
+                    .filter(|name| !name.is_empty())
+                    .unwrap_or_else(|| p.id().to_string()),
+                group_id: info.map(|i| i.group_id.clone()).unwrap_or_default(),
+                min_value,
+                max_value,
+                default_value: p.default_value(),
+            }
+        })
+        .collect())
+}
+
+/// 获取 cdi3.json 中的参数分组；未读入时为空
+#[flutter_rust_bridge::frb(sync)]
+pub fn live2d_model_get_parameter_groups(handle: u64) -> Result<Vec<ParameterGroupInfoDto>, String> {
+    let map = models().lock().expect("models mutex poisoned");
+    let instance = map
+        .get(&handle)
+        .ok_or_else(|| "live2d_model_get_parameter_groups: invalid handle".to_string())?;
+
+    Ok(instance
+        .display_info
+        .iter()
+        .flat_map(|d| &d.parameter_groups)
+        .map(|g| ParameterGroupInfoDto {
+            id: g.id.clone(),
+            name: g.name.clone(),
+            parent_id: g.group_id.clone(),
+        })
+        .collect())
+}
+
+/// 获取所有 Part 的信息（显示名称）
+#[flutter_rust_bridge::frb(sync)]
+pub fn live2d_model_get_part_infos(handle: u64) -> Result<Vec<PartInfoDto>, String> {
+    let map = models().lock().expect("models mutex poisoned");
+    let instance = map
+        .get(&handle)
+        .ok_or_else(|| "live2d_model_get_part_infos: invalid handle".to_string())?;
+
+    let display_info = instance.display_info.as_ref();
+    Ok(instance
+        .model
+        .get_static()
+        .parts()
+        .iter()
+        .map(|p| PartInfoDto {
+            id: p.id().to_string(),
+            name: display_info
+                .and_then(|d| d.part(p.id()))
+                .map(|i| i.name.clone())
+                .filter(|name| !name.is_empty())
+                .unwrap_or_else(|| p.id().to_string()),
+        })
+        .collect())
+}
+
+/// 获取 userdata3.json 中附加在 drawable 上的字符串；未读入时为空
+#[flutter_rust_bridge::frb(sync)]
+pub fn live2d_model_get_drawable_user_data(handle: u64) -> Result<Vec<DrawableUserDataDto>, String> {
+    let map = models().lock().expect("models mutex poisoned");
+    let instance = map
+        .get(&handle)
+        .ok_or_else(|| "live2d_model_get_drawable_user_data: invalid handle".to_string())?;
+
+    let model_static = instance.model.get_static();
+    Ok(instance
+        .user_data
+        .iter()
+        .flat_map(|u| u.art_mesh_entries(model_static))
+        .map(|(index, entry)| DrawableUserDataDto {
+            drawable_index: index.as_usize() as u32,
+            drawable_id: entry.id.clone(),
+            value: entry.value.clone(),
+        })
+        .collect())
+}
+
 /// 获取所有参数 ID
 #[flutter_rust_bridge::frb(sync)]
 pub fn live2d_model_get_parameter_ids(handle: u64) -> Result<Vec<String>, String> {
@@ -0,0 +1,95 @@
+use std::path::Path;
+
+use live2d_core::framework::{LoadedTexture, ModelSettings, TextureLoadOptions, TextureLoader};
+
+/// 纹理加载选项
+#[derive(Debug, Clone)]
+pub struct TextureOptionsDto {
+    /// 是否预乘 alpha（与 Cubism 的预乘混合保持一致）
+    pub premultiply_alpha: bool,
+    /// 是否生成 mipmap
+    pub generate_mipmaps: bool,
+    /// 所有纹理（含 mipmap）的总字节上限；超出时统一减半缩放，None 表示不限制
+    pub memory_budget_bytes: Option<u64>,
+}
+
+/// 单个 mipmap 层级
+#[derive(Debug, Clone)]
+pub struct TextureLevelDto {
+    pub width: u32,
+    pub height: u32,
+    /// RGBA8 像素数据，逐行从上到下
+    pub rgba: Vec<u8>,
+}
+
+/// 可直接上传到 GPU 的纹理
+#[derive(Debug, Clone)]
+pub struct TextureDto {
+    /// 对应 drawable 的 `texture_index`
+    pub texture_index: u32,
+    pub width: u32,
+    pub height: u32,
+    pub premultiplied_alpha: bool,
+    /// levels[0] 为原图，其后依次为 mipmap
+    pub levels: Vec<TextureLevelDto>,
+}
+
+fn loader(options: &TextureOptionsDto) -> TextureLoader {
+    TextureLoader::new(TextureLoadOptions {
+        premultiply_alpha: options.premultiply_alpha,
+        generate_mipmaps: options.generate_mipmaps,
+        memory_budget: options.memory_budget_bytes.map(|b| b as usize),
+    })
+}
+
+fn texture_dto(texture: LoadedTexture) -> TextureDto {
+    let base = &texture.levels[0];
+    TextureDto {
+        texture_index: texture.index.as_usize() as u32,
+        width: base.width,
+        height: base.height,
+        premultiplied_alpha: texture.premultiplied_alpha,
+        levels: texture
+            .levels
+            .into_iter()
+            .map(|level| TextureLevelDto {
+                width: level.width,
+                height: level.height,
+                rgba: level.pixels,
+            })
+            .collect(),
+    }
+}
+
+/// 解码 PNG 字节数组，第 n 张图片对应 texture_index n
+///
+/// Dart 端应使用此函数：Flutter 资源（`rootBundle`）不是文件系统中的文件，
+/// 需先在 Dart 中读取字节再传入。
+pub fn live2d_textures_decode(
+    pngs: Vec<Vec<u8>>,
+    options: TextureOptionsDto,
+) -> Result<Vec<TextureDto>, String> {
+    let textures = loader(&options)
+        .load_pngs(pngs.iter().map(Vec::as_slice))
+        .map_err(|e| format!("live2d_textures_decode: {e}"))?;
+    Ok(textures.into_iter().map(texture_dto).collect())
+}
+
+/// 读取 model3.json 中列出的全部纹理
+///
+/// 只适用于本地文件系统中的模型目录（桌面端或下载到应用目录的模型），
+/// 无法读取 Flutter 资源，也不支持 Web；其他情况请使用 `live2d_textures_decode`。
+pub fn live2d_textures_load_from_model3(
+    model3_json_path: String,
+    options: TextureOptionsDto,
+) -> Result<Vec<TextureDto>, String> {
+    let path = Path::new(&model3_json_path);
+    let settings = ModelSettings::from_file(path)
+        .map_err(|e| format!("live2d_textures_load_from_model3: {e}"))?;
+    let model_dir = path.parent().unwrap_or_else(|| Path::new(""));
+
+    let textures = loader(&options)
+        .load_model_textures(&settings, model_dir)
+        .map_err(|e| format!("live2d_textures_load_from_model3: {e}"))?;
+    Ok(textures.into_iter().map(texture_dto).collect())
+}
@@ -1,2 +1,4 @@
 pub mod wise_lover_boot_api;
 pub mod live2d_model_api;
+pub mod live2d_texture_api;
+pub mod chat_api;
@@ -37,7 +37,7 @@ flutter_rust_bridge::frb_generated_boilerplate!(
     default_rust_auto_opaque = RustAutoOpaqueMoi,
 );
 pub(crate) const FLUTTER_RUST_BRIDGE_CODEGEN_VERSION: &str = "2.11.1";
-pub(crate) const FLUTTER_RUST_BRIDGE_CODEGEN_CONTENT_HASH: i32 = 1822512288;
+pub(crate) const FLUTTER_RUST_BRIDGE_CODEGEN_CONTENT_HASH: i32 = 895375696;
 
 // Section: executor
 
@@ -45,14 +45,14 @@ flutter_rust_bridge::frb_generated_default_handler!();
 
 // Section: wire_funcs
 
-fn wire__crate__api__wise_lover_boot_api__live2d_core_check_moc_consistency_impl(
+fn wire__crate__api__chat_api__chat_cancel_impl(
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "live2d_core_check_moc_consistency",
+            debug_name: "chat_cancel",
             port: None,
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
         },
@@ -66,29 +66,27 @@ fn wire__crate__api__wise_lover_boot_api__live2d_core_check_moc_consistency_impl
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_moc_bytes = <Vec<u8>>::sse_decode(&mut deserializer);
             deserializer.end();
             transform_result_sse::<_, ()>((move || {
-                let output_ok = Result::<_, ()>::Ok(
-                    crate::api::wise_lover_boot_api::live2d_core_check_moc_consistency(
-                        api_moc_bytes,
-                    ),
-                )?;
+                let output_ok = Result::<_, ()>::Ok({
+                    crate::api::chat_api::chat_cancel();
+                })?;
                 Ok(output_ok)
             })())
         },
     )
 }
-fn wire__crate__api__wise_lover_boot_api__live2d_core_latest_moc_version_impl(
+fn wire__crate__api__chat_api__chat_capabilities_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
-) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "live2d_core_latest_moc_version",
-            port: None,
-            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+            debug_name: "chat_capabilities",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
         move || {
             let message = unsafe {
@@ -101,25 +99,29 @@ fn wire__crate__api__wise_lover_boot_api__live2d_core_latest_moc_version_impl(
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
             deserializer.end();
-            transform_result_sse::<_, ()>((move || {
-                let output_ok = Result::<_, ()>::Ok(
-                    crate::api::wise_lover_boot_api::live2d_core_latest_moc_version(),
-                )?;
-                Ok(output_ok)
-            })())
+            move |context| async move {
+                transform_result_sse::<_, String>(
+                    (move || async move {
+                        let output_ok = crate::api::chat_api::chat_capabilities().await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
         },
     )
 }
-fn wire__crate__api__wise_lover_boot_api__live2d_core_loader_impl(
+fn wire__crate__api__chat_api__chat_close_session_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
-) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "live2d_core_loader",
-            port: None,
-            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+            debug_name: "chat_close_session",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
         move || {
             let message = unsafe {
@@ -132,24 +134,31 @@ fn wire__crate__api__wise_lover_boot_api__live2d_core_loader_impl(
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
             deserializer.end();
-            transform_result_sse::<_, ()>((move || {
-                let output_ok =
-                    Result::<_, ()>::Ok(crate::api::wise_lover_boot_api::live2d_core_loader())?;
-                Ok(output_ok)
-            })())
+            move |context| async move {
+                transform_result_sse::<_, ()>(
+                    (move || async move {
+                        let output_ok = Result::<_, ()>::Ok({
+                            crate::api::chat_api::chat_close_session().await;
+                        })?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
         },
     )
 }
-fn wire__crate__api__wise_lover_boot_api__live2d_core_moc_version_impl(
+fn wire__crate__api__chat_api__chat_configure_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
-) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "live2d_core_moc_version",
-            port: None,
-            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+            debug_name: "chat_configure",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
         move || {
             let message = unsafe {
@@ -161,24 +170,113 @@ fn wire__crate__api__wise_lover_boot_api__live2d_core_moc_version_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_moc_bytes = <Vec<u8>>::sse_decode(&mut deserializer);
+            let api_provider =
+                <crate::api::chat_api::ChatProviderDto>::sse_decode(&mut deserializer);
+            let api_config = <crate::api::chat_api::ChatConfigDto>::sse_decode(&mut deserializer);
             deserializer.end();
-            transform_result_sse::<_, String>((move || {
-                let output_ok =
-                    crate::api::wise_lover_boot_api::live2d_core_moc_version(api_moc_bytes)?;
-                Ok(output_ok)
-            })())
+            move |context| async move {
+                transform_result_sse::<_, String>(
+                    (move || async move {
+                        let output_ok =
+                            crate::api::chat_api::chat_configure(api_provider, api_config).await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
         },
     )
 }
-fn wire__crate__api__wise_lover_boot_api__live2d_core_version_impl(
+fn wire__crate__api__chat_api__chat_configure_from_file_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "chat_configure_from_file",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_path = <String>::sse_decode(&mut deserializer);
+            let api_profile = <Option<String>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, String>(
+                    (move || async move {
+                        let output_ok =
+                            crate::api::chat_api::chat_configure_from_file(api_path, api_profile)
+                                .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__chat_api__chat_configure_from_toml_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "chat_configure_from_toml",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_config_toml = <String>::sse_decode(&mut deserializer);
+            let api_profile = <Option<String>>::sse_decode(&mut deserializer);
+            let api_api_key = <Option<String>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, String>(
+                    (move || async move {
+                        let output_ok = crate::api::chat_api::chat_configure_from_toml(
+                            api_config_toml,
+                            api_profile,
+                            api_api_key,
+                        )
+                        .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__chat_api__chat_default_config_impl(
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "live2d_core_version",
+            debug_name: "chat_default_config",
             port: None,
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
         },
@@ -194,21 +292,20 @@ fn wire__crate__api__wise_lover_boot_api__live2d_core_version_impl(
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
             deserializer.end();
             transform_result_sse::<_, ()>((move || {
-                let output_ok =
-                    Result::<_, ()>::Ok(crate::api::wise_lover_boot_api::live2d_core_version())?;
+                let output_ok = Result::<_, ()>::Ok(crate::api::chat_api::chat_default_config())?;
                 Ok(output_ok)
             })())
         },
     )
 }
-fn wire__crate__api__live2d_model_api__live2d_model_get_parameter_ids_impl(
+fn wire__crate__api__chat_api__chat_default_provider_impl(
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "live2d_model_get_parameter_ids",
+            debug_name: "chat_default_provider",
             port: None,
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
         },
@@ -222,26 +319,28 @@ fn wire__crate__api__live2d_model_api__live2d_model_get_parameter_ids_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_handle = <u64>::sse_decode(&mut deserializer);
+            let api_kind =
+                <crate::api::chat_api::ChatProviderKindDto>::sse_decode(&mut deserializer);
             deserializer.end();
-            transform_result_sse::<_, String>((move || {
+            transform_result_sse::<_, ()>((move || {
                 let output_ok =
-                    crate::api::live2d_model_api::live2d_model_get_parameter_ids(api_handle)?;
+                    Result::<_, ()>::Ok(crate::api::chat_api::chat_default_provider(api_kind))?;
                 Ok(output_ok)
             })())
         },
     )
 }
-fn wire__crate__api__live2d_model_api__live2d_model_get_part_ids_impl(
+fn wire__crate__api__chat_api__chat_delete_session_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
-) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "live2d_model_get_part_ids",
-            port: None,
-            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+            debug_name: "chat_delete_session",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
         move || {
             let message = unsafe {
@@ -253,26 +352,34 @@ fn wire__crate__api__live2d_model_api__live2d_model_get_part_ids_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_handle = <u64>::sse_decode(&mut deserializer);
+            let api_store_dir = <String>::sse_decode(&mut deserializer);
+            let api_character = <String>::sse_decode(&mut deserializer);
+            let api_session_id = <String>::sse_decode(&mut deserializer);
             deserializer.end();
-            transform_result_sse::<_, String>((move || {
-                let output_ok =
-                    crate::api::live2d_model_api::live2d_model_get_part_ids(api_handle)?;
-                Ok(output_ok)
-            })())
+            move |context| {
+                transform_result_sse::<_, String>((move || {
+                    let output_ok = crate::api::chat_api::chat_delete_session(
+                        api_store_dir,
+                        api_character,
+                        api_session_id,
+                    )?;
+                    Ok(output_ok)
+                })())
+            }
         },
     )
 }
-fn wire__crate__api__live2d_model_api__live2d_model_load_impl(
+fn wire__crate__api__chat_api__chat_history_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
-) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "live2d_model_load",
-            port: None,
-            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+            debug_name: "chat_history",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
         move || {
             let message = unsafe {
@@ -284,25 +391,31 @@ fn wire__crate__api__live2d_model_api__live2d_model_load_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_moc_bytes = <Vec<u8>>::sse_decode(&mut deserializer);
             deserializer.end();
-            transform_result_sse::<_, String>((move || {
-                let output_ok = crate::api::live2d_model_api::live2d_model_load(api_moc_bytes)?;
-                Ok(output_ok)
-            })())
+            move |context| async move {
+                transform_result_sse::<_, ()>(
+                    (move || async move {
+                        let output_ok =
+                            Result::<_, ()>::Ok(crate::api::chat_api::chat_history().await)?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
         },
     )
 }
-fn wire__crate__api__live2d_model_api__live2d_model_set_parameter_impl(
+fn wire__crate__api__chat_api__chat_key_usage_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
-) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "live2d_model_set_parameter",
-            port: None,
-            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+            debug_name: "chat_key_usage",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
         move || {
             let message = unsafe {
@@ -314,31 +427,31 @@ fn wire__crate__api__live2d_model_api__live2d_model_set_parameter_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_handle = <u64>::sse_decode(&mut deserializer);
-            let api_parameter_id = <String>::sse_decode(&mut deserializer);
-            let api_value = <f32>::sse_decode(&mut deserializer);
             deserializer.end();
-            transform_result_sse::<_, String>((move || {
-                let output_ok = crate::api::live2d_model_api::live2d_model_set_parameter(
-                    api_handle,
-                    api_parameter_id,
-                    api_value,
-                )?;
-                Ok(output_ok)
-            })())
+            move |context| async move {
+                transform_result_sse::<_, ()>(
+                    (move || async move {
+                        let output_ok =
+                            Result::<_, ()>::Ok(crate::api::chat_api::chat_key_usage().await)?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
         },
     )
 }
-fn wire__crate__api__live2d_model_api__live2d_model_set_part_opacity_impl(
+fn wire__crate__api__chat_api__chat_list_models_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
-) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "live2d_model_set_part_opacity",
-            port: None,
-            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+            debug_name: "chat_list_models",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
         move || {
             let message = unsafe {
@@ -350,31 +463,30 @@ fn wire__crate__api__live2d_model_api__live2d_model_set_part_opacity_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_handle = <u64>::sse_decode(&mut deserializer);
-            let api_part_id = <String>::sse_decode(&mut deserializer);
-            let api_opacity = <f32>::sse_decode(&mut deserializer);
             deserializer.end();
-            transform_result_sse::<_, String>((move || {
-                let output_ok = crate::api::live2d_model_api::live2d_model_set_part_opacity(
-                    api_handle,
-                    api_part_id,
-                    api_opacity,
-                )?;
-                Ok(output_ok)
-            })())
+            move |context| async move {
+                transform_result_sse::<_, String>(
+                    (move || async move {
+                        let output_ok = crate::api::chat_api::chat_list_models().await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
         },
     )
 }
-fn wire__crate__api__live2d_model_api__live2d_model_step_impl(
+fn wire__crate__api__chat_api__chat_list_sessions_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
-) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "live2d_model_step",
-            port: None,
-            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+            debug_name: "chat_list_sessions",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
         move || {
             let message = unsafe {
@@ -386,25 +498,30 @@ fn wire__crate__api__live2d_model_api__live2d_model_step_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_handle = <u64>::sse_decode(&mut deserializer);
+            let api_store_dir = <String>::sse_decode(&mut deserializer);
+            let api_character = <String>::sse_decode(&mut deserializer);
             deserializer.end();
-            transform_result_sse::<_, String>((move || {
-                let output_ok = crate::api::live2d_model_api::live2d_model_step(api_handle)?;
-                Ok(output_ok)
-            })())
+            move |context| {
+                transform_result_sse::<_, String>((move || {
+                    let output_ok =
+                        crate::api::chat_api::chat_list_sessions(api_store_dir, api_character)?;
+                    Ok(output_ok)
+                })())
+            }
         },
     )
 }
-fn wire__crate__api__live2d_model_api__live2d_model_unload_impl(
+fn wire__crate__api__chat_api__chat_load_config_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
-) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "live2d_model_unload",
-            port: None,
-            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+            debug_name: "chat_load_config",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
         move || {
             let message = unsafe {
@@ -416,18 +533,18 @@ fn wire__crate__api__live2d_model_api__live2d_model_unload_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_handle = <u64>::sse_decode(&mut deserializer);
+            let api_path = <String>::sse_decode(&mut deserializer);
             deserializer.end();
-            transform_result_sse::<_, ()>((move || {
-                let output_ok = Result::<_, ()>::Ok({
-                    crate::api::live2d_model_api::live2d_model_unload(api_handle);
-                })?;
-                Ok(output_ok)
-            })())
+            move |context| {
+                transform_result_sse::<_, String>((move || {
+                    let output_ok = crate::api::chat_api::chat_load_config(api_path)?;
+                    Ok(output_ok)
+                })())
+            }
         },
     )
 }
-fn wire__crate__api__wise_lover_boot_api__wise_lover_boot_init_app_impl(
+fn wire__crate__api__chat_api__chat_model_profile_dto_default_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -435,7 +552,7 @@ fn wire__crate__api__wise_lover_boot_api__wise_lover_boot_init_app_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "wise_lover_boot_init_app",
+            debug_name: "chat_model_profile_dto_default",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -452,446 +569,5858 @@ fn wire__crate__api__wise_lover_boot_api__wise_lover_boot_init_app_impl(
             deserializer.end();
             move |context| {
                 transform_result_sse::<_, ()>((move || {
-                    let output_ok = Result::<_, ()>::Ok({
-                        crate::api::wise_lover_boot_api::wise_lover_boot_init_app();
-                    })?;
+                    let output_ok =
+                        Result::<_, ()>::Ok(crate::api::chat_api::ChatModelProfileDto::default())?;
                     Ok(output_ok)
                 })())
             }
         },
     )
 }
+fn wire__crate__api__chat_api__chat_open_session_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "chat_open_session",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_store_dir = <String>::sse_decode(&mut deserializer);
+            let api_character = <String>::sse_decode(&mut deserializer);
+            let api_session_id = <Option<String>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, String>(
+                    (move || async move {
+                        let output_ok = crate::api::chat_api::chat_open_session(
+                            api_store_dir,
+                            api_character,
+                            api_session_id,
+                        )
+                        .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__chat_api__chat_reset_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "chat_reset",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, ()>(
+                    (move || async move {
+                        let output_ok = Result::<_, ()>::Ok({
+                            crate::api::chat_api::chat_reset().await;
+                        })?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__chat_api__chat_save_config_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "chat_save_config",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_path = <String>::sse_decode(&mut deserializer);
+            let api_config =
+                <crate::api::chat_api::ChatAppConfigDto>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, String>((move || {
+                    let output_ok = crate::api::chat_api::chat_save_config(api_path, api_config)?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__chat_api__chat_send_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "chat_send",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_message = <String>::sse_decode(&mut deserializer);
+            let api_mode =
+                <Option<crate::api::chat_api::ChatInvokeModeDto>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, String>(
+                    (move || async move {
+                        let output_ok =
+                            crate::api::chat_api::chat_send(api_message, api_mode).await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__chat_api__chat_send_stream_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "chat_send_stream",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_message = <String>::sse_decode(&mut deserializer);
+            let api_sink =
+                <StreamSink<String, flutter_rust_bridge::for_generated::SseCodec>>::sse_decode(
+                    &mut deserializer,
+                );
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, String>(
+                    (move || async move {
+                        let output_ok =
+                            crate::api::chat_api::chat_send_stream(api_message, api_sink).await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__chat_api__chat_summary_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "chat_summary",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, ()>(
+                    (move || async move {
+                        let output_ok =
+                            Result::<_, ()>::Ok(crate::api::chat_api::chat_summary().await)?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__wise_lover_boot_api__live2d_core_check_moc_consistency_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "live2d_core_check_moc_consistency",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_moc_bytes = <Vec<u8>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let output_ok = Result::<_, ()>::Ok(
+                    crate::api::wise_lover_boot_api::live2d_core_check_moc_consistency(
+                        api_moc_bytes,
+                    ),
+                )?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__wise_lover_boot_api__live2d_core_latest_moc_version_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "live2d_core_latest_moc_version",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let output_ok = Result::<_, ()>::Ok(
+                    crate::api::wise_lover_boot_api::live2d_core_latest_moc_version(),
+                )?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__wise_lover_boot_api__live2d_core_loader_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "live2d_core_loader",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let output_ok =
+                    Result::<_, ()>::Ok(crate::api::wise_lover_boot_api::live2d_core_loader())?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__wise_lover_boot_api__live2d_core_moc_version_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "live2d_core_moc_version",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_moc_bytes = <Vec<u8>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, String>((move || {
+                let output_ok =
+                    crate::api::wise_lover_boot_api::live2d_core_moc_version(api_moc_bytes)?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__wise_lover_boot_api__live2d_core_version_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "live2d_core_version",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let output_ok =
+                    Result::<_, ()>::Ok(crate::api::wise_lover_boot_api::live2d_core_version())?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__live2d_model_api__live2d_default_idle_effects_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "live2d_default_idle_effects",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let output_ok = Result::<_, ()>::Ok(
+                    crate::api::live2d_model_api::live2d_default_idle_effects(),
+                )?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__live2d_model_api__live2d_lip_sync_analyze_wav_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "live2d_lip_sync_analyze_wav",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_wav = <Vec<u8>>::sse_decode(&mut deserializer);
+            let api_config =
+                <crate::api::live2d_model_api::LipSyncConfigDto>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, String>((move || {
+                    let output_ok = crate::api::live2d_model_api::live2d_lip_sync_analyze_wav(
+                        api_wav, api_config,
+                    )?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__live2d_model_api__live2d_model_apply_display_info_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "live2d_model_apply_display_info",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_handle = <u64>::sse_decode(&mut deserializer);
+            let api_cdi3_json = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, String>((move || {
+                let output_ok = crate::api::live2d_model_api::live2d_model_apply_display_info(
+                    api_handle,
+                    api_cdi3_json,
+                )?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__live2d_model_api__live2d_model_apply_settings_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "live2d_model_apply_settings",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_handle = <u64>::sse_decode(&mut deserializer);
+            let api_model3_json = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, String>((move || {
+                let output_ok = crate::api::live2d_model_api::live2d_model_apply_settings(
+                    api_handle,
+                    api_model3_json,
+                )?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__live2d_model_api__live2d_model_apply_user_data_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "live2d_model_apply_user_data",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_handle = <u64>::sse_decode(&mut deserializer);
+            let api_userdata3_json = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, String>((move || {
+                let output_ok = crate::api::live2d_model_api::live2d_model_apply_user_data(
+                    api_handle,
+                    api_userdata3_json,
+                )?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__live2d_model_api__live2d_model_clear_emotion_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "live2d_model_clear_emotion",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_handle = <u64>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, String>((move || {
+                let output_ok =
+                    crate::api::live2d_model_api::live2d_model_clear_emotion(api_handle)?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__live2d_model_api__live2d_model_clear_parameter_smoothing_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "live2d_model_clear_parameter_smoothing",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_handle = <u64>::sse_decode(&mut deserializer);
+            let api_parameter_id = <Option<String>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, String>((move || {
+                let output_ok =
+                    crate::api::live2d_model_api::live2d_model_clear_parameter_smoothing(
+                        api_handle,
+                        api_parameter_id,
+                    )?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__live2d_model_api__live2d_model_configure_eye_blink_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "live2d_model_configure_eye_blink",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_handle = <u64>::sse_decode(&mut deserializer);
+            let api_config =
+                <crate::api::live2d_model_api::EyeBlinkConfigDto>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, String>((move || {
+                let output_ok = crate::api::live2d_model_api::live2d_model_configure_eye_blink(
+                    api_handle, api_config,
+                )?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__live2d_model_api__live2d_model_configure_lip_sync_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "live2d_model_configure_lip_sync",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_handle = <u64>::sse_decode(&mut deserializer);
+            let api_config =
+                <crate::api::live2d_model_api::LipSyncConfigDto>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, String>((move || {
+                let output_ok = crate::api::live2d_model_api::live2d_model_configure_lip_sync(
+                    api_handle, api_config,
+                )?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__live2d_model_api__live2d_model_configure_look_at_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "live2d_model_configure_look_at",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_handle = <u64>::sse_decode(&mut deserializer);
+            let api_config =
+                <crate::api::live2d_model_api::LookAtConfigDto>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, String>((move || {
+                let output_ok = crate::api::live2d_model_api::live2d_model_configure_look_at(
+                    api_handle, api_config,
+                )?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__live2d_model_api__live2d_model_force_blink_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "live2d_model_force_blink",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_handle = <u64>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, String>((move || {
+                let output_ok = crate::api::live2d_model_api::live2d_model_force_blink(api_handle)?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__live2d_model_api__live2d_model_get_drawable_user_data_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "live2d_model_get_drawable_user_data",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_handle = <u64>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, String>((move || {
+                let output_ok =
+                    crate::api::live2d_model_api::live2d_model_get_drawable_user_data(api_handle)?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__live2d_model_api__live2d_model_get_parameter_groups_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "live2d_model_get_parameter_groups",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_handle = <u64>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, String>((move || {
+                let output_ok =
+                    crate::api::live2d_model_api::live2d_model_get_parameter_groups(api_handle)?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__live2d_model_api__live2d_model_get_parameter_ids_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "live2d_model_get_parameter_ids",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_handle = <u64>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, String>((move || {
+                let output_ok =
+                    crate::api::live2d_model_api::live2d_model_get_parameter_ids(api_handle)?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__live2d_model_api__live2d_model_get_parameter_infos_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "live2d_model_get_parameter_infos",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_handle = <u64>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, String>((move || {
+                let output_ok =
+                    crate::api::live2d_model_api::live2d_model_get_parameter_infos(api_handle)?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__live2d_model_api__live2d_model_get_part_ids_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "live2d_model_get_part_ids",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_handle = <u64>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, String>((move || {
+                let output_ok =
+                    crate::api::live2d_model_api::live2d_model_get_part_ids(api_handle)?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__live2d_model_api__live2d_model_get_part_infos_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "live2d_model_get_part_infos",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_handle = <u64>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, String>((move || {
+                let output_ok =
+                    crate::api::live2d_model_api::live2d_model_get_part_infos(api_handle)?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__live2d_model_api__live2d_model_lip_sync_play_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "live2d_model_lip_sync_play",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_handle = <u64>::sse_decode(&mut deserializer);
+            let api_timeline =
+                <crate::api::live2d_model_api::LipSyncTimelineDto>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, String>((move || {
+                let output_ok = crate::api::live2d_model_api::live2d_model_lip_sync_play(
+                    api_handle,
+                    api_timeline,
+                )?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__live2d_model_api__live2d_model_lip_sync_play_wav_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "live2d_model_lip_sync_play_wav",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_handle = <u64>::sse_decode(&mut deserializer);
+            let api_wav = <Vec<u8>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, String>((move || {
+                let output_ok = crate::api::live2d_model_api::live2d_model_lip_sync_play_wav(
+                    api_handle, api_wav,
+                )?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__live2d_model_api__live2d_model_lip_sync_push_pcm_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "live2d_model_lip_sync_push_pcm",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_handle = <u64>::sse_decode(&mut deserializer);
+            let api_samples = <Vec<f32>>::sse_decode(&mut deserializer);
+            let api_sample_rate = <u32>::sse_decode(&mut deserializer);
+            let api_channels = <u16>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, String>((move || {
+                let output_ok = crate::api::live2d_model_api::live2d_model_lip_sync_push_pcm(
+                    api_handle,
+                    api_samples,
+                    api_sample_rate,
+                    api_channels,
+                )?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__live2d_model_api__live2d_model_lip_sync_push_pcm16_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "live2d_model_lip_sync_push_pcm16",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_handle = <u64>::sse_decode(&mut deserializer);
+            let api_samples = <Vec<i16>>::sse_decode(&mut deserializer);
+            let api_sample_rate = <u32>::sse_decode(&mut deserializer);
+            let api_channels = <u16>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, String>((move || {
+                let output_ok = crate::api::live2d_model_api::live2d_model_lip_sync_push_pcm16(
+                    api_handle,
+                    api_samples,
+                    api_sample_rate,
+                    api_channels,
+                )?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__live2d_model_api__live2d_model_lip_sync_stop_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "live2d_model_lip_sync_stop",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_handle = <u64>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, String>((move || {
+                let output_ok =
+                    crate::api::live2d_model_api::live2d_model_lip_sync_stop(api_handle)?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__live2d_model_api__live2d_model_load_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "live2d_model_load",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_moc_bytes = <Vec<u8>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, String>((move || {
+                let output_ok = crate::api::live2d_model_api::live2d_model_load(api_moc_bytes)?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__live2d_model_api__live2d_model_load_emotion_map_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "live2d_model_load_emotion_map",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_handle = <u64>::sse_decode(&mut deserializer);
+            let api_mapping_json = <String>::sse_decode(&mut deserializer);
+            let api_model_name = <Option<String>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, String>((move || {
+                let output_ok = crate::api::live2d_model_api::live2d_model_load_emotion_map(
+                    api_handle,
+                    api_mapping_json,
+                    api_model_name,
+                )?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__live2d_model_api__live2d_model_load_expression_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "live2d_model_load_expression",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_handle = <u64>::sse_decode(&mut deserializer);
+            let api_name = <String>::sse_decode(&mut deserializer);
+            let api_exp3_json = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, String>((move || {
+                let output_ok = crate::api::live2d_model_api::live2d_model_load_expression(
+                    api_handle,
+                    api_name,
+                    api_exp3_json,
+                )?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__live2d_model_api__live2d_model_load_motion_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "live2d_model_load_motion",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_handle = <u64>::sse_decode(&mut deserializer);
+            let api_name = <String>::sse_decode(&mut deserializer);
+            let api_motion3_json = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, String>((move || {
+                let output_ok = crate::api::live2d_model_api::live2d_model_load_motion(
+                    api_handle,
+                    api_name,
+                    api_motion3_json,
+                )?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__live2d_model_api__live2d_model_play_text_visemes_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "live2d_model_play_text_visemes",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_handle = <u64>::sse_decode(&mut deserializer);
+            let api_text = <String>::sse_decode(&mut deserializer);
+            let api_chars_per_second = <f32>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, String>((move || {
+                let output_ok = crate::api::live2d_model_api::live2d_model_play_text_visemes(
+                    api_handle,
+                    api_text,
+                    api_chars_per_second,
+                )?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__live2d_model_api__live2d_model_set_draw_order_mode_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "live2d_model_set_draw_order_mode",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_handle = <u64>::sse_decode(&mut deserializer);
+            let api_mode =
+                <crate::api::live2d_model_api::DrawOrderModeDto>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, String>((move || {
+                let output_ok = crate::api::live2d_model_api::live2d_model_set_draw_order_mode(
+                    api_handle, api_mode,
+                )?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__live2d_model_api__live2d_model_set_emotion_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "live2d_model_set_emotion",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_handle = <u64>::sse_decode(&mut deserializer);
+            let api_name = <String>::sse_decode(&mut deserializer);
+            let api_intensity = <f32>::sse_decode(&mut deserializer);
+            let api_duration_seconds = <Option<f32>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, String>((move || {
+                let output_ok = crate::api::live2d_model_api::live2d_model_set_emotion(
+                    api_handle,
+                    api_name,
+                    api_intensity,
+                    api_duration_seconds,
+                )?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__live2d_model_api__live2d_model_set_emotion_timing_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "live2d_model_set_emotion_timing",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_handle = <u64>::sse_decode(&mut deserializer);
+            let api_transition_seconds = <f32>::sse_decode(&mut deserializer);
+            let api_decay_seconds = <f32>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, String>((move || {
+                let output_ok = crate::api::live2d_model_api::live2d_model_set_emotion_timing(
+                    api_handle,
+                    api_transition_seconds,
+                    api_decay_seconds,
+                )?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__live2d_model_api__live2d_model_set_idle_effects_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "live2d_model_set_idle_effects",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_handle = <u64>::sse_decode(&mut deserializer);
+            let api_effects =
+                <Vec<crate::api::live2d_model_api::IdleEffectDto>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, String>((move || {
+                let output_ok = crate::api::live2d_model_api::live2d_model_set_idle_effects(
+                    api_handle,
+                    api_effects,
+                )?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__live2d_model_api__live2d_model_set_layer_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "live2d_model_set_layer",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_handle = <u64>::sse_decode(&mut deserializer);
+            let api_layer =
+                <crate::api::live2d_model_api::AnimationLayerDto>::sse_decode(&mut deserializer);
+            let api_enabled = <bool>::sse_decode(&mut deserializer);
+            let api_weight = <f32>::sse_decode(&mut deserializer);
+            let api_blend =
+                <crate::api::live2d_model_api::LayerBlendDto>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, String>((move || {
+                let output_ok = crate::api::live2d_model_api::live2d_model_set_layer(
+                    api_handle,
+                    api_layer,
+                    api_enabled,
+                    api_weight,
+                    api_blend,
+                )?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__live2d_model_api__live2d_model_set_look_target_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "live2d_model_set_look_target",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_handle = <u64>::sse_decode(&mut deserializer);
+            let api_x = <f32>::sse_decode(&mut deserializer);
+            let api_y = <f32>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, String>((move || {
+                let output_ok = crate::api::live2d_model_api::live2d_model_set_look_target(
+                    api_handle, api_x, api_y,
+                )?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__live2d_model_api__live2d_model_set_parameter_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "live2d_model_set_parameter",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_handle = <u64>::sse_decode(&mut deserializer);
+            let api_parameter_id = <String>::sse_decode(&mut deserializer);
+            let api_value = <f32>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, String>((move || {
+                let output_ok = crate::api::live2d_model_api::live2d_model_set_parameter(
+                    api_handle,
+                    api_parameter_id,
+                    api_value,
+                )?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__live2d_model_api__live2d_model_set_parameter_override_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "live2d_model_set_parameter_override",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_handle = <u64>::sse_decode(&mut deserializer);
+            let api_parameter_id = <String>::sse_decode(&mut deserializer);
+            let api_value = <Option<f32>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, String>((move || {
+                let output_ok = crate::api::live2d_model_api::live2d_model_set_parameter_override(
+                    api_handle,
+                    api_parameter_id,
+                    api_value,
+                )?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__live2d_model_api__live2d_model_set_parameter_smoothing_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "live2d_model_set_parameter_smoothing",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_handle = <u64>::sse_decode(&mut deserializer);
+            let api_parameter_id = <String>::sse_decode(&mut deserializer);
+            let api_smoothing = <crate::api::live2d_model_api::ParameterSmoothingDto>::sse_decode(
+                &mut deserializer,
+            );
+            deserializer.end();
+            transform_result_sse::<_, String>((move || {
+                let output_ok = crate::api::live2d_model_api::live2d_model_set_parameter_smoothing(
+                    api_handle,
+                    api_parameter_id,
+                    api_smoothing,
+                )?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__live2d_model_api__live2d_model_set_part_opacity_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "live2d_model_set_part_opacity",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_handle = <u64>::sse_decode(&mut deserializer);
+            let api_part_id = <String>::sse_decode(&mut deserializer);
+            let api_opacity = <f32>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, String>((move || {
+                let output_ok = crate::api::live2d_model_api::live2d_model_set_part_opacity(
+                    api_handle,
+                    api_part_id,
+                    api_opacity,
+                )?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__live2d_model_api__live2d_model_start_motion_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "live2d_model_start_motion",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_handle = <u64>::sse_decode(&mut deserializer);
+            let api_name = <String>::sse_decode(&mut deserializer);
+            let api_looped = <Option<bool>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, String>((move || {
+                let output_ok = crate::api::live2d_model_api::live2d_model_start_motion(
+                    api_handle, api_name, api_looped,
+                )?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__live2d_model_api__live2d_model_step_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "live2d_model_step",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_handle = <u64>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, String>((move || {
+                let output_ok = crate::api::live2d_model_api::live2d_model_step(api_handle)?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__live2d_model_api__live2d_model_step_batched_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "live2d_model_step_batched",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_handle = <u64>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, String>((move || {
+                let output_ok =
+                    crate::api::live2d_model_api::live2d_model_step_batched(api_handle)?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__live2d_model_api__live2d_model_stop_motions_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "live2d_model_stop_motions",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_handle = <u64>::sse_decode(&mut deserializer);
+            let api_fade = <bool>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, String>((move || {
+                let output_ok =
+                    crate::api::live2d_model_api::live2d_model_stop_motions(api_handle, api_fade)?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__live2d_model_api__live2d_model_unload_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "live2d_model_unload",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_handle = <u64>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let output_ok = Result::<_, ()>::Ok({
+                    crate::api::live2d_model_api::live2d_model_unload(api_handle);
+                })?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__live2d_model_api__live2d_model_update_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "live2d_model_update",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_handle = <u64>::sse_decode(&mut deserializer);
+            let api_delta_seconds = <f32>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, String>((move || {
+                let output_ok = crate::api::live2d_model_api::live2d_model_update(
+                    api_handle,
+                    api_delta_seconds,
+                )?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__live2d_model_api__live2d_model_view_transform_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "live2d_model_view_transform",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_handle = <u64>::sse_decode(&mut deserializer);
+            let api_viewport =
+                <crate::api::live2d_model_api::ViewportDto>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, String>((move || {
+                let output_ok = crate::api::live2d_model_api::live2d_model_view_transform(
+                    api_handle,
+                    api_viewport,
+                )?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__live2d_model_api__live2d_model_viewport_to_model_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "live2d_model_viewport_to_model",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_handle = <u64>::sse_decode(&mut deserializer);
+            let api_viewport =
+                <crate::api::live2d_model_api::ViewportDto>::sse_decode(&mut deserializer);
+            let api_x = <f32>::sse_decode(&mut deserializer);
+            let api_y = <f32>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, String>((move || {
+                let output_ok = crate::api::live2d_model_api::live2d_model_viewport_to_model(
+                    api_handle,
+                    api_viewport,
+                    api_x,
+                    api_y,
+                )?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__live2d_model_api__live2d_motion_events_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "live2d_motion_events",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_sink = <StreamSink<
+                crate::api::live2d_model_api::MotionEventDto,
+                flutter_rust_bridge::for_generated::SseCodec,
+            >>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok({
+                        crate::api::live2d_model_api::live2d_motion_events(api_sink);
+                    })?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__live2d_model_api__live2d_text_visemes_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "live2d_text_visemes",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_text = <String>::sse_decode(&mut deserializer);
+            let api_chars_per_second = <f32>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let output_ok =
+                    Result::<_, ()>::Ok(crate::api::live2d_model_api::live2d_text_visemes(
+                        api_text,
+                        api_chars_per_second,
+                    ))?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__live2d_texture_api__live2d_textures_decode_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "live2d_textures_decode",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_pngs = <Vec<Vec<u8>>>::sse_decode(&mut deserializer);
+            let api_options =
+                <crate::api::live2d_texture_api::TextureOptionsDto>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, String>((move || {
+                    let output_ok = crate::api::live2d_texture_api::live2d_textures_decode(
+                        api_pngs,
+                        api_options,
+                    )?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__live2d_texture_api__live2d_textures_load_from_model3_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "live2d_textures_load_from_model3",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_model3_json_path = <String>::sse_decode(&mut deserializer);
+            let api_options =
+                <crate::api::live2d_texture_api::TextureOptionsDto>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, String>((move || {
+                    let output_ok =
+                        crate::api::live2d_texture_api::live2d_textures_load_from_model3(
+                            api_model3_json_path,
+                            api_options,
+                        )?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__wise_lover_boot_api__wise_lover_boot_init_app_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "wise_lover_boot_init_app",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok({
+                        crate::api::wise_lover_boot_api::wise_lover_boot_init_app();
+                    })?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+
+// Section: dart2rust
+
+impl SseDecode for flutter_rust_bridge::for_generated::anyhow::Error {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <String>::sse_decode(deserializer);
+        return flutter_rust_bridge::for_generated::anyhow::anyhow!("{}", inner);
+    }
+}
+
+impl SseDecode for StreamSink<String, flutter_rust_bridge::for_generated::SseCodec> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <String>::sse_decode(deserializer);
+        return StreamSink::deserialize(inner);
+    }
+}
+
+impl SseDecode
+    for StreamSink<
+        crate::api::live2d_model_api::MotionEventDto,
+        flutter_rust_bridge::for_generated::SseCodec,
+    >
+{
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <String>::sse_decode(deserializer);
+        return StreamSink::deserialize(inner);
+    }
+}
+
+impl SseDecode for String {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <Vec<u8>>::sse_decode(deserializer);
+        return String::from_utf8(inner).unwrap();
+    }
+}
+
+impl SseDecode for crate::api::live2d_model_api::AnimationLayerDto {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::live2d_model_api::AnimationLayerDto::Motion,
+            1 => crate::api::live2d_model_api::AnimationLayerDto::EyeBlink,
+            2 => crate::api::live2d_model_api::AnimationLayerDto::Expression,
+            3 => crate::api::live2d_model_api::AnimationLayerDto::Idle,
+            4 => crate::api::live2d_model_api::AnimationLayerDto::LipSync,
+            5 => crate::api::live2d_model_api::AnimationLayerDto::LookAt,
+            6 => crate::api::live2d_model_api::AnimationLayerDto::UserOverride,
+            _ => unreachable!("Invalid variant for AnimationLayerDto: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::api::live2d_model_api::BatchedFrameDto {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_canvasWidth = <f32>::sse_decode(deserializer);
+        let mut var_canvasHeight = <f32>::sse_decode(deserializer);
+        let mut var_canvasOriginX = <f32>::sse_decode(deserializer);
+        let mut var_canvasOriginY = <f32>::sse_decode(deserializer);
+        let mut var_pixelsPerUnit = <f32>::sse_decode(deserializer);
+        let mut var_batches =
+            <Vec<crate::api::live2d_model_api::DrawBatchDto>>::sse_decode(deserializer);
+        let mut var_maskTextureCount = <u32>::sse_decode(deserializer);
+        let mut var_maskGroups =
+            <Vec<crate::api::live2d_model_api::MaskGroupDto>>::sse_decode(deserializer);
+        let mut var_maskBatches =
+            <Vec<crate::api::live2d_model_api::DrawBatchDto>>::sse_decode(deserializer);
+        return crate::api::live2d_model_api::BatchedFrameDto {
+            canvas_width: var_canvasWidth,
+            canvas_height: var_canvasHeight,
+            canvas_origin_x: var_canvasOriginX,
+            canvas_origin_y: var_canvasOriginY,
+            pixels_per_unit: var_pixelsPerUnit,
+            batches: var_batches,
+            mask_texture_count: var_maskTextureCount,
+            mask_groups: var_maskGroups,
+            mask_batches: var_maskBatches,
+        };
+    }
+}
+
+impl SseDecode for crate::api::live2d_model_api::BlendModeDto {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::live2d_model_api::BlendModeDto::Normal,
+            1 => crate::api::live2d_model_api::BlendModeDto::Additive,
+            2 => crate::api::live2d_model_api::BlendModeDto::Multiplicative,
+            _ => unreachable!("Invalid variant for BlendModeDto: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::api::live2d_model_api::BlinkIntervalDto {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::live2d_model_api::BlinkIntervalDto::Fixed,
+            1 => crate::api::live2d_model_api::BlinkIntervalDto::Uniform,
+            2 => crate::api::live2d_model_api::BlinkIntervalDto::Exponential,
+            _ => unreachable!("Invalid variant for BlinkIntervalDto: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for bool {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_u8().unwrap() != 0
+    }
+}
+
+impl SseDecode for crate::api::chat_api::ChatAppConfigDto {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_defaultProfile = <Option<String>>::sse_decode(deserializer);
+        let mut var_provider = <crate::api::chat_api::ChatProviderDto>::sse_decode(deserializer);
+        let mut var_defaults =
+            <crate::api::chat_api::ChatModelProfileDto>::sse_decode(deserializer);
+        let mut var_profiles =
+            <Vec<crate::api::chat_api::ChatProfileDto>>::sse_decode(deserializer);
+        return crate::api::chat_api::ChatAppConfigDto {
+            default_profile: var_defaultProfile,
+            provider: var_provider,
+            defaults: var_defaults,
+            profiles: var_profiles,
+        };
+    }
+}
+
+impl SseDecode for crate::api::chat_api::ChatCapabilitiesDto {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_streaming = <bool>::sse_decode(deserializer);
+        let mut var_asyncTask = <bool>::sse_decode(deserializer);
+        let mut var_listModels = <bool>::sse_decode(deserializer);
+        return crate::api::chat_api::ChatCapabilitiesDto {
+            streaming: var_streaming,
+            async_task: var_asyncTask,
+            list_models: var_listModels,
+        };
+    }
+}
+
+impl SseDecode for crate::api::chat_api::ChatConfigDto {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_languageModel = <String>::sse_decode(deserializer);
+        let mut var_systemContent = <Option<String>>::sse_decode(deserializer);
+        let mut var_temperature = <f64>::sse_decode(deserializer);
+        let mut var_topP = <f64>::sse_decode(deserializer);
+        let mut var_maxHistory = <u32>::sse_decode(deserializer);
+        let mut var_contextTokens = <Option<u32>>::sse_decode(deserializer);
+        let mut var_replyTokens = <Option<u32>>::sse_decode(deserializer);
+        let mut var_summarize = <bool>::sse_decode(deserializer);
+        return crate::api::chat_api::ChatConfigDto {
+            language_model: var_languageModel,
+            system_content: var_systemContent,
+            temperature: var_temperature,
+            top_p: var_topP,
+            max_history: var_maxHistory,
+            context_tokens: var_contextTokens,
+            reply_tokens: var_replyTokens,
+            summarize: var_summarize,
+        };
+    }
+}
+
+impl SseDecode for crate::api::chat_api::ChatInvokeModeDto {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::chat_api::ChatInvokeModeDto::Sync,
+            1 => crate::api::chat_api::ChatInvokeModeDto::AsyncTask,
+            2 => crate::api::chat_api::ChatInvokeModeDto::Stream,
+            _ => unreachable!("Invalid variant for ChatInvokeModeDto: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::api::chat_api::ChatKeySelectionDto {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::chat_api::ChatKeySelectionDto::RoundRobin,
+            1 => crate::api::chat_api::ChatKeySelectionDto::Priority,
+            _ => unreachable!("Invalid variant for ChatKeySelectionDto: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::api::chat_api::ChatKeyUsageDto {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_label = <String>::sse_decode(deserializer);
+        let mut var_requests = <u64>::sse_decode(deserializer);
+        let mut var_successes = <u64>::sse_decode(deserializer);
+        let mut var_failures = <u64>::sse_decode(deserializer);
+        let mut var_rateLimited = <u64>::sse_decode(deserializer);
+        let mut var_unauthorized = <u64>::sse_decode(deserializer);
+        let mut var_disabledForSeconds = <Option<u32>>::sse_decode(deserializer);
+        return crate::api::chat_api::ChatKeyUsageDto {
+            label: var_label,
+            requests: var_requests,
+            successes: var_successes,
+            failures: var_failures,
+            rate_limited: var_rateLimited,
+            unauthorized: var_unauthorized,
+            disabled_for_seconds: var_disabledForSeconds,
+        };
+    }
+}
+
+impl SseDecode for crate::api::chat_api::ChatMessageDto {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_role = <crate::api::chat_api::ChatRoleDto>::sse_decode(deserializer);
+        let mut var_content = <String>::sse_decode(deserializer);
+        return crate::api::chat_api::ChatMessageDto {
+            role: var_role,
+            content: var_content,
+        };
+    }
+}
+
+impl SseDecode for crate::api::chat_api::ChatModelProfileDto {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_languageModel = <Option<String>>::sse_decode(deserializer);
+        let mut var_systemContent = <Option<String>>::sse_decode(deserializer);
+        let mut var_temperature = <Option<f64>>::sse_decode(deserializer);
+        let mut var_topP = <Option<f64>>::sse_decode(deserializer);
+        let mut var_maxHistory = <Option<u32>>::sse_decode(deserializer);
+        let mut var_contextTokens = <Option<u32>>::sse_decode(deserializer);
+        let mut var_replyTokens = <Option<u32>>::sse_decode(deserializer);
+        let mut var_summarize = <Option<bool>>::sse_decode(deserializer);
+        return crate::api::chat_api::ChatModelProfileDto {
+            language_model: var_languageModel,
+            system_content: var_systemContent,
+            temperature: var_temperature,
+            top_p: var_topP,
+            max_history: var_maxHistory,
+            context_tokens: var_contextTokens,
+            reply_tokens: var_replyTokens,
+            summarize: var_summarize,
+        };
+    }
+}
+
+impl SseDecode for crate::api::chat_api::ChatProfileDto {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_name = <String>::sse_decode(deserializer);
+        let mut var_profile = <crate::api::chat_api::ChatModelProfileDto>::sse_decode(deserializer);
+        return crate::api::chat_api::ChatProfileDto {
+            name: var_name,
+            profile: var_profile,
+        };
+    }
+}
+
+impl SseDecode for crate::api::chat_api::ChatProviderDto {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_kind = <crate::api::chat_api::ChatProviderKindDto>::sse_decode(deserializer);
+        let mut var_baseUrl = <Option<String>>::sse_decode(deserializer);
+        let mut var_apiKey = <Option<String>>::sse_decode(deserializer);
+        let mut var_apiKeys = <Vec<String>>::sse_decode(deserializer);
+        let mut var_keySelection =
+            <crate::api::chat_api::ChatKeySelectionDto>::sse_decode(deserializer);
+        let mut var_pollIntervalMs = <u32>::sse_decode(deserializer);
+        let mut var_maxPollIntervalMs = <u32>::sse_decode(deserializer);
+        let mut var_taskTimeoutSeconds = <u32>::sse_decode(deserializer);
+        let mut var_pollMultiplier = <f64>::sse_decode(deserializer);
+        let mut var_pollJitter = <f64>::sse_decode(deserializer);
+        return crate::api::chat_api::ChatProviderDto {
+            kind: var_kind,
+            base_url: var_baseUrl,
+            api_key: var_apiKey,
+            api_keys: var_apiKeys,
+            key_selection: var_keySelection,
+            poll_interval_ms: var_pollIntervalMs,
+            max_poll_interval_ms: var_maxPollIntervalMs,
+            task_timeout_seconds: var_taskTimeoutSeconds,
+            poll_multiplier: var_pollMultiplier,
+            poll_jitter: var_pollJitter,
+        };
+    }
+}
+
+impl SseDecode for crate::api::chat_api::ChatProviderKindDto {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::chat_api::ChatProviderKindDto::ChatGlm,
+            1 => crate::api::chat_api::ChatProviderKindDto::OpenAi,
+            2 => crate::api::chat_api::ChatProviderKindDto::Ollama,
+            _ => unreachable!("Invalid variant for ChatProviderKindDto: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::api::chat_api::ChatReplyDto {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_content = <String>::sse_decode(deserializer);
+        let mut var_warnings = <Vec<String>>::sse_decode(deserializer);
+        return crate::api::chat_api::ChatReplyDto {
+            content: var_content,
+            warnings: var_warnings,
+        };
+    }
+}
+
+impl SseDecode for crate::api::chat_api::ChatRoleDto {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::chat_api::ChatRoleDto::System,
+            1 => crate::api::chat_api::ChatRoleDto::User,
+            2 => crate::api::chat_api::ChatRoleDto::Assistant,
+            _ => unreachable!("Invalid variant for ChatRoleDto: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::api::chat_api::ChatSessionDto {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_id = <String>::sse_decode(deserializer);
+        let mut var_messageCount = <u32>::sse_decode(deserializer);
+        let mut var_tokens = <u64>::sse_decode(deserializer);
+        let mut var_createdAtMs = <Option<i64>>::sse_decode(deserializer);
+        let mut var_updatedAtMs = <Option<i64>>::sse_decode(deserializer);
+        return crate::api::chat_api::ChatSessionDto {
+            id: var_id,
+            message_count: var_messageCount,
+            tokens: var_tokens,
+            created_at_ms: var_createdAtMs,
+            updated_at_ms: var_updatedAtMs,
+        };
+    }
+}
+
+impl SseDecode for crate::api::live2d_model_api::DrawBatchDto {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_textureIndex = <u32>::sse_decode(deserializer);
+        let mut var_blendMode =
+            <crate::api::live2d_model_api::BlendModeDto>::sse_decode(deserializer);
+        let mut var_maskGroup = <Option<u32>>::sse_decode(deserializer);
+        let mut var_invertedMask = <bool>::sse_decode(deserializer);
+        let mut var_drawables = <Vec<u32>>::sse_decode(deserializer);
+        let mut var_vertices = <Vec<f32>>::sse_decode(deserializer);
+        let mut var_uvs = <Vec<f32>>::sse_decode(deserializer);
+        let mut var_indices = <Vec<u16>>::sse_decode(deserializer);
+        let mut var_multiplyColors = <Vec<f32>>::sse_decode(deserializer);
+        let mut var_screenColors = <Vec<f32>>::sse_decode(deserializer);
+        return crate::api::live2d_model_api::DrawBatchDto {
+            texture_index: var_textureIndex,
+            blend_mode: var_blendMode,
+            mask_group: var_maskGroup,
+            inverted_mask: var_invertedMask,
+            drawables: var_drawables,
+            vertices: var_vertices,
+            uvs: var_uvs,
+            indices: var_indices,
+            multiply_colors: var_multiplyColors,
+            screen_colors: var_screenColors,
+        };
+    }
+}
+
+impl SseDecode for crate::api::live2d_model_api::DrawOrderModeDto {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::live2d_model_api::DrawOrderModeDto::RenderOrder,
+            1 => crate::api::live2d_model_api::DrawOrderModeDto::DrawOrder,
+            _ => unreachable!("Invalid variant for DrawOrderModeDto: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::api::live2d_model_api::DrawableFrameDto {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_index = <u32>::sse_decode(deserializer);
+        let mut var_textureIndex = <u32>::sse_decode(deserializer);
+        let mut var_vertices = <Vec<f32>>::sse_decode(deserializer);
+        let mut var_uvs = <Vec<f32>>::sse_decode(deserializer);
+        let mut var_indices = <Vec<u16>>::sse_decode(deserializer);
+        let mut var_masks = <Vec<u16>>::sse_decode(deserializer);
+        let mut var_maskGroup = <Option<u32>>::sse_decode(deserializer);
+        let mut var_opacity = <f32>::sse_decode(deserializer);
+        let mut var_multiplyColor = <[f32; 4]>::sse_decode(deserializer);
+        let mut var_screenColor = <[f32; 4]>::sse_decode(deserializer);
+        let mut var_drawOrder = <i32>::sse_decode(deserializer);
+        return crate::api::live2d_model_api::DrawableFrameDto {
+            index: var_index,
+            texture_index: var_textureIndex,
+            vertices: var_vertices,
+            uvs: var_uvs,
+            indices: var_indices,
+            masks: var_masks,
+            mask_group: var_maskGroup,
+            opacity: var_opacity,
+            multiply_color: var_multiplyColor,
+            screen_color: var_screenColor,
+            draw_order: var_drawOrder,
+        };
+    }
+}
+
+impl SseDecode for crate::api::live2d_model_api::DrawableUserDataDto {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_drawableIndex = <u32>::sse_decode(deserializer);
+        let mut var_drawableId = <String>::sse_decode(deserializer);
+        let mut var_value = <String>::sse_decode(deserializer);
+        return crate::api::live2d_model_api::DrawableUserDataDto {
+            drawable_index: var_drawableIndex,
+            drawable_id: var_drawableId,
+            value: var_value,
+        };
+    }
+}
+
+impl SseDecode for crate::api::live2d_model_api::EmotionResultDto {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_expressions = <Vec<String>>::sse_decode(deserializer);
+        let mut var_startedMotion = <Option<String>>::sse_decode(deserializer);
+        return crate::api::live2d_model_api::EmotionResultDto {
+            expressions: var_expressions,
+            started_motion: var_startedMotion,
+        };
+    }
+}
+
+impl SseDecode for crate::api::live2d_model_api::EyeBlinkConfigDto {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_enabled = <bool>::sse_decode(deserializer);
+        let mut var_interval =
+            <crate::api::live2d_model_api::BlinkIntervalDto>::sse_decode(deserializer);
+        let mut var_minSeconds = <f32>::sse_decode(deserializer);
+        let mut var_maxSeconds = <f32>::sse_decode(deserializer);
+        let mut var_meanSeconds = <f32>::sse_decode(deserializer);
+        let mut var_closingSeconds = <f32>::sse_decode(deserializer);
+        let mut var_closedSeconds = <f32>::sse_decode(deserializer);
+        let mut var_openingSeconds = <f32>::sse_decode(deserializer);
+        let mut var_doubleBlinkProbability = <f32>::sse_decode(deserializer);
+        let mut var_doubleBlinkGapSeconds = <f32>::sse_decode(deserializer);
+        let mut var_parameterIds = <Vec<String>>::sse_decode(deserializer);
+        let mut var_seed = <Option<u64>>::sse_decode(deserializer);
+        return crate::api::live2d_model_api::EyeBlinkConfigDto {
+            enabled: var_enabled,
+            interval: var_interval,
+            min_seconds: var_minSeconds,
+            max_seconds: var_maxSeconds,
+            mean_seconds: var_meanSeconds,
+            closing_seconds: var_closingSeconds,
+            closed_seconds: var_closedSeconds,
+            opening_seconds: var_openingSeconds,
+            double_blink_probability: var_doubleBlinkProbability,
+            double_blink_gap_seconds: var_doubleBlinkGapSeconds,
+            parameter_ids: var_parameterIds,
+            seed: var_seed,
+        };
+    }
+}
+
+impl SseDecode for f32 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_f32::<NativeEndian>().unwrap()
+    }
+}
+
+impl SseDecode for [f32; 16] {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <Vec<f32>>::sse_decode(deserializer);
+        return flutter_rust_bridge::for_generated::from_vec_to_array(inner);
+    }
+}
+
+impl SseDecode for [f32; 2] {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <Vec<f32>>::sse_decode(deserializer);
+        return flutter_rust_bridge::for_generated::from_vec_to_array(inner);
+    }
+}
+
+impl SseDecode for [f32; 4] {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <Vec<f32>>::sse_decode(deserializer);
+        return flutter_rust_bridge::for_generated::from_vec_to_array(inner);
+    }
+}
+
+impl SseDecode for f64 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_f64::<NativeEndian>().unwrap()
+    }
+}
+
+impl SseDecode for crate::api::live2d_model_api::FrameDto {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_canvasWidth = <f32>::sse_decode(deserializer);
+        let mut var_canvasHeight = <f32>::sse_decode(deserializer);
+        let mut var_canvasOriginX = <f32>::sse_decode(deserializer);
+        let mut var_canvasOriginY = <f32>::sse_decode(deserializer);
+        let mut var_pixelsPerUnit = <f32>::sse_decode(deserializer);
+        let mut var_drawables =
+            <Vec<crate::api::live2d_model_api::DrawableFrameDto>>::sse_decode(deserializer);
+        let mut var_maskTextureCount = <u32>::sse_decode(deserializer);
+        let mut var_maskGroups =
+            <Vec<crate::api::live2d_model_api::MaskGroupDto>>::sse_decode(deserializer);
+        return crate::api::live2d_model_api::FrameDto {
+            canvas_width: var_canvasWidth,
+            canvas_height: var_canvasHeight,
+            canvas_origin_x: var_canvasOriginX,
+            canvas_origin_y: var_canvasOriginY,
+            pixels_per_unit: var_pixelsPerUnit,
+            drawables: var_drawables,
+            mask_texture_count: var_maskTextureCount,
+            mask_groups: var_maskGroups,
+        };
+    }
+}
+
+impl SseDecode for i16 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_i16::<NativeEndian>().unwrap()
+    }
+}
+
+impl SseDecode for i32 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_i32::<NativeEndian>().unwrap()
+    }
+}
+
+impl SseDecode for i64 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_i64::<NativeEndian>().unwrap()
+    }
+}
+
+impl SseDecode for crate::api::live2d_model_api::IdleEffectDto {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_parameterId = <String>::sse_decode(deserializer);
+        let mut var_offset = <f32>::sse_decode(deserializer);
+        let mut var_peak = <f32>::sse_decode(deserializer);
+        let mut var_cycleSeconds = <f32>::sse_decode(deserializer);
+        let mut var_weight = <f32>::sse_decode(deserializer);
+        return crate::api::live2d_model_api::IdleEffectDto {
+            parameter_id: var_parameterId,
+            offset: var_offset,
+            peak: var_peak,
+            cycle_seconds: var_cycleSeconds,
+            weight: var_weight,
+        };
+    }
+}
+
+impl SseDecode for crate::api::live2d_model_api::LayerBlendDto {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::live2d_model_api::LayerBlendDto::Normal,
+            1 => crate::api::live2d_model_api::LayerBlendDto::Additive,
+            _ => unreachable!("Invalid variant for LayerBlendDto: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::api::live2d_model_api::LipSyncConfigDto {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_frameSeconds = <f32>::sse_decode(deserializer);
+        let mut var_gain = <f32>::sse_decode(deserializer);
+        let mut var_noiseFloor = <f32>::sse_decode(deserializer);
+        let mut var_attackSeconds = <f32>::sse_decode(deserializer);
+        let mut var_releaseSeconds = <f32>::sse_decode(deserializer);
+        let mut var_weight = <f32>::sse_decode(deserializer);
+        return crate::api::live2d_model_api::LipSyncConfigDto {
+            frame_seconds: var_frameSeconds,
+            gain: var_gain,
+            noise_floor: var_noiseFloor,
+            attack_seconds: var_attackSeconds,
+            release_seconds: var_releaseSeconds,
+            weight: var_weight,
+        };
+    }
+}
+
+impl SseDecode for crate::api::live2d_model_api::LipSyncTimelineDto {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_frameSeconds = <f32>::sse_decode(deserializer);
+        let mut var_values = <Vec<f32>>::sse_decode(deserializer);
+        return crate::api::live2d_model_api::LipSyncTimelineDto {
+            frame_seconds: var_frameSeconds,
+            values: var_values,
+        };
+    }
+}
+
+impl SseDecode for Vec<String> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<String>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::chat_api::ChatKeyUsageDto> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::chat_api::ChatKeyUsageDto>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::chat_api::ChatMessageDto> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::chat_api::ChatMessageDto>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::chat_api::ChatProfileDto> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::chat_api::ChatProfileDto>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::chat_api::ChatSessionDto> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::chat_api::ChatSessionDto>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::live2d_model_api::DrawBatchDto> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::live2d_model_api::DrawBatchDto>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::live2d_model_api::DrawableFrameDto> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::live2d_model_api::DrawableFrameDto>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::live2d_model_api::DrawableUserDataDto> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(
+                <crate::api::live2d_model_api::DrawableUserDataDto>::sse_decode(deserializer),
+            );
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::live2d_model_api::IdleEffectDto> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::live2d_model_api::IdleEffectDto>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<Vec<u8>> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<Vec<u8>>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::live2d_model_api::LookAtMappingDto> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::live2d_model_api::LookAtMappingDto>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::live2d_model_api::MaskGroupDto> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::live2d_model_api::MaskGroupDto>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::live2d_model_api::ParameterGroupInfoDto> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(
+                <crate::api::live2d_model_api::ParameterGroupInfoDto>::sse_decode(deserializer),
+            );
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::live2d_model_api::ParameterInfoDto> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::live2d_model_api::ParameterInfoDto>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::live2d_model_api::PartInfoDto> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::live2d_model_api::PartInfoDto>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<f32> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<f32>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<i16> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<i16>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<u16> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<u16>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<u32> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<u32>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<u8> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<u8>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::live2d_texture_api::TextureDto> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::live2d_texture_api::TextureDto>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::live2d_texture_api::TextureLevelDto> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::live2d_texture_api::TextureLevelDto>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::live2d_model_api::VisemeKeyframeDto> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::live2d_model_api::VisemeKeyframeDto>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for crate::api::live2d_model_api::LookAtConfigDto {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_maxSpeed = <f32>::sse_decode(deserializer);
+        let mut var_timeToMaxSpeed = <f32>::sse_decode(deserializer);
+        let mut var_mappings =
+            <Vec<crate::api::live2d_model_api::LookAtMappingDto>>::sse_decode(deserializer);
+        return crate::api::live2d_model_api::LookAtConfigDto {
+            max_speed: var_maxSpeed,
+            time_to_max_speed: var_timeToMaxSpeed,
+            mappings: var_mappings,
+        };
+    }
+}
+
+impl SseDecode for crate::api::live2d_model_api::LookAtMappingDto {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_parameterId = <String>::sse_decode(deserializer);
+        let mut var_xWeight = <f32>::sse_decode(deserializer);
+        let mut var_yWeight = <f32>::sse_decode(deserializer);
+        let mut var_xyWeight = <f32>::sse_decode(deserializer);
+        return crate::api::live2d_model_api::LookAtMappingDto {
+            parameter_id: var_parameterId,
+            x_weight: var_xWeight,
+            y_weight: var_yWeight,
+            xy_weight: var_xyWeight,
+        };
+    }
+}
+
+impl SseDecode for crate::api::live2d_model_api::MaskGroupDto {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_textureSlot = <u32>::sse_decode(deserializer);
+        let mut var_channel = <u32>::sse_decode(deserializer);
+        let mut var_layout = <[f32; 4]>::sse_decode(deserializer);
+        let mut var_maskDrawables = <Vec<u32>>::sse_decode(deserializer);
+        let mut var_clippedDrawables = <Vec<u32>>::sse_decode(deserializer);
+        let mut var_maskMatrix = <[f32; 16]>::sse_decode(deserializer);
+        let mut var_drawMatrix = <[f32; 16]>::sse_decode(deserializer);
+        return crate::api::live2d_model_api::MaskGroupDto {
+            texture_slot: var_textureSlot,
+            channel: var_channel,
+            layout: var_layout,
+            mask_drawables: var_maskDrawables,
+            clipped_drawables: var_clippedDrawables,
+            mask_matrix: var_maskMatrix,
+            draw_matrix: var_drawMatrix,
+        };
+    }
+}
+
+impl SseDecode for crate::api::live2d_model_api::MotionEventDto {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_handle = <u64>::sse_decode(deserializer);
+        let mut var_motionName = <String>::sse_decode(deserializer);
+        let mut var_value = <String>::sse_decode(deserializer);
+        return crate::api::live2d_model_api::MotionEventDto {
+            handle: var_handle,
+            motion_name: var_motionName,
+            value: var_value,
+        };
+    }
+}
+
+impl SseDecode for Option<String> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<String>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<bool> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<bool>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::api::chat_api::ChatInvokeModeDto> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::api::chat_api::ChatInvokeModeDto>::sse_decode(
+                deserializer,
+            ));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<f32> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<f32>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<f64> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<f64>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<i64> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<i64>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<u32> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<u32>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<u64> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<u64>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for crate::api::live2d_model_api::ParameterGroupInfoDto {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_id = <String>::sse_decode(deserializer);
+        let mut var_name = <String>::sse_decode(deserializer);
+        let mut var_parentId = <String>::sse_decode(deserializer);
+        return crate::api::live2d_model_api::ParameterGroupInfoDto {
+            id: var_id,
+            name: var_name,
+            parent_id: var_parentId,
+        };
+    }
+}
+
+impl SseDecode for crate::api::live2d_model_api::ParameterInfoDto {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_id = <String>::sse_decode(deserializer);
+        let mut var_name = <String>::sse_decode(deserializer);
+        let mut var_groupId = <String>::sse_decode(deserializer);
+        let mut var_minValue = <f32>::sse_decode(deserializer);
+        let mut var_maxValue = <f32>::sse_decode(deserializer);
+        let mut var_defaultValue = <f32>::sse_decode(deserializer);
+        return crate::api::live2d_model_api::ParameterInfoDto {
+            id: var_id,
+            name: var_name,
+            group_id: var_groupId,
+            min_value: var_minValue,
+            max_value: var_maxValue,
+            default_value: var_defaultValue,
+        };
+    }
+}
+
+impl SseDecode for crate::api::live2d_model_api::ParameterSmoothingDto {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_filter =
+            <crate::api::live2d_model_api::SmoothingFilterDto>::sse_decode(deserializer);
+        let mut var_timeSeconds = <f32>::sse_decode(deserializer);
+        let mut var_maxVelocity = <Option<f32>>::sse_decode(deserializer);
+        return crate::api::live2d_model_api::ParameterSmoothingDto {
+            filter: var_filter,
+            time_seconds: var_timeSeconds,
+            max_velocity: var_maxVelocity,
+        };
+    }
+}
+
+impl SseDecode for crate::api::live2d_model_api::PartInfoDto {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_id = <String>::sse_decode(deserializer);
+        let mut var_name = <String>::sse_decode(deserializer);
+        return crate::api::live2d_model_api::PartInfoDto {
+            id: var_id,
+            name: var_name,
+        };
+    }
+}
+
+impl SseDecode for crate::api::live2d_model_api::SmoothingFilterDto {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::live2d_model_api::SmoothingFilterDto::None,
+            1 => crate::api::live2d_model_api::SmoothingFilterDto::Exponential,
+            2 => crate::api::live2d_model_api::SmoothingFilterDto::Spring,
+            _ => unreachable!("Invalid variant for SmoothingFilterDto: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::api::live2d_texture_api::TextureDto {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_textureIndex = <u32>::sse_decode(deserializer);
+        let mut var_width = <u32>::sse_decode(deserializer);
+        let mut var_height = <u32>::sse_decode(deserializer);
+        let mut var_premultipliedAlpha = <bool>::sse_decode(deserializer);
+        let mut var_levels =
+            <Vec<crate::api::live2d_texture_api::TextureLevelDto>>::sse_decode(deserializer);
+        return crate::api::live2d_texture_api::TextureDto {
+            texture_index: var_textureIndex,
+            width: var_width,
+            height: var_height,
+            premultiplied_alpha: var_premultipliedAlpha,
+            levels: var_levels,
+        };
+    }
+}
+
+impl SseDecode for crate::api::live2d_texture_api::TextureLevelDto {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_width = <u32>::sse_decode(deserializer);
+        let mut var_height = <u32>::sse_decode(deserializer);
+        let mut var_rgba = <Vec<u8>>::sse_decode(deserializer);
+        return crate::api::live2d_texture_api::TextureLevelDto {
+            width: var_width,
+            height: var_height,
+            rgba: var_rgba,
+        };
+    }
+}
+
+impl SseDecode for crate::api::live2d_texture_api::TextureOptionsDto {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_premultiplyAlpha = <bool>::sse_decode(deserializer);
+        let mut var_generateMipmaps = <bool>::sse_decode(deserializer);
+        let mut var_memoryBudgetBytes = <Option<u64>>::sse_decode(deserializer);
+        return crate::api::live2d_texture_api::TextureOptionsDto {
+            premultiply_alpha: var_premultiplyAlpha,
+            generate_mipmaps: var_generateMipmaps,
+            memory_budget_bytes: var_memoryBudgetBytes,
+        };
+    }
+}
+
+impl SseDecode for u16 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_u16::<NativeEndian>().unwrap()
+    }
+}
+
+impl SseDecode for u32 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_u32::<NativeEndian>().unwrap()
+    }
+}
+
+impl SseDecode for u64 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_u64::<NativeEndian>().unwrap()
+    }
+}
+
+impl SseDecode for u8 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_u8().unwrap()
+    }
+}
+
+impl SseDecode for () {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {}
+}
+
+impl SseDecode for crate::api::live2d_model_api::ViewFitModeDto {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::live2d_model_api::ViewFitModeDto::Contain,
+            1 => crate::api::live2d_model_api::ViewFitModeDto::Cover,
+            2 => crate::api::live2d_model_api::ViewFitModeDto::FixedScale,
+            _ => unreachable!("Invalid variant for ViewFitModeDto: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::api::live2d_model_api::ViewTransformDto {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_pixelsPerUnit = <f32>::sse_decode(deserializer);
+        let mut var_modelToViewport = <[f32; 16]>::sse_decode(deserializer);
+        let mut var_modelToNdc = <[f32; 16]>::sse_decode(deserializer);
+        return crate::api::live2d_model_api::ViewTransformDto {
+            pixels_per_unit: var_pixelsPerUnit,
+            model_to_viewport: var_modelToViewport,
+            model_to_ndc: var_modelToNdc,
+        };
+    }
+}
+
+impl SseDecode for crate::api::live2d_model_api::ViewportDto {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_width = <f32>::sse_decode(deserializer);
+        let mut var_height = <f32>::sse_decode(deserializer);
+        let mut var_fitMode =
+            <crate::api::live2d_model_api::ViewFitModeDto>::sse_decode(deserializer);
+        let mut var_fixedScale = <f32>::sse_decode(deserializer);
+        let mut var_panX = <f32>::sse_decode(deserializer);
+        let mut var_panY = <f32>::sse_decode(deserializer);
+        let mut var_zoom = <f32>::sse_decode(deserializer);
+        return crate::api::live2d_model_api::ViewportDto {
+            width: var_width,
+            height: var_height,
+            fit_mode: var_fitMode,
+            fixed_scale: var_fixedScale,
+            pan_x: var_panX,
+            pan_y: var_panY,
+            zoom: var_zoom,
+        };
+    }
+}
+
+impl SseDecode for crate::api::live2d_model_api::VisemeDto {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::live2d_model_api::VisemeDto::A,
+            1 => crate::api::live2d_model_api::VisemeDto::I,
+            2 => crate::api::live2d_model_api::VisemeDto::U,
+            3 => crate::api::live2d_model_api::VisemeDto::E,
+            4 => crate::api::live2d_model_api::VisemeDto::O,
+            5 => crate::api::live2d_model_api::VisemeDto::Closed,
+            _ => unreachable!("Invalid variant for VisemeDto: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::api::live2d_model_api::VisemeKeyframeDto {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_time = <f32>::sse_decode(deserializer);
+        let mut var_viseme = <crate::api::live2d_model_api::VisemeDto>::sse_decode(deserializer);
+        let mut var_mouthOpen = <f32>::sse_decode(deserializer);
+        let mut var_mouthForm = <f32>::sse_decode(deserializer);
+        return crate::api::live2d_model_api::VisemeKeyframeDto {
+            time: var_time,
+            viseme: var_viseme,
+            mouth_open: var_mouthOpen,
+            mouth_form: var_mouthForm,
+        };
+    }
+}
+
+impl SseDecode for crate::api::live2d_model_api::VisemeTimelineDto {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_duration = <f32>::sse_decode(deserializer);
+        let mut var_keyframes =
+            <Vec<crate::api::live2d_model_api::VisemeKeyframeDto>>::sse_decode(deserializer);
+        return crate::api::live2d_model_api::VisemeTimelineDto {
+            duration: var_duration,
+            keyframes: var_keyframes,
+        };
+    }
+}
+
+fn pde_ffi_dispatcher_primary_impl(
+    func_id: i32,
+    port: flutter_rust_bridge::for_generated::MessagePort,
+    ptr: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len: i32,
+    data_len: i32,
+) {
+    // Codec=Pde (Serialization + dispatch), see doc to use other codecs
+    match func_id {
+        2 => wire__crate__api__chat_api__chat_capabilities_impl(port, ptr, rust_vec_len, data_len),
+        3 => wire__crate__api__chat_api__chat_close_session_impl(port, ptr, rust_vec_len, data_len),
+        4 => wire__crate__api__chat_api__chat_configure_impl(port, ptr, rust_vec_len, data_len),
+        5 => wire__crate__api__chat_api__chat_configure_from_file_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        6 => wire__crate__api__chat_api__chat_configure_from_toml_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        9 => {
+            wire__crate__api__chat_api__chat_delete_session_impl(port, ptr, rust_vec_len, data_len)
+        }
+        10 => wire__crate__api__chat_api__chat_history_impl(port, ptr, rust_vec_len, data_len),
+        11 => wire__crate__api__chat_api__chat_key_usage_impl(port, ptr, rust_vec_len, data_len),
+        12 => wire__crate__api__chat_api__chat_list_models_impl(port, ptr, rust_vec_len, data_len),
+        13 => {
+            wire__crate__api__chat_api__chat_list_sessions_impl(port, ptr, rust_vec_len, data_len)
+        }
+        14 => wire__crate__api__chat_api__chat_load_config_impl(port, ptr, rust_vec_len, data_len),
+        15 => wire__crate__api__chat_api__chat_model_profile_dto_default_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        16 => wire__crate__api__chat_api__chat_open_session_impl(port, ptr, rust_vec_len, data_len),
+        17 => wire__crate__api__chat_api__chat_reset_impl(port, ptr, rust_vec_len, data_len),
+        18 => wire__crate__api__chat_api__chat_save_config_impl(port, ptr, rust_vec_len, data_len),
+        19 => wire__crate__api__chat_api__chat_send_impl(port, ptr, rust_vec_len, data_len),
+        20 => wire__crate__api__chat_api__chat_send_stream_impl(port, ptr, rust_vec_len, data_len),
+        21 => wire__crate__api__chat_api__chat_summary_impl(port, ptr, rust_vec_len, data_len),
+        28 => wire__crate__api__live2d_model_api__live2d_lip_sync_analyze_wav_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        72 => wire__crate__api__live2d_model_api__live2d_motion_events_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        74 => wire__crate__api__live2d_texture_api__live2d_textures_decode_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        75 => wire__crate__api__live2d_texture_api__live2d_textures_load_from_model3_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        76 => wire__crate__api__wise_lover_boot_api__wise_lover_boot_init_app_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        _ => unreachable!(),
+    }
+}
+
+fn pde_ffi_dispatcher_sync_impl(
+    func_id: i32,
+    ptr: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len: i32,
+    data_len: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    // Codec=Pde (Serialization + dispatch), see doc to use other codecs
+    match func_id {
+        1 => wire__crate__api__chat_api__chat_cancel_impl(ptr, rust_vec_len, data_len),
+        7 => wire__crate__api__chat_api__chat_default_config_impl(ptr, rust_vec_len, data_len),
+        8 => wire__crate__api__chat_api__chat_default_provider_impl(ptr, rust_vec_len, data_len),
+        22 => wire__crate__api__wise_lover_boot_api__live2d_core_check_moc_consistency_impl(
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        23 => wire__crate__api__wise_lover_boot_api__live2d_core_latest_moc_version_impl(
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        24 => wire__crate__api__wise_lover_boot_api__live2d_core_loader_impl(
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        25 => wire__crate__api__wise_lover_boot_api__live2d_core_moc_version_impl(
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        26 => wire__crate__api__wise_lover_boot_api__live2d_core_version_impl(
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        27 => wire__crate__api__live2d_model_api__live2d_default_idle_effects_impl(
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        29 => wire__crate__api__live2d_model_api__live2d_model_apply_display_info_impl(
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        30 => wire__crate__api__live2d_model_api__live2d_model_apply_settings_impl(
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        31 => wire__crate__api__live2d_model_api__live2d_model_apply_user_data_impl(
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        32 => wire__crate__api__live2d_model_api__live2d_model_clear_emotion_impl(
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        33 => wire__crate__api__live2d_model_api__live2d_model_clear_parameter_smoothing_impl(
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        34 => wire__crate__api__live2d_model_api__live2d_model_configure_eye_blink_impl(
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        35 => wire__crate__api__live2d_model_api__live2d_model_configure_lip_sync_impl(
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        36 => wire__crate__api__live2d_model_api__live2d_model_configure_look_at_impl(
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        37 => wire__crate__api__live2d_model_api__live2d_model_force_blink_impl(
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        38 => wire__crate__api__live2d_model_api__live2d_model_get_drawable_user_data_impl(
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        39 => wire__crate__api__live2d_model_api__live2d_model_get_parameter_groups_impl(
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        40 => wire__crate__api__live2d_model_api__live2d_model_get_parameter_ids_impl(
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        41 => wire__crate__api__live2d_model_api__live2d_model_get_parameter_infos_impl(
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        42 => wire__crate__api__live2d_model_api__live2d_model_get_part_ids_impl(
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        43 => wire__crate__api__live2d_model_api__live2d_model_get_part_infos_impl(
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        44 => wire__crate__api__live2d_model_api__live2d_model_lip_sync_play_impl(
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        45 => wire__crate__api__live2d_model_api__live2d_model_lip_sync_play_wav_impl(
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        46 => wire__crate__api__live2d_model_api__live2d_model_lip_sync_push_pcm_impl(
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        47 => wire__crate__api__live2d_model_api__live2d_model_lip_sync_push_pcm16_impl(
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        48 => wire__crate__api__live2d_model_api__live2d_model_lip_sync_stop_impl(
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        49 => {
+            wire__crate__api__live2d_model_api__live2d_model_load_impl(ptr, rust_vec_len, data_len)
+        }
+        50 => wire__crate__api__live2d_model_api__live2d_model_load_emotion_map_impl(
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        51 => wire__crate__api__live2d_model_api__live2d_model_load_expression_impl(
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        52 => wire__crate__api__live2d_model_api__live2d_model_load_motion_impl(
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        53 => wire__crate__api__live2d_model_api__live2d_model_play_text_visemes_impl(
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        54 => wire__crate__api__live2d_model_api__live2d_model_set_draw_order_mode_impl(
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        55 => wire__crate__api__live2d_model_api__live2d_model_set_emotion_impl(
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        56 => wire__crate__api__live2d_model_api__live2d_model_set_emotion_timing_impl(
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        57 => wire__crate__api__live2d_model_api__live2d_model_set_idle_effects_impl(
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        58 => wire__crate__api__live2d_model_api__live2d_model_set_layer_impl(
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        59 => wire__crate__api__live2d_model_api__live2d_model_set_look_target_impl(
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        60 => wire__crate__api__live2d_model_api__live2d_model_set_parameter_impl(
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        61 => wire__crate__api__live2d_model_api__live2d_model_set_parameter_override_impl(
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        62 => wire__crate__api__live2d_model_api__live2d_model_set_parameter_smoothing_impl(
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        63 => wire__crate__api__live2d_model_api__live2d_model_set_part_opacity_impl(
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        64 => wire__crate__api__live2d_model_api__live2d_model_start_motion_impl(
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        65 => {
+            wire__crate__api__live2d_model_api__live2d_model_step_impl(ptr, rust_vec_len, data_len)
+        }
+        66 => wire__crate__api__live2d_model_api__live2d_model_step_batched_impl(
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        67 => wire__crate__api__live2d_model_api__live2d_model_stop_motions_impl(
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        68 => wire__crate__api__live2d_model_api__live2d_model_unload_impl(
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        69 => wire__crate__api__live2d_model_api__live2d_model_update_impl(
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        70 => wire__crate__api__live2d_model_api__live2d_model_view_transform_impl(
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        71 => wire__crate__api__live2d_model_api__live2d_model_viewport_to_model_impl(
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        73 => wire__crate__api__live2d_model_api__live2d_text_visemes_impl(
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        _ => unreachable!(),
+    }
+}
+
+// Section: rust2dart
+
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::live2d_model_api::AnimationLayerDto {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::Motion => 0.into_dart(),
+            Self::EyeBlink => 1.into_dart(),
+            Self::Expression => 2.into_dart(),
+            Self::Idle => 3.into_dart(),
+            Self::LipSync => 4.into_dart(),
+            Self::LookAt => 5.into_dart(),
+            Self::UserOverride => 6.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::live2d_model_api::AnimationLayerDto
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::live2d_model_api::AnimationLayerDto>
+    for crate::api::live2d_model_api::AnimationLayerDto
+{
+    fn into_into_dart(self) -> crate::api::live2d_model_api::AnimationLayerDto {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::live2d_model_api::BatchedFrameDto {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.canvas_width.into_into_dart().into_dart(),
+            self.canvas_height.into_into_dart().into_dart(),
+            self.canvas_origin_x.into_into_dart().into_dart(),
+            self.canvas_origin_y.into_into_dart().into_dart(),
+            self.pixels_per_unit.into_into_dart().into_dart(),
+            self.batches.into_into_dart().into_dart(),
+            self.mask_texture_count.into_into_dart().into_dart(),
+            self.mask_groups.into_into_dart().into_dart(),
+            self.mask_batches.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::live2d_model_api::BatchedFrameDto
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::live2d_model_api::BatchedFrameDto>
+    for crate::api::live2d_model_api::BatchedFrameDto
+{
+    fn into_into_dart(self) -> crate::api::live2d_model_api::BatchedFrameDto {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::live2d_model_api::BlendModeDto {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::Normal => 0.into_dart(),
+            Self::Additive => 1.into_dart(),
+            Self::Multiplicative => 2.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::live2d_model_api::BlendModeDto
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::live2d_model_api::BlendModeDto>
+    for crate::api::live2d_model_api::BlendModeDto
+{
+    fn into_into_dart(self) -> crate::api::live2d_model_api::BlendModeDto {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::live2d_model_api::BlinkIntervalDto {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::Fixed => 0.into_dart(),
+            Self::Uniform => 1.into_dart(),
+            Self::Exponential => 2.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::live2d_model_api::BlinkIntervalDto
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::live2d_model_api::BlinkIntervalDto>
+    for crate::api::live2d_model_api::BlinkIntervalDto
+{
+    fn into_into_dart(self) -> crate::api::live2d_model_api::BlinkIntervalDto {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::chat_api::ChatAppConfigDto {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.default_profile.into_into_dart().into_dart(),
+            self.provider.into_into_dart().into_dart(),
+            self.defaults.into_into_dart().into_dart(),
+            self.profiles.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::chat_api::ChatAppConfigDto
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::chat_api::ChatAppConfigDto>
+    for crate::api::chat_api::ChatAppConfigDto
+{
+    fn into_into_dart(self) -> crate::api::chat_api::ChatAppConfigDto {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::chat_api::ChatCapabilitiesDto {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.streaming.into_into_dart().into_dart(),
+            self.async_task.into_into_dart().into_dart(),
+            self.list_models.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::chat_api::ChatCapabilitiesDto
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::chat_api::ChatCapabilitiesDto>
+    for crate::api::chat_api::ChatCapabilitiesDto
+{
+    fn into_into_dart(self) -> crate::api::chat_api::ChatCapabilitiesDto {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::chat_api::ChatConfigDto {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.language_model.into_into_dart().into_dart(),
+            self.system_content.into_into_dart().into_dart(),
+            self.temperature.into_into_dart().into_dart(),
+            self.top_p.into_into_dart().into_dart(),
+            self.max_history.into_into_dart().into_dart(),
+            self.context_tokens.into_into_dart().into_dart(),
+            self.reply_tokens.into_into_dart().into_dart(),
+            self.summarize.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::chat_api::ChatConfigDto
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::chat_api::ChatConfigDto>
+    for crate::api::chat_api::ChatConfigDto
+{
+    fn into_into_dart(self) -> crate::api::chat_api::ChatConfigDto {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::chat_api::ChatInvokeModeDto {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::Sync => 0.into_dart(),
+            Self::AsyncTask => 1.into_dart(),
+            Self::Stream => 2.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::chat_api::ChatInvokeModeDto
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::chat_api::ChatInvokeModeDto>
+    for crate::api::chat_api::ChatInvokeModeDto
+{
+    fn into_into_dart(self) -> crate::api::chat_api::ChatInvokeModeDto {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::chat_api::ChatKeySelectionDto {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::RoundRobin => 0.into_dart(),
+            Self::Priority => 1.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::chat_api::ChatKeySelectionDto
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::chat_api::ChatKeySelectionDto>
+    for crate::api::chat_api::ChatKeySelectionDto
+{
+    fn into_into_dart(self) -> crate::api::chat_api::ChatKeySelectionDto {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::chat_api::ChatKeyUsageDto {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.label.into_into_dart().into_dart(),
+            self.requests.into_into_dart().into_dart(),
+            self.successes.into_into_dart().into_dart(),
+            self.failures.into_into_dart().into_dart(),
+            self.rate_limited.into_into_dart().into_dart(),
+            self.unauthorized.into_into_dart().into_dart(),
+            self.disabled_for_seconds.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::chat_api::ChatKeyUsageDto
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::chat_api::ChatKeyUsageDto>
+    for crate::api::chat_api::ChatKeyUsageDto
+{
+    fn into_into_dart(self) -> crate::api::chat_api::ChatKeyUsageDto {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::chat_api::ChatMessageDto {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.role.into_into_dart().into_dart(),
+            self.content.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::chat_api::ChatMessageDto
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::chat_api::ChatMessageDto>
+    for crate::api::chat_api::ChatMessageDto
+{
+    fn into_into_dart(self) -> crate::api::chat_api::ChatMessageDto {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::chat_api::ChatModelProfileDto {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.language_model.into_into_dart().into_dart(),
+            self.system_content.into_into_dart().into_dart(),
+            self.temperature.into_into_dart().into_dart(),
+            self.top_p.into_into_dart().into_dart(),
+            self.max_history.into_into_dart().into_dart(),
+            self.context_tokens.into_into_dart().into_dart(),
+            self.reply_tokens.into_into_dart().into_dart(),
+            self.summarize.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::chat_api::ChatModelProfileDto
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::chat_api::ChatModelProfileDto>
+    for crate::api::chat_api::ChatModelProfileDto
+{
+    fn into_into_dart(self) -> crate::api::chat_api::ChatModelProfileDto {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::chat_api::ChatProfileDto {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.name.into_into_dart().into_dart(),
+            self.profile.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::chat_api::ChatProfileDto
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::chat_api::ChatProfileDto>
+    for crate::api::chat_api::ChatProfileDto
+{
+    fn into_into_dart(self) -> crate::api::chat_api::ChatProfileDto {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::chat_api::ChatProviderDto {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.kind.into_into_dart().into_dart(),
+            self.base_url.into_into_dart().into_dart(),
+            self.api_key.into_into_dart().into_dart(),
+            self.api_keys.into_into_dart().into_dart(),
+            self.key_selection.into_into_dart().into_dart(),
+            self.poll_interval_ms.into_into_dart().into_dart(),
+            self.max_poll_interval_ms.into_into_dart().into_dart(),
+            self.task_timeout_seconds.into_into_dart().into_dart(),
+            self.poll_multiplier.into_into_dart().into_dart(),
+            self.poll_jitter.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::chat_api::ChatProviderDto
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::chat_api::ChatProviderDto>
+    for crate::api::chat_api::ChatProviderDto
+{
+    fn into_into_dart(self) -> crate::api::chat_api::ChatProviderDto {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::chat_api::ChatProviderKindDto {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::ChatGlm => 0.into_dart(),
+            Self::OpenAi => 1.into_dart(),
+            Self::Ollama => 2.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::chat_api::ChatProviderKindDto
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::chat_api::ChatProviderKindDto>
+    for crate::api::chat_api::ChatProviderKindDto
+{
+    fn into_into_dart(self) -> crate::api::chat_api::ChatProviderKindDto {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::chat_api::ChatReplyDto {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.content.into_into_dart().into_dart(),
+            self.warnings.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::chat_api::ChatReplyDto
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::chat_api::ChatReplyDto>
+    for crate::api::chat_api::ChatReplyDto
+{
+    fn into_into_dart(self) -> crate::api::chat_api::ChatReplyDto {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::chat_api::ChatRoleDto {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::System => 0.into_dart(),
+            Self::User => 1.into_dart(),
+            Self::Assistant => 2.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::chat_api::ChatRoleDto
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::chat_api::ChatRoleDto>
+    for crate::api::chat_api::ChatRoleDto
+{
+    fn into_into_dart(self) -> crate::api::chat_api::ChatRoleDto {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::chat_api::ChatSessionDto {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.id.into_into_dart().into_dart(),
+            self.message_count.into_into_dart().into_dart(),
+            self.tokens.into_into_dart().into_dart(),
+            self.created_at_ms.into_into_dart().into_dart(),
+            self.updated_at_ms.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::chat_api::ChatSessionDto
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::chat_api::ChatSessionDto>
+    for crate::api::chat_api::ChatSessionDto
+{
+    fn into_into_dart(self) -> crate::api::chat_api::ChatSessionDto {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::live2d_model_api::DrawBatchDto {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.texture_index.into_into_dart().into_dart(),
+            self.blend_mode.into_into_dart().into_dart(),
+            self.mask_group.into_into_dart().into_dart(),
+            self.inverted_mask.into_into_dart().into_dart(),
+            self.drawables.into_into_dart().into_dart(),
+            self.vertices.into_into_dart().into_dart(),
+            self.uvs.into_into_dart().into_dart(),
+            self.indices.into_into_dart().into_dart(),
+            self.multiply_colors.into_into_dart().into_dart(),
+            self.screen_colors.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::live2d_model_api::DrawBatchDto
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::live2d_model_api::DrawBatchDto>
+    for crate::api::live2d_model_api::DrawBatchDto
+{
+    fn into_into_dart(self) -> crate::api::live2d_model_api::DrawBatchDto {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::live2d_model_api::DrawOrderModeDto {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::RenderOrder => 0.into_dart(),
+            Self::DrawOrder => 1.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::live2d_model_api::DrawOrderModeDto
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::live2d_model_api::DrawOrderModeDto>
+    for crate::api::live2d_model_api::DrawOrderModeDto
+{
+    fn into_into_dart(self) -> crate::api::live2d_model_api::DrawOrderModeDto {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::live2d_model_api::DrawableFrameDto {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.index.into_into_dart().into_dart(),
+            self.texture_index.into_into_dart().into_dart(),
+            self.vertices.into_into_dart().into_dart(),
+            self.uvs.into_into_dart().into_dart(),
+            self.indices.into_into_dart().into_dart(),
+            self.masks.into_into_dart().into_dart(),
+            self.mask_group.into_into_dart().into_dart(),
+            self.opacity.into_into_dart().into_dart(),
+            self.multiply_color.into_into_dart().into_dart(),
+            self.screen_color.into_into_dart().into_dart(),
+            self.draw_order.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::live2d_model_api::DrawableFrameDto
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::live2d_model_api::DrawableFrameDto>
+    for crate::api::live2d_model_api::DrawableFrameDto
+{
+    fn into_into_dart(self) -> crate::api::live2d_model_api::DrawableFrameDto {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::live2d_model_api::DrawableUserDataDto {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.drawable_index.into_into_dart().into_dart(),
+            self.drawable_id.into_into_dart().into_dart(),
+            self.value.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::live2d_model_api::DrawableUserDataDto
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::live2d_model_api::DrawableUserDataDto>
+    for crate::api::live2d_model_api::DrawableUserDataDto
+{
+    fn into_into_dart(self) -> crate::api::live2d_model_api::DrawableUserDataDto {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::live2d_model_api::EmotionResultDto {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.expressions.into_into_dart().into_dart(),
+            self.started_motion.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::live2d_model_api::EmotionResultDto
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::live2d_model_api::EmotionResultDto>
+    for crate::api::live2d_model_api::EmotionResultDto
+{
+    fn into_into_dart(self) -> crate::api::live2d_model_api::EmotionResultDto {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::live2d_model_api::EyeBlinkConfigDto {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.enabled.into_into_dart().into_dart(),
+            self.interval.into_into_dart().into_dart(),
+            self.min_seconds.into_into_dart().into_dart(),
+            self.max_seconds.into_into_dart().into_dart(),
+            self.mean_seconds.into_into_dart().into_dart(),
+            self.closing_seconds.into_into_dart().into_dart(),
+            self.closed_seconds.into_into_dart().into_dart(),
+            self.opening_seconds.into_into_dart().into_dart(),
+            self.double_blink_probability.into_into_dart().into_dart(),
+            self.double_blink_gap_seconds.into_into_dart().into_dart(),
+            self.parameter_ids.into_into_dart().into_dart(),
+            self.seed.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::live2d_model_api::EyeBlinkConfigDto
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::live2d_model_api::EyeBlinkConfigDto>
+    for crate::api::live2d_model_api::EyeBlinkConfigDto
+{
+    fn into_into_dart(self) -> crate::api::live2d_model_api::EyeBlinkConfigDto {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::live2d_model_api::FrameDto {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.canvas_width.into_into_dart().into_dart(),
+            self.canvas_height.into_into_dart().into_dart(),
+            self.canvas_origin_x.into_into_dart().into_dart(),
+            self.canvas_origin_y.into_into_dart().into_dart(),
+            self.pixels_per_unit.into_into_dart().into_dart(),
+            self.drawables.into_into_dart().into_dart(),
+            self.mask_texture_count.into_into_dart().into_dart(),
+            self.mask_groups.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::live2d_model_api::FrameDto
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::live2d_model_api::FrameDto>
+    for crate::api::live2d_model_api::FrameDto
+{
+    fn into_into_dart(self) -> crate::api::live2d_model_api::FrameDto {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::live2d_model_api::IdleEffectDto {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.parameter_id.into_into_dart().into_dart(),
+            self.offset.into_into_dart().into_dart(),
+            self.peak.into_into_dart().into_dart(),
+            self.cycle_seconds.into_into_dart().into_dart(),
+            self.weight.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::live2d_model_api::IdleEffectDto
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::live2d_model_api::IdleEffectDto>
+    for crate::api::live2d_model_api::IdleEffectDto
+{
+    fn into_into_dart(self) -> crate::api::live2d_model_api::IdleEffectDto {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::live2d_model_api::LayerBlendDto {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::Normal => 0.into_dart(),
+            Self::Additive => 1.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::live2d_model_api::LayerBlendDto
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::live2d_model_api::LayerBlendDto>
+    for crate::api::live2d_model_api::LayerBlendDto
+{
+    fn into_into_dart(self) -> crate::api::live2d_model_api::LayerBlendDto {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::live2d_model_api::LipSyncConfigDto {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.frame_seconds.into_into_dart().into_dart(),
+            self.gain.into_into_dart().into_dart(),
+            self.noise_floor.into_into_dart().into_dart(),
+            self.attack_seconds.into_into_dart().into_dart(),
+            self.release_seconds.into_into_dart().into_dart(),
+            self.weight.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::live2d_model_api::LipSyncConfigDto
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::live2d_model_api::LipSyncConfigDto>
+    for crate::api::live2d_model_api::LipSyncConfigDto
+{
+    fn into_into_dart(self) -> crate::api::live2d_model_api::LipSyncConfigDto {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::live2d_model_api::LipSyncTimelineDto {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.frame_seconds.into_into_dart().into_dart(),
+            self.values.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::live2d_model_api::LipSyncTimelineDto
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::live2d_model_api::LipSyncTimelineDto>
+    for crate::api::live2d_model_api::LipSyncTimelineDto
+{
+    fn into_into_dart(self) -> crate::api::live2d_model_api::LipSyncTimelineDto {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::live2d_model_api::LookAtConfigDto {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.max_speed.into_into_dart().into_dart(),
+            self.time_to_max_speed.into_into_dart().into_dart(),
+            self.mappings.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::live2d_model_api::LookAtConfigDto
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::live2d_model_api::LookAtConfigDto>
+    for crate::api::live2d_model_api::LookAtConfigDto
+{
+    fn into_into_dart(self) -> crate::api::live2d_model_api::LookAtConfigDto {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::live2d_model_api::LookAtMappingDto {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.parameter_id.into_into_dart().into_dart(),
+            self.x_weight.into_into_dart().into_dart(),
+            self.y_weight.into_into_dart().into_dart(),
+            self.xy_weight.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::live2d_model_api::LookAtMappingDto
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::live2d_model_api::LookAtMappingDto>
+    for crate::api::live2d_model_api::LookAtMappingDto
+{
+    fn into_into_dart(self) -> crate::api::live2d_model_api::LookAtMappingDto {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::live2d_model_api::MaskGroupDto {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.texture_slot.into_into_dart().into_dart(),
+            self.channel.into_into_dart().into_dart(),
+            self.layout.into_into_dart().into_dart(),
+            self.mask_drawables.into_into_dart().into_dart(),
+            self.clipped_drawables.into_into_dart().into_dart(),
+            self.mask_matrix.into_into_dart().into_dart(),
+            self.draw_matrix.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::live2d_model_api::MaskGroupDto
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::live2d_model_api::MaskGroupDto>
+    for crate::api::live2d_model_api::MaskGroupDto
+{
+    fn into_into_dart(self) -> crate::api::live2d_model_api::MaskGroupDto {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::live2d_model_api::MotionEventDto {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.handle.into_into_dart().into_dart(),
+            self.motion_name.into_into_dart().into_dart(),
+            self.value.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::live2d_model_api::MotionEventDto
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::live2d_model_api::MotionEventDto>
+    for crate::api::live2d_model_api::MotionEventDto
+{
+    fn into_into_dart(self) -> crate::api::live2d_model_api::MotionEventDto {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::live2d_model_api::ParameterGroupInfoDto {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.id.into_into_dart().into_dart(),
+            self.name.into_into_dart().into_dart(),
+            self.parent_id.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::live2d_model_api::ParameterGroupInfoDto
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::live2d_model_api::ParameterGroupInfoDto>
+    for crate::api::live2d_model_api::ParameterGroupInfoDto
+{
+    fn into_into_dart(self) -> crate::api::live2d_model_api::ParameterGroupInfoDto {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::live2d_model_api::ParameterInfoDto {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.id.into_into_dart().into_dart(),
+            self.name.into_into_dart().into_dart(),
+            self.group_id.into_into_dart().into_dart(),
+            self.min_value.into_into_dart().into_dart(),
+            self.max_value.into_into_dart().into_dart(),
+            self.default_value.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::live2d_model_api::ParameterInfoDto
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::live2d_model_api::ParameterInfoDto>
+    for crate::api::live2d_model_api::ParameterInfoDto
+{
+    fn into_into_dart(self) -> crate::api::live2d_model_api::ParameterInfoDto {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::live2d_model_api::ParameterSmoothingDto {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.filter.into_into_dart().into_dart(),
+            self.time_seconds.into_into_dart().into_dart(),
+            self.max_velocity.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::live2d_model_api::ParameterSmoothingDto
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::live2d_model_api::ParameterSmoothingDto>
+    for crate::api::live2d_model_api::ParameterSmoothingDto
+{
+    fn into_into_dart(self) -> crate::api::live2d_model_api::ParameterSmoothingDto {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::live2d_model_api::PartInfoDto {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.id.into_into_dart().into_dart(),
+            self.name.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::live2d_model_api::PartInfoDto
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::live2d_model_api::PartInfoDto>
+    for crate::api::live2d_model_api::PartInfoDto
+{
+    fn into_into_dart(self) -> crate::api::live2d_model_api::PartInfoDto {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::live2d_model_api::SmoothingFilterDto {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::None => 0.into_dart(),
+            Self::Exponential => 1.into_dart(),
+            Self::Spring => 2.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::live2d_model_api::SmoothingFilterDto
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::live2d_model_api::SmoothingFilterDto>
+    for crate::api::live2d_model_api::SmoothingFilterDto
+{
+    fn into_into_dart(self) -> crate::api::live2d_model_api::SmoothingFilterDto {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::live2d_texture_api::TextureDto {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.texture_index.into_into_dart().into_dart(),
+            self.width.into_into_dart().into_dart(),
+            self.height.into_into_dart().into_dart(),
+            self.premultiplied_alpha.into_into_dart().into_dart(),
+            self.levels.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::live2d_texture_api::TextureDto
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::live2d_texture_api::TextureDto>
+    for crate::api::live2d_texture_api::TextureDto
+{
+    fn into_into_dart(self) -> crate::api::live2d_texture_api::TextureDto {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::live2d_texture_api::TextureLevelDto {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.width.into_into_dart().into_dart(),
+            self.height.into_into_dart().into_dart(),
+            self.rgba.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::live2d_texture_api::TextureLevelDto
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::live2d_texture_api::TextureLevelDto>
+    for crate::api::live2d_texture_api::TextureLevelDto
+{
+    fn into_into_dart(self) -> crate::api::live2d_texture_api::TextureLevelDto {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::live2d_texture_api::TextureOptionsDto {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.premultiply_alpha.into_into_dart().into_dart(),
+            self.generate_mipmaps.into_into_dart().into_dart(),
+            self.memory_budget_bytes.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::live2d_texture_api::TextureOptionsDto
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::live2d_texture_api::TextureOptionsDto>
+    for crate::api::live2d_texture_api::TextureOptionsDto
+{
+    fn into_into_dart(self) -> crate::api::live2d_texture_api::TextureOptionsDto {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::live2d_model_api::ViewFitModeDto {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::Contain => 0.into_dart(),
+            Self::Cover => 1.into_dart(),
+            Self::FixedScale => 2.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::live2d_model_api::ViewFitModeDto
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::live2d_model_api::ViewFitModeDto>
+    for crate::api::live2d_model_api::ViewFitModeDto
+{
+    fn into_into_dart(self) -> crate::api::live2d_model_api::ViewFitModeDto {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::live2d_model_api::ViewTransformDto {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.pixels_per_unit.into_into_dart().into_dart(),
+            self.model_to_viewport.into_into_dart().into_dart(),
+            self.model_to_ndc.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::live2d_model_api::ViewTransformDto
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::live2d_model_api::ViewTransformDto>
+    for crate::api::live2d_model_api::ViewTransformDto
+{
+    fn into_into_dart(self) -> crate::api::live2d_model_api::ViewTransformDto {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::live2d_model_api::ViewportDto {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.width.into_into_dart().into_dart(),
+            self.height.into_into_dart().into_dart(),
+            self.fit_mode.into_into_dart().into_dart(),
+            self.fixed_scale.into_into_dart().into_dart(),
+            self.pan_x.into_into_dart().into_dart(),
+            self.pan_y.into_into_dart().into_dart(),
+            self.zoom.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::live2d_model_api::ViewportDto
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::live2d_model_api::ViewportDto>
+    for crate::api::live2d_model_api::ViewportDto
+{
+    fn into_into_dart(self) -> crate::api::live2d_model_api::ViewportDto {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::live2d_model_api::VisemeDto {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::A => 0.into_dart(),
+            Self::I => 1.into_dart(),
+            Self::U => 2.into_dart(),
+            Self::E => 3.into_dart(),
+            Self::O => 4.into_dart(),
+            Self::Closed => 5.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::live2d_model_api::VisemeDto
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::live2d_model_api::VisemeDto>
+    for crate::api::live2d_model_api::VisemeDto
+{
+    fn into_into_dart(self) -> crate::api::live2d_model_api::VisemeDto {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::live2d_model_api::VisemeKeyframeDto {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.time.into_into_dart().into_dart(),
+            self.viseme.into_into_dart().into_dart(),
+            self.mouth_open.into_into_dart().into_dart(),
+            self.mouth_form.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::live2d_model_api::VisemeKeyframeDto
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::live2d_model_api::VisemeKeyframeDto>
+    for crate::api::live2d_model_api::VisemeKeyframeDto
+{
+    fn into_into_dart(self) -> crate::api::live2d_model_api::VisemeKeyframeDto {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::live2d_model_api::VisemeTimelineDto {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.duration.into_into_dart().into_dart(),
+            self.keyframes.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::live2d_model_api::VisemeTimelineDto
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::live2d_model_api::VisemeTimelineDto>
+    for crate::api::live2d_model_api::VisemeTimelineDto
+{
+    fn into_into_dart(self) -> crate::api::live2d_model_api::VisemeTimelineDto {
+        self
+    }
+}
+
+impl SseEncode for flutter_rust_bridge::for_generated::anyhow::Error {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(format!("{:?}", self), serializer);
+    }
+}
+
+impl SseEncode for StreamSink<String, flutter_rust_bridge::for_generated::SseCodec> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        unimplemented!("")
+    }
+}
+
+impl SseEncode
+    for StreamSink<
+        crate::api::live2d_model_api::MotionEventDto,
+        flutter_rust_bridge::for_generated::SseCodec,
+    >
+{
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        unimplemented!("")
+    }
+}
+
+impl SseEncode for String {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <Vec<u8>>::sse_encode(self.into_bytes(), serializer);
+    }
+}
+
+impl SseEncode for crate::api::live2d_model_api::AnimationLayerDto {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::api::live2d_model_api::AnimationLayerDto::Motion => 0,
+                crate::api::live2d_model_api::AnimationLayerDto::EyeBlink => 1,
+                crate::api::live2d_model_api::AnimationLayerDto::Expression => 2,
+                crate::api::live2d_model_api::AnimationLayerDto::Idle => 3,
+                crate::api::live2d_model_api::AnimationLayerDto::LipSync => 4,
+                crate::api::live2d_model_api::AnimationLayerDto::LookAt => 5,
+                crate::api::live2d_model_api::AnimationLayerDto::UserOverride => 6,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
+    }
+}
+
+impl SseEncode for crate::api::live2d_model_api::BatchedFrameDto {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <f32>::sse_encode(self.canvas_width, serializer);
+        <f32>::sse_encode(self.canvas_height, serializer);
+        <f32>::sse_encode(self.canvas_origin_x, serializer);
+        <f32>::sse_encode(self.canvas_origin_y, serializer);
+        <f32>::sse_encode(self.pixels_per_unit, serializer);
+        <Vec<crate::api::live2d_model_api::DrawBatchDto>>::sse_encode(self.batches, serializer);
+        <u32>::sse_encode(self.mask_texture_count, serializer);
+        <Vec<crate::api::live2d_model_api::MaskGroupDto>>::sse_encode(self.mask_groups, serializer);
+        <Vec<crate::api::live2d_model_api::DrawBatchDto>>::sse_encode(
+            self.mask_batches,
+            serializer,
+        );
+    }
+}
+
+impl SseEncode for crate::api::live2d_model_api::BlendModeDto {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::api::live2d_model_api::BlendModeDto::Normal => 0,
+                crate::api::live2d_model_api::BlendModeDto::Additive => 1,
+                crate::api::live2d_model_api::BlendModeDto::Multiplicative => 2,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
+    }
+}
+
+impl SseEncode for crate::api::live2d_model_api::BlinkIntervalDto {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::api::live2d_model_api::BlinkIntervalDto::Fixed => 0,
+                crate::api::live2d_model_api::BlinkIntervalDto::Uniform => 1,
+                crate::api::live2d_model_api::BlinkIntervalDto::Exponential => 2,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
+    }
+}
+
+impl SseEncode for bool {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        serializer.cursor.write_u8(self as _).unwrap();
+    }
+}
+
+impl SseEncode for crate::api::chat_api::ChatAppConfigDto {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <Option<String>>::sse_encode(self.default_profile, serializer);
+        <crate::api::chat_api::ChatProviderDto>::sse_encode(self.provider, serializer);
+        <crate::api::chat_api::ChatModelProfileDto>::sse_encode(self.defaults, serializer);
+        <Vec<crate::api::chat_api::ChatProfileDto>>::sse_encode(self.profiles, serializer);
+    }
+}
+
+impl SseEncode for crate::api::chat_api::ChatCapabilitiesDto {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.streaming, serializer);
+        <bool>::sse_encode(self.async_task, serializer);
+        <bool>::sse_encode(self.list_models, serializer);
+    }
+}
+
+impl SseEncode for crate::api::chat_api::ChatConfigDto {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.language_model, serializer);
+        <Option<String>>::sse_encode(self.system_content, serializer);
+        <f64>::sse_encode(self.temperature, serializer);
+        <f64>::sse_encode(self.top_p, serializer);
+        <u32>::sse_encode(self.max_history, serializer);
+        <Option<u32>>::sse_encode(self.context_tokens, serializer);
+        <Option<u32>>::sse_encode(self.reply_tokens, serializer);
+        <bool>::sse_encode(self.summarize, serializer);
+    }
+}
+
+impl SseEncode for crate::api::chat_api::ChatInvokeModeDto {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::api::chat_api::ChatInvokeModeDto::Sync => 0,
+                crate::api::chat_api::ChatInvokeModeDto::AsyncTask => 1,
+                crate::api::chat_api::ChatInvokeModeDto::Stream => 2,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
+    }
+}
+
+impl SseEncode for crate::api::chat_api::ChatKeySelectionDto {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::api::chat_api::ChatKeySelectionDto::RoundRobin => 0,
+                crate::api::chat_api::ChatKeySelectionDto::Priority => 1,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
+    }
+}
+
+impl SseEncode for crate::api::chat_api::ChatKeyUsageDto {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.label, serializer);
+        <u64>::sse_encode(self.requests, serializer);
+        <u64>::sse_encode(self.successes, serializer);
+        <u64>::sse_encode(self.failures, serializer);
+        <u64>::sse_encode(self.rate_limited, serializer);
+        <u64>::sse_encode(self.unauthorized, serializer);
+        <Option<u32>>::sse_encode(self.disabled_for_seconds, serializer);
+    }
+}
+
+impl SseEncode for crate::api::chat_api::ChatMessageDto {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <crate::api::chat_api::ChatRoleDto>::sse_encode(self.role, serializer);
+        <String>::sse_encode(self.content, serializer);
+    }
+}
+
+impl SseEncode for crate::api::chat_api::ChatModelProfileDto {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <Option<String>>::sse_encode(self.language_model, serializer);
+        <Option<String>>::sse_encode(self.system_content, serializer);
+        <Option<f64>>::sse_encode(self.temperature, serializer);
+        <Option<f64>>::sse_encode(self.top_p, serializer);
+        <Option<u32>>::sse_encode(self.max_history, serializer);
+        <Option<u32>>::sse_encode(self.context_tokens, serializer);
+        <Option<u32>>::sse_encode(self.reply_tokens, serializer);
+        <Option<bool>>::sse_encode(self.summarize, serializer);
+    }
+}
+
+impl SseEncode for crate::api::chat_api::ChatProfileDto {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.name, serializer);
+        <crate::api::chat_api::ChatModelProfileDto>::sse_encode(self.profile, serializer);
+    }
+}
+
+impl SseEncode for crate::api::chat_api::ChatProviderDto {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <crate::api::chat_api::ChatProviderKindDto>::sse_encode(self.kind, serializer);
+        <Option<String>>::sse_encode(self.base_url, serializer);
+        <Option<String>>::sse_encode(self.api_key, serializer);
+        <Vec<String>>::sse_encode(self.api_keys, serializer);
+        <crate::api::chat_api::ChatKeySelectionDto>::sse_encode(self.key_selection, serializer);
+        <u32>::sse_encode(self.poll_interval_ms, serializer);
+        <u32>::sse_encode(self.max_poll_interval_ms, serializer);
+        <u32>::sse_encode(self.task_timeout_seconds, serializer);
+        <f64>::sse_encode(self.poll_multiplier, serializer);
+        <f64>::sse_encode(self.poll_jitter, serializer);
+    }
+}
+
+impl SseEncode for crate::api::chat_api::ChatProviderKindDto {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::api::chat_api::ChatProviderKindDto::ChatGlm => 0,
+                crate::api::chat_api::ChatProviderKindDto::OpenAi => 1,
+                crate::api::chat_api::ChatProviderKindDto::Ollama => 2,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
+    }
+}
+
+impl SseEncode for crate::api::chat_api::ChatReplyDto {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.content, serializer);
+        <Vec<String>>::sse_encode(self.warnings, serializer);
+    }
+}
+
+impl SseEncode for crate::api::chat_api::ChatRoleDto {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::api::chat_api::ChatRoleDto::System => 0,
+                crate::api::chat_api::ChatRoleDto::User => 1,
+                crate::api::chat_api::ChatRoleDto::Assistant => 2,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
+    }
+}
+
+impl SseEncode for crate::api::chat_api::ChatSessionDto {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.id, serializer);
+        <u32>::sse_encode(self.message_count, serializer);
+        <u64>::sse_encode(self.tokens, serializer);
+        <Option<i64>>::sse_encode(self.created_at_ms, serializer);
+        <Option<i64>>::sse_encode(self.updated_at_ms, serializer);
+    }
+}
+
+impl SseEncode for crate::api::live2d_model_api::DrawBatchDto {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <u32>::sse_encode(self.texture_index, serializer);
+        <crate::api::live2d_model_api::BlendModeDto>::sse_encode(self.blend_mode, serializer);
+        <Option<u32>>::sse_encode(self.mask_group, serializer);
+        <bool>::sse_encode(self.inverted_mask, serializer);
+        <Vec<u32>>::sse_encode(self.drawables, serializer);
+        <Vec<f32>>::sse_encode(self.vertices, serializer);
+        <Vec<f32>>::sse_encode(self.uvs, serializer);
+        <Vec<u16>>::sse_encode(self.indices, serializer);
+        <Vec<f32>>::sse_encode(self.multiply_colors, serializer);
+        <Vec<f32>>::sse_encode(self.screen_colors, serializer);
+    }
+}
+
+impl SseEncode for crate::api::live2d_model_api::DrawOrderModeDto {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::api::live2d_model_api::DrawOrderModeDto::RenderOrder => 0,
+                crate::api::live2d_model_api::DrawOrderModeDto::DrawOrder => 1,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
+    }
+}
+
+impl SseEncode for crate::api::live2d_model_api::DrawableFrameDto {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <u32>::sse_encode(self.index, serializer);
+        <u32>::sse_encode(self.texture_index, serializer);
+        <Vec<f32>>::sse_encode(self.vertices, serializer);
+        <Vec<f32>>::sse_encode(self.uvs, serializer);
+        <Vec<u16>>::sse_encode(self.indices, serializer);
+        <Vec<u16>>::sse_encode(self.masks, serializer);
+        <Option<u32>>::sse_encode(self.mask_group, serializer);
+        <f32>::sse_encode(self.opacity, serializer);
+        <[f32; 4]>::sse_encode(self.multiply_color, serializer);
+        <[f32; 4]>::sse_encode(self.screen_color, serializer);
+        <i32>::sse_encode(self.draw_order, serializer);
+    }
+}
+
+impl SseEncode for crate::api::live2d_model_api::DrawableUserDataDto {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <u32>::sse_encode(self.drawable_index, serializer);
+        <String>::sse_encode(self.drawable_id, serializer);
+        <String>::sse_encode(self.value, serializer);
+    }
+}
+
+impl SseEncode for crate::api::live2d_model_api::EmotionResultDto {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <Vec<String>>::sse_encode(self.expressions, serializer);
+        <Option<String>>::sse_encode(self.started_motion, serializer);
+    }
+}
+
+impl SseEncode for crate::api::live2d_model_api::EyeBlinkConfigDto {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.enabled, serializer);
+        <crate::api::live2d_model_api::BlinkIntervalDto>::sse_encode(self.interval, serializer);
+        <f32>::sse_encode(self.min_seconds, serializer);
+        <f32>::sse_encode(self.max_seconds, serializer);
+        <f32>::sse_encode(self.mean_seconds, serializer);
+        <f32>::sse_encode(self.closing_seconds, serializer);
+        <f32>::sse_encode(self.closed_seconds, serializer);
+        <f32>::sse_encode(self.opening_seconds, serializer);
+        <f32>::sse_encode(self.double_blink_probability, serializer);
+        <f32>::sse_encode(self.double_blink_gap_seconds, serializer);
+        <Vec<String>>::sse_encode(self.parameter_ids, serializer);
+        <Option<u64>>::sse_encode(self.seed, serializer);
+    }
+}
+
+impl SseEncode for f32 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        serializer.cursor.write_f32::<NativeEndian>(self).unwrap();
+    }
+}
+
+impl SseEncode for [f32; 16] {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <Vec<f32>>::sse_encode(
+            {
+                let boxed: Box<[_]> = Box::new(self);
+                boxed.into_vec()
+            },
+            serializer,
+        );
+    }
+}
+
+impl SseEncode for [f32; 2] {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <Vec<f32>>::sse_encode(
+            {
+                let boxed: Box<[_]> = Box::new(self);
+                boxed.into_vec()
+            },
+            serializer,
+        );
+    }
+}
+
+impl SseEncode for [f32; 4] {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <Vec<f32>>::sse_encode(
+            {
+                let boxed: Box<[_]> = Box::new(self);
+                boxed.into_vec()
+            },
+            serializer,
+        );
+    }
+}
+
+impl SseEncode for f64 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        serializer.cursor.write_f64::<NativeEndian>(self).unwrap();
+    }
+}
+
+impl SseEncode for crate::api::live2d_model_api::FrameDto {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <f32>::sse_encode(self.canvas_width, serializer);
+        <f32>::sse_encode(self.canvas_height, serializer);
+        <f32>::sse_encode(self.canvas_origin_x, serializer);
+        <f32>::sse_encode(self.canvas_origin_y, serializer);
+        <f32>::sse_encode(self.pixels_per_unit, serializer);
+        <Vec<crate::api::live2d_model_api::DrawableFrameDto>>::sse_encode(
+            self.drawables,
+            serializer,
+        );
+        <u32>::sse_encode(self.mask_texture_count, serializer);
+        <Vec<crate::api::live2d_model_api::MaskGroupDto>>::sse_encode(self.mask_groups, serializer);
+    }
+}
+
+impl SseEncode for i16 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        serializer.cursor.write_i16::<NativeEndian>(self).unwrap();
+    }
+}
 
-// Section: dart2rust
-
-impl SseDecode for String {
+impl SseEncode for i32 {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut inner = <Vec<u8>>::sse_decode(deserializer);
-        return String::from_utf8(inner).unwrap();
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        serializer.cursor.write_i32::<NativeEndian>(self).unwrap();
     }
 }
 
-impl SseDecode for bool {
+impl SseEncode for i64 {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        deserializer.cursor.read_u8().unwrap() != 0
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        serializer.cursor.write_i64::<NativeEndian>(self).unwrap();
     }
 }
 
-impl SseDecode for crate::api::live2d_model_api::DrawableFrameDto {
+impl SseEncode for crate::api::live2d_model_api::IdleEffectDto {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_index = <u32>::sse_decode(deserializer);
-        let mut var_textureIndex = <u32>::sse_decode(deserializer);
-        let mut var_vertices = <Vec<f32>>::sse_decode(deserializer);
-        let mut var_uvs = <Vec<f32>>::sse_decode(deserializer);
-        let mut var_indices = <Vec<u16>>::sse_decode(deserializer);
-        let mut var_masks = <Vec<u16>>::sse_decode(deserializer);
-        let mut var_opacity = <f32>::sse_decode(deserializer);
-        let mut var_multiplyColor = <[f32; 4]>::sse_decode(deserializer);
-        let mut var_screenColor = <[f32; 4]>::sse_decode(deserializer);
-        let mut var_drawOrder = <i32>::sse_decode(deserializer);
-        return crate::api::live2d_model_api::DrawableFrameDto {
-            index: var_index,
-            texture_index: var_textureIndex,
-            vertices: var_vertices,
-            uvs: var_uvs,
-            indices: var_indices,
-            masks: var_masks,
-            opacity: var_opacity,
-            multiply_color: var_multiplyColor,
-            screen_color: var_screenColor,
-            draw_order: var_drawOrder,
-        };
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.parameter_id, serializer);
+        <f32>::sse_encode(self.offset, serializer);
+        <f32>::sse_encode(self.peak, serializer);
+        <f32>::sse_encode(self.cycle_seconds, serializer);
+        <f32>::sse_encode(self.weight, serializer);
     }
 }
 
-impl SseDecode for f32 {
+impl SseEncode for crate::api::live2d_model_api::LayerBlendDto {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        deserializer.cursor.read_f32::<NativeEndian>().unwrap()
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::api::live2d_model_api::LayerBlendDto::Normal => 0,
+                crate::api::live2d_model_api::LayerBlendDto::Additive => 1,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
     }
 }
 
-impl SseDecode for [f32; 4] {
+impl SseEncode for crate::api::live2d_model_api::LipSyncConfigDto {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut inner = <Vec<f32>>::sse_decode(deserializer);
-        return flutter_rust_bridge::for_generated::from_vec_to_array(inner);
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <f32>::sse_encode(self.frame_seconds, serializer);
+        <f32>::sse_encode(self.gain, serializer);
+        <f32>::sse_encode(self.noise_floor, serializer);
+        <f32>::sse_encode(self.attack_seconds, serializer);
+        <f32>::sse_encode(self.release_seconds, serializer);
+        <f32>::sse_encode(self.weight, serializer);
     }
 }
 
-impl SseDecode for crate::api::live2d_model_api::FrameDto {
+impl SseEncode for crate::api::live2d_model_api::LipSyncTimelineDto {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_canvasWidth = <f32>::sse_decode(deserializer);
-        let mut var_canvasHeight = <f32>::sse_decode(deserializer);
-        let mut var_drawables =
-            <Vec<crate::api::live2d_model_api::DrawableFrameDto>>::sse_decode(deserializer);
-        return crate::api::live2d_model_api::FrameDto {
-            canvas_width: var_canvasWidth,
-            canvas_height: var_canvasHeight,
-            drawables: var_drawables,
-        };
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <f32>::sse_encode(self.frame_seconds, serializer);
+        <Vec<f32>>::sse_encode(self.values, serializer);
     }
 }
 
-impl SseDecode for i32 {
+impl SseEncode for Vec<String> {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        deserializer.cursor.read_i32::<NativeEndian>().unwrap()
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <String>::sse_encode(item, serializer);
+        }
     }
 }
 
-impl SseDecode for Vec<String> {
+impl SseEncode for Vec<crate::api::chat_api::ChatKeyUsageDto> {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<String>::sse_decode(deserializer));
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::chat_api::ChatKeyUsageDto>::sse_encode(item, serializer);
         }
-        return ans_;
     }
 }
 
-impl SseDecode for Vec<crate::api::live2d_model_api::DrawableFrameDto> {
+impl SseEncode for Vec<crate::api::chat_api::ChatMessageDto> {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<crate::api::live2d_model_api::DrawableFrameDto>::sse_decode(deserializer));
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::chat_api::ChatMessageDto>::sse_encode(item, serializer);
         }
-        return ans_;
     }
 }
 
-impl SseDecode for Vec<f32> {
+impl SseEncode for Vec<crate::api::chat_api::ChatProfileDto> {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<f32>::sse_decode(deserializer));
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::chat_api::ChatProfileDto>::sse_encode(item, serializer);
         }
-        return ans_;
     }
 }
 
-impl SseDecode for Vec<u16> {
+impl SseEncode for Vec<crate::api::chat_api::ChatSessionDto> {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<u16>::sse_decode(deserializer));
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::chat_api::ChatSessionDto>::sse_encode(item, serializer);
         }
-        return ans_;
     }
 }
 
-impl SseDecode for Vec<u8> {
+impl SseEncode for Vec<crate::api::live2d_model_api::DrawBatchDto> {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<u8>::sse_decode(deserializer));
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::live2d_model_api::DrawBatchDto>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<crate::api::live2d_model_api::DrawableFrameDto> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::live2d_model_api::DrawableFrameDto>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<crate::api::live2d_model_api::DrawableUserDataDto> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::live2d_model_api::DrawableUserDataDto>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<crate::api::live2d_model_api::IdleEffectDto> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::live2d_model_api::IdleEffectDto>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<Vec<u8>> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <Vec<u8>>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<crate::api::live2d_model_api::LookAtMappingDto> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::live2d_model_api::LookAtMappingDto>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<crate::api::live2d_model_api::MaskGroupDto> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::live2d_model_api::MaskGroupDto>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<crate::api::live2d_model_api::ParameterGroupInfoDto> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::live2d_model_api::ParameterGroupInfoDto>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<crate::api::live2d_model_api::ParameterInfoDto> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::live2d_model_api::ParameterInfoDto>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<crate::api::live2d_model_api::PartInfoDto> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::live2d_model_api::PartInfoDto>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<f32> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <f32>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<i16> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <i16>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<u16> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <u16>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<u32> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <u32>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<u8> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <u8>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<crate::api::live2d_texture_api::TextureDto> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::live2d_texture_api::TextureDto>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<crate::api::live2d_texture_api::TextureLevelDto> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::live2d_texture_api::TextureLevelDto>::sse_encode(item, serializer);
         }
-        return ans_;
     }
 }
 
-impl SseDecode for u16 {
+impl SseEncode for Vec<crate::api::live2d_model_api::VisemeKeyframeDto> {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        deserializer.cursor.read_u16::<NativeEndian>().unwrap()
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::live2d_model_api::VisemeKeyframeDto>::sse_encode(item, serializer);
+        }
     }
 }
 
-impl SseDecode for u32 {
+impl SseEncode for crate::api::live2d_model_api::LookAtConfigDto {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        deserializer.cursor.read_u32::<NativeEndian>().unwrap()
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <f32>::sse_encode(self.max_speed, serializer);
+        <f32>::sse_encode(self.time_to_max_speed, serializer);
+        <Vec<crate::api::live2d_model_api::LookAtMappingDto>>::sse_encode(
+            self.mappings,
+            serializer,
+        );
     }
 }
 
-impl SseDecode for u64 {
+impl SseEncode for crate::api::live2d_model_api::LookAtMappingDto {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        deserializer.cursor.read_u64::<NativeEndian>().unwrap()
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.parameter_id, serializer);
+        <f32>::sse_encode(self.x_weight, serializer);
+        <f32>::sse_encode(self.y_weight, serializer);
+        <f32>::sse_encode(self.xy_weight, serializer);
     }
 }
 
-impl SseDecode for u8 {
+impl SseEncode for crate::api::live2d_model_api::MaskGroupDto {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        deserializer.cursor.read_u8().unwrap()
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <u32>::sse_encode(self.texture_slot, serializer);
+        <u32>::sse_encode(self.channel, serializer);
+        <[f32; 4]>::sse_encode(self.layout, serializer);
+        <Vec<u32>>::sse_encode(self.mask_drawables, serializer);
+        <Vec<u32>>::sse_encode(self.clipped_drawables, serializer);
+        <[f32; 16]>::sse_encode(self.mask_matrix, serializer);
+        <[f32; 16]>::sse_encode(self.draw_matrix, serializer);
     }
 }
 
-impl SseDecode for () {
+impl SseEncode for crate::api::live2d_model_api::MotionEventDto {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {}
-}
-
-fn pde_ffi_dispatcher_primary_impl(
-    func_id: i32,
-    port: flutter_rust_bridge::for_generated::MessagePort,
-    ptr: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
-    rust_vec_len: i32,
-    data_len: i32,
-) {
-    // Codec=Pde (Serialization + dispatch), see doc to use other codecs
-    match func_id {
-        13 => wire__crate__api__wise_lover_boot_api__wise_lover_boot_init_app_impl(
-            port,
-            ptr,
-            rust_vec_len,
-            data_len,
-        ),
-        _ => unreachable!(),
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <u64>::sse_encode(self.handle, serializer);
+        <String>::sse_encode(self.motion_name, serializer);
+        <String>::sse_encode(self.value, serializer);
     }
 }
 
-fn pde_ffi_dispatcher_sync_impl(
-    func_id: i32,
-    ptr: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
-    rust_vec_len: i32,
-    data_len: i32,
-) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
-    // Codec=Pde (Serialization + dispatch), see doc to use other codecs
-    match func_id {
-        1 => wire__crate__api__wise_lover_boot_api__live2d_core_check_moc_consistency_impl(
-            ptr,
-            rust_vec_len,
-            data_len,
-        ),
-        2 => wire__crate__api__wise_lover_boot_api__live2d_core_latest_moc_version_impl(
-            ptr,
-            rust_vec_len,
-            data_len,
-        ),
-        3 => wire__crate__api__wise_lover_boot_api__live2d_core_loader_impl(
-            ptr,
-            rust_vec_len,
-            data_len,
-        ),
-        4 => wire__crate__api__wise_lover_boot_api__live2d_core_moc_version_impl(
-            ptr,
-            rust_vec_len,
-            data_len,
-        ),
-        5 => wire__crate__api__wise_lover_boot_api__live2d_core_version_impl(
-            ptr,
-            rust_vec_len,
-            data_len,
-        ),
-        6 => wire__crate__api__live2d_model_api__live2d_model_get_parameter_ids_impl(
-            ptr,
-            rust_vec_len,
-            data_len,
-        ),
-        7 => wire__crate__api__live2d_model_api__live2d_model_get_part_ids_impl(
-            ptr,
-            rust_vec_len,
-            data_len,
-        ),
-        8 => {
-            wire__crate__api__live2d_model_api__live2d_model_load_impl(ptr, rust_vec_len, data_len)
-        }
-        9 => wire__crate__api__live2d_model_api__live2d_model_set_parameter_impl(
-            ptr,
-            rust_vec_len,
-            data_len,
-        ),
-        10 => wire__crate__api__live2d_model_api__live2d_model_set_part_opacity_impl(
-            ptr,
-            rust_vec_len,
-            data_len,
-        ),
-        11 => {
-            wire__crate__api__live2d_model_api__live2d_model_step_impl(ptr, rust_vec_len, data_len)
+impl SseEncode for Option<String> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <String>::sse_encode(value, serializer);
         }
-        12 => wire__crate__api__live2d_model_api__live2d_model_unload_impl(
-            ptr,
-            rust_vec_len,
-            data_len,
-        ),
-        _ => unreachable!(),
     }
 }
 
-// Section: rust2dart
-
-// Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::api::live2d_model_api::DrawableFrameDto {
-    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        [
-            self.texture_index.into_into_dart().into_dart(),
-            self.vertices.into_into_dart().into_dart(),
-            self.uvs.into_into_dart().into_dart(),
-            self.indices.into_into_dart().into_dart(),
-            self.opacity.into_into_dart().into_dart(),
-            self.multiply_color.into_into_dart().into_dart(),
-            self.screen_color.into_into_dart().into_dart(),
-            self.draw_order.into_into_dart().into_dart(),
-        ]
-        .into_dart()
-    }
-}
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
-    for crate::api::live2d_model_api::DrawableFrameDto
-{
-}
-impl flutter_rust_bridge::IntoIntoDart<crate::api::live2d_model_api::DrawableFrameDto>
-    for crate::api::live2d_model_api::DrawableFrameDto
-{
-    fn into_into_dart(self) -> crate::api::live2d_model_api::DrawableFrameDto {
-        self
+impl SseEncode for Option<bool> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <bool>::sse_encode(value, serializer);
+        }
     }
 }
-// Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::api::live2d_model_api::FrameDto {
-    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        [
-            self.canvas_width.into_into_dart().into_dart(),
-            self.canvas_height.into_into_dart().into_dart(),
-            self.drawables.into_into_dart().into_dart(),
-        ]
-        .into_dart()
+
+impl SseEncode for Option<crate::api::chat_api::ChatInvokeModeDto> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::api::chat_api::ChatInvokeModeDto>::sse_encode(value, serializer);
+        }
     }
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
-    for crate::api::live2d_model_api::FrameDto
-{
-}
-impl flutter_rust_bridge::IntoIntoDart<crate::api::live2d_model_api::FrameDto>
-    for crate::api::live2d_model_api::FrameDto
-{
-    fn into_into_dart(self) -> crate::api::live2d_model_api::FrameDto {
-        self
+
+impl SseEncode for Option<f32> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <f32>::sse_encode(value, serializer);
+        }
     }
 }
 
-impl SseEncode for String {
+impl SseEncode for Option<f64> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <Vec<u8>>::sse_encode(self.into_bytes(), serializer);
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <f64>::sse_encode(value, serializer);
+        }
     }
 }
 
-impl SseEncode for bool {
+impl SseEncode for Option<i64> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        serializer.cursor.write_u8(self as _).unwrap();
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <i64>::sse_encode(value, serializer);
+        }
     }
 }
 
-impl SseEncode for crate::api::live2d_model_api::DrawableFrameDto {
+impl SseEncode for Option<u32> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <u32>::sse_encode(self.index, serializer);
-        <u32>::sse_encode(self.texture_index, serializer);
-        <Vec<f32>>::sse_encode(self.vertices, serializer);
-        <Vec<f32>>::sse_encode(self.uvs, serializer);
-        <Vec<u16>>::sse_encode(self.indices, serializer);
-        <Vec<u16>>::sse_encode(self.masks, serializer);
-        <f32>::sse_encode(self.opacity, serializer);
-        <[f32; 4]>::sse_encode(self.multiply_color, serializer);
-        <[f32; 4]>::sse_encode(self.screen_color, serializer);
-        <i32>::sse_encode(self.draw_order, serializer);
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <u32>::sse_encode(value, serializer);
+        }
     }
 }
 
-impl SseEncode for f32 {
+impl SseEncode for Option<u64> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        serializer.cursor.write_f32::<NativeEndian>(self).unwrap();
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <u64>::sse_encode(value, serializer);
+        }
     }
 }
 
-impl SseEncode for [f32; 4] {
+impl SseEncode for crate::api::live2d_model_api::ParameterGroupInfoDto {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <Vec<f32>>::sse_encode(
-            {
-                let boxed: Box<[_]> = Box::new(self);
-                boxed.into_vec()
-            },
-            serializer,
-        );
+        <String>::sse_encode(self.id, serializer);
+        <String>::sse_encode(self.name, serializer);
+        <String>::sse_encode(self.parent_id, serializer);
     }
 }
 
-impl SseEncode for crate::api::live2d_model_api::FrameDto {
+impl SseEncode for crate::api::live2d_model_api::ParameterInfoDto {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <f32>::sse_encode(self.canvas_width, serializer);
-        <f32>::sse_encode(self.canvas_height, serializer);
-        <Vec<crate::api::live2d_model_api::DrawableFrameDto>>::sse_encode(
-            self.drawables,
-            serializer,
-        );
+        <String>::sse_encode(self.id, serializer);
+        <String>::sse_encode(self.name, serializer);
+        <String>::sse_encode(self.group_id, serializer);
+        <f32>::sse_encode(self.min_value, serializer);
+        <f32>::sse_encode(self.max_value, serializer);
+        <f32>::sse_encode(self.default_value, serializer);
     }
 }
 
-impl SseEncode for i32 {
+impl SseEncode for crate::api::live2d_model_api::ParameterSmoothingDto {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        serializer.cursor.write_i32::<NativeEndian>(self).unwrap();
+        <crate::api::live2d_model_api::SmoothingFilterDto>::sse_encode(self.filter, serializer);
+        <f32>::sse_encode(self.time_seconds, serializer);
+        <Option<f32>>::sse_encode(self.max_velocity, serializer);
     }
 }
 
-impl SseEncode for Vec<String> {
+impl SseEncode for crate::api::live2d_model_api::PartInfoDto {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <String>::sse_encode(item, serializer);
-        }
+        <String>::sse_encode(self.id, serializer);
+        <String>::sse_encode(self.name, serializer);
     }
 }
 
-impl SseEncode for Vec<crate::api::live2d_model_api::DrawableFrameDto> {
+impl SseEncode for crate::api::live2d_model_api::SmoothingFilterDto {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <crate::api::live2d_model_api::DrawableFrameDto>::sse_encode(item, serializer);
-        }
+        <i32>::sse_encode(
+            match self {
+                crate::api::live2d_model_api::SmoothingFilterDto::None => 0,
+                crate::api::live2d_model_api::SmoothingFilterDto::Exponential => 1,
+                crate::api::live2d_model_api::SmoothingFilterDto::Spring => 2,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
     }
 }
 
-impl SseEncode for Vec<f32> {
+impl SseEncode for crate::api::live2d_texture_api::TextureDto {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <f32>::sse_encode(item, serializer);
-        }
+        <u32>::sse_encode(self.texture_index, serializer);
+        <u32>::sse_encode(self.width, serializer);
+        <u32>::sse_encode(self.height, serializer);
+        <bool>::sse_encode(self.premultiplied_alpha, serializer);
+        <Vec<crate::api::live2d_texture_api::TextureLevelDto>>::sse_encode(self.levels, serializer);
     }
 }
 
-impl SseEncode for Vec<u16> {
+impl SseEncode for crate::api::live2d_texture_api::TextureLevelDto {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <u16>::sse_encode(item, serializer);
-        }
+        <u32>::sse_encode(self.width, serializer);
+        <u32>::sse_encode(self.height, serializer);
+        <Vec<u8>>::sse_encode(self.rgba, serializer);
     }
 }
 
-impl SseEncode for Vec<u8> {
+impl SseEncode for crate::api::live2d_texture_api::TextureOptionsDto {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <u8>::sse_encode(item, serializer);
-        }
+        <bool>::sse_encode(self.premultiply_alpha, serializer);
+        <bool>::sse_encode(self.generate_mipmaps, serializer);
+        <Option<u64>>::sse_encode(self.memory_budget_bytes, serializer);
     }
 }
 
@@ -928,6 +6457,86 @@ impl SseEncode for () {
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {}
 }
 
+impl SseEncode for crate::api::live2d_model_api::ViewFitModeDto {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::api::live2d_model_api::ViewFitModeDto::Contain => 0,
+                crate::api::live2d_model_api::ViewFitModeDto::Cover => 1,
+                crate::api::live2d_model_api::ViewFitModeDto::FixedScale => 2,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
+    }
+}
+
+impl SseEncode for crate::api::live2d_model_api::ViewTransformDto {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <f32>::sse_encode(self.pixels_per_unit, serializer);
+        <[f32; 16]>::sse_encode(self.model_to_viewport, serializer);
+        <[f32; 16]>::sse_encode(self.model_to_ndc, serializer);
+    }
+}
+
+impl SseEncode for crate::api::live2d_model_api::ViewportDto {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <f32>::sse_encode(self.width, serializer);
+        <f32>::sse_encode(self.height, serializer);
+        <crate::api::live2d_model_api::ViewFitModeDto>::sse_encode(self.fit_mode, serializer);
+        <f32>::sse_encode(self.fixed_scale, serializer);
+        <f32>::sse_encode(self.pan_x, serializer);
+        <f32>::sse_encode(self.pan_y, serializer);
+        <f32>::sse_encode(self.zoom, serializer);
+    }
+}
+
+impl SseEncode for crate::api::live2d_model_api::VisemeDto {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::api::live2d_model_api::VisemeDto::A => 0,
+                crate::api::live2d_model_api::VisemeDto::I => 1,
+                crate::api::live2d_model_api::VisemeDto::U => 2,
+                crate::api::live2d_model_api::VisemeDto::E => 3,
+                crate::api::live2d_model_api::VisemeDto::O => 4,
+                crate::api::live2d_model_api::VisemeDto::Closed => 5,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
+    }
+}
+
+impl SseEncode for crate::api::live2d_model_api::VisemeKeyframeDto {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <f32>::sse_encode(self.time, serializer);
+        <crate::api::live2d_model_api::VisemeDto>::sse_encode(self.viseme, serializer);
+        <f32>::sse_encode(self.mouth_open, serializer);
+        <f32>::sse_encode(self.mouth_form, serializer);
+    }
+}
+
+impl SseEncode for crate::api::live2d_model_api::VisemeTimelineDto {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <f32>::sse_encode(self.duration, serializer);
+        <Vec<crate::api::live2d_model_api::VisemeKeyframeDto>>::sse_encode(
+            self.keyframes,
+            serializer,
+        );
+    }
+}
+
 #[cfg(not(target_family = "wasm"))]
 mod io {
     // This file is automatically generated, so please do not edit it.